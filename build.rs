@@ -0,0 +1,25 @@
+//! Generates the C header from `src/ffi/mod.rs` into `OUT_DIR` when the
+//! `ffi` feature is enabled. A unit test compares it with the committed
+//! `include/lumine.h`, so the header cannot drift from the exported
+//! functions.
+
+fn main() {
+  #[cfg(feature = "ffi")]
+  generate_header();
+}
+
+#[cfg(feature = "ffi")]
+fn generate_header() {
+  println!("cargo::rerun-if-changed=cbindgen.toml");
+  println!("cargo::rerun-if-changed=src/ffi/mod.rs");
+
+  let out_dir = std::env::var("OUT_DIR").expect("Cargo sets OUT_DIR");
+  let config = cbindgen::Config::from_file("cbindgen.toml")
+    .expect("cbindgen.toml should be a valid cbindgen configuration");
+  cbindgen::Builder::new()
+    .with_config(config)
+    .with_src("src/ffi/mod.rs")
+    .generate()
+    .expect("src/ffi/mod.rs should declare a valid C interface")
+    .write_to_file(std::path::Path::new(&out_dir).join("lumine.h"));
+}
@@ -0,0 +1,123 @@
+/*
+ * C interface of Lumine, built with the `ffi` feature:
+ *
+ *   cargo rustc --release --lib --features ffi --crate-type cdylib
+ *
+ * Every call loads the configuration like the `lumine` command does and
+ * blocks until it finishes. Returned strings belong to the caller and are
+ * freed with lumine_string_free(). Failed calls return NULL, and
+ * lumine_last_error() describes the failure on the same thread.
+ *
+ * Generated from src/ffi/mod.rs with cbindgen, do not edit. Refresh with:
+ *
+ *   cbindgen --config cbindgen.toml --output include/lumine.h src/ffi/mod.rs
+ */
+
+#ifndef LUMINE_H
+#define LUMINE_H
+
+
+
+/**
+ * Callback receiving progress events.
+ *
+ * Called with the event name, such as `recording_started`, its detail,
+ * such as the recording path (empty when the event has none), and the
+ * user data passed to the call. Both strings are only valid during the
+ * callback.
+ */
+typedef void (*lumine_progress_callback)(const char *event,
+                                         const char *detail,
+                                         void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Transcribes an audio file.
+ *
+ * # Safety
+ *
+ * `path` and `format` must be `NULL` or point to NUL-terminated strings,
+ * and `callback` must be safe to call with `user_data` until the call
+ * returns.
+ *
+ * # Arguments
+ *
+ * * `path` - Path to the audio file to transcribe
+ * * `format` - Output format accepted by `--format`, `NULL` for text
+ * * `callback` - Callback receiving progress events, or `NULL`
+ * * `user_data` - Pointer passed to the callback unchanged
+ *
+ * # Returns
+ *
+ * The transcript in the requested format, or `NULL` on failure.
+ */
+char *lumine_transcribe_file(const char *path,
+                             const char *format,
+                             lumine_progress_callback callback,
+                             void *user_data);
+
+/**
+ * Records from the microphone and transcribes the recording.
+ *
+ * Recording stops on silence like `lumine` without a command, or when
+ * [`lumine_cancel`] is called.
+ *
+ * # Safety
+ *
+ * `format` must be `NULL` or point to a NUL-terminated string, and
+ * `callback` must be safe to call with `user_data` until the call returns.
+ *
+ * # Arguments
+ *
+ * * `format` - Output format accepted by `--format`, `NULL` for text
+ * * `callback` - Callback receiving progress events, or `NULL`
+ * * `user_data` - Pointer passed to the callback unchanged
+ *
+ * # Returns
+ *
+ * The transcript in the requested format, or `NULL` on failure.
+ */
+char *lumine_record(const char *format,
+                    lumine_progress_callback callback,
+                    void *user_data);
+
+/**
+ * Stops every running call.
+ *
+ * The stopped calls clean up, then return `NULL`. Calls started
+ * afterwards run normally.
+ */
+void lumine_cancel(void);
+
+/**
+ * Gets the error of the last failed call on this thread.
+ *
+ * # Returns
+ *
+ * A description of the error, valid until the next call on this thread,
+ * or `NULL` if the last call succeeded.
+ */
+const char *lumine_last_error(void);
+
+/**
+ * Frees a string returned by Lumine.
+ *
+ * # Safety
+ *
+ * `string` must be `NULL` or a string returned by Lumine that was not
+ * freed yet.
+ *
+ * # Arguments
+ *
+ * * `string` - The string to free
+ */
+void lumine_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* LUMINE_H */
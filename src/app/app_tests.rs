@@ -0,0 +1,47 @@
+use crate::app::App;
+use crate::app::errors::RuntimeError;
+use crate::app::recording::get_named_file_path;
+use crate::config::Config;
+use crate::events::Event;
+use crate::state::State;
+
+#[test]
+fn test_get_named_file_path_appends_extension() {
+  let path = get_named_file_path("/tmp/interviews", "guest").unwrap();
+  assert_eq!(path, "/tmp/interviews/guest.wav");
+}
+
+#[test]
+fn test_get_named_file_path_keeps_extension() {
+  let path = get_named_file_path("/tmp/interviews", "guest.WAV").unwrap();
+  assert_eq!(path, "/tmp/interviews/guest.WAV");
+
+  let path = get_named_file_path("/tmp/interviews", "..guest").unwrap();
+  assert_eq!(path, "/tmp/interviews/..guest.wav");
+}
+
+#[test]
+fn test_get_named_file_path_rejects_paths() {
+  for name in ["", ".", "..", "../guest", "/etc/guest", "a/b", "a\\b"] {
+    match get_named_file_path("/tmp/interviews", name) {
+      Err(RuntimeError::File(_)) => (),
+      _ => panic!("Expected File error for '{}'", name),
+    }
+  }
+}
+
+#[tokio::test]
+async fn test_warnings_are_sent_as_events() {
+  let mut config = Config::default();
+  config.recorder.calendar = Some(String::from("/nonexistent/calendar.ics"));
+  let app = App::new(config, State::default(), None);
+  let mut events = app.subscribe();
+
+  assert_eq!(app.resolve_session_name().await, "");
+  match events.try_recv() {
+    Ok(Event::Warning { message }) => {
+      assert!(message.contains("calendar.ics"), "{}", message)
+    }
+    event => panic!("Expected a warning event, got {:?}", event),
+  }
+}
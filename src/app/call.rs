@@ -0,0 +1,129 @@
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::{App, map_recording_error};
+use crate::audio::Audio;
+use crate::files::operations::validate_file_exists;
+use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
+use crate::output::dialogue::format_dialogue;
+use crate::output::format::OutputFormat;
+use crate::tr;
+
+impl App {
+  /// Records a call and transcribes it as a labeled dialogue.
+  ///
+  /// The input device and the call source are recorded into the two channels
+  /// of one recording until both sides are silent for the call silence
+  /// limit. Each channel is transcribed separately and the results are
+  /// interleaved by time, labeled with the local and remote speaker labels.
+  ///
+  /// # Arguments
+  ///
+  /// * `source` - Source with the other side of the call, overriding the
+  ///   configuration
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the dialogue or an error.
+  pub async fn record_call(
+    &self,
+    source: Option<String>,
+  ) -> RuntimeResult<String> {
+    let result = self.run_record_call(source).await;
+    return self.finish(result);
+  }
+
+  async fn run_record_call(
+    &self,
+    source: Option<String>,
+  ) -> RuntimeResult<String> {
+    let source = source.unwrap_or_else(|| self.config.get_call_source());
+    if source.is_empty() {
+      return Err(RuntimeError::Recording(tr!("call-no-source")));
+    }
+
+    let audio = self.create_audio_in(
+      self.config.get_recordings_directory(),
+      self.resolve_session_name().await,
+    );
+    let recording = audio
+      .record_call(&source, self.config.get_call_silence_limit())
+      .await
+      .map_err(map_recording_error)?;
+    self.remember_device(&recording).await;
+
+    let mut temp_original_file = TemporaryFile::new(recording.path.clone());
+    let labels = [
+      self.config.get_call_local_label(),
+      self.config.get_call_remote_label(),
+    ];
+    let transcribed = self
+      .transcribe_channels(&audio, &recording.path, &labels)
+      .await;
+    self
+      .cleanup_recording(&mut temp_original_file, recording.interrupted)
+      .await;
+    return transcribed;
+  }
+
+  /// Transcribes the channels of a stereo file as a labeled dialogue.
+  ///
+  /// The left and right channel are transcribed separately and interleaved
+  /// by time as `Speaker A` and `Speaker B`, as used for call-center
+  /// recordings with each party on its own channel.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the stereo audio file
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the dialogue or an error.
+  pub async fn transcribe_split_channels(
+    &self,
+    file_path: &str,
+  ) -> RuntimeResult<String> {
+    let result = self.run_transcribe_split_channels(file_path).await;
+    return self.finish(result);
+  }
+
+  async fn run_transcribe_split_channels(
+    &self,
+    file_path: &str,
+  ) -> RuntimeResult<String> {
+    validate_file_exists(file_path)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+
+    let labels = [tr!("speaker-a"), tr!("speaker-b")];
+    return self
+      .transcribe_channels(&self.create_audio(), file_path, &labels)
+      .await;
+  }
+
+  async fn transcribe_channels(
+    &self,
+    audio: &Audio,
+    file_path: &str,
+    labels: &[String],
+  ) -> RuntimeResult<String> {
+    let mut speakers = Vec::new();
+    for (channel, label) in labels.iter().enumerate() {
+      let converted_file_path = audio
+        .convert_channel(file_path, channel, self.keeps_converted())
+        .await
+        .map_err(|e| RuntimeError::AudioConversion(describe_error(&e)))?;
+      let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+
+      let transcribed = self
+        .transcribe_final(&temp_converted_file, OutputFormat::FullJson)
+        .await;
+      self
+        .abort_if_cancelled(vec![&mut temp_converted_file])
+        .await?;
+      self.cleanup_converted(&mut temp_converted_file).await;
+      let transcribed = transcribed?;
+      speakers.push((label.clone(), transcribed.response.segments()));
+    }
+    return Ok(format_dialogue(&speakers));
+  }
+}
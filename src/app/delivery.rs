@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use crate::app::App;
+use crate::app::results::TranscriptionOutcome;
+use crate::audio::STDIN_INPUT;
+use crate::files::operations;
+use crate::integrations::{
+  ChatNotifier, Clipboard, EmailAttachment, EmailNotifier,
+};
+use crate::tr;
+
+impl App {
+  pub(super) async fn deliver_transcript(
+    &self,
+    outcome: &TranscriptionOutcome,
+  ) {
+    if self.config.get_copy_to_clipboard()
+      && let Err(e) = Clipboard::new().copy(outcome.text.trim()).await
+    {
+      self.warn(tr!("warning", message = e));
+    }
+
+    let webhook_url = self.config.get_chat_webhook_url();
+    if !webhook_url.is_empty() {
+      let result = ChatNotifier::new(
+        &self.config.get_chat_service(),
+        webhook_url,
+        self.cancel.clone(),
+      );
+      let delivered = match result {
+        Ok(notifier) => notifier.send(&outcome.text, outcome.duration()).await,
+        Err(e) => Err(e),
+      };
+      if let Err(e) = delivered {
+        self.warn(tr!("warning", message = e));
+      }
+    }
+
+    let server = self.config.get_email_server();
+    if !server.is_empty() {
+      let notifier = EmailNotifier::new(
+        server,
+        self.config.get_email_from(),
+        self.config.get_email_to(),
+      );
+      if let Err(e) = notifier
+        .send(
+          &outcome.text,
+          outcome.duration(),
+          outcome.attachment.clone(),
+        )
+        .await
+      {
+        self.warn(tr!("warning", message = e));
+      }
+    }
+  }
+
+  pub(super) async fn email_attachment(
+    &self,
+    source_path: &str,
+    audio_path: &str,
+  ) -> Option<EmailAttachment> {
+    if self.config.get_email_server().is_empty()
+      || !self.config.get_email_attach_audio()
+    {
+      return None;
+    }
+
+    let content = match operations::read_bytes(audio_path).await {
+      Ok(content) => content,
+      Err(e) => {
+        self.warn(tr!("email-attachment-failed", error = e));
+        return None;
+      }
+    };
+    let stem = match source_path {
+      STDIN_INPUT => String::from("stdin"),
+      path => Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default(),
+    };
+    let name = match Path::new(audio_path).extension() {
+      Some(extension) => format!("{}.{}", stem, extension.to_string_lossy()),
+      None => stem,
+    };
+    return Some(EmailAttachment { name, content });
+  }
+}
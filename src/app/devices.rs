@@ -0,0 +1,197 @@
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::results::DeviceTestReport;
+use crate::app::{App, map_recording_error};
+use crate::audio::{
+  Audio, AudioInputDevice, AudioInputDevices, AudioLevels, RecordingResult,
+};
+use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::state::InputDeviceRecord;
+use crate::tr;
+use crate::vlog;
+
+const DEVICE_TEST_NAME: &str = "device-test";
+const DEVICE_TEST_SECONDS: i32 = 3;
+
+impl App {
+  /// Lists the available audio input devices.
+  ///
+  /// Device names or indexes can be used for `preferred_audio_input_device`
+  /// and `--device`.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted device list or an
+  /// error.
+  pub async fn list_devices(
+    &self,
+    format: OutputFormat,
+  ) -> RuntimeResult<String> {
+    let devices = self
+      .create_audio()
+      .list_input_devices()
+      .await
+      .map_err(map_recording_error)?;
+    let records = device_records(&devices);
+    if matches!(format, OutputFormat::Json | OutputFormat::FullJson) {
+      return serde_json::to_string_pretty(&records)
+        .map_err(|e| RuntimeError::Output(describe_error(&e)));
+    }
+    if records.is_empty() {
+      return Ok(tr!("devices-none"));
+    }
+    let lines = records
+      .iter()
+      .map(|record| {
+        let id = if record.system_default {
+          "device-entry-default"
+        } else {
+          "device-entry"
+        };
+        return tr!(id, index = record.index, name = record.name);
+      })
+      .collect::<Vec<String>>();
+    return Ok(lines.join("\n"));
+  }
+
+  /// Records a short test with an audio input device and checks its level.
+  ///
+  /// Records a few seconds into the temporary directory, measures the peak
+  /// and RMS levels, optionally plays the recording back and reports
+  /// whether the device appears to work. Silence detection does not end the
+  /// test early, and the recording is removed afterwards.
+  ///
+  /// # Arguments
+  ///
+  /// * `device` - Device to test by index or part of its name, `None` for
+  ///   the configured device
+  /// * `play` - Whether to play the test recording back
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted report or an error.
+  pub async fn test_device(
+    &self,
+    device: Option<String>,
+    play: bool,
+    format: OutputFormat,
+  ) -> RuntimeResult<String> {
+    let result = self.run_test_device(device, play).await;
+    return self.finish(result)?.format(format);
+  }
+
+  async fn run_test_device(
+    &self,
+    device: Option<String>,
+    play: bool,
+  ) -> RuntimeResult<DeviceTestReport> {
+    let mut settings = self.recorder_settings(
+      self.config.get_temp_directory_or_system(),
+      String::from(DEVICE_TEST_NAME),
+    );
+    if let Some(device) = device {
+      settings.preferred_audio_input_device = device;
+    }
+    settings.max_recording_duration = DEVICE_TEST_SECONDS;
+    settings.silence_limit = DEVICE_TEST_SECONDS + 1;
+    for overrides in settings.device_overrides.values_mut() {
+      overrides.silence_limit = None;
+    }
+    settings.min_duration = 0;
+    settings.max_duration_warning = 0;
+    settings.resume_on_device_loss = false;
+    let audio =
+      Audio::new(settings.clone(), self.cancel.clone(), self.events.clone());
+
+    self.notify(tr!("device-test-started", seconds = DEVICE_TEST_SECONDS));
+    let recording = audio.record_audio().await.map_err(map_recording_error)?;
+    let mut temp_file = TemporaryFile::new(recording.path.clone());
+    let levels = AudioLevels::from_wav(&recording.path)
+      .map_err(|e| RuntimeError::Recording(describe_error(&e)))?;
+
+    if play {
+      self.notify(tr!("device-test-playing"));
+      if let Err(e) = audio.play(&recording.path).await {
+        self.warn(tr!("warning", message = describe_error(&e)));
+      }
+    }
+    let _ = temp_file.cleanup().await;
+
+    let silence_detect_noise =
+      settings.for_device(&recording.device).silence_detect_noise;
+    return Ok(DeviceTestReport {
+      device: recording.device.get_name().clone(),
+      index: recording.device.get_index().clone(),
+      duration: recording.duration,
+      levels,
+      silence_detect_noise,
+      check: levels.check(silence_detect_noise),
+    });
+  }
+
+  pub(super) fn cached_devices(&self) -> AudioInputDevices {
+    let max_age = self.config.get_device_cache_ttl() as i64;
+    let now = chrono::Utc::now().timestamp();
+    return self
+      .state
+      .get_input_devices(max_age, now)
+      .iter()
+      .map(|record| {
+        let device =
+          AudioInputDevice::new(record.index.clone(), record.name.clone());
+        if record.system_default {
+          return device.into_system_default();
+        }
+        return device;
+      })
+      .collect();
+  }
+
+  pub(super) async fn remember_device(&self, recording: &RecordingResult) {
+    let device = &recording.device;
+    let new_device = !device.is_default()
+      && device.get_name() != &self.state.get_last_audio_input_device();
+    if !new_device && recording.listed_devices.is_none() {
+      return;
+    }
+
+    let mut state = match self.load_state().await {
+      Ok(state) => state,
+      Err(e) => {
+        vlog!("Could not remember audio input device: {}", e);
+        return;
+      }
+    };
+    if new_device {
+      state.set_last_audio_input_device(device.get_name().clone());
+    }
+    if let Some(devices) = &recording.listed_devices {
+      state.set_input_devices(
+        device_records(devices),
+        chrono::Utc::now().timestamp(),
+      );
+    }
+    if let Err(e) = state.save(self.file_encryptor()).await {
+      vlog!("Could not remember audio input device: {}", e);
+    }
+  }
+}
+
+fn device_records(devices: &AudioInputDevices) -> Vec<InputDeviceRecord> {
+  return devices
+    .iter()
+    .map(|device| {
+      return InputDeviceRecord {
+        index: device.get_index().clone(),
+        name: device.get_name().clone(),
+        system_default: device.is_system_default(),
+      };
+    })
+    .collect();
+}
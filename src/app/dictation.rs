@@ -0,0 +1,115 @@
+use std::time::Instant;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::{App, map_recording_error};
+use crate::audio::AudioError;
+use crate::events::PartialTranscript;
+use crate::hotkey::HotkeyListener;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::tr;
+
+const DICTATION_SIGNAL: &str = "SIGUSR1";
+
+impl App {
+  /// Dictates with a global hotkey.
+  ///
+  /// Stays running and records while the configured hotkey is held, or
+  /// from one press to the next in toggle mode. The `SIGUSR1` signal starts
+  /// and stops recording as well. While the hotkey is held, silence does not
+  /// end the recording, and presses made during transcription are ignored. Each recording is transcribed, delivered
+  /// like other transcripts and sent to the partial transcript channel as
+  /// a [`PartialTranscript::Dictation`]. Dictation continues until
+  /// cancelled or until a transcript contains the stop phrase.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format of each transcript
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing a summary of the dictations or an
+  /// error.
+  pub async fn dictate(&self, format: OutputFormat) -> RuntimeResult<String> {
+    let result = self.run_dictate(format).await;
+    return self.finish(result);
+  }
+
+  async fn run_dictate(&self, format: OutputFormat) -> RuntimeResult<String> {
+    let _captions = self.start_captions().await;
+    let hotkey = self.config.get_dictation_hotkey();
+    let toggle = self.config.get_dictation_toggle();
+    let mut listener = HotkeyListener::new(&hotkey)
+      .map_err(|e| RuntimeError::Hotkey(describe_error(&e)))?;
+    let trigger = if hotkey.is_empty() {
+      DICTATION_SIGNAL
+    } else {
+      &hotkey
+    };
+    self.notify(tr!("dictation-started", trigger = trigger));
+
+    let mut count = 0;
+    loop {
+      listener.discard_pending();
+      let event = tokio::select! {
+        _ = self.cancel.cancelled() => break,
+        event = listener.next() => event,
+      };
+      let started_by = match event {
+        Some(event) if event.starts_recording() => event,
+        Some(_) => continue,
+        None => break,
+      };
+
+      let stop = CancellationToken::new();
+      let mut audio = self.create_audio();
+      audio.set_stop_token(stop.clone());
+      audio.set_ignore_silence(started_by.holds_recording(toggle));
+      let recording_started = Instant::now();
+      let recorded = {
+        let recording = audio.record_audio();
+        tokio::pin!(recording);
+        loop {
+          tokio::select! {
+            result = &mut recording => break result,
+            event = listener.next(), if !stop.is_cancelled() => {
+              if event.is_none_or(|event| event.stops_recording(toggle)) {
+                stop.cancel();
+              }
+            }
+          }
+        }
+      };
+      let recording = match recorded {
+        Ok(recording) => recording,
+        Err(AudioError::RecordingTooShort(..)) => continue,
+        Err(AudioError::Cancelled) => break,
+        Err(e) => return Err(map_recording_error(e)),
+      };
+      let recording_time = recording_started.elapsed().as_secs_f64();
+      self.remember_device(&recording).await;
+
+      let mut outcome = match self
+        .transcribe_recording(&audio, recording, recording_time, format)
+        .await
+      {
+        Ok(outcome) => outcome,
+        Err(RuntimeError::Cancelled) => break,
+        Err(e) => {
+          self.warn(tr!("dictation-failed", error = e));
+          continue;
+        }
+      };
+      self.complete_transcript(&mut outcome).await;
+      self.send_partial(PartialTranscript::Dictation(outcome.format(format)?));
+      count += 1;
+      if outcome.stop_phrase_heard {
+        self.notify(tr!("stop-phrase-heard"));
+        break;
+      }
+    }
+    return Ok(tr!("dictation-stopped", count = count));
+  }
+}
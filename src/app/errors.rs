@@ -1,21 +1,44 @@
 use thiserror::Error;
 
+use crate::tr;
+
 /// Application runtime errors.
 ///
 /// Represents high-level errors that can occur during application workflows.
 #[derive(Error, Debug)]
 pub enum RuntimeError {
-  #[error("File Error: {0}")]
+  #[error("{}", tr!("configuration-error", error = .0))]
+  Configuration(String),
+
+  #[error("{}", tr!("error-file", error = .0))]
   File(String),
 
-  #[error("Recording Error: {0}")]
+  #[error("{}", tr!("error-recording", error = .0))]
   Recording(String),
 
-  #[error("Audio Conversion Error: {0}")]
+  #[error("{}", tr!("error-audio-conversion", error = .0))]
   AudioConversion(String),
 
-  #[error("Transcription Error: {0}")]
+  #[error("{}", tr!("error-transcription", error = .0))]
   Transcription(String),
+
+  #[error("{}", tr!("error-post-processing", error = .0))]
+  PostProcessing(String),
+
+  #[error("{}", tr!("error-output", error = .0))]
+  Output(String),
+
+  #[error("{}", tr!("error-encryption", error = .0))]
+  Encryption(String),
+
+  #[error("{}", tr!("error-update", error = .0))]
+  Update(String),
+
+  #[error("{}", tr!("error-hotkey", error = .0))]
+  Hotkey(String),
+
+  #[error("{}", tr!("error-cancelled"))]
+  Cancelled,
 }
 
 /// Result type for application runtime operations.
@@ -0,0 +1,128 @@
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::evaluation::corpus::{self, CorpusEntry, CorpusReport};
+use crate::evaluation::{Evaluation, Normalization};
+use crate::files::operations;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::tr;
+use crate::vlog;
+
+impl App {
+  /// Transcribes an audio file and compares it with a reference transcript.
+  ///
+  /// The transcript is not delivered to chat or email.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the audio file to transcribe
+  /// * `reference_path` - Path to the text file with the reference transcript
+  /// * `normalization` - How both transcripts are normalized before comparing
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted evaluation or an
+  /// error.
+  pub async fn evaluate(
+    &self,
+    file_path: &str,
+    reference_path: &str,
+    normalization: Normalization,
+    format: OutputFormat,
+  ) -> RuntimeResult<String> {
+    let result = self
+      .run_evaluate(file_path, reference_path, normalization)
+      .await
+      .and_then(|evaluation| match format {
+        OutputFormat::Json | OutputFormat::FullJson => {
+          serde_json::to_string_pretty(&evaluation)
+            .map_err(|e| RuntimeError::Output(describe_error(&e)))
+        }
+        _ => Ok(evaluation.to_text()),
+      });
+    return self.finish(result);
+  }
+
+  async fn run_evaluate(
+    &self,
+    file_path: &str,
+    reference_path: &str,
+    normalization: Normalization,
+  ) -> RuntimeResult<Evaluation> {
+    let reference = operations::read_to_string(reference_path)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let outcome = self
+      .run_transcribe_file(file_path, OutputFormat::Text, false, None)
+      .await?;
+    return Ok(Evaluation::new(&reference, &outcome.text, normalization));
+  }
+
+  /// Evaluates every recording of a corpus directory.
+  ///
+  /// Each audio file is compared with the `.txt` reference transcript of
+  /// the same name. Files that fail to transcribe are reported and skipped.
+  ///
+  /// # Arguments
+  ///
+  /// * `directory` - Path to the corpus directory
+  /// * `normalization` - How transcripts are normalized before comparing
+  /// * `format` - JSON formats print JSON, any other format prints CSV
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted report or an error.
+  pub async fn evaluate_corpus(
+    &self,
+    directory: &str,
+    normalization: Normalization,
+    format: OutputFormat,
+  ) -> RuntimeResult<String> {
+    let result = self
+      .run_evaluate_corpus(directory, normalization)
+      .await
+      .and_then(|report| match format {
+        OutputFormat::Json | OutputFormat::FullJson => {
+          serde_json::to_string_pretty(&report)
+            .map_err(|e| RuntimeError::Output(describe_error(&e)))
+        }
+        _ => Ok(report.to_csv()),
+      });
+    return self.finish(result);
+  }
+
+  async fn run_evaluate_corpus(
+    &self,
+    directory: &str,
+    normalization: Normalization,
+  ) -> RuntimeResult<CorpusReport> {
+    let files = operations::list_files(directory)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let pairs = corpus::find_pairs(&files);
+    if pairs.is_empty() {
+      return Err(RuntimeError::File(tr!(
+        "corpus-empty",
+        directory = directory
+      )));
+    }
+
+    let mut entries = Vec::new();
+    for pair in pairs {
+      vlog!("Evaluating {}", pair.audio);
+      match self
+        .run_evaluate(&pair.audio, &pair.reference, normalization)
+        .await
+      {
+        Ok(evaluation) => entries.push(CorpusEntry {
+          file: pair.audio,
+          evaluation,
+        }),
+        Err(RuntimeError::Cancelled) => return Err(RuntimeError::Cancelled),
+        Err(e) => self.warn(tr!("file-skipped", file = pair.audio, error = e)),
+      }
+    }
+    return Ok(CorpusReport::new(entries));
+  }
+}
@@ -0,0 +1,139 @@
+use std::path::Path;
+
+use crate::app::App;
+use crate::audio::{AudioFingerprint, STDIN_INPUT};
+use crate::files::digest::sha256_hex;
+use crate::files::temporary::TemporaryFile;
+use crate::state::errors::StateResult;
+use crate::state::{State, TranscriptRecord};
+use crate::vlog;
+
+const WATCH_TEXT_EXTENSION: &str = "txt";
+
+impl App {
+  /// Finds the saved transcript of earlier audio matching a fingerprint.
+  ///
+  /// Transcripts that were moved, removed or changed since they were saved
+  /// are not reused.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<(String, String)>` containing the path of the earlier file
+  /// and its transcript, or `None` if no usable transcript was found.
+  pub(super) async fn find_transcript(
+    &self,
+    fingerprint: &AudioFingerprint,
+  ) -> Option<(String, String)> {
+    let matches = self.state.get_transcripts().iter().rev().filter(|record| {
+      return AudioFingerprint::decode(record.duration, &record.fingerprint)
+        .is_some_and(|known| fingerprint.matches(&known));
+    });
+    for record in matches {
+      if record.output.is_empty() {
+        continue;
+      }
+      match self.read_transcript(&record.output).await {
+        Ok(content) if sha256_hex(content.as_bytes()) == record.digest => {
+          return Some((record.source.clone(), content.trim().to_string()));
+        }
+        Ok(_) => vlog!("Saved transcript changed: {}", record.output),
+        Err(e) => vlog!("Could not read saved transcript: {}", e),
+      }
+    }
+    return None;
+  }
+
+  /// Loads the current state before changing it, so that changes saved
+  /// earlier in this run, or by other runs, are not overwritten.
+  ///
+  /// Fails when the state file exists but cannot be read, in which case
+  /// callers skip saving rather than overwrite it.
+  pub(super) async fn load_state(&self) -> StateResult<State> {
+    return State::load(self.encryptor.as_ref()).await;
+  }
+
+  /// Saves the transcript of a file next to it, or in the transcripts
+  /// directory, and remembers its fingerprint, path and digest so that
+  /// matching audio can reuse it.
+  pub(super) async fn remember_transcript(
+    &self,
+    fingerprint: &AudioFingerprint,
+    file_path: &str,
+    text: &str,
+  ) {
+    if file_path == STDIN_INPUT {
+      return;
+    }
+    let content = format!("{}\n", text.trim());
+    let path = Path::new(file_path).with_extension(WATCH_TEXT_EXTENSION);
+    let output = match self.transcript_path(&path.to_string_lossy()).await {
+      Ok(path) => self.write_transcript(&path, &content).await,
+      Err(e) => Err(e),
+    };
+    let output = match output {
+      Ok(output) => output,
+      Err(e) => {
+        vlog!("Could not save transcript: {}", e);
+        return;
+      }
+    };
+    vlog!("Transcript saved in: {}", output);
+    self.update_latest_transcript(&output).await;
+
+    let mut state = match self.load_state().await {
+      Ok(state) => state,
+      Err(e) => {
+        vlog!("Could not remember transcript: {}", e);
+        return;
+      }
+    };
+    state.remember_transcript(TranscriptRecord {
+      fingerprint: fingerprint.encode(),
+      duration: fingerprint.duration(),
+      source: file_path.to_string(),
+      output,
+      digest: sha256_hex(content.as_bytes()),
+    });
+    if let Err(e) = state.save(self.file_encryptor()).await {
+      vlog!("Could not remember transcript: {}", e);
+    }
+  }
+
+  pub(super) async fn remember_processed(
+    &self,
+    file_path: &str,
+    modified: i64,
+  ) {
+    let mut state = match self.load_state().await {
+      Ok(state) => state,
+      Err(e) => {
+        vlog!("Could not remember processed file: {}", e);
+        return;
+      }
+    };
+    state.remember_processed(file_path, modified);
+    if let Err(e) = state.save(self.file_encryptor()).await {
+      vlog!("Could not remember processed file: {}", e);
+    }
+  }
+
+  pub(super) async fn fingerprint(
+    &self,
+    temp_converted_file: &TemporaryFile,
+  ) -> Option<AudioFingerprint> {
+    let path = temp_converted_file.path().to_string();
+    let fingerprint = tokio::task::spawn_blocking(move || {
+      return AudioFingerprint::from_wav(&path);
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|fingerprint| fingerprint.map_err(|e| e.to_string()));
+    return match fingerprint {
+      Ok(fingerprint) => Some(fingerprint),
+      Err(e) => {
+        vlog!("Could not fingerprint audio: {}", e);
+        None
+      }
+    };
+  }
+}
@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::files::operations;
+use crate::logging::describe_error;
+use crate::tr;
+use crate::vlog;
+
+const LATEST_RECORDING_LINK: &str = "latest.wav";
+const LATEST_TRANSCRIPT_LINK: &str = "latest.txt";
+
+impl App {
+  fn get_latest_link(&self, link_name: &str) -> String {
+    return Path::new(&self.config.get_recordings_directory())
+      .join(link_name)
+      .to_string_lossy()
+      .to_string();
+  }
+
+  pub(super) async fn update_latest_recording(&self, file_path: &str) {
+    self
+      .update_latest_link(LATEST_RECORDING_LINK, file_path)
+      .await;
+  }
+
+  pub(super) async fn update_latest_transcript(&self, file_path: &str) {
+    self
+      .update_latest_link(LATEST_TRANSCRIPT_LINK, file_path)
+      .await;
+  }
+
+  async fn update_latest_link(&self, link_name: &str, file_path: &str) {
+    let link_path = self.get_latest_link(link_name);
+    match operations::update_symlink(file_path, &link_path).await {
+      Ok(_) => vlog!("Latest: {} → {}", link_path, file_path),
+      Err(e) => vlog!("Could not update {}: {}", link_path, e),
+    }
+  }
+
+  /// Gets the path of the latest kept recording.
+  ///
+  /// Reads the `latest.wav` link maintained in the recordings directory.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the path of the latest recording
+  /// or an error if no recording has been kept yet.
+  pub async fn latest_recording(&self) -> RuntimeResult<String> {
+    let link_path = self.get_latest_link(LATEST_RECORDING_LINK);
+    return operations::read_symlink(&link_path).await.map_err(|e| {
+      return RuntimeError::File(tr!(
+        "no-kept-recording",
+        error = describe_error(&e)
+      ));
+    });
+  }
+
+  /// Gets the path of the latest saved transcript.
+  ///
+  /// Reads the `latest.txt` link maintained in the recordings directory,
+  /// which follows the text transcripts saved by watch mode and duplicate
+  /// detection. Transcripts that are only printed are not saved.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the path of the latest transcript
+  /// or an error if no transcript has been saved yet.
+  pub async fn latest_transcript(&self) -> RuntimeResult<String> {
+    let link_path = self.get_latest_link(LATEST_TRANSCRIPT_LINK);
+    return operations::read_symlink(&link_path).await.map_err(|e| {
+      return RuntimeError::File(tr!(
+        "no-saved-transcript",
+        error = describe_error(&e)
+      ));
+    });
+  }
+}
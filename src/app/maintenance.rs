@@ -0,0 +1,85 @@
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::files::sweep::sweep_directory;
+use crate::logging::describe_error;
+use crate::tr;
+use crate::update::Updater;
+use crate::vlog;
+
+impl App {
+  /// Removes temporary files left behind by failed or killed runs.
+  ///
+  /// Sweeps the recordings directory, the temporary directory and the system
+  /// temporary directory for intermediate files and stream chunk
+  /// directories. Only files named as intermediate files by Lumine are
+  /// removed, so kept recordings and converted files are never touched.
+  /// Should not run while another recording or transcription is active.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing a summary of the removed files or
+  /// an error.
+  pub async fn clean_temp(&self) -> RuntimeResult<String> {
+    let mut directories = vec![self.config.get_recordings_directory()];
+    let system_directory = std::env::temp_dir().to_string_lossy().to_string();
+    for directory in [self.config.get_temp_directory(), system_directory] {
+      if !directory.is_empty() && !directories.contains(&directory) {
+        directories.push(directory);
+      }
+    }
+
+    let mut count = 0;
+    for directory in directories {
+      let removed = sweep_directory(&directory)
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+      for path in &removed {
+        vlog!("File removed: {}", path);
+      }
+      count += removed.len();
+    }
+    return Ok(tr!("temp-cleaned", count = count));
+  }
+
+  /// Updates Lumine to the latest release.
+  ///
+  /// The release archive for this platform is only installed when its
+  /// checksum matches the one published with the release.
+  ///
+  /// # Arguments
+  ///
+  /// * `check_only` - Only report whether a newer release is available
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing a summary of the update or an
+  /// error.
+  pub async fn self_update(&self, check_only: bool) -> RuntimeResult<String> {
+    if !self.config.get_update_enabled() {
+      return Err(RuntimeError::Update(tr!("update-disabled")));
+    }
+
+    let updater = Updater::new().with_cancellation(self.cancel.clone());
+    let release = updater
+      .latest_release()
+      .await
+      .map_err(|e| RuntimeError::Update(describe_error(&e)))?;
+    let current = env!("CARGO_PKG_VERSION");
+    if !release.is_newer_than(current) {
+      return Ok(tr!("update-current", version = current));
+    }
+    if check_only {
+      return Ok(tr!("update-available", version = release.version()));
+    }
+
+    let path = updater
+      .install(&release)
+      .await
+      .map_err(|e| RuntimeError::Update(describe_error(&e)))?;
+    return Ok(tr!(
+      "update-installed",
+      version = release.version(),
+      path = path
+    ));
+  }
+}
@@ -7,6 +7,10 @@
 //! ## Main Components
 //!
 //! - [`App`]: The primary application orchestrator that manages all workflows
+//! - [`RecordOutput`]: Structured result of a record-only run
+//! - [`DeviceTestReport`]: Levels and verdict of a device test recording
+//! - [`TranscriptionOutcome`]: Structured result of a transcription run
+//! - [`Workflow`]: Workflow run for the C ABI and the Python module
 //! - [`RuntimeError`]: Error types for application-level failures
 //! - [`RuntimeResult<T>`]: Result type alias for application operations
 //!
@@ -14,19 +18,94 @@
 //!
 //! The `App` struct provides three main workflows:
 //! - **Record and Transcribe**: Record audio and immediately transcribe it
-//! - **Record Only**: Record audio and save to configured directory
+//! - **Record Only**: Record audio and save to the configured or given directory
 //! - **Transcribe File**: Process an existing audio file for transcription
+//! - **Take Notes**: Record and transcribe a meeting chunk by chunk into a
+//!   Markdown notes file that grows while the meeting goes on
+//! - **Evaluate**: Transcribe a file and compare it with a reference transcript
+//! - **Evaluate Corpus**: Evaluate every recording of a directory
+//! - **Watch**: Transcribe audio files as they appear in a directory
+//! - **Dictate**: Record while a global hotkey is held and transcribe on
+//!   release
+//!
+//! Recordings are named after the current calendar event when a calendar is
+//! configured. Kept recordings are linked from `latest.wav` in the
+//! recordings directory.
+//! In two-pass mode a quick draft from the draft Whisper service is emitted
+//! as an event while the configured service produces the final transcript.
+//! Finished transcripts can be post-processed by a language model, for
+//! example into a meeting summary written next to the audio, and translated
+//! by the language model or DeepL. Long transcripts can be split into
+//! chapters.
+//! Finished transcripts are posted to the configured chat channel and mailed
+//! to the configured recipients, if any.
+//!
+//! ## Submodules
+//!
+//! Each workflow lives in its own submodule, next to the shared steps:
+//!
+//! - `recording`, `transcription`, `call`, `stream`, `dictation`, `watch`,
+//!   `notes` and `evaluate`: The workflows
+//! - `postprocess`: Language-model post-processing, translation and chapters
+//! - `delivery`: Clipboard, chat and email delivery of finished transcripts
+//! - `storage`: Transcript files, encryption at rest and intermediate files
+//! - `history`: Transcripts and files remembered in the state
+//! - `latest`: Links to the latest recording and transcript
+//! - `devices`: Device listing, caching and test recordings
+//! - `usage`: Local usage statistics and cost estimates
+//! - `maintenance`: Cleaning temporary files and updating Lumine
+//! - `startup` and `workflow`: Startup and workflow runs shared with the C
+//!   ABI and the Python module
 
+mod call;
+mod delivery;
+mod devices;
+mod dictation;
 mod errors;
+mod evaluate;
+mod history;
+mod latest;
+mod maintenance;
+mod notes;
+mod postprocess;
+mod recording;
+mod results;
+mod startup;
+mod storage;
+mod stream;
+mod transcription;
+mod usage;
+mod watch;
+mod workflow;
+
+#[cfg(test)]
+mod app_tests;
+#[cfg(test)]
+mod results_tests;
 
-use crate::app::errors::{RuntimeError, RuntimeResult};
-use crate::audio::Audio;
+use tokio::sync::{broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
+
+pub use crate::app::errors::RuntimeError;
+pub use crate::app::errors::RuntimeResult;
+pub use crate::app::results::{
+  DeviceTestReport, RecordOutput, RecordingSummary, RunOptions, Timings,
+  TranscriptionOutcome, Translation, UsageReport,
+};
+pub use crate::app::workflow::{Workflow, report_events, shared_runtime};
+use crate::audio::{Audio, AudioError, DeviceOverrides, RecorderSettings};
+use crate::calendar::{self, Calendar};
 use crate::config::Config;
-use crate::files::operations::validate_file_exists;
+use crate::encryption::Encryptor;
+use crate::events::{Event, EventBus, PartialTranscript};
+use crate::files::operations;
 use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
 use crate::output::format::OutputFormat;
+use crate::state::State;
+use crate::tr;
 use crate::vlog;
-use crate::whisper::Whisper;
+use crate::whisper::{TranscriptionOptions, Whisper, WhisperResponse};
 
 /// Main application orchestrator for Lumine.
 ///
@@ -34,6 +113,18 @@ use crate::whisper::Whisper;
 /// using the provided configuration settings.
 pub struct App {
   config: Config,
+  state: State,
+  encryptor: Option<Encryptor>,
+  cancel: CancellationToken,
+  events: EventBus,
+  partials: Option<mpsc::UnboundedSender<PartialTranscript>>,
+}
+
+/// Transcript of one converted audio file.
+struct Transcribed {
+  response: WhisperResponse,
+  backend: String,
+  stop_phrase_heard: bool,
 }
 
 impl App {
@@ -42,167 +133,231 @@ impl App {
   /// # Arguments
   ///
   /// * `config` - Configuration containing all application settings
+  /// * `state` - Persisted state remembered from previous runs
+  /// * `encryptor` - Encryptor for files encrypted at rest (`None` without a
+  ///   passphrase)
   ///
   /// # Returns
   ///
   /// A new `App` instance.
-  pub fn new(config: Config) -> Self {
-    return App { config };
-  }
-
-  fn create_audio(&self) -> Audio {
-    return Audio::new(
-      self.config.get_recordings_directory(),
-      self.config.get_silence_limit(),
-      self.config.get_silence_detect_noise(),
-      self.config.get_preferred_audio_input_device(),
-      self.config.get_max_recording_duration(),
-    );
-  }
-
-  fn create_whisper_instance(
-    &self,
-    file_path: String,
-    format: OutputFormat,
-  ) -> Whisper {
-    return Whisper::new(self.config.get_whisper_url(), file_path, format);
+  pub fn new(
+    config: Config,
+    state: State,
+    encryptor: Option<Encryptor>,
+  ) -> Self {
+    return App {
+      config,
+      state,
+      encryptor,
+      cancel: CancellationToken::new(),
+      events: EventBus::new(),
+      partials: None,
+    };
   }
 
-  async fn cleanup_file(&self, temp_file: &mut TemporaryFile) {
-    if self.config.get_remove_after_transcript() {
-      let _ = temp_file.cleanup().await;
-      vlog!("File removed: {}", temp_file.path());
-    } else {
-      temp_file.keep();
-    }
-  }
-
-  /// Transcribes an existing audio file.
+  /// Sends partial transcripts to a channel as they are transcribed.
   ///
-  /// Converts the input audio to Whisper-compatible format and performs
-  /// transcription using the configured Whisper service or local model.
+  /// The channel closes when the App is dropped, so a receiver can print
+  /// everything sent to it before the process exits.
   ///
   /// # Arguments
   ///
-  /// * `file_path` - Path to the audio file to transcribe
-  /// * `format` - The desired output format
+  /// * `sender` - Sender of the channel receiving partial transcripts
   ///
   /// # Returns
   ///
-  /// A `RuntimeResult<String>` containing the formatted transcription or an error.
-  pub async fn transcribe_file(
-    &self,
-    file_path: &str,
-    format: OutputFormat,
-  ) -> RuntimeResult<String> {
-    validate_file_exists(file_path)
-      .await
-      .map_err(|e| RuntimeError::File(e.to_string()))?;
-
-    let audio = self.create_audio();
-    let converted_file_path = audio
-      .convert_audio(file_path)
-      .await
-      .map_err(|e| RuntimeError::AudioConversion(e.to_string()))?;
-
-    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
-
-    let whisper = self
-      .create_whisper_instance(temp_converted_file.path().to_string(), format);
-    let output = whisper
-      .transcribe()
-      .await
-      .map_err(|e| RuntimeError::Transcription(e.to_string()))?;
+  /// The `App` sending partial transcripts to the channel.
+  pub fn with_partial_transcripts(
+    mut self,
+    sender: mpsc::UnboundedSender<PartialTranscript>,
+  ) -> Self {
+    self.partials = Some(sender);
+    return self;
+  }
 
-    self.cleanup_file(&mut temp_converted_file).await;
+  /// Uses the given token to cancel the workflows run by this App.
+  ///
+  /// # Arguments
+  ///
+  /// * `cancel` - Token that stops the running workflow when cancelled
+  ///
+  /// # Returns
+  ///
+  /// The `App` stopping when the token is cancelled.
+  pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+    self.cancel = cancel;
+    return self;
+  }
 
-    return output
-      .format(format)
-      .map_err(|e| RuntimeError::Transcription(e.to_string()));
+  /// Subscribes to progress events of the workflows run by this App.
+  ///
+  /// # Returns
+  ///
+  /// A `broadcast::Receiver<Event>` receiving subsequent events.
+  pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+    return self.events.subscribe();
   }
 
-  /// Records audio without transcription.
+  /// Gets the token that cancels the running workflow.
   ///
-  /// Records audio using configured settings and converts it to Whisper-compatible
-  /// format, keeping both original and converted files based on configuration.
+  /// Cancelling it stops recording or transcription, kills FFmpeg and
+  /// removes the run's temporary files.
   ///
   /// # Returns
   ///
-  /// A `RuntimeResult<String>` containing the path to the converted audio file
-  /// and a success message.
-  pub async fn record_only(&self) -> RuntimeResult<String> {
-    let audio = self.create_audio();
-    let file_path = audio
-      .record_audio()
-      .await
-      .map_err(|e| RuntimeError::Recording(e.to_string()))?;
-
-    let mut temp_original_file = TemporaryFile::new(file_path.clone());
+  /// A `CancellationToken` shared with all stages of the workflow.
+  pub fn cancellation_token(&self) -> CancellationToken {
+    return self.cancel.clone();
+  }
 
-    let converted_file_path = audio
-      .convert_audio(&file_path)
-      .await
-      .map_err(|e| RuntimeError::AudioConversion(e.to_string()))?;
+  fn create_audio(&self) -> Audio {
+    return self
+      .create_audio_in(self.config.get_recordings_directory(), String::new());
+  }
 
-    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+  fn create_audio_in(
+    &self,
+    recordings_directory: String,
+    session_name: String,
+  ) -> Audio {
+    let settings = self.recorder_settings(recordings_directory, session_name);
+    return Audio::new(settings, self.cancel.clone(), self.events.clone());
+  }
 
-    vlog!("File saved in: {}", self.config.get_recordings_directory());
-    vlog!("Format: 16kHz mono WAV (Whisper-ready)");
+  fn recorder_settings(
+    &self,
+    recordings_directory: String,
+    session_name: String,
+  ) -> RecorderSettings {
+    return RecorderSettings {
+      backend: self.config.get_recorder_backend(),
+      recordings_directory,
+      session_name,
+      temp_directory: self.config.get_temp_directory(),
+      silence_limit: self.config.get_silence_limit(),
+      ignore_silence: false,
+      silence_detect_noise: self.config.get_silence_detect_noise(),
+      preferred_audio_input_device: self
+        .config
+        .get_preferred_audio_input_device(),
+      pin_device: self.config.get_pin_device(),
+      cached_devices: self.cached_devices(),
+      last_audio_input_device: self.state.get_last_audio_input_device(),
+      max_recording_duration: self.config.get_max_recording_duration(),
+      min_duration: self.config.get_min_duration(),
+      max_duration_warning: self.config.get_max_duration_warning(),
+      max_duration_extension: self.config.get_max_duration_extension(),
+      resume_on_device_loss: self.config.get_resume_on_device_loss(),
+      whisper_format: self.config.get_record_whisper_format(),
+      gain: 0.0,
+      channel: self.config.get_channel(),
+      call_source: String::new(),
+      device_overrides: self
+        .config
+        .get_device_overrides()
+        .into_iter()
+        .map(|(name, device)| {
+          let overrides = DeviceOverrides {
+            silence_limit: device.silence_limit,
+            silence_detect_noise: device.silence_detect_noise,
+            gain: device.gain,
+            channel: device.channel,
+          };
+          return (name, overrides);
+        })
+        .collect(),
+    };
+  }
 
-    let result = Ok(format!(
-      "Audio recorded and converted successfully: {}",
-      temp_converted_file.path()
-    ));
+  async fn resolve_session_name(&self) -> String {
+    let source = self.config.get_calendar();
+    if source.is_empty() {
+      return String::new();
+    }
 
-    self.cleanup_file(&mut temp_original_file).await;
-    temp_converted_file.keep();
+    let calendar = match Calendar::load(&source, &self.cancel).await {
+      Ok(calendar) => calendar,
+      Err(e) => {
+        self.warn(tr!("warning", message = e));
+        return String::new();
+      }
+    };
 
-    return result;
+    let now = chrono::Local::now();
+    return match calendar.current_event(now) {
+      Some(event) => {
+        vlog!("Naming recording after calendar event: {}", event.summary);
+        calendar::session_name(&event, now)
+      }
+      None => {
+        vlog!("No calendar event in progress");
+        String::new()
+      }
+    };
   }
 
-  /// Records audio and transcribes it in sequence.
-  ///
-  /// Records audio using configured settings, converts it to Whisper-compatible
-  /// format, and performs transcription using the configured Whisper service.
-  ///
-  /// # Arguments
-  ///
-  /// * `format` - The desired output format
-  ///
-  /// # Returns
-  ///
-  /// A `RuntimeResult<String>` containing the formatted transcription or an error.
-  pub async fn record_and_transcribe(
+  fn create_whisper_instance(
     &self,
+    url: String,
+    file_path: String,
     format: OutputFormat,
-  ) -> RuntimeResult<String> {
-    let audio = self.create_audio();
-    let file_path = audio
-      .record_audio()
-      .await
-      .map_err(|e| RuntimeError::Recording(e.to_string()))?;
+    options: TranscriptionOptions,
+  ) -> Whisper {
+    return Whisper::new(url, file_path, format, options, self.cancel.clone());
+  }
 
-    let mut temp_original_file = TemporaryFile::new(file_path.clone());
+  /// Reports a step that failed without stopping the run.
+  fn warn(&self, message: String) {
+    self.events.emit(Event::Warning { message });
+  }
 
-    let converted_file_path = audio
-      .convert_audio(&file_path)
-      .await
-      .map_err(|e| RuntimeError::AudioConversion(e.to_string()))?;
+  /// Reports a message about the run, such as how to stop it.
+  fn notify(&self, message: String) {
+    self.events.emit(Event::Notice { message });
+  }
 
-    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+  fn send_partial(&self, partial: PartialTranscript) {
+    if let Some(sender) = &self.partials {
+      let _ = sender.send(partial);
+    }
+  }
 
-    let whisper = self
-      .create_whisper_instance(temp_converted_file.path().to_string(), format);
-    let output = whisper
-      .transcribe()
-      .await
-      .map_err(|e| RuntimeError::Transcription(e.to_string()))?;
+  fn finish<T>(&self, result: RuntimeResult<T>) -> RuntimeResult<T> {
+    match &result {
+      Ok(_) => self.events.emit(Event::Completed),
+      Err(e) => self.events.emit(Event::Failed {
+        error: e.to_string(),
+      }),
+    }
+    return result;
+  }
 
-    self.cleanup_file(&mut temp_original_file).await;
-    self.cleanup_file(&mut temp_converted_file).await;
+  async fn abort_if_cancelled(
+    &self,
+    temp_files: Vec<&mut TemporaryFile>,
+  ) -> RuntimeResult<()> {
+    if !self.cancel.is_cancelled() {
+      return Ok(());
+    }
 
-    return output
-      .format(format)
-      .map_err(|e| RuntimeError::Transcription(e.to_string()));
+    for temp_file in temp_files {
+      let _ = temp_file.cleanup().await;
+      vlog!("File removed: {}", temp_file.path());
+    }
+    return Err(RuntimeError::Cancelled);
   }
 }
+
+async fn refuse_overwrite(path: &str, overwrite: bool) -> RuntimeResult<()> {
+  if !overwrite && operations::file_exists(path).await {
+    return Err(RuntimeError::File(tr!("recording-exists", path = path)));
+  }
+  return Ok(());
+}
+
+fn map_recording_error(error: AudioError) -> RuntimeError {
+  return match error {
+    AudioError::Cancelled => RuntimeError::Cancelled,
+    e => RuntimeError::Recording(describe_error(&e)),
+  };
+}
@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::SendError;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::{App, map_recording_error};
+use crate::audio::{Audio, AudioError, RecordingResult};
+use crate::files::operations;
+use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::output::notes::{format_note, format_notes_header};
+use crate::tr;
+use crate::vlog;
+
+const NOTES_EXTENSION: &str = "md";
+const NOTES_QUEUE_SIZE: usize = 4;
+
+impl App {
+  /// Takes live notes of a meeting.
+  ///
+  /// Records the meeting in chunks that end on silence or at the maximum
+  /// duration. The next chunk is recorded as soon as one ends, while earlier
+  /// chunks are transcribed in order and appended to a Markdown notes file,
+  /// labeled with the time they started. Recording waits only when several
+  /// chunks are still queued for transcription.
+  /// Taking notes continues until cancelled, discarding the chunks that were
+  /// not transcribed yet, or until the stop phrase is heard, discarding the
+  /// chunks recorded after it.
+  ///
+  /// # Arguments
+  ///
+  /// * `output` - Path to the notes file, by default a file in the recordings directory
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the path of the notes file or an
+  /// error.
+  pub async fn take_notes(
+    &self,
+    output: Option<String>,
+  ) -> RuntimeResult<String> {
+    let result = self.run_take_notes(output).await;
+    return self.finish(result);
+  }
+
+  async fn run_take_notes(
+    &self,
+    output: Option<String>,
+  ) -> RuntimeResult<String> {
+    let started = chrono::Local::now();
+    let session_name = self.resolve_session_name().await;
+    let notes_path = match output {
+      Some(path) => path,
+      None => {
+        let name = if session_name.is_empty() {
+          format!("notes {}", started.format("%Y-%m-%d %H-%M-%S"))
+        } else {
+          session_name.clone()
+        };
+        let path = Path::new(&self.config.get_recordings_directory())
+          .join(name)
+          .with_extension(NOTES_EXTENSION);
+        self.transcript_path(&path.to_string_lossy()).await?
+      }
+    };
+    if let Some(directory) = Path::new(&notes_path).parent()
+      && !directory.as_os_str().is_empty()
+    {
+      operations::create_directory_all(&directory.to_string_lossy())
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    }
+    let mut notes = self.read_notes(&notes_path).await?;
+    let written_path = self
+      .append_notes(
+        &notes_path,
+        &mut notes,
+        &format_notes_header(&session_name, &started),
+      )
+      .await?;
+    self.notify(tr!("notes-started", path = written_path));
+    let _captions = self.start_captions().await;
+
+    let stop = CancellationToken::new();
+    let mut audio = self
+      .create_audio_in(self.config.get_recordings_directory(), String::new());
+    audio.set_stop_token(stop.clone());
+    let converter = audio.clone();
+    let (sender, mut receiver) = mpsc::channel(NOTES_QUEUE_SIZE);
+    let recording_stop = stop.clone();
+    let recording = async move {
+      while !recording_stop.is_cancelled() {
+        let chunk_started = chrono::Local::now();
+        match audio.record_audio().await {
+          Ok(recording) => {
+            if let Some(devices) = &recording.listed_devices {
+              audio.set_cached_devices(devices.clone());
+            }
+            if recording_stop.is_cancelled() {
+              self.discard_note(recording).await;
+              return Ok(());
+            }
+            if let Err(SendError((recording, _))) =
+              sender.send((recording, chunk_started)).await
+            {
+              self.discard_note(recording).await;
+              return Ok(());
+            }
+          }
+          Err(AudioError::RecordingTooShort(..)) => continue,
+          Err(AudioError::Cancelled) => return Ok(()),
+          Err(e) => return Err(map_recording_error(e)),
+        }
+      }
+      return Ok(());
+    };
+    let writing = async {
+      while let Some((recording, started)) = receiver.recv().await {
+        self.remember_device(&recording).await;
+        let stop_phrase_heard = self
+          .write_note(&converter, &notes_path, &mut notes, recording, started)
+          .await?;
+        if stop_phrase_heard {
+          self.notify(tr!("stop-phrase-heard"));
+          stop.cancel();
+          receiver.close();
+          while let Some((recording, _)) = receiver.recv().await {
+            self.discard_note(recording).await;
+          }
+        }
+      }
+      return Ok(());
+    };
+    let (recorded, written) = tokio::join!(recording, writing);
+    written?;
+    recorded?;
+    return Ok(written_path);
+  }
+
+  async fn write_note(
+    &self,
+    audio: &Audio,
+    notes_path: &str,
+    notes: &mut String,
+    recording: RecordingResult,
+    started: chrono::DateTime<chrono::Local>,
+  ) -> RuntimeResult<bool> {
+    let mut temp_original_file = TemporaryFile::new(recording.path.clone());
+    let converted_file_path = match audio
+      .convert_audio(&recording.path, true, self.keeps_converted())
+      .await
+    {
+      Ok(path) => path,
+      Err(e) => {
+        self.warn(tr!("notes-chunk-skipped", error = e));
+        return Ok(false);
+      }
+    };
+    let passed_through = converted_file_path == recording.path;
+    if passed_through {
+      temp_original_file.keep();
+    }
+    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+
+    let transcribed = self
+      .transcribe_converted(&temp_converted_file, OutputFormat::Text)
+      .await;
+    if !passed_through {
+      self.cleanup_file(&mut temp_original_file).await;
+    }
+    self.cleanup_converted(&mut temp_converted_file).await;
+    let transcribed = match transcribed {
+      Ok(transcribed) => transcribed,
+      Err(RuntimeError::Cancelled) => return Ok(false),
+      Err(e) => {
+        self.warn(tr!("notes-chunk-skipped", error = e));
+        return Ok(false);
+      }
+    };
+    let text = transcribed.response.text().trim();
+    if !text.is_empty() {
+      self
+        .append_notes(notes_path, notes, &format_note(&started, text))
+        .await?;
+    }
+    return Ok(transcribed.stop_phrase_heard);
+  }
+
+  /// Disposes of a notes recording that is not transcribed because the stop
+  /// phrase was heard before it.
+  async fn discard_note(&self, recording: RecordingResult) {
+    vlog!("Not transcribing after the stop phrase: {}", recording.path);
+    self
+      .cleanup_file(&mut TemporaryFile::new(recording.path))
+      .await;
+  }
+}
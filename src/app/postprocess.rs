@@ -0,0 +1,203 @@
+use std::path::Path;
+
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::results::{TranscriptionOutcome, Translation};
+use crate::logging::describe_error;
+use crate::output::chapters::{Chapter, split_chapters};
+use crate::output::format::OutputFormat;
+use crate::output::subtitles::{format_srt, format_vtt, translate_segments};
+use crate::postprocess::errors::PostProcessError;
+use crate::postprocess::{
+  LanguageModel, PostProfile, TranslationService, Translator, title_chapters,
+};
+use crate::tr;
+use crate::vlog;
+
+impl App {
+  pub(super) async fn complete_transcript(
+    &self,
+    outcome: &mut TranscriptionOutcome,
+  ) {
+    self.process_transcript(outcome).await;
+    self.deliver_transcript(outcome).await;
+  }
+
+  /// Adds chapters, post-processing and a translation to a transcript
+  /// without delivering it.
+  pub(super) async fn process_transcript(
+    &self,
+    outcome: &mut TranscriptionOutcome,
+  ) {
+    outcome.chapters = self.chapters(outcome).await;
+    match self.post_process(outcome).await {
+      Ok(path) => outcome.post_path = path,
+      Err(e) => self.warn(tr!("warning", message = e)),
+    }
+    match self.translate(outcome).await {
+      Ok(translation) => outcome.translation = translation,
+      Err(e) => self.warn(tr!("warning", message = e)),
+    }
+  }
+
+  async fn chapters(&self, outcome: &TranscriptionOutcome) -> Vec<Chapter> {
+    if !self.config.get_chapters_enabled() {
+      return Vec::new();
+    }
+
+    let mut chapters = split_chapters(
+      &outcome.segments,
+      self.config.get_chapter_min_pause(),
+      self.config.get_chapter_min_duration(),
+    );
+    vlog!("Split transcript into {} chapters", chapters.len());
+    if chapters.is_empty() || !self.config.get_chapter_llm_titles() {
+      return chapters;
+    }
+
+    let texts: Vec<String> = chapters
+      .iter()
+      .map(|chapter| chapter.text.clone())
+      .collect();
+    let titles = match self.create_language_model() {
+      Ok(model) => title_chapters(&model, &texts)
+        .await
+        .map_err(map_post_process_error),
+      Err(e) => Err(e),
+    };
+    match titles {
+      Ok(titles) => {
+        for (chapter, title) in chapters.iter_mut().zip(titles) {
+          chapter.title = title;
+        }
+      }
+      Err(e) => self.warn(tr!("warning", message = e)),
+    }
+    return chapters;
+  }
+
+  fn create_language_model(&self) -> RuntimeResult<LanguageModel> {
+    return LanguageModel::new(
+      self.config.get_llm_url(),
+      self.config.get_llm_model(),
+      self.config.get_llm_api_key(),
+      self.cancel.clone(),
+    )
+    .map_err(map_post_process_error);
+  }
+
+  fn create_translator(&self) -> RuntimeResult<Translator> {
+    let service =
+      TranslationService::from_name(&self.config.get_translation_service())
+        .map_err(map_post_process_error)?;
+    return match service {
+      TranslationService::LanguageModel => Ok(Translator::with_language_model(
+        self.create_language_model()?,
+      )),
+      TranslationService::DeepL => Translator::with_deepl(
+        self.config.get_deepl_url(),
+        self.config.get_deepl_api_key(),
+        self.cancel.clone(),
+      )
+      .map_err(map_post_process_error),
+    };
+  }
+
+  async fn translate(
+    &self,
+    outcome: &TranscriptionOutcome,
+  ) -> RuntimeResult<Option<Translation>> {
+    let language = self.config.get_translation_target_language();
+    if language.is_empty() {
+      return Ok(None);
+    }
+
+    let translator = self.create_translator()?;
+    let mut translation = if outcome.segments.is_empty() {
+      let texts = vec![outcome.text.clone()];
+      let translated = translator
+        .translate(&texts, &language)
+        .await
+        .map_err(map_post_process_error)?;
+      Translation {
+        language,
+        text: translated.join(" "),
+        segments: Vec::new(),
+        track_path: None,
+        bilingual: false,
+      }
+    } else {
+      let texts: Vec<String> = outcome
+        .segments
+        .iter()
+        .map(|segment| segment.text.clone())
+        .collect();
+      let translated = translator
+        .translate(&texts, &language)
+        .await
+        .map_err(map_post_process_error)?;
+      Translation {
+        language,
+        text: translated.join(" "),
+        segments: translated,
+        track_path: None,
+        bilingual: self.config.get_bilingual_subtitles(),
+      }
+    };
+
+    let format = outcome.options.format;
+    if format.is_subtitles()
+      && !translation.bilingual
+      && !translation.segments.is_empty()
+    {
+      let segments =
+        translate_segments(&outcome.segments, &translation.segments);
+      let (extension, track) = match format {
+        OutputFormat::Vtt => ("vtt", format_vtt(&segments)),
+        _ => ("srt", format_srt(&segments)),
+      };
+      let track_path = self
+        .transcript_path(
+          &Path::new(&outcome.source_path)
+            .with_extension(format!("{}.{}", translation.language, extension))
+            .to_string_lossy(),
+        )
+        .await?;
+      let track_path = self.write_transcript(&track_path, &track).await?;
+      vlog!("Translated subtitles saved in: {}", track_path);
+      translation.track_path = Some(track_path);
+    }
+    return Ok(Some(translation));
+  }
+
+  async fn post_process(
+    &self,
+    outcome: &TranscriptionOutcome,
+  ) -> RuntimeResult<Option<String>> {
+    let name = self.config.get_post_profile();
+    if name.is_empty() {
+      return Ok(None);
+    }
+
+    let profile =
+      PostProfile::from_name(&name).map_err(map_post_process_error)?;
+    let model = self.create_language_model()?;
+    let reply = model
+      .complete(profile.instructions(), &outcome.text)
+      .await
+      .map_err(map_post_process_error)?;
+
+    let path = self
+      .transcript_path(&profile.output_path(&outcome.source_path))
+      .await?;
+    let path = self
+      .write_transcript(&path, &format!("{}\n", reply))
+      .await?;
+    vlog!("Post-processing output saved in: {}", path);
+    return Ok(Some(path));
+  }
+}
+
+fn map_post_process_error(error: PostProcessError) -> RuntimeError {
+  return RuntimeError::PostProcessing(describe_error(&error));
+}
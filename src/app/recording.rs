@@ -0,0 +1,369 @@
+use std::path::Path;
+use std::time::Instant;
+
+use tokio::io::AsyncWriteExt;
+
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::results::{
+  RecordOutput, RecordingSummary, Timings, TranscriptionOutcome,
+};
+use crate::app::{App, map_recording_error, refuse_overwrite};
+use crate::audio::{Audio, RecordingResult};
+use crate::files::operations;
+use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::tr;
+use crate::vlog;
+
+impl App {
+  /// Records audio without transcription.
+  ///
+  /// Records audio using configured settings and converts it to Whisper-compatible
+  /// format, keeping both original and converted files based on configuration.
+  ///
+  /// # Arguments
+  ///
+  /// * `directory` - Directory to save the recording in, overriding the configured one
+  /// * `file_name` - File name for the converted recording, `.wav` is appended if missing
+  /// * `overwrite` - Whether to replace an existing file named `file_name`
+  /// * `intervals` - Whether to include the speech and silence intervals
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<RecordOutput>` containing the path, duration and size
+  /// of the converted audio file.
+  pub async fn record_only(
+    &self,
+    directory: Option<String>,
+    file_name: Option<String>,
+    overwrite: bool,
+    intervals: bool,
+  ) -> RuntimeResult<RecordOutput> {
+    let result = self
+      .run_record_only(directory, file_name, overwrite, intervals)
+      .await;
+    return self.finish(result);
+  }
+
+  /// Records audio and writes it to stdout.
+  ///
+  /// The recording is converted to 16 kHz mono WAV like in record-only mode
+  /// and written to stdout once recording stops, so it can be piped into
+  /// other tools. No files are kept.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<()>` indicating success or failure.
+  pub async fn record_to_stdout(&self) -> RuntimeResult<()> {
+    let result = self.run_record_to_stdout().await;
+    return self.finish(result);
+  }
+
+  async fn run_record_to_stdout(&self) -> RuntimeResult<()> {
+    let audio = self.create_audio();
+    let recording = audio.record_audio().await.map_err(map_recording_error)?;
+    self.remember_device(&recording).await;
+    let mut temp_original_file = TemporaryFile::new(recording.path.clone());
+
+    let converted_file_path = audio
+      .convert_audio(&recording.path, true, false)
+      .await
+      .map_err(|e| RuntimeError::AudioConversion(describe_error(&e)))?;
+    let passed_through = converted_file_path == recording.path;
+    if passed_through {
+      temp_original_file.keep();
+    }
+    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+    let temp_files = if passed_through {
+      vec![&mut temp_converted_file]
+    } else {
+      vec![&mut temp_original_file, &mut temp_converted_file]
+    };
+    self.abort_if_cancelled(temp_files).await?;
+
+    let data = operations::read_bytes(temp_converted_file.path())
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let mut stdout = tokio::io::stdout();
+    stdout
+      .write_all(&data)
+      .await
+      .map_err(|e| RuntimeError::Output(describe_error(&e)))?;
+    stdout
+      .flush()
+      .await
+      .map_err(|e| RuntimeError::Output(describe_error(&e)))?;
+    vlog!("Wrote {} bytes of audio to stdout", data.len());
+    return Ok(());
+  }
+
+  async fn run_record_only(
+    &self,
+    directory: Option<String>,
+    file_name: Option<String>,
+    overwrite: bool,
+    intervals: bool,
+  ) -> RuntimeResult<RecordOutput> {
+    let recordings_directory =
+      directory.unwrap_or_else(|| self.config.get_recordings_directory());
+    // Checked before recording so that a bad name does not waste the take.
+    let target = match &file_name {
+      Some(name) => Some(get_named_file_path(&recordings_directory, name)?),
+      None => None,
+    };
+    if let Some(target) = &target {
+      refuse_overwrite(target, overwrite).await?;
+    }
+    let audio = self.create_audio_in(
+      recordings_directory.clone(),
+      self.resolve_session_name().await,
+    );
+    let recording = audio.record_audio().await.map_err(map_recording_error)?;
+    self.remember_device(&recording).await;
+    let interrupted = recording.interrupted;
+    let duration = recording.duration;
+    let speech_ratio = recording.speech_ratio;
+    let (speech, silence) = if intervals {
+      (
+        Some(recording.speech_intervals()),
+        Some(recording.silences.clone()),
+      )
+    } else {
+      (None, None)
+    };
+    let file_path = recording.path;
+
+    let mut temp_original_file = TemporaryFile::new(file_path.clone());
+    if interrupted {
+      temp_original_file.keep();
+    }
+
+    // Interrupted recordings are kept, so they are converted to a separate
+    // file even when recorded in the Whisper format.
+    let converted_file_path = audio
+      .convert_audio(&file_path, !interrupted, true)
+      .await
+      .map_err(|e| RuntimeError::AudioConversion(describe_error(&e)))?;
+    let passed_through = converted_file_path == file_path;
+    if passed_through {
+      temp_original_file.keep();
+    }
+    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+
+    if let Some(target) = target {
+      // The target may have appeared while recording. The take is kept
+      // under its own name then instead of being thrown away.
+      if !overwrite && operations::file_exists(&target).await {
+        self.keep_file(&mut temp_converted_file).await;
+        return Err(RuntimeError::File(tr!(
+          "recording-exists-kept",
+          path = target,
+          kept = temp_converted_file.path()
+        )));
+      }
+      operations::move_file(temp_converted_file.path(), &target)
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+      temp_converted_file.keep();
+      temp_converted_file = TemporaryFile::new(target);
+    }
+
+    let temp_files = if passed_through {
+      vec![&mut temp_converted_file]
+    } else {
+      vec![&mut temp_original_file, &mut temp_converted_file]
+    };
+    self.abort_if_cancelled(temp_files).await?;
+
+    vlog!("File saved in: {}", recordings_directory);
+    vlog!("Format: 16kHz mono WAV (Whisper-ready)");
+
+    if !passed_through {
+      self
+        .cleanup_recording(&mut temp_original_file, interrupted)
+        .await;
+    }
+    self.keep_file(&mut temp_converted_file).await;
+
+    let size = operations::file_size(temp_converted_file.path())
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+
+    let result = RecordOutput {
+      path: temp_converted_file.path().to_string(),
+      duration,
+      size,
+      speech_ratio,
+      speech,
+      silence,
+    };
+
+    self
+      .update_latest_recording(temp_converted_file.path())
+      .await;
+
+    return Ok(result);
+  }
+
+  /// Records audio and transcribes it in sequence.
+  ///
+  /// Records audio using configured settings, converts it to Whisper-compatible
+  /// format, and performs transcription using the configured Whisper service.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<TranscriptionOutcome>` containing the transcription
+  /// and recording details or an error.
+  pub async fn record_and_transcribe(
+    &self,
+    format: OutputFormat,
+  ) -> RuntimeResult<TranscriptionOutcome> {
+    let mut result = self.run_record_and_transcribe(format).await;
+    if let Ok(outcome) = &mut result {
+      self.complete_transcript(outcome).await;
+    }
+    return self.finish(result);
+  }
+
+  async fn run_record_and_transcribe(
+    &self,
+    format: OutputFormat,
+  ) -> RuntimeResult<TranscriptionOutcome> {
+    let audio = self.create_audio_in(
+      self.config.get_recordings_directory(),
+      self.resolve_session_name().await,
+    );
+    let recording_started = Instant::now();
+    let recording = audio.record_audio().await.map_err(map_recording_error)?;
+    let recording_time = recording_started.elapsed().as_secs_f64();
+    self.remember_device(&recording).await;
+    return self
+      .transcribe_recording(&audio, recording, recording_time, format)
+      .await;
+  }
+
+  pub(super) async fn transcribe_recording(
+    &self,
+    audio: &Audio,
+    recording: RecordingResult,
+    recording_time: f64,
+    format: OutputFormat,
+  ) -> RuntimeResult<TranscriptionOutcome> {
+    let interrupted = recording.interrupted;
+    let file_path = recording.path.clone();
+
+    let mut temp_original_file = TemporaryFile::new(file_path.clone());
+    if interrupted {
+      temp_original_file.keep();
+    }
+
+    // Interrupted recordings are kept, so they are converted to a separate
+    // file even when recorded in the Whisper format.
+    let conversion_started = Instant::now();
+    let converted_file_path = audio
+      .convert_audio(&file_path, !interrupted, self.keeps_converted())
+      .await
+      .map_err(|e| RuntimeError::AudioConversion(describe_error(&e)))?;
+    let passed_through = converted_file_path == file_path;
+    if passed_through {
+      temp_original_file.keep();
+    }
+    let conversion = conversion_started.elapsed().as_secs_f64();
+
+    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+
+    let transcription_started = Instant::now();
+    let transcribed = self
+      .transcribe_converted(&temp_converted_file, format)
+      .await;
+    let temp_files = if passed_through {
+      vec![&mut temp_converted_file]
+    } else {
+      vec![&mut temp_original_file, &mut temp_converted_file]
+    };
+    self.abort_if_cancelled(temp_files).await?;
+    let transcribed = transcribed?;
+    let transcription = transcription_started.elapsed().as_secs_f64();
+
+    let attachment = self
+      .email_attachment(&file_path, temp_converted_file.path())
+      .await;
+    if !passed_through {
+      self
+        .cleanup_recording(&mut temp_original_file, interrupted)
+        .await;
+    }
+    self.cleanup_converted(&mut temp_converted_file).await;
+    if !self.config.get_remove_after_transcript() {
+      self
+        .update_latest_recording(temp_converted_file.path())
+        .await;
+    }
+
+    let mut outcome = TranscriptionOutcome::new(
+      transcribed.response,
+      file_path,
+      transcribed.backend,
+      self.run_options(format),
+    );
+    outcome.converted_path = self.kept_path(&temp_converted_file);
+    outcome.attachment = attachment;
+    outcome.stop_phrase_heard = transcribed.stop_phrase_heard;
+    outcome.recording = Some(RecordingSummary {
+      device: recording.device.get_name().clone(),
+      duration: recording.duration,
+      speech_ratio: recording.speech_ratio,
+      interrupted,
+    });
+    outcome.timings = Timings {
+      recording: Some(recording_time),
+      conversion,
+      transcription,
+    };
+    outcome.cost = outcome
+      .duration()
+      .and_then(|duration| self.estimate_cost(duration));
+    return Ok(outcome);
+  }
+}
+
+/// Builds the path of a recording saved under a given name.
+///
+/// # Arguments
+///
+/// * `directory` - Directory to save the recording in
+/// * `file_name` - File name for the recording, `.wav` is appended if missing
+///
+/// # Returns
+///
+/// A `RuntimeResult<String>` containing the path, or an error if the name
+/// contains a path separator or is not a plain file name.
+pub(crate) fn get_named_file_path(
+  directory: &str,
+  file_name: &str,
+) -> RuntimeResult<String> {
+  if file_name.is_empty()
+    || file_name.contains(['/', '\\'])
+    || file_name == "."
+    || file_name == ".."
+  {
+    return Err(RuntimeError::File(tr!(
+      "recording-name-invalid",
+      name = file_name
+    )));
+  }
+
+  let path = Path::new(directory).join(file_name);
+  if path
+    .extension()
+    .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+  {
+    return Ok(path.to_string_lossy().to_string());
+  }
+  return Ok(format!("{}.wav", path.to_string_lossy()));
+}
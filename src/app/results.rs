@@ -0,0 +1,463 @@
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::audio::{AudioInterval, AudioLevels, DeviceCheck};
+use crate::integrations::EmailAttachment;
+use crate::output::chapters::{Chapter, format_chapters};
+use crate::output::format::OutputFormat;
+use crate::output::markdown::format_markdown;
+use crate::output::subtitles::{bilingual_segments, format_srt, format_vtt};
+use crate::state::{MonthlyCost, UsageRecord};
+use crate::tr;
+use crate::whisper::responses::WhisperVerboseJsonResponse;
+use crate::whisper::{WhisperResponse, WhisperSegment};
+
+/// Result of a record-only run.
+///
+/// Describes the saved Whisper-ready recording so that callers and scripts
+/// can consume it without parsing a message string.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecordOutput {
+  /// Path to the saved recording
+  pub path: String,
+  /// Duration of the recording in seconds
+  pub duration: f64,
+  /// Size of the recording in bytes
+  pub size: u64,
+  /// Share of the recording that contained speech, between 0.0 and 1.0
+  pub speech_ratio: f64,
+  /// Intervals that contained speech, if requested
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub speech: Option<Vec<AudioInterval>>,
+  /// Intervals that were silent, if requested
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub silence: Option<Vec<AudioInterval>>,
+}
+
+impl RecordOutput {
+  /// Formats the record result for display.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted result or an error.
+  pub fn format(&self, format: OutputFormat) -> RuntimeResult<String> {
+    return match format {
+      OutputFormat::Json | OutputFormat::FullJson => {
+        serde_json::to_string_pretty(self)
+          .map_err(|e| RuntimeError::Output(e.to_string()))
+      }
+      _ => Ok(format!(
+        "Audio recorded and converted successfully: {}",
+        self.path
+      )),
+    };
+  }
+}
+
+/// Result of a device test recording.
+///
+/// Reports the levels measured on the tested device and whether it appears
+/// to work.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeviceTestReport {
+  /// Name of the device that was recorded
+  pub device: String,
+  /// Index of the device, usable for `preferred_audio_input_device`
+  pub index: String,
+  /// Duration of the test recording in seconds
+  pub duration: f64,
+  /// Peak and RMS levels of the test recording
+  pub levels: AudioLevels,
+  /// Noise threshold in dB below which audio counts as silence
+  pub silence_detect_noise: i32,
+  /// Whether the device appears to work
+  pub check: DeviceCheck,
+}
+
+impl DeviceTestReport {
+  /// Formats the report for display.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted report or an error.
+  pub fn format(&self, format: OutputFormat) -> RuntimeResult<String> {
+    if matches!(format, OutputFormat::Json | OutputFormat::FullJson) {
+      return serde_json::to_string_pretty(self)
+        .map_err(|e| RuntimeError::Output(e.to_string()));
+    }
+
+    let verdict = match self.check {
+      DeviceCheck::Works => tr!("device-test-works", name = self.device),
+      DeviceCheck::Silent => tr!("device-test-silent"),
+      DeviceCheck::TooQuiet => tr!(
+        "device-test-too-quiet",
+        threshold = self.silence_detect_noise.abs()
+      ),
+      DeviceCheck::Clipping => tr!("device-test-clipping"),
+    };
+    return Ok(
+      [
+        tr!("device-test-device", index = self.index, name = self.device),
+        tr!(
+          "device-test-levels",
+          peak = format!("{:.1}", self.levels.peak_db),
+          rms = format!("{:.1}", self.levels.rms_db)
+        ),
+        verdict,
+      ]
+      .join("\n"),
+    );
+  }
+}
+
+/// Local usage statistics of all transcription runs.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UsageReport {
+  /// Number of transcription runs
+  pub runs: u64,
+  /// Minutes of audio transcribed
+  pub audio_minutes: f64,
+  /// Seconds spent transcribing per second of audio, if any was transcribed
+  pub real_time_factor: Option<f64>,
+  /// Usage of each backend
+  pub backends: Vec<UsageRecord>,
+  /// Estimated transcription costs per month
+  pub costs: Vec<MonthlyCost>,
+  /// Currency of the estimated costs
+  pub currency: String,
+}
+
+impl UsageReport {
+  /// Creates a report from the usage of each backend.
+  ///
+  /// # Arguments
+  ///
+  /// * `backends` - The usage aggregated per backend
+  /// * `costs` - The estimated costs per month
+  /// * `currency` - Currency of the estimated costs
+  ///
+  /// # Returns
+  ///
+  /// A new `UsageReport` with the totals over all backends.
+  pub fn new(
+    backends: &[UsageRecord],
+    costs: &[MonthlyCost],
+    currency: String,
+  ) -> Self {
+    let total = UsageRecord {
+      backend: String::new(),
+      runs: backends.iter().map(|record| record.runs).sum(),
+      audio_seconds: backends.iter().map(|record| record.audio_seconds).sum(),
+      transcription_seconds: backends
+        .iter()
+        .map(|record| record.transcription_seconds)
+        .sum(),
+    };
+    return UsageReport {
+      runs: total.runs,
+      audio_minutes: total.audio_seconds / 60.0,
+      real_time_factor: total.real_time_factor(),
+      backends: backends.to_vec(),
+      costs: costs.to_vec(),
+      currency,
+    };
+  }
+
+  /// Formats the report for display.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted report or an error.
+  pub fn format(&self, format: OutputFormat) -> RuntimeResult<String> {
+    if matches!(format, OutputFormat::Json | OutputFormat::FullJson) {
+      return serde_json::to_string_pretty(self)
+        .map_err(|e| RuntimeError::Output(e.to_string()));
+    }
+    if self.runs == 0 && self.costs.is_empty() {
+      return Ok(tr!("stats-empty"));
+    }
+
+    let mut lines = vec![tr!(
+      "stats-total",
+      minutes = format!("{:.1}", self.audio_minutes),
+      runs = self.runs
+    )];
+    if let Some(factor) = self.real_time_factor {
+      lines.push(tr!("stats-rtf", rtf = format!("{:.2}", factor)));
+    }
+    lines.push(String::new());
+    for record in &self.backends {
+      lines.push(tr!(
+        "stats-backend",
+        backend = record.backend,
+        runs = record.runs,
+        minutes = format!("{:.1}", record.audio_seconds / 60.0),
+        rtf = format!("{:.2}", record.real_time_factor().unwrap_or_default())
+      ));
+    }
+    if !self.costs.is_empty() {
+      lines.push(String::new());
+    }
+    for record in &self.costs {
+      lines.push(tr!(
+        "stats-cost",
+        month = record.month,
+        cost = format!("{:.2}", record.cost),
+        currency = self.currency,
+        minutes = format!("{:.1}", record.audio_seconds / 60.0)
+      ));
+    }
+    return Ok(lines.join("\n"));
+  }
+}
+
+/// Result of a transcription run.
+///
+/// Collects the transcript together with information about the audio and
+/// the run, leaving formatting to the caller.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TranscriptionOutcome {
+  /// Transcribed text
+  pub text: String,
+  /// Language reported by Whisper, when available
+  pub language: Option<String>,
+  /// Timed transcript segments, when available
+  pub segments: Vec<WhisperSegment>,
+  /// Path to the input file or recording
+  pub source_path: String,
+  /// Path to the Whisper-ready audio file, if it was kept
+  pub converted_path: Option<String>,
+  /// Details of the recording, if the audio was recorded in this run
+  pub recording: Option<RecordingSummary>,
+  /// Time spent in each stage of the run
+  pub timings: Timings,
+  /// Transcription backend that produced the transcript
+  pub backend: String,
+  /// Estimated cost of the transcription, if a price per minute is set
+  pub cost: Option<f64>,
+  /// Options the run was performed with
+  pub options: RunOptions,
+  /// Path to the post-processing output, if one was written
+  pub post_path: Option<String>,
+  /// Translation of the transcript, if translation is enabled
+  pub translation: Option<Translation>,
+  /// Chapters of the transcript, empty if chaptering is disabled
+  pub chapters: Vec<Chapter>,
+  /// Whether the stop phrase was spoken and removed from the transcript
+  pub stop_phrase_heard: bool,
+  /// Audio to attach to the transcript email, read before the audio files
+  /// of the run are removed
+  #[serde(skip)]
+  pub(crate) attachment: Option<EmailAttachment>,
+  #[serde(skip)]
+  response: WhisperResponse,
+}
+
+/// Translation of a transcript.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Translation {
+  /// Language of the translation
+  pub language: String,
+  /// Translated text
+  pub text: String,
+  /// Translations of the transcript segments, in the same order
+  pub segments: Vec<String>,
+  /// Path to the translated subtitle track, if one was written
+  pub track_path: Option<String>,
+  /// Whether subtitle cues show the original and translated line
+  pub bilingual: bool,
+}
+
+/// Full JSON response extended with the chapter list.
+#[derive(serde::Serialize)]
+struct ChapteredResponse<'a> {
+  #[serde(flatten)]
+  response: &'a WhisperVerboseJsonResponse,
+  chapters: &'a [Chapter],
+}
+
+/// Details of a recording made during a run.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecordingSummary {
+  /// Name of the audio input device
+  pub device: String,
+  /// Duration of the recording in seconds
+  pub duration: f64,
+  /// Share of the recording that contained speech, between 0.0 and 1.0
+  pub speech_ratio: f64,
+  /// Whether the input device was lost while recording
+  pub interrupted: bool,
+}
+
+/// Options a transcription run was performed with.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunOptions {
+  /// Requested output format
+  pub format: OutputFormat,
+  /// Seconds of silence before stopping recording
+  pub silence_limit: i32,
+  /// Noise threshold in decibels for silence detection
+  pub silence_detect_noise: i32,
+  /// Maximum recording duration in seconds (0 for unlimited)
+  pub max_recording_duration: i32,
+  /// Whether audio files are removed after transcription
+  pub remove_after_transcript: bool,
+  /// Phrase that ends dictation, empty if disabled
+  pub stop_phrase: String,
+}
+
+/// Time spent in each stage of a run, in seconds.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Timings {
+  /// Time spent recording, if audio was recorded
+  pub recording: Option<f64>,
+  /// Time spent converting audio to the Whisper format
+  pub conversion: f64,
+  /// Time spent transcribing
+  pub transcription: f64,
+}
+
+impl TranscriptionOutcome {
+  /// Creates a new TranscriptionOutcome from a Whisper response.
+  ///
+  /// # Arguments
+  ///
+  /// * `response` - The Whisper response
+  /// * `source_path` - Path to the input file or recording
+  /// * `backend` - Transcription backend that produced the response
+  /// * `options` - Options the run was performed with
+  ///
+  /// # Returns
+  ///
+  /// A new `TranscriptionOutcome` without recording details or timings.
+  pub fn new(
+    response: WhisperResponse,
+    source_path: String,
+    backend: String,
+    options: RunOptions,
+  ) -> Self {
+    return TranscriptionOutcome {
+      text: response.text().to_string(),
+      language: response.language(),
+      segments: response.segments(),
+      source_path,
+      converted_path: None,
+      recording: None,
+      timings: Timings::default(),
+      backend,
+      cost: None,
+      options,
+      post_path: None,
+      translation: None,
+      chapters: Vec::new(),
+      stop_phrase_heard: false,
+      attachment: None,
+      response,
+    };
+  }
+
+  /// Formats the transcript for display.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted transcript or an error.
+  pub fn format(&self, format: OutputFormat) -> RuntimeResult<String> {
+    if self.chapters.is_empty() {
+      return self.format_transcript(format);
+    }
+    return match (&self.response, format) {
+      (_, OutputFormat::Markdown) => Ok(format!(
+        "## Chapters\n\n{}\n\n## Transcript\n\n{}",
+        format_chapters(&self.chapters),
+        self.format_transcript(format)?
+      )),
+      (WhisperResponse::VerboseJson(response), OutputFormat::FullJson) => {
+        serde_json::to_string_pretty(&ChapteredResponse {
+          response,
+          chapters: &self.chapters,
+        })
+        .map_err(|e| RuntimeError::Output(e.to_string()))
+      }
+      _ => self.format_transcript(format),
+    };
+  }
+
+  fn format_transcript(&self, format: OutputFormat) -> RuntimeResult<String> {
+    if let Some(translation) = &self.translation {
+      match format {
+        OutputFormat::Text => {
+          return Ok(format!(
+            "{}\n\n{}",
+            self.text.trim(),
+            translation.text.trim()
+          ));
+        }
+        OutputFormat::Markdown => {
+          return Ok(format_markdown(
+            &self.segments,
+            Some(&translation.segments),
+          ));
+        }
+        OutputFormat::Srt if translation.bilingual => {
+          return Ok(format_srt(&bilingual_segments(
+            &self.segments,
+            &translation.segments,
+          )));
+        }
+        OutputFormat::Vtt if translation.bilingual => {
+          return Ok(format_vtt(&bilingual_segments(
+            &self.segments,
+            &translation.segments,
+          )));
+        }
+        _ => {}
+      }
+    }
+    return self
+      .response
+      .format(format)
+      .map_err(|e| RuntimeError::Transcription(e.to_string()));
+  }
+
+  /// Gets the duration of the transcribed audio.
+  ///
+  /// Uses the recording duration when the audio was recorded, otherwise the
+  /// end of the last transcribed segment.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<f64>` containing the duration in seconds, if known.
+  pub fn duration(&self) -> Option<f64> {
+    if let Some(recording) = &self.recording {
+      return Some(recording.duration);
+    }
+    return self.segments.last().map(|segment| segment.end);
+  }
+
+  /// Builds a machine-readable summary of the run.
+  ///
+  /// The summary is a single JSON object with the transcript, paths,
+  /// durations, backend and options, intended for tools such as `jq`.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the JSON summary or an error.
+  pub fn summary(&self) -> RuntimeResult<String> {
+    return serde_json::to_string(self)
+      .map_err(|e| RuntimeError::Output(e.to_string()));
+  }
+}
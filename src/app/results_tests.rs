@@ -0,0 +1,178 @@
+use crate::app::results::{
+  RecordOutput, RecordingSummary, RunOptions, TranscriptionOutcome, UsageReport,
+};
+use crate::audio::AudioInterval;
+use crate::output::chapters::Chapter;
+use crate::output::format::OutputFormat;
+use crate::state::{MonthlyCost, UsageRecord};
+use crate::whisper::WhisperResponse;
+use crate::whisper::responses::WhisperTextResponse;
+
+fn create_outcome() -> TranscriptionOutcome {
+  let response = WhisperResponse::Text(WhisperTextResponse {
+    text: String::from(" And so my fellow Americans"),
+  });
+  return TranscriptionOutcome::new(
+    response,
+    String::from("sample/jfk.wav"),
+    String::from("remote (http://127.0.0.1:9090)"),
+    RunOptions {
+      format: OutputFormat::Text,
+      silence_limit: 2,
+      silence_detect_noise: 40,
+      max_recording_duration: 60,
+      remove_after_transcript: true,
+      stop_phrase: String::new(),
+    },
+  );
+}
+
+#[test]
+fn test_transcription_outcome_from_text_response() {
+  let outcome = create_outcome();
+  assert_eq!(outcome.text, " And so my fellow Americans");
+  assert!(outcome.language.is_none());
+  assert!(outcome.segments.is_empty());
+  assert!(outcome.recording.is_none());
+}
+
+#[test]
+fn test_transcription_outcome_format() {
+  let outcome = create_outcome();
+  assert_eq!(
+    outcome.format(OutputFormat::Text).unwrap(),
+    " And so my fellow Americans"
+  );
+  assert!(outcome.format(OutputFormat::FullJson).is_err());
+}
+
+#[test]
+fn test_transcription_outcome_serialization() {
+  let outcome = create_outcome();
+  let value = serde_json::to_value(&outcome).unwrap();
+  assert_eq!(value["source_path"], "sample/jfk.wav");
+  assert!(value.get("response").is_none());
+}
+
+#[test]
+fn test_transcription_outcome_summary() {
+  let outcome = create_outcome();
+  let summary = outcome.summary().unwrap();
+  assert!(!summary.contains('\n'));
+
+  let value: serde_json::Value = serde_json::from_str(&summary).unwrap();
+  assert_eq!(value["text"], " And so my fellow Americans");
+  assert_eq!(value["options"]["format"], "text");
+  assert_eq!(value["backend"], "remote (http://127.0.0.1:9090)");
+}
+
+#[test]
+fn test_transcription_outcome_duration() {
+  let mut outcome = create_outcome();
+  assert!(outcome.duration().is_none());
+
+  outcome.recording = Some(RecordingSummary {
+    device: String::from("default"),
+    duration: 11.5,
+    speech_ratio: 0.8,
+    interrupted: false,
+  });
+  assert_eq!(outcome.duration(), Some(11.5));
+}
+
+#[test]
+fn test_transcription_outcome_chapters_only_in_timed_formats() {
+  let mut outcome = create_outcome();
+  outcome.chapters = vec![Chapter {
+    start: 0.0,
+    title: String::from("Intro"),
+    text: outcome.text.clone(),
+  }];
+
+  assert_eq!(
+    outcome.format(OutputFormat::Text).unwrap(),
+    " And so my fellow Americans"
+  );
+  let value = serde_json::to_value(&outcome).unwrap();
+  assert_eq!(value["chapters"][0]["title"], "Intro");
+  assert!(value["chapters"][0].get("text").is_none());
+}
+
+#[test]
+fn test_usage_report() {
+  let costs = [MonthlyCost {
+    month: String::from("2026-10"),
+    audio_seconds: 540.0,
+    cost: 0.054,
+  }];
+  let report = UsageReport::new(
+    &[
+      UsageRecord {
+        backend: String::from("remote (http://127.0.0.1:9090)"),
+        runs: 3,
+        audio_seconds: 540.0,
+        transcription_seconds: 54.0,
+      },
+      UsageRecord {
+        backend: String::from("remote (http://draft:9090)"),
+        runs: 1,
+        audio_seconds: 60.0,
+        transcription_seconds: 6.0,
+      },
+    ],
+    &costs,
+    String::from("USD"),
+  );
+  assert_eq!(report.runs, 4);
+  assert_eq!(report.audio_minutes, 10.0);
+  assert_eq!(report.real_time_factor, Some(0.1));
+
+  let text = report.format(OutputFormat::Text).unwrap();
+  assert!(text.starts_with("10.0 minutes of audio transcribed in 4 runs"));
+  assert!(text.contains("remote (http://draft:9090): 1 runs, 1.0 minutes"));
+  assert!(text.contains("2026-10: 0.05 USD for 9.0 minutes"));
+
+  let json = report.format(OutputFormat::Json).unwrap();
+  assert!(json.contains("\"audio_minutes\": 10.0"));
+}
+
+#[test]
+fn test_usage_report_empty() {
+  let report = UsageReport::new(&[], &[], String::from("USD"));
+  assert!(report.real_time_factor.is_none());
+  assert!(
+    report
+      .format(OutputFormat::Text)
+      .unwrap()
+      .contains("[usage]")
+  );
+}
+
+#[test]
+fn test_record_output_intervals() {
+  let mut output = RecordOutput {
+    path: String::from("/tmp/memo.wav"),
+    duration: 4.0,
+    size: 128_044,
+    speech_ratio: 0.5,
+    speech: None,
+    silence: None,
+  };
+  let value: serde_json::Value =
+    serde_json::from_str(&output.format(OutputFormat::Json).unwrap()).unwrap();
+  assert!(value.get("speech").is_none());
+  assert!(value.get("silence").is_none());
+
+  output.speech = Some(vec![AudioInterval {
+    start: 0.0,
+    end: 2.0,
+  }]);
+  output.silence = Some(vec![AudioInterval {
+    start: 2.0,
+    end: 4.0,
+  }]);
+  let value: serde_json::Value =
+    serde_json::from_str(&output.format(OutputFormat::Json).unwrap()).unwrap();
+  assert_eq!(value["speech"][0]["end"], 2.0);
+  assert_eq!(value["silence"][0]["start"], 2.0);
+}
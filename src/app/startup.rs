@@ -0,0 +1,60 @@
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::config::Config;
+use crate::encryption::{EncryptionError, Encryptor};
+use crate::files::operations::set_secure_delete;
+use crate::i18n::set_language;
+use crate::state::State;
+use crate::vlog;
+
+impl App {
+  /// Creates the App from a configuration like the `lumine` command does.
+  ///
+  /// Applies the language and secure delete settings, resolves the
+  /// passphrase and loads the state, starting fresh when it cannot be read.
+  ///
+  /// # Arguments
+  ///
+  /// * `config` - The loaded configuration
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<App>` containing the App or an error if the
+  /// passphrase could not be resolved.
+  pub async fn bootstrap(config: Config) -> RuntimeResult<App> {
+    set_language(&config.get_language());
+    set_secure_delete(config.get_secure_delete());
+
+    let encryptor = App::load_encryptor(&config).await?;
+    let state = State::load(encryptor.as_ref()).await.unwrap_or_else(|e| {
+      vlog!("Could not load state, starting fresh: {}", e);
+      return State::default();
+    });
+    return Ok(App::new(config, state, encryptor));
+  }
+
+  /// Resolves the passphrase of a configuration.
+  ///
+  /// # Arguments
+  ///
+  /// * `config` - The loaded configuration
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<Option<Encryptor>>` containing the encryptor, `None`
+  /// if no passphrase is configured, or an error if the key command failed
+  /// or encryption is enabled without a passphrase.
+  pub async fn load_encryptor(
+    config: &Config,
+  ) -> RuntimeResult<Option<Encryptor>> {
+    let encryptor =
+      Encryptor::from_environment(&config.get_encryption_key_command())
+        .await
+        .map_err(|e| RuntimeError::Configuration(e.to_string()))?;
+    if config.get_encryption_enabled() && encryptor.is_none() {
+      let error = EncryptionError::NoPassphrase;
+      return Err(RuntimeError::Configuration(error.to_string()));
+    }
+    return Ok(encryptor);
+  }
+}
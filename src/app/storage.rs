@@ -0,0 +1,281 @@
+use std::path::Path;
+
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::{App, refuse_overwrite};
+use crate::encryption::{ENCRYPTED_EXTENSION, EncryptionError, Encryptor};
+use crate::files::layout::relocate;
+use crate::files::operations;
+use crate::files::operations::validate_file_exists;
+use crate::files::temporary::TemporaryFile;
+use crate::logging::describe_error;
+use crate::tr;
+use crate::vlog;
+
+impl App {
+  pub(super) async fn transcript_path(
+    &self,
+    path: &str,
+  ) -> RuntimeResult<String> {
+    let transcripts_directory = self.config.get_transcripts_directory();
+    if transcripts_directory.is_empty() {
+      return Ok(path.to_string());
+    }
+
+    let path = relocate(
+      path,
+      &self.config.get_recordings_directory(),
+      &transcripts_directory,
+    );
+    if let Some(directory) = Path::new(&path).parent() {
+      operations::create_directory_all(&directory.to_string_lossy())
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    }
+    return Ok(path);
+  }
+
+  pub(super) async fn write_transcript(
+    &self,
+    path: &str,
+    content: &str,
+  ) -> RuntimeResult<String> {
+    if let Some(encryptor) = self.file_encryptor() {
+      return encryptor
+        .write_file(path, content.as_bytes().to_vec())
+        .await
+        .map_err(|e| RuntimeError::Encryption(describe_error(&e)));
+    }
+    operations::write_file(path, content)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    return Ok(path.to_string());
+  }
+
+  /// Reads the notes already written to a notes file, to keep them when the
+  /// notes are encrypted and rewritten as a whole.
+  pub(super) async fn read_notes(
+    &self,
+    notes_path: &str,
+  ) -> RuntimeResult<String> {
+    if self.file_encryptor().is_none() {
+      return Ok(String::new());
+    }
+    let encrypted_path = format!("{}.{}", notes_path, ENCRYPTED_EXTENSION);
+    if !operations::file_exists(&encrypted_path).await {
+      return Ok(String::new());
+    }
+    return self.read_transcript(&encrypted_path).await;
+  }
+
+  /// Reads a file written by `write_transcript`, decrypting it when it was
+  /// encrypted.
+  pub(super) async fn read_transcript(
+    &self,
+    path: &str,
+  ) -> RuntimeResult<String> {
+    let data = operations::read_bytes(path)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    if !path.ends_with(&format!(".{}", ENCRYPTED_EXTENSION)) {
+      return Ok(String::from_utf8_lossy(&data).to_string());
+    }
+    let Some(encryptor) = self.encryptor.as_ref() else {
+      let error = EncryptionError::NoPassphrase;
+      return Err(RuntimeError::Encryption(describe_error(&error)));
+    };
+    let plaintext = encryptor
+      .decrypt_async(data)
+      .await
+      .map_err(|e| RuntimeError::Encryption(describe_error(&e)))?;
+    return Ok(String::from_utf8_lossy(&plaintext).to_string());
+  }
+
+  /// Adds to the notes of a meeting.
+  ///
+  /// With encryption enabled, the notes are kept in memory and their
+  /// encrypted file is rewritten after each addition, so no plaintext is
+  /// written to disk.
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the path of the written notes
+  /// file or an error.
+  pub(super) async fn append_notes(
+    &self,
+    notes_path: &str,
+    notes: &mut String,
+    addition: &str,
+  ) -> RuntimeResult<String> {
+    let Some(encryptor) = self.file_encryptor() else {
+      operations::append_to_file(notes_path, addition)
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+      return Ok(notes_path.to_string());
+    };
+    notes.push_str(addition);
+    return encryptor
+      .write_file(notes_path, notes.as_bytes().to_vec())
+      .await
+      .map_err(|e| RuntimeError::Encryption(describe_error(&e)));
+  }
+
+  pub(super) fn file_encryptor(&self) -> Option<&Encryptor> {
+    if !self.config.get_encryption_enabled() {
+      return None;
+    }
+    return self.encryptor.as_ref();
+  }
+
+  pub(super) async fn keep_file(&self, temp_file: &mut TemporaryFile) {
+    temp_file.keep();
+    let Some(encryptor) = self.file_encryptor() else {
+      return;
+    };
+    match encryptor.encrypt_file(temp_file.path()).await {
+      Ok(path) => {
+        *temp_file = TemporaryFile::new(path);
+        temp_file.keep();
+      }
+      Err(e) => {
+        self.warn(tr!("encryption-failed", path = temp_file.path(), error = e))
+      }
+    }
+  }
+
+  pub(super) async fn decrypt_input(
+    &self,
+    file_path: &str,
+  ) -> RuntimeResult<String> {
+    let encryptor = self.encryptor.as_ref().ok_or_else(|| {
+      return RuntimeError::Encryption(describe_error(
+        &EncryptionError::NoPassphrase,
+      ));
+    })?;
+    let temp_directory = self.config.get_temp_directory_or_system();
+    let name = Path::new(file_path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    operations::create_directory_all(&temp_directory)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let decrypted_path =
+      operations::create_private_file(&temp_directory, &decrypted_name(&name))
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    if let Err(e) = encryptor
+      .decrypt_file(file_path, &decrypted_path, true)
+      .await
+    {
+      let _ = operations::remove_file(&decrypted_path).await;
+      return Err(RuntimeError::Encryption(describe_error(&e)));
+    }
+    return Ok(decrypted_path);
+  }
+
+  /// Decrypts a file encrypted at rest.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the encrypted file
+  /// * `output` - Path of the decrypted file, by default the encrypted path
+  ///   without its `.enc` extension
+  /// * `overwrite` - Whether an existing file at the output path may be
+  ///   replaced
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the path of the decrypted file or
+  /// an error.
+  pub async fn decrypt_file(
+    &self,
+    file_path: &str,
+    output: Option<String>,
+    overwrite: bool,
+  ) -> RuntimeResult<String> {
+    validate_file_exists(file_path)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let encryptor = self.encryptor.as_ref().ok_or_else(|| {
+      return RuntimeError::Encryption(describe_error(
+        &EncryptionError::NoPassphrase,
+      ));
+    })?;
+    let output = output.unwrap_or_else(|| decrypted_name(file_path));
+    refuse_overwrite(&output, overwrite).await?;
+    encryptor
+      .decrypt_file(file_path, &output, overwrite)
+      .await
+      .map_err(|e| RuntimeError::Encryption(describe_error(&e)))?;
+    return Ok(output);
+  }
+
+  pub(super) async fn cleanup_file(&self, temp_file: &mut TemporaryFile) {
+    if self.config.get_remove_after_transcript() {
+      let _ = temp_file.cleanup().await;
+      vlog!("File removed: {}", temp_file.path());
+    } else {
+      self.keep_file(temp_file).await;
+    }
+  }
+
+  pub(super) async fn cleanup_converted(&self, temp_file: &mut TemporaryFile) {
+    if self.config.get_keep_converted() {
+      vlog!("Keeping converted file: {}", temp_file.path());
+      self.keep_file(temp_file).await;
+    } else {
+      self.cleanup_file(temp_file).await;
+    }
+  }
+
+  /// Removes or keeps the converted file of a transcribed input.
+  ///
+  /// An input used as is belongs to the user and is left alone, while a
+  /// decrypted copy used as is is always removed, so no plaintext of an
+  /// encrypted file stays behind.
+  pub(super) async fn release_converted(
+    &self,
+    temp_file: &mut TemporaryFile,
+    input_passed_through: bool,
+    decrypted_passed_through: bool,
+  ) {
+    if decrypted_passed_through {
+      let _ = temp_file.cleanup().await;
+      vlog!("Decrypted copy removed: {}", temp_file.path());
+    } else if !input_passed_through {
+      self.cleanup_converted(temp_file).await;
+    }
+  }
+
+  pub(super) async fn cleanup_recording(
+    &self,
+    temp_file: &mut TemporaryFile,
+    interrupted: bool,
+  ) {
+    if interrupted {
+      vlog!("Keeping interrupted recording: {}", temp_file.path());
+      self.keep_file(temp_file).await;
+    } else {
+      self.cleanup_file(temp_file).await;
+    }
+  }
+
+  pub(super) fn keeps_converted(&self) -> bool {
+    return self.config.get_keep_converted()
+      || !self.config.get_remove_after_transcript();
+  }
+
+  pub(super) fn kept_path(&self, temp_file: &TemporaryFile) -> Option<String> {
+    if !self.keeps_converted() {
+      return None;
+    }
+    return Some(temp_file.path().to_string());
+  }
+}
+
+fn decrypted_name(path: &str) -> String {
+  return match path.strip_suffix(&format!(".{}", ENCRYPTED_EXTENSION)) {
+    Some(name) => name.to_string(),
+    None => format!("{}.decrypted", path),
+  };
+}
@@ -0,0 +1,75 @@
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::{App, map_recording_error};
+use crate::audio::StreamCapture;
+use crate::events::PartialTranscript;
+use crate::files::temporary::TemporaryFile;
+use crate::output::format::OutputFormat;
+use crate::tr;
+
+impl App {
+  /// Transcribes a live network stream until it ends or is cancelled.
+  ///
+  /// FFmpeg pulls the stream in chunks that are transcribed one after another
+  /// while the next chunk is captured. The text of each chunk is sent as a
+  /// [`PartialTranscript::Chunk`] as soon as it is transcribed.
+  /// Transcription stops after the chunk in which the stop phrase is heard.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - URL of the stream, such as `rtsp://` or an HLS playlist
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<()>` indicating whether the stream could be captured.
+  pub async fn transcribe_stream(&self, url: &str) -> RuntimeResult<()> {
+    let result = self.run_transcribe_stream(url).await;
+    return self.finish(result);
+  }
+
+  async fn run_transcribe_stream(&self, url: &str) -> RuntimeResult<()> {
+    let _captions = self.start_captions().await;
+    let audio = self.create_audio();
+    let mut capture = audio
+      .capture_stream(url, self.config.get_stream_chunk_duration())
+      .await
+      .map_err(map_recording_error)?;
+    self.notify(tr!("stream-started", url = url));
+
+    let transcribed = self.transcribe_stream_chunks(&mut capture).await;
+    capture.finish().await;
+    return transcribed;
+  }
+
+  async fn transcribe_stream_chunks(
+    &self,
+    capture: &mut StreamCapture,
+  ) -> RuntimeResult<()> {
+    while let Some(chunk) =
+      capture.next_chunk().await.map_err(map_recording_error)?
+    {
+      let mut temp_chunk_file = TemporaryFile::new(chunk);
+      let transcribed = self
+        .transcribe_converted(&temp_chunk_file, OutputFormat::Text)
+        .await;
+      let _ = temp_chunk_file.cleanup().await;
+      if let Ok(transcribed) = &transcribed {
+        let text = transcribed.response.text().trim();
+        if !text.is_empty() {
+          self.send_partial(PartialTranscript::Chunk(text.to_string()));
+        }
+      }
+      match transcribed {
+        Ok(transcribed) if transcribed.stop_phrase_heard => {
+          self.notify(tr!("stop-phrase-heard"));
+          break;
+        }
+        Ok(_) | Err(RuntimeError::Cancelled) => {}
+        Err(e) => self.warn(tr!("stream-chunk-skipped", error = e)),
+      }
+      if self.cancel.is_cancelled() {
+        break;
+      }
+    }
+    return Ok(());
+  }
+}
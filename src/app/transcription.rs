@@ -0,0 +1,446 @@
+use std::time::Instant;
+
+use tokio::sync::broadcast;
+use tokio_util::sync::{CancellationToken, DropGuard};
+
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::results::{RunOptions, Timings, TranscriptionOutcome};
+use crate::app::{App, Transcribed};
+use crate::audio::{Audio, RawAudioFormat, STDIN_INPUT, detect_music};
+use crate::encryption::ENCRYPTED_EXTENSION;
+use crate::events::{Event, PartialTranscript};
+use crate::files::operations::validate_file_exists;
+use crate::files::temporary::TemporaryFile;
+use crate::integrations::ObsCaptions;
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::tr;
+use crate::vlog;
+use crate::whisper::responses::{WhisperJsonResponse, WhisperTextResponse};
+use crate::whisper::{TranscriptionOptions, WhisperError, WhisperResponse};
+
+impl App {
+  async fn skip_music(
+    &self,
+    audio: &Audio,
+    temp_converted_file: &TemporaryFile,
+  ) {
+    let path = temp_converted_file.path().to_string();
+    let min_duration = self.config.get_music_min_duration();
+    let ranges = tokio::task::spawn_blocking(move || {
+      return detect_music(&path, min_duration);
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|ranges| ranges.map_err(|e| e.to_string()));
+    let ranges = match ranges {
+      Ok(ranges) if ranges.is_empty() => return,
+      Ok(ranges) => ranges,
+      Err(e) => {
+        vlog!("Could not detect music: {}", e);
+        return;
+      }
+    };
+
+    if let Err(e) = audio.mute_ranges(temp_converted_file.path(), &ranges).await
+    {
+      vlog!("Could not skip music: {}", e);
+      return;
+    }
+    let ranges = ranges
+      .iter()
+      .map(|range| range.to_string())
+      .collect::<Vec<String>>()
+      .join(", ");
+    self.notify(tr!("music-skipped", ranges = ranges));
+  }
+
+  pub(super) async fn transcribe_converted(
+    &self,
+    temp_converted_file: &TemporaryFile,
+    format: OutputFormat,
+  ) -> RuntimeResult<Transcribed> {
+    if !self.config.get_two_pass() {
+      return self.transcribe_final(temp_converted_file, format).await;
+    }
+
+    // The draft is sent as soon as it arrives, but the final transcript
+    // never waits for it; a draft still pending by then is dropped.
+    let draft = self.transcribe_draft(temp_converted_file);
+    let transcribed = self.transcribe_final(temp_converted_file, format);
+    tokio::pin!(draft, transcribed);
+    tokio::select! {
+      result = &mut transcribed => {
+        vlog!("Final transcript finished before the draft");
+        return result;
+      }
+      _ = &mut draft => {}
+    }
+    return transcribed.await;
+  }
+
+  async fn transcribe_draft(&self, temp_converted_file: &TemporaryFile) {
+    let url = self.config.get_whisper_draft_url();
+    if url.is_empty() {
+      self.warn(tr!("two-pass-no-draft-url"));
+      return;
+    }
+
+    let whisper = self.create_whisper_instance(
+      url,
+      temp_converted_file.path().to_string(),
+      OutputFormat::Text,
+      self.transcription_options(),
+    );
+    match whisper.transcribe().await {
+      Ok(mut response) => {
+        response.strip_stop_phrase(&self.config.get_stop_phrase());
+        if self.config.get_strip_annotations() {
+          response.strip_annotations();
+        }
+        let text = response.text().to_string();
+        self
+          .events
+          .emit(Event::DraftTranscribed { text: text.clone() });
+        self.send_partial(PartialTranscript::Draft(text));
+      }
+      Err(e) => vlog!("Could not transcribe draft: {}", e),
+    }
+  }
+
+  pub(super) async fn transcribe_final(
+    &self,
+    temp_converted_file: &TemporaryFile,
+    format: OutputFormat,
+  ) -> RuntimeResult<Transcribed> {
+    let options = self.transcription_options();
+    let request_format = if options.no_speech_threshold.is_some() {
+      OutputFormat::FullJson
+    } else {
+      format
+    };
+    let whisper = self.create_whisper_instance(
+      self.config.get_whisper_url(),
+      temp_converted_file.path().to_string(),
+      request_format,
+      options.clone(),
+    );
+    self.events.emit(Event::TranscriptionStarted);
+    let started = Instant::now();
+    let mut response = whisper.transcribe().await.map_err(|e| match e {
+      WhisperError::Cancelled => RuntimeError::Cancelled,
+      e => RuntimeError::Transcription(describe_error(&e)),
+    })?;
+    self
+      .record_usage(
+        &whisper.backend(),
+        temp_converted_file.path(),
+        started.elapsed().as_secs_f64(),
+      )
+      .await;
+    if let Some(threshold) = options.no_speech_threshold {
+      let dropped =
+        response.drop_silent_segments(threshold, options.logprob_threshold);
+      vlog!("Dropped {} segments without speech", dropped);
+    }
+    let mut response = response.into_format(format);
+    let stop_phrase_heard =
+      response.strip_stop_phrase(&self.config.get_stop_phrase());
+    if self.config.get_strip_annotations() {
+      response.strip_annotations();
+    }
+
+    let segments = response.segments();
+    if segments.is_empty() {
+      self.events.emit(Event::ChunkTranscribed {
+        text: response.text().to_string(),
+      });
+    }
+    for segment in segments {
+      self
+        .events
+        .emit(Event::ChunkTranscribed { text: segment.text });
+    }
+    return Ok(Transcribed {
+      response,
+      backend: whisper.backend(),
+      stop_phrase_heard,
+    });
+  }
+
+  /// Starts showing transcribed chunks as OBS stream captions.
+  ///
+  /// Does nothing unless an obs-websocket URL is configured. A connection
+  /// failure is only a warning, since the live mode works without OBS.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<DropGuard>` that stops the captions when dropped, or `None`
+  /// if no captions are shown.
+  pub(super) async fn start_captions(&self) -> Option<DropGuard> {
+    let url = self.config.get_obs_url();
+    if url.is_empty() {
+      return None;
+    }
+    let password = self.config.get_obs_password();
+    let mut captions = match ObsCaptions::connect(&url, &password).await {
+      Ok(captions) => captions,
+      Err(e) => {
+        self.warn(tr!("warning", message = describe_error(&e)));
+        return None;
+      }
+    };
+
+    let mut events = self.subscribe();
+    let stop = CancellationToken::new();
+    let stopped = stop.clone();
+    tokio::spawn(async move {
+      loop {
+        // Captions already transcribed are shown before stopping.
+        let event = tokio::select! {
+          biased;
+          event = events.recv() => event,
+          _ = stopped.cancelled() => return,
+        };
+        let text = match event {
+          Ok(Event::ChunkTranscribed { text }) => text,
+          Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+          Err(broadcast::error::RecvError::Closed) => return,
+        };
+        let text = text.trim();
+        if text.is_empty() {
+          continue;
+        }
+        if let Err(e) = captions.show(text).await {
+          vlog!("Could not show caption: {}", e);
+        }
+      }
+    });
+    return Some(stop.drop_guard());
+  }
+
+  fn transcription_options(&self) -> TranscriptionOptions {
+    let decoding = self.config.get_decoding();
+    return TranscriptionOptions {
+      vad: self.config.get_whisper_vad(),
+      temperature: decoding.temperature,
+      temperature_increment: decoding.temperature_increment,
+      entropy_threshold: decoding.entropy_threshold,
+      logprob_threshold: decoding.logprob_threshold,
+      no_speech_threshold: decoding.no_speech_threshold,
+      suppress_non_speech: decoding.suppress_non_speech,
+      max_segment_length: decoding.max_segment_length,
+      split_on_word: decoding.split_on_word,
+      word_threshold: decoding.word_threshold,
+      beam_size: decoding.beam_size,
+      best_of: decoding.best_of,
+    };
+  }
+
+  pub(super) fn run_options(&self, format: OutputFormat) -> RunOptions {
+    return RunOptions {
+      format,
+      silence_limit: self.config.get_silence_limit(),
+      silence_detect_noise: self.config.get_silence_detect_noise(),
+      max_recording_duration: self.config.get_max_recording_duration(),
+      remove_after_transcript: self.config.get_remove_after_transcript(),
+      stop_phrase: self.config.get_stop_phrase(),
+    };
+  }
+
+  /// Transcribes an existing audio file.
+  ///
+  /// Converts the input audio to Whisper-compatible format and performs
+  /// transcription using the configured Whisper service or local model.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the audio file to transcribe, `-` for stdin
+  /// * `format` - The desired output format
+  /// * `raw_format` - Format of headerless PCM input (`None` to detect it)
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<TranscriptionOutcome>` containing the transcription
+  /// or an error.
+  pub async fn transcribe_file(
+    &self,
+    file_path: &str,
+    format: OutputFormat,
+    raw_format: Option<RawAudioFormat>,
+  ) -> RuntimeResult<TranscriptionOutcome> {
+    let mut result = self
+      .run_transcribe_file(
+        file_path,
+        format,
+        self.config.get_skip_duplicates(),
+        raw_format.as_ref(),
+      )
+      .await;
+    if let Ok(outcome) = &mut result {
+      self.complete_transcript(outcome).await;
+    }
+    return self.finish(result);
+  }
+
+  pub(super) async fn run_transcribe_file(
+    &self,
+    file_path: &str,
+    format: OutputFormat,
+    skip_duplicates: bool,
+    raw_format: Option<&RawAudioFormat>,
+  ) -> RuntimeResult<TranscriptionOutcome> {
+    if file_path != STDIN_INPUT {
+      validate_file_exists(file_path)
+        .await
+        .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    }
+
+    let decrypted_file =
+      if file_path.ends_with(&format!(".{}", ENCRYPTED_EXTENSION)) {
+        Some(TemporaryFile::new(self.decrypt_input(file_path).await?))
+      } else {
+        None
+      };
+    let input_path = decrypted_file
+      .as_ref()
+      .map(|file| file.path())
+      .unwrap_or(file_path);
+
+    let audio = self.create_audio();
+    let conversion_started = Instant::now();
+    // Music is muted in the converted file, so the input itself is only
+    // used as is when it is a decrypted copy or stays unchanged.
+    let pass_through =
+      decrypted_file.is_some() || !self.config.get_skip_music();
+    let converted = match raw_format {
+      Some(raw_format) => {
+        audio
+          .convert_raw_audio(input_path, raw_format, self.keeps_converted())
+          .await
+      }
+      None => {
+        audio
+          .convert_audio(input_path, pass_through, self.keeps_converted())
+          .await
+      }
+    };
+    let passed_through =
+      converted.as_ref().is_ok_and(|path| path == input_path);
+    let input_passed_through = passed_through && decrypted_file.is_none();
+    let decrypted_passed_through = passed_through && decrypted_file.is_some();
+    if let Some(mut decrypted_file) = decrypted_file {
+      if passed_through {
+        decrypted_file.keep();
+      } else {
+        let _ = decrypted_file.cleanup().await;
+      }
+    }
+    let converted_file_path = converted
+      .map_err(|e| RuntimeError::AudioConversion(describe_error(&e)))?;
+    let conversion = conversion_started.elapsed().as_secs_f64();
+
+    // The input file belongs to the user and is never removed.
+    let mut temp_converted_file = TemporaryFile::new(converted_file_path);
+    if input_passed_through {
+      temp_converted_file.keep();
+    }
+
+    let fingerprint = if skip_duplicates && !format.is_timed() {
+      self.fingerprint(&temp_converted_file).await
+    } else {
+      None
+    };
+    if let Some(fingerprint) = &fingerprint
+      && let Some((source, text)) = self.find_transcript(fingerprint).await
+    {
+      self.notify(tr!(
+        "duplicate-skipped",
+        file = file_path,
+        source = source.as_str()
+      ));
+      let attachment = self
+        .email_attachment(file_path, temp_converted_file.path())
+        .await;
+      self
+        .release_converted(
+          &mut temp_converted_file,
+          input_passed_through,
+          decrypted_passed_through,
+        )
+        .await;
+      let mut outcome = TranscriptionOutcome::new(
+        duplicate_response(text, format),
+        file_path.to_string(),
+        format!("duplicate of {}", source),
+        self.run_options(format),
+      );
+      outcome.timings.conversion = conversion;
+      outcome.attachment = attachment;
+      return Ok(outcome);
+    }
+
+    if self.config.get_skip_music() {
+      self.skip_music(&audio, &temp_converted_file).await;
+    }
+
+    let transcription_started = Instant::now();
+    let transcribed = self
+      .transcribe_converted(&temp_converted_file, format)
+      .await;
+    let temp_files = if input_passed_through {
+      vec![]
+    } else {
+      vec![&mut temp_converted_file]
+    };
+    self.abort_if_cancelled(temp_files).await?;
+    let transcribed = transcribed?;
+    let transcription = transcription_started.elapsed().as_secs_f64();
+
+    let attachment = self
+      .email_attachment(file_path, temp_converted_file.path())
+      .await;
+    self
+      .release_converted(
+        &mut temp_converted_file,
+        input_passed_through,
+        decrypted_passed_through,
+      )
+      .await;
+    if let Some(fingerprint) = fingerprint {
+      self
+        .remember_transcript(
+          &fingerprint,
+          file_path,
+          transcribed.response.text(),
+        )
+        .await;
+    }
+
+    let mut outcome = TranscriptionOutcome::new(
+      transcribed.response,
+      file_path.to_string(),
+      transcribed.backend,
+      self.run_options(format),
+    );
+    outcome.converted_path = self.kept_path(&temp_converted_file);
+    outcome.attachment = attachment;
+    outcome.stop_phrase_heard = transcribed.stop_phrase_heard;
+    outcome.timings = Timings {
+      recording: None,
+      conversion,
+      transcription,
+    };
+    outcome.cost = outcome
+      .duration()
+      .and_then(|duration| self.estimate_cost(duration));
+    return Ok(outcome);
+  }
+}
+
+fn duplicate_response(text: String, format: OutputFormat) -> WhisperResponse {
+  return match format {
+    OutputFormat::Json => WhisperResponse::Json(WhisperJsonResponse { text }),
+    _ => WhisperResponse::Text(WhisperTextResponse { text }),
+  };
+}
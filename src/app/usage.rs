@@ -0,0 +1,77 @@
+use crate::app::App;
+use crate::app::errors::RuntimeResult;
+use crate::app::results::UsageReport;
+use crate::audio::WavInspector;
+use crate::output::format::OutputFormat;
+use crate::vlog;
+
+impl App {
+  pub(super) fn estimate_cost(&self, duration: f64) -> Option<f64> {
+    let cost_per_minute = self.config.get_usage_cost_per_minute();
+    if cost_per_minute <= 0.0 {
+      return None;
+    }
+    return Some(duration / 60.0 * cost_per_minute);
+  }
+
+  /// Records the usage and cost of one file sent to Whisper.
+  ///
+  /// Called for every transcription request, whichever workflow sent it,
+  /// so notes, calls, streams and dictation are counted like files.
+  pub(super) async fn record_usage(
+    &self,
+    backend: &str,
+    file_path: &str,
+    transcription: f64,
+  ) {
+    let usage_enabled = self.config.get_usage_enabled();
+    let costs_enabled = self.config.get_usage_cost_per_minute() > 0.0;
+    if !usage_enabled && !costs_enabled {
+      return;
+    }
+    let duration = match WavInspector::read_duration(file_path) {
+      Ok(duration) => duration,
+      Err(e) => {
+        vlog!("Could not record usage: {}", e);
+        return;
+      }
+    };
+
+    let mut state = match self.load_state().await {
+      Ok(state) => state,
+      Err(e) => {
+        vlog!("Could not record usage: {}", e);
+        return;
+      }
+    };
+    if usage_enabled {
+      state.record_usage(backend, duration, transcription);
+    }
+    if let Some(cost) = self.estimate_cost(duration) {
+      let month = chrono::Local::now().format("%Y-%m").to_string();
+      state.record_cost(&month, duration, cost);
+    }
+    if let Err(e) = state.save(self.file_encryptor()).await {
+      vlog!("Could not record usage: {}", e);
+    }
+  }
+
+  /// Reports the locally recorded usage statistics.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the formatted statistics or an
+  /// error.
+  pub fn usage_stats(&self, format: OutputFormat) -> RuntimeResult<String> {
+    let report = UsageReport::new(
+      self.state.get_usage(),
+      self.state.get_costs(),
+      self.config.get_usage_currency(),
+    );
+    return report.format(format);
+  }
+}
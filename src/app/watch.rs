@@ -0,0 +1,156 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::app::App;
+use crate::app::errors::{RuntimeError, RuntimeResult};
+use crate::app::results::TranscriptionOutcome;
+use crate::files::operations;
+use crate::files::watch::{DirectoryWatcher, PendingFiles, is_watched_audio};
+use crate::logging::describe_error;
+use crate::output::format::OutputFormat;
+use crate::state::State;
+use crate::tr;
+use crate::vlog;
+
+const WATCH_SETTLE_INTERVAL: Duration = Duration::from_secs(1);
+const WATCH_TEXT_EXTENSION: &str = "txt";
+const WATCH_SUBTITLES_EXTENSION: &str = "srt";
+
+impl App {
+  /// Watches a directory and transcribes new audio files.
+  ///
+  /// Audio files already in the directory and files that appear later are
+  /// transcribed once they are completely written. The transcript of each
+  /// file is saved next to it, or in the transcripts directory, as a `.txt`
+  /// and an `.srt` file. Transcribed files are remembered in the state, so
+  /// they are skipped when watching is restarted unless they changed.
+  /// Transcripts are not copied to the clipboard, posted to chat or emailed.
+  /// Watching continues until cancelled.
+  ///
+  /// # Arguments
+  ///
+  /// * `directory` - Path to the directory to watch
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing a summary of the transcribed files
+  /// or an error.
+  pub async fn watch(&self, directory: &str) -> RuntimeResult<String> {
+    let result = self.run_watch(directory).await;
+    return self.finish(result);
+  }
+
+  async fn run_watch(&self, directory: &str) -> RuntimeResult<String> {
+    if !Path::new(directory).is_dir() {
+      return Err(RuntimeError::File(tr!(
+        "watch-not-directory",
+        directory = directory
+      )));
+    }
+    let mut watcher = DirectoryWatcher::new(directory)
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    let mut pending = PendingFiles::default();
+    let files = operations::list_files(directory)
+      .await
+      .map_err(|e| RuntimeError::File(describe_error(&e)))?;
+    for file in files.into_iter().filter(|file| is_watched_audio(file)) {
+      pending.add(file);
+    }
+    self.notify(tr!("watch-started", directory = directory));
+
+    let mut state = self.state.clone();
+    let mut count = 0;
+    let mut settle = tokio::time::interval(WATCH_SETTLE_INTERVAL);
+    loop {
+      tokio::select! {
+        _ = self.cancel.cancelled() => break,
+        path = watcher.next() => match path {
+          Some(path) if is_watched_audio(&path) => pending.add(path),
+          Some(_) => {}
+          None => break,
+        },
+        _ = settle.tick(), if !pending.is_empty() => {
+          for path in pending.take_settled().await {
+            if self.transcribe_watched(&path, &mut state).await? {
+              count += 1;
+            }
+          }
+        }
+      }
+    }
+    return Ok(tr!("watch-stopped", count = count));
+  }
+
+  async fn transcribe_watched(
+    &self,
+    file_path: &str,
+    state: &mut State,
+  ) -> RuntimeResult<bool> {
+    let Ok(modified) = operations::modified_time(file_path).await else {
+      return Ok(false);
+    };
+    if state.is_processed(file_path, modified) {
+      vlog!("Already transcribed: {}", file_path);
+      return Ok(false);
+    }
+
+    // Watched files are not delivered, so a folder of recordings does not
+    // flood the clipboard, chat or email.
+    let result = self
+      .run_transcribe_file(
+        file_path,
+        OutputFormat::FullJson,
+        self.config.get_skip_duplicates(),
+        None,
+      )
+      .await;
+    let mut outcome = match result {
+      Ok(outcome) => outcome,
+      Err(RuntimeError::Cancelled) => return Err(RuntimeError::Cancelled),
+      Err(e) => {
+        self.warn(tr!("watch-file-failed", file = file_path, error = e));
+        return Ok(false);
+      }
+    };
+    self.process_transcript(&mut outcome).await;
+    if let Err(e) = self.save_watched(file_path, &outcome).await {
+      self.warn(tr!("watch-file-failed", file = file_path, error = e));
+      return Ok(false);
+    }
+    self.notify(tr!("watch-transcribed", file = file_path));
+
+    state.remember_processed(file_path, modified);
+    self.remember_processed(file_path, modified).await;
+    return Ok(true);
+  }
+
+  /// Saves the text and subtitles of a watched file's transcript.
+  async fn save_watched(
+    &self,
+    file_path: &str,
+    outcome: &TranscriptionOutcome,
+  ) -> RuntimeResult<()> {
+    let sidecars = [
+      (WATCH_TEXT_EXTENSION, format!("{}\n", outcome.text.trim())),
+      (
+        WATCH_SUBTITLES_EXTENSION,
+        outcome.format(OutputFormat::Srt)?,
+      ),
+    ];
+    for (extension, content) in sidecars {
+      let path = self
+        .transcript_path(
+          &Path::new(file_path)
+            .with_extension(extension)
+            .to_string_lossy(),
+        )
+        .await?;
+      let path = self.write_transcript(&path, &content).await?;
+      vlog!("Transcript saved in: {}", path);
+      if extension == WATCH_TEXT_EXTENSION {
+        self.update_latest_transcript(&path).await;
+      }
+    }
+    return Ok(());
+  }
+}
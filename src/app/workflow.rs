@@ -0,0 +1,113 @@
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio_util::sync::CancellationToken;
+
+use crate::app::App;
+use crate::app::errors::RuntimeResult;
+use crate::events::Event;
+use crate::output::format::OutputFormat;
+
+static RUNTIME: OnceLock<Result<Runtime, String>> = OnceLock::new();
+
+/// Workflow run on behalf of a caller outside the CLI, such as the C ABI or
+/// the Python module.
+pub enum Workflow {
+  /// Transcribe an audio file
+  TranscribeFile { path: String, format: OutputFormat },
+  /// Record from the microphone and transcribe the recording
+  Record { format: OutputFormat },
+}
+
+impl App {
+  /// Runs a workflow to completion, passing its progress events on.
+  ///
+  /// Events sent before the workflow finishes are all reported before this
+  /// returns.
+  ///
+  /// # Arguments
+  ///
+  /// * `workflow` - The workflow to run
+  /// * `report` - Called with each progress event
+  ///
+  /// # Returns
+  ///
+  /// A `RuntimeResult<String>` containing the transcript in the requested
+  /// format or an error.
+  pub async fn run_workflow(
+    &self,
+    workflow: Workflow,
+    report: impl Fn(&Event),
+  ) -> RuntimeResult<String> {
+    let events = self.subscribe();
+    let done = CancellationToken::new();
+    let workflow = async {
+      let result = match workflow {
+        Workflow::TranscribeFile { path, format } => self
+          .transcribe_file(&path, format, None)
+          .await
+          .and_then(|outcome| outcome.format(format)),
+        Workflow::Record { format } => self
+          .record_and_transcribe(format)
+          .await
+          .and_then(|outcome| outcome.format(format)),
+      };
+      done.cancel();
+      return result;
+    };
+    let (result, _) =
+      tokio::join!(workflow, report_events(events, &report, &done));
+    return result;
+  }
+}
+
+/// Gets the async runtime shared by callers that block on workflows.
+///
+/// # Returns
+///
+/// A `Result<&'static Runtime, String>` containing the runtime or the
+/// reason it could not be started.
+pub fn shared_runtime() -> Result<&'static Runtime, String> {
+  return RUNTIME
+    .get_or_init(|| return Runtime::new().map_err(|e| e.to_string()))
+    .as_ref()
+    .map_err(|e| e.clone());
+}
+
+/// Passes events on until a workflow is done.
+///
+/// Events sent before `done` is cancelled are all reported before this
+/// returns.
+///
+/// # Arguments
+///
+/// * `events` - Receiver subscribed before the workflow started
+/// * `report` - Called with each progress event
+/// * `done` - Token cancelled once the workflow finished
+pub async fn report_events(
+  mut events: broadcast::Receiver<Event>,
+  report: &impl Fn(&Event),
+  done: &CancellationToken,
+) {
+  loop {
+    let event = tokio::select! {
+      biased;
+      event = events.recv() => event,
+      _ = done.cancelled() => break,
+    };
+    match event {
+      Ok(event) => report(&event),
+      Err(RecvError::Lagged(_)) => continue,
+      Err(RecvError::Closed) => return,
+    }
+  }
+  loop {
+    match events.try_recv() {
+      Ok(event) => report(&event),
+      Err(TryRecvError::Lagged(_)) => continue,
+      Err(_) => return,
+    }
+  }
+}
@@ -0,0 +1,95 @@
+use crate::audio::backend::{Backend, create_backend, has_recorded_audio};
+use crate::audio::errors::AudioError;
+
+#[test]
+fn test_create_backend_ffmpeg() {
+  assert!(matches!(create_backend("ffmpeg"), Ok(Backend::Ffmpeg(_))));
+  assert!(matches!(create_backend(" FFmpeg "), Ok(Backend::Ffmpeg(_))));
+}
+
+#[cfg(not(feature = "cpal"))]
+#[test]
+fn test_create_backend_native_without_feature() {
+  assert!(matches!(
+    create_backend("native"),
+    Err(AudioError::RecorderBackendNotBuilt(name)) if name == "native"
+  ));
+}
+
+#[cfg(feature = "cpal")]
+#[test]
+fn test_create_backend_native() {
+  assert!(matches!(create_backend("native"), Ok(Backend::Native(_))));
+}
+
+#[cfg(not(feature = "gstreamer"))]
+#[test]
+fn test_create_backend_gstreamer_without_feature() {
+  assert!(matches!(
+    create_backend("gstreamer"),
+    Err(AudioError::RecorderBackendNotBuilt(name)) if name == "gstreamer"
+  ));
+}
+
+#[cfg(feature = "gstreamer")]
+#[test]
+fn test_create_backend_gstreamer() {
+  assert!(matches!(
+    create_backend("GStreamer"),
+    Ok(Backend::GStreamer(_))
+  ));
+}
+
+#[test]
+fn test_create_backend_unknown() {
+  assert!(matches!(
+    create_backend("sox"),
+    Err(AudioError::UnknownRecorderBackend(name)) if name == "sox"
+  ));
+}
+
+/// Writes a WAV header with a metadata chunk, as FFmpeg does before the
+/// first sample, followed by the given audio data.
+fn write_recording(name: &str, data: &[u8]) -> String {
+  let mut bytes = Vec::new();
+  bytes.extend_from_slice(b"RIFF");
+  bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+  bytes.extend_from_slice(b"WAVE");
+  bytes.extend_from_slice(b"fmt ");
+  bytes.extend_from_slice(&16u32.to_le_bytes());
+  bytes.extend_from_slice(&1u16.to_le_bytes());
+  bytes.extend_from_slice(&1u16.to_le_bytes());
+  bytes.extend_from_slice(&16000u32.to_le_bytes());
+  bytes.extend_from_slice(&32000u32.to_le_bytes());
+  bytes.extend_from_slice(&2u16.to_le_bytes());
+  bytes.extend_from_slice(&16u16.to_le_bytes());
+  bytes.extend_from_slice(b"LIST");
+  bytes.extend_from_slice(&256u32.to_le_bytes());
+  bytes.extend_from_slice(&[0; 256]);
+  bytes.extend_from_slice(b"data");
+  bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+  bytes.extend_from_slice(data);
+
+  let path = std::env::temp_dir().join(name);
+  std::fs::write(&path, bytes).unwrap();
+  return path.to_string_lossy().to_string();
+}
+
+#[test]
+fn test_has_recorded_audio_with_header_only() {
+  let path = write_recording("test_has_recorded_audio_header.wav", &[]);
+  assert!(!has_recorded_audio(&path));
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_has_recorded_audio_with_samples() {
+  let path = write_recording("test_has_recorded_audio_samples.wav", &[0; 3200]);
+  assert!(has_recorded_audio(&path));
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_has_recorded_audio_missing_file() {
+  assert!(!has_recorded_audio("nonexistent_recording.wav"));
+}
@@ -0,0 +1,297 @@
+use std::collections::VecDeque;
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::backend::{
+  RecorderBackend, RecordingEnd, RecordingSegment, SegmentRequest,
+  has_recorded_audio,
+};
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::audio::errors::{
+  AudioError, AudioResult, FFMPEG_OUTPUT_LINES, FfmpegError,
+};
+use crate::audio::ffmpeg;
+use crate::audio::parser::{SilenceEvent, parse_version};
+use crate::audio::platform::AudioPlatform;
+use crate::audio::silence::SilenceTracker;
+use crate::audio::wav::WavInspector;
+use crate::events::Event;
+use crate::files::operations;
+use crate::vlog;
+
+const DEVICE_ERROR_PATTERNS: [&str; 4] = [
+  "Input/output error",
+  "Error during demuxing",
+  "No such device",
+  "Connection terminated",
+];
+
+/// Recorder backend that runs FFmpeg with the platform's audio input.
+///
+/// Devices are listed and recording arguments built by the AudioPlatform
+/// implementation, while silence is detected by FFmpeg's silencedetect
+/// filter.
+#[derive(Debug, Clone)]
+pub(crate) struct FfmpegBackend<P: AudioPlatform> {
+  platform: P,
+}
+
+impl<P: AudioPlatform> FfmpegBackend<P> {
+  /// Creates a new FfmpegBackend for the given platform.
+  ///
+  /// # Arguments
+  ///
+  /// * `platform` - Platform-specific implementation for audio operations
+  ///
+  /// # Returns
+  ///
+  /// A new `FfmpegBackend<P>` instance.
+  pub fn new(platform: P) -> Self {
+    return Self { platform };
+  }
+}
+
+impl<P: AudioPlatform> RecorderBackend for FfmpegBackend<P> {
+  async fn check_available(&self) -> AudioResult<()> {
+    let output = ffmpeg::run(&["-version"])
+      .await
+      .map_err(|e| AudioError::FFMPEGNotFound(e.into()))?;
+
+    if let Some(version) = parse_version(&output.stdout) {
+      vlog!("Found ffmpeg version {}", version);
+      return Ok(());
+    }
+    let first_line = output.stdout.lines().next().unwrap_or_default();
+    return Err(AudioError::FFMPEGNotFound(FfmpegError::UnknownVersion(
+      first_line.to_string(),
+    )));
+  }
+
+  async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
+    return self.platform.get_audio_input_devices().await;
+  }
+
+  async fn select_audio_input_device(
+    &self,
+    devices: AudioInputDevices,
+    preferred_audio_input_device: String,
+  ) -> AudioInputDevice {
+    return self
+      .platform
+      .select_audio_input_device(devices, preferred_audio_input_device)
+      .await;
+  }
+
+  async fn record_segment(
+    &self,
+    request: SegmentRequest<'_>,
+  ) -> AudioResult<RecordingSegment> {
+    let SegmentRequest {
+      device,
+      settings,
+      metadata,
+      output_file,
+      deadline,
+      cancel,
+      stop,
+      events,
+    } = request;
+
+    let args = self.platform.build_ffmpeg_recording_arguments(
+      device.get_index().clone(),
+      settings,
+      metadata,
+      output_file.to_string(),
+    );
+
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let mut child = ffmpeg::spawn(&args_refs)
+      .await
+      .map_err(|e| AudioError::CouldNotExecuteFFMPEG(e.into()))?;
+
+    events.emit(Event::DeviceSelected {
+      name: device.get_name().clone(),
+    });
+    events.emit(Event::RecordingStarted {
+      path: output_file.to_string(),
+    });
+    vlog!("Recording audio to: {}", output_file);
+    vlog!(
+      "Recording... will stop after {}s of silence",
+      settings.silence_limit
+    );
+
+    let stderr = child
+      .stderr
+      .take()
+      .ok_or(AudioError::CouldNotReadFFMPEGOutput)?;
+
+    let mut reader = BufReader::new(stderr).lines();
+
+    let silence_limit = settings.silence_limit;
+    let child_mutex = Arc::new(Mutex::new(child));
+    let mut timer_handle: Option<JoinHandle<()>> = None;
+    let deadline_reached = CancellationToken::new();
+    let deadline_handle = if deadline.is_active() {
+      Some(tokio::spawn(
+        deadline.watch(deadline_reached.clone(), events.clone()),
+      ))
+    } else {
+      None
+    };
+
+    let mut device_error = false;
+    let mut silence_tracker = SilenceTracker::new();
+    let mut error_output: VecDeque<String> = VecDeque::new();
+
+    loop {
+      let line = tokio::select! {
+        line = reader.next_line() => line,
+        _ = cancel.cancelled() => {
+          vlog!("Recording cancelled. Stopping FFmpeg...");
+          if let Some(handle) = timer_handle.take() {
+            handle.abort();
+          }
+          if let Some(handle) = &deadline_handle {
+            handle.abort();
+          }
+          let mut child = child_mutex.lock().await;
+          let _ = child.kill().await;
+          drop(child);
+          let _ = operations::remove_file(output_file).await;
+          return Err(AudioError::Cancelled);
+        }
+        _ = stop.cancelled() => {
+          vlog!("Recording stopped. Stopping FFmpeg...");
+          if let Some(handle) = timer_handle.take() {
+            handle.abort();
+          }
+          let _ = child_mutex.lock().await.kill().await;
+          break;
+        }
+        _ = deadline_reached.cancelled() => {
+          if let Some(handle) = timer_handle.take() {
+            handle.abort();
+          }
+          let _ = child_mutex.lock().await.kill().await;
+          break;
+        }
+      };
+      let Ok(Some(line)) = line else {
+        break;
+      };
+
+      let event = silence_tracker.observe(&line);
+      remember_error_output(&mut error_output, &line);
+
+      if is_device_error(&line) {
+        vlog!("FFmpeg reported an input error: {}", line);
+        device_error = true;
+      }
+
+      if let Some(SilenceEvent::Start(_)) = event {
+        events.emit(Event::SilenceDetected);
+      }
+
+      if let Some(SilenceEvent::Start(_)) = event
+        && !settings.ignore_silence
+      {
+        vlog!(
+          "Possible silence detected... starting {}s countdown.",
+          silence_limit
+        );
+
+        let child_for_timer = Arc::clone(&child_mutex);
+        timer_handle = Some(tokio::spawn(async move {
+          tokio::time::sleep(Duration::from_secs(silence_limit as u64)).await;
+          vlog!("Silence limit reached. Stopping recording...");
+          let _ = child_for_timer.lock().await.kill().await;
+        }));
+      }
+
+      if let Some(SilenceEvent::End { .. }) = event {
+        vlog!("Sound detected. Resetting silence timer.");
+        if let Some(handle) = timer_handle.take() {
+          handle.abort();
+        }
+      }
+    }
+
+    vlog!("Recording ended.");
+
+    if let Some(handle) = deadline_handle {
+      handle.abort();
+    }
+
+    let mut failure = None;
+    if let Ok(status) = child_mutex.lock().await.wait().await
+      && !status.success()
+      && status.code() != Some(255)
+      && status.signal() != Some(9)
+    {
+      vlog!("Process failed with exit code: {:?}", status.code());
+      failure = Some(status);
+    }
+
+    let end = segment_end(
+      device_error,
+      has_recorded_audio(output_file),
+      failure,
+      error_output,
+    )?;
+
+    let duration = WavInspector::read_duration(output_file).unwrap_or(0.0);
+    return Ok(RecordingSegment {
+      end,
+      silence: silence_tracker.total_silence(duration),
+      silences: silence_tracker.intervals(duration),
+    });
+  }
+}
+
+/// Keeps the last lines FFmpeg printed, apart from silence detection and
+/// progress, to explain why it failed.
+fn remember_error_output(error_output: &mut VecDeque<String>, line: &str) {
+  if line.contains("silencedetect") || line.trim_start().starts_with("size=") {
+    return;
+  }
+  if error_output.len() == FFMPEG_OUTPUT_LINES {
+    error_output.pop_front();
+  }
+  error_output.push_back(line.to_string());
+}
+
+/// Decides how a segment ended.
+///
+/// Only a device error that FFmpeg reported after audio was written counts as
+/// a lost device. Any other failure is an error, even when audio was written.
+pub(crate) fn segment_end(
+  device_error: bool,
+  recorded: bool,
+  failure: Option<ExitStatus>,
+  error_output: VecDeque<String>,
+) -> AudioResult<RecordingEnd> {
+  if device_error && recorded {
+    return Ok(RecordingEnd::DeviceLost);
+  }
+  if let Some(status) = failure {
+    let output = Vec::from(error_output).join("\n");
+    return Err(AudioError::CouldNotExecuteFFMPEG(FfmpegError::exited(
+      status, &output,
+    )));
+  }
+  return Ok(RecordingEnd::Completed);
+}
+
+pub(crate) fn is_device_error(line: &str) -> bool {
+  return DEVICE_ERROR_PATTERNS
+    .iter()
+    .any(|pattern| line.contains(pattern));
+}
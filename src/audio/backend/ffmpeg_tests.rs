@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+use crate::audio::backend::RecordingEnd;
+use crate::audio::backend::ffmpeg::{is_device_error, segment_end};
+use crate::audio::errors::AudioError;
+
+fn exit_code(code: i32) -> Option<ExitStatus> {
+  return Some(ExitStatus::from_raw(code << 8));
+}
+
+fn error_output() -> VecDeque<String> {
+  return VecDeque::from([String::from("Conversion failed!")]);
+}
+
+#[test]
+fn test_is_device_error() {
+  for line in [
+    "[alsa @ 0x5581] cannot read: Input/output error",
+    "Error during demuxing: Input/output error",
+    "[in#0/pulse @ 0x55d1] Error opening input: No such device",
+    "[pulse @ 0x7f00] Connection terminated",
+  ] {
+    assert!(is_device_error(line), "{}", line);
+  }
+}
+
+#[test]
+fn test_is_device_error_ignores_other_output() {
+  for line in [
+    "[silencedetect @ 0x55d1] silence_start: 1.5",
+    "size=     256kB time=00:00:08.00 bitrate= 256.0kbits/s speed=1x",
+    "Stream #0:0: Audio: pcm_s16le, 48000 Hz, stereo, s16, 1536 kb/s",
+    "Conversion failed!",
+  ] {
+    assert!(!is_device_error(line), "{}", line);
+  }
+}
+
+#[test]
+fn test_segment_end_device_lost_after_audio() {
+  assert!(matches!(
+    segment_end(true, true, exit_code(1), error_output()),
+    Ok(RecordingEnd::DeviceLost)
+  ));
+  assert!(matches!(
+    segment_end(true, true, None, error_output()),
+    Ok(RecordingEnd::DeviceLost)
+  ));
+}
+
+#[test]
+fn test_segment_end_failure_after_audio_is_error() {
+  match segment_end(false, true, exit_code(1), error_output()) {
+    Err(AudioError::CouldNotExecuteFFMPEG(error)) => {
+      assert!(error.to_string().contains("Conversion failed!"));
+    }
+    _ => panic!("Expected CouldNotExecuteFFMPEG error"),
+  }
+}
+
+#[test]
+fn test_segment_end_device_error_without_audio_is_error() {
+  assert!(matches!(
+    segment_end(true, false, exit_code(1), error_output()),
+    Err(AudioError::CouldNotExecuteFFMPEG(_))
+  ));
+}
+
+#[test]
+fn test_segment_end_completed() {
+  assert!(matches!(
+    segment_end(false, true, None, VecDeque::new()),
+    Ok(RecordingEnd::Completed)
+  ));
+  assert!(matches!(
+    segment_end(true, false, None, VecDeque::new()),
+    Ok(RecordingEnd::Completed)
+  ));
+}
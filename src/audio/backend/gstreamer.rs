@@ -0,0 +1,373 @@
+use std::collections::VecDeque;
+use std::os::unix::process::ExitStatusExt;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::backend::{
+  RecorderBackend, RecordingEnd, RecordingSegment, SegmentRequest,
+  has_recorded_audio,
+};
+use crate::audio::devices::{
+  AudioInputDevice, AudioInputDevices, find_audio_input_device,
+};
+use crate::audio::errors::{AudioError, AudioResult, FFMPEG_OUTPUT_LINES};
+use crate::audio::parser::{
+  SilenceEvent, parse_gstreamer_devices, parse_level_message,
+};
+use crate::audio::settings::RecorderSettings;
+use crate::audio::silence::{LevelSilenceDetector, SilenceTracker};
+use crate::events::Event;
+use crate::files::operations;
+use crate::process::executor::ProcessExecutor;
+use crate::vlog;
+
+const GST_LAUNCH: &str = "gst-launch-1.0";
+const GST_DEVICE_MONITOR: &str = "gst-device-monitor-1.0";
+const DEFAULT_SOURCE: &str = "autoaudiosrc";
+const LEVEL_INTERVAL_NS: u64 = 100_000_000;
+const MAX_VOLUME: f64 = 10.0;
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Recorder backend that runs a GStreamer pipeline with `gst-launch-1.0`.
+///
+/// Devices are listed with `gst-device-monitor-1.0` and recorded with the
+/// source element GStreamer suggests for them, so PulseAudio, PipeWire and
+/// ALSA devices work alike. Silence is detected from the messages of the
+/// `level` element with the same threshold and duration as FFmpeg's
+/// silencedetect filter. Call recording and provenance metadata need FFmpeg
+/// and are not supported.
+#[derive(Debug, Clone)]
+pub(crate) struct GStreamerBackend {}
+
+impl GStreamerBackend {
+  /// Creates a new GStreamerBackend instance.
+  ///
+  /// # Returns
+  ///
+  /// A new `GStreamerBackend` instance.
+  pub fn new() -> Self {
+    return Self {};
+  }
+}
+
+impl RecorderBackend for GStreamerBackend {
+  async fn check_available(&self) -> AudioResult<()> {
+    let output = ProcessExecutor::run(GST_LAUNCH, &["--version"])
+      .await
+      .map_err(AudioError::GStreamerNotFound)?;
+
+    if let Some(version) = output.stdout.lines().next() {
+      vlog!("Found {}", version);
+    }
+    return Ok(());
+  }
+
+  async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
+    let output = ProcessExecutor::run(GST_DEVICE_MONITOR, &["Audio/Source"])
+      .await
+      .map_err(AudioError::GStreamerNotFound)?;
+    let devices = parse_gstreamer_devices(&output.stdout);
+
+    vlog!("Audio Devices Found:");
+    for device in &devices {
+      vlog!("- [{}] {}", device.get_index(), device.get_name());
+    }
+
+    return Ok(devices);
+  }
+
+  async fn select_audio_input_device(
+    &self,
+    devices: AudioInputDevices,
+    preferred_audio_input_device: String,
+  ) -> AudioInputDevice {
+    if preferred_audio_input_device.is_empty() {
+      vlog!("No preferred audio input device specified, using default device");
+      return AudioInputDevice::default();
+    }
+
+    return find_audio_input_device(devices, &preferred_audio_input_device)
+      .unwrap_or_else(|| {
+        vlog!("No preferred audio input device found, using default device");
+        return AudioInputDevice::default();
+      });
+  }
+
+  async fn record_segment(
+    &self,
+    request: SegmentRequest<'_>,
+  ) -> AudioResult<RecordingSegment> {
+    let SegmentRequest {
+      device,
+      settings,
+      metadata: _,
+      output_file,
+      deadline,
+      cancel,
+      stop,
+      events,
+    } = request;
+
+    if !settings.call_source.is_empty() {
+      return Err(AudioError::CaptureFailed(String::from(
+        "call recording needs the ffmpeg recorder backend",
+      )));
+    }
+
+    let args = pipeline_arguments(device, settings, output_file);
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let mut child =
+      ProcessExecutor::spawn_with_output_piped(GST_LAUNCH, &args_refs)
+        .await
+        .map_err(AudioError::GStreamerNotFound)?;
+
+    events.emit(Event::DeviceSelected {
+      name: device.get_name().clone(),
+    });
+    events.emit(Event::RecordingStarted {
+      path: output_file.to_string(),
+    });
+    vlog!("Recording audio to: {}", output_file);
+    vlog!(
+      "Recording... will stop after {}s of silence",
+      settings.silence_limit
+    );
+
+    let (Some(stdout), Some(stderr)) =
+      (child.stdout.take(), child.stderr.take())
+    else {
+      let _ = child.kill().await;
+      return Err(AudioError::CaptureFailed(String::from(
+        "cannot read the output of gst-launch-1.0",
+      )));
+    };
+    let mut messages = BufReader::new(stdout).lines();
+    let mut errors = BufReader::new(stderr).lines();
+
+    let deadline_reached = CancellationToken::new();
+    let deadline_handle = if deadline.is_active() {
+      Some(tokio::spawn(
+        deadline.watch(deadline_reached.clone(), events.clone()),
+      ))
+    } else {
+      None
+    };
+
+    let silence_limit = settings.silence_limit.max(0) as f64;
+    let max_duration = settings.max_recording_duration.max(0) as f64;
+    let mut detector = LevelSilenceDetector::new(
+      settings.silence_detect_noise,
+      settings.silence_limit,
+    );
+    let mut silence_tracker = SilenceTracker::new();
+    let mut silence_detected_at: Option<f64> = None;
+    let mut elapsed = 0.0;
+    let mut error_output: VecDeque<String> = VecDeque::new();
+    let mut errors_closed = false;
+    let mut device_error = false;
+
+    loop {
+      let line = tokio::select! {
+        line = messages.next_line() => line,
+        line = errors.next_line(), if !errors_closed => {
+          match line {
+            Ok(Some(line)) => {
+              if line.starts_with("ERROR") {
+                vlog!("GStreamer reported an input error: {}", line);
+                device_error = true;
+              }
+              remember_error_output(&mut error_output, line);
+            }
+            _ => errors_closed = true,
+          }
+          continue;
+        }
+        _ = cancel.cancelled() => {
+          vlog!("Recording cancelled. Stopping GStreamer...");
+          if let Some(handle) = &deadline_handle {
+            handle.abort();
+          }
+          let _ = child.kill().await;
+          let _ = operations::remove_file(output_file).await;
+          return Err(AudioError::Cancelled);
+        }
+        _ = stop.cancelled() => {
+          vlog!("Recording stopped. Stopping GStreamer...");
+          break;
+        }
+        _ = deadline_reached.cancelled() => break,
+      };
+      let Ok(Some(line)) = line else {
+        break;
+      };
+      let Some(level) = parse_level_message(&line) else {
+        continue;
+      };
+      elapsed = level.end;
+
+      match detector.observe(level.start, level.rms_db) {
+        Some(event @ SilenceEvent::Start(_)) => {
+          silence_tracker.record(event);
+          events.emit(Event::SilenceDetected);
+          vlog!(
+            "Possible silence detected... starting {}s countdown.",
+            silence_limit
+          );
+          silence_detected_at = Some(elapsed);
+        }
+        Some(event @ SilenceEvent::End { .. }) => {
+          silence_tracker.record(event);
+          vlog!("Sound detected. Resetting silence timer.");
+          silence_detected_at = None;
+        }
+        None => {}
+      }
+
+      if !settings.ignore_silence
+        && silence_detected_at.is_some_and(|at| elapsed - at >= silence_limit)
+      {
+        vlog!("Silence limit reached. Stopping recording...");
+        break;
+      }
+      if max_duration > 0.0 && elapsed >= max_duration {
+        vlog!("Maximum recording duration reached. Stopping recording...");
+        break;
+      }
+    }
+
+    if let Some(handle) = deadline_handle {
+      handle.abort();
+    }
+
+    // An interrupted pipeline sends end-of-stream first, so the WAV header
+    // is completed before gst-launch-1.0 exits.
+    if let Some(pid) = child.id() {
+      let _ = ProcessExecutor::interrupt(pid).await;
+    }
+    let status =
+      match tokio::time::timeout(SHUTDOWN_TIMEOUT, child.wait()).await {
+        Ok(status) => status.ok(),
+        Err(_) => {
+          vlog!("GStreamer did not shut down in time. Killing it...");
+          let _ = child.kill().await;
+          None
+        }
+      };
+    while let Ok(Ok(Some(line))) =
+      tokio::time::timeout(Duration::ZERO, errors.next_line()).await
+    {
+      remember_error_output(&mut error_output, line);
+    }
+    vlog!("Recording ended.");
+
+    let failed = status.is_some_and(|status| {
+      return !status.success() && status.signal() != Some(9);
+    });
+    let end = if !device_error && !failed {
+      RecordingEnd::Completed
+    } else if has_recorded_audio(output_file) {
+      RecordingEnd::DeviceLost
+    } else {
+      let _ = operations::remove_file(output_file).await;
+      return Err(AudioError::CaptureFailed(
+        Vec::from(error_output).join("; "),
+      ));
+    };
+
+    return Ok(RecordingSegment {
+      end,
+      silence: silence_tracker.total_silence(elapsed),
+      silences: silence_tracker.intervals(elapsed),
+    });
+  }
+}
+
+/// Builds the `gst-launch-1.0` arguments that record a device.
+///
+/// The pipeline converts the audio to 16-bit mono, applies the configured
+/// gain, posts a `level` message every 100 ms and writes a WAV file. Each
+/// argument is one pipeline token, since `gst-launch-1.0` escapes spaces
+/// within arguments.
+///
+/// # Arguments
+///
+/// * `device` - Device to record from
+/// * `settings` - Recorder settings resolved for the device
+/// * `output_file` - Path of the WAV file to write
+///
+/// # Returns
+///
+/// The arguments of `gst-launch-1.0`.
+pub(crate) fn pipeline_arguments(
+  device: &AudioInputDevice,
+  settings: &RecorderSettings,
+  output_file: &str,
+) -> Vec<String> {
+  let mut args = vec!["-m".to_string(), "-e".to_string()];
+
+  if device.is_default() {
+    args.push(DEFAULT_SOURCE.to_string());
+  } else {
+    args.extend(device.get_index().split_whitespace().map(String::from));
+  }
+  args.extend(["!".to_string(), "audioconvert".to_string()]);
+
+  if settings.channel > 0 {
+    args.extend([
+      "!".to_string(),
+      "deinterleave".to_string(),
+      "name=channels".to_string(),
+      format!("channels.src_{}", settings.channel - 1),
+      "!".to_string(),
+      "queue".to_string(),
+      "!".to_string(),
+      "audioconvert".to_string(),
+    ]);
+  }
+
+  let mut caps = String::from("audio/x-raw,format=S16LE,channels=1");
+  if settings.whisper_format {
+    caps.push_str(",rate=16000");
+  }
+  args.extend([
+    "!".to_string(),
+    "audioresample".to_string(),
+    "!".to_string(),
+    caps,
+  ]);
+
+  if settings.gain != 0.0 {
+    let volume = 10f64.powf(settings.gain / 20.0).min(MAX_VOLUME);
+    args.extend([
+      "!".to_string(),
+      "volume".to_string(),
+      format!("volume={}", volume),
+    ]);
+  }
+
+  args.extend([
+    "!".to_string(),
+    "level".to_string(),
+    format!("interval={}", LEVEL_INTERVAL_NS),
+    "!".to_string(),
+    "wavenc".to_string(),
+    "!".to_string(),
+    "filesink".to_string(),
+    format!("location={}", output_file),
+  ]);
+  return args;
+}
+
+/// Keeps the last lines GStreamer printed to its error output, to explain
+/// why it failed.
+fn remember_error_output(error_output: &mut VecDeque<String>, line: String) {
+  if line.trim().is_empty() {
+    return;
+  }
+  if error_output.len() == FFMPEG_OUTPUT_LINES {
+    error_output.pop_front();
+  }
+  error_output.push_back(line);
+}
@@ -0,0 +1,53 @@
+use crate::audio::backend::gstreamer::pipeline_arguments;
+use crate::audio::devices::AudioInputDevice;
+use crate::audio::settings::RecorderSettings;
+
+fn pipeline(device: &AudioInputDevice, settings: &RecorderSettings) -> String {
+  return pipeline_arguments(device, settings, "/tmp/my recording.wav")
+    .join(" ");
+}
+
+#[test]
+fn test_pipeline_arguments_default_device() {
+  let settings = RecorderSettings::default();
+
+  assert_eq!(
+    pipeline(&AudioInputDevice::default(), &settings),
+    "-m -e autoaudiosrc ! audioconvert ! audioresample ! \
+     audio/x-raw,format=S16LE,channels=1 ! level interval=100000000 ! \
+     wavenc ! filesink location=/tmp/my recording.wav"
+  );
+}
+
+#[test]
+fn test_pipeline_arguments_split_source_element() {
+  let device = AudioInputDevice::new(
+    String::from("pulsesrc device=alsa_input.usb-mic"),
+    String::from("USB Microphone"),
+  );
+  let args =
+    pipeline_arguments(&device, &RecorderSettings::default(), "out.wav");
+
+  assert_eq!(args[2], "pulsesrc");
+  assert_eq!(args[3], "device=alsa_input.usb-mic");
+  assert_eq!(args.last().unwrap(), "location=out.wav");
+}
+
+#[test]
+fn test_pipeline_arguments_with_settings() {
+  let settings = RecorderSettings {
+    whisper_format: true,
+    gain: 20.0,
+    channel: 2,
+    ..Default::default()
+  };
+
+  assert_eq!(
+    pipeline(&AudioInputDevice::default(), &settings),
+    "-m -e autoaudiosrc ! audioconvert ! deinterleave name=channels \
+     channels.src_1 ! queue ! audioconvert ! audioresample ! \
+     audio/x-raw,format=S16LE,channels=1,rate=16000 ! volume volume=10 ! \
+     level interval=100000000 ! wavenc ! filesink \
+     location=/tmp/my recording.wav"
+  );
+}
@@ -0,0 +1,246 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::deadline::RecordingDeadline;
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::audio::errors::{AudioError, AudioResult};
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::platform::{CurrentPlatform, get_platform};
+use crate::audio::settings::RecorderSettings;
+use crate::audio::silence::AudioInterval;
+use crate::audio::wav::WavInspector;
+use crate::events::EventBus;
+
+mod ffmpeg;
+#[cfg(feature = "gstreamer")]
+mod gstreamer;
+#[cfg(feature = "cpal")]
+mod native;
+
+#[cfg(test)]
+mod backend_tests;
+#[cfg(test)]
+mod ffmpeg_tests;
+#[cfg(all(test, feature = "gstreamer"))]
+mod gstreamer_tests;
+
+pub(crate) use crate::audio::backend::ffmpeg::FfmpegBackend;
+#[cfg(feature = "gstreamer")]
+pub(crate) use crate::audio::backend::gstreamer::GStreamerBackend;
+#[cfg(feature = "cpal")]
+pub(crate) use crate::audio::backend::native::NativeBackend;
+
+const FFMPEG_BACKEND: &str = "ffmpeg";
+const NATIVE_BACKEND: &str = "native";
+const GSTREAMER_BACKEND: &str = "gstreamer";
+
+/// Captures audio from input devices.
+///
+/// Backends list and select devices and record single segments with silence
+/// detection. Everything around a segment, such as device caching, resuming
+/// after device loss and naming the output, is shared by the recorder.
+pub(crate) trait RecorderBackend {
+  /// Check that the backend can record on this system
+  ///
+  /// # Returns
+  ///
+  /// Nothing if the backend is usable or an error explaining why not
+  async fn check_available(&self) -> AudioResult<()>;
+
+  /// Get list of available audio input devices
+  ///
+  /// # Returns
+  ///
+  /// List of available audio input devices or error
+  async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices>;
+
+  /// Select an audio input device based on the provided list of devices
+  ///
+  /// # Arguments
+  ///
+  /// * `devices` - List of available audio input devices
+  /// * `preferred_audio_input_device` - Preferred device index or name
+  ///
+  /// # Returns
+  ///
+  /// Audio input device or default device
+  async fn select_audio_input_device(
+    &self,
+    devices: AudioInputDevices,
+    preferred_audio_input_device: String,
+  ) -> AudioInputDevice;
+
+  /// Record one segment until silence, the deadline or a stop request
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - Device, settings and output file of the segment
+  ///
+  /// # Returns
+  ///
+  /// How the segment ended and the silence detected in it, or error
+  async fn record_segment(
+    &self,
+    request: SegmentRequest<'_>,
+  ) -> AudioResult<RecordingSegment>;
+}
+
+/// Everything a backend needs to record one segment.
+pub(crate) struct SegmentRequest<'a> {
+  /// Device to record from
+  pub device: &'a AudioInputDevice,
+  /// Recorder settings resolved for the device
+  pub settings: &'a RecorderSettings,
+  /// Provenance metadata to write into the recording
+  pub metadata: &'a RecordingMetadata,
+  /// Path of the WAV file to write
+  pub output_file: &'a str,
+  /// Maximum duration watcher, enforced by the backend when active
+  pub deadline: RecordingDeadline,
+  /// Token that stops and discards the segment when cancelled
+  pub cancel: &'a CancellationToken,
+  /// Token that stops and keeps the segment when cancelled
+  pub stop: &'a CancellationToken,
+  /// Bus that receives recording progress events
+  pub events: &'a EventBus,
+}
+
+/// Outcome of a single recording run.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordingSegment {
+  /// How the run ended
+  pub end: RecordingEnd,
+  /// Seconds of silence detected during the run
+  pub silence: f64,
+  /// Silent intervals detected during the run
+  pub silences: Vec<AudioInterval>,
+}
+
+/// How a single recording run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RecordingEnd {
+  /// Stopped by silence detection, the maximum duration or the user
+  Completed,
+  /// Stopped because the input device stopped delivering audio
+  DeviceLost,
+}
+
+/// Recorder backend selected in the configuration.
+pub(crate) enum Backend {
+  /// Records through FFmpeg with the platform's audio input
+  Ffmpeg(FfmpegBackend<CurrentPlatform>),
+  /// Records through the operating system's audio API with cpal
+  #[cfg(feature = "cpal")]
+  Native(NativeBackend),
+  /// Records through a GStreamer pipeline run by `gst-launch-1.0`
+  #[cfg(feature = "gstreamer")]
+  GStreamer(GStreamerBackend),
+}
+
+/// Creates the recorder backend with the given name.
+///
+/// # Arguments
+///
+/// * `name` - Backend name (`ffmpeg`, `native` or `gstreamer`),
+///   case-insensitive
+///
+/// # Returns
+///
+/// An `AudioResult<Backend>` containing the backend or an error if it is
+/// unknown or not included in this build.
+pub(crate) fn create_backend(name: &str) -> AudioResult<Backend> {
+  return match name.trim().to_lowercase().as_str() {
+    FFMPEG_BACKEND => Ok(Backend::Ffmpeg(FfmpegBackend::new(get_platform()))),
+    #[cfg(feature = "cpal")]
+    NATIVE_BACKEND => Ok(Backend::Native(NativeBackend::new())),
+    #[cfg(not(feature = "cpal"))]
+    NATIVE_BACKEND => {
+      Err(AudioError::RecorderBackendNotBuilt(name.to_string()))
+    }
+    #[cfg(feature = "gstreamer")]
+    GSTREAMER_BACKEND => Ok(Backend::GStreamer(GStreamerBackend::new())),
+    #[cfg(not(feature = "gstreamer"))]
+    GSTREAMER_BACKEND => {
+      Err(AudioError::RecorderBackendNotBuilt(name.to_string()))
+    }
+    _ => Err(AudioError::UnknownRecorderBackend(name.to_string())),
+  };
+}
+
+impl RecorderBackend for Backend {
+  async fn check_available(&self) -> AudioResult<()> {
+    return match self {
+      Backend::Ffmpeg(backend) => backend.check_available().await,
+      #[cfg(feature = "cpal")]
+      Backend::Native(backend) => backend.check_available().await,
+      #[cfg(feature = "gstreamer")]
+      Backend::GStreamer(backend) => backend.check_available().await,
+    };
+  }
+
+  async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
+    return match self {
+      Backend::Ffmpeg(backend) => backend.get_audio_input_devices().await,
+      #[cfg(feature = "cpal")]
+      Backend::Native(backend) => backend.get_audio_input_devices().await,
+      #[cfg(feature = "gstreamer")]
+      Backend::GStreamer(backend) => backend.get_audio_input_devices().await,
+    };
+  }
+
+  async fn select_audio_input_device(
+    &self,
+    devices: AudioInputDevices,
+    preferred_audio_input_device: String,
+  ) -> AudioInputDevice {
+    return match self {
+      Backend::Ffmpeg(backend) => {
+        backend
+          .select_audio_input_device(devices, preferred_audio_input_device)
+          .await
+      }
+      #[cfg(feature = "cpal")]
+      Backend::Native(backend) => {
+        backend
+          .select_audio_input_device(devices, preferred_audio_input_device)
+          .await
+      }
+      #[cfg(feature = "gstreamer")]
+      Backend::GStreamer(backend) => {
+        backend
+          .select_audio_input_device(devices, preferred_audio_input_device)
+          .await
+      }
+    };
+  }
+
+  async fn record_segment(
+    &self,
+    request: SegmentRequest<'_>,
+  ) -> AudioResult<RecordingSegment> {
+    return match self {
+      Backend::Ffmpeg(backend) => backend.record_segment(request).await,
+      #[cfg(feature = "cpal")]
+      Backend::Native(backend) => backend.record_segment(request).await,
+      #[cfg(feature = "gstreamer")]
+      Backend::GStreamer(backend) => backend.record_segment(request).await,
+    };
+  }
+}
+
+/// Checks whether a recording holds any audio after its header.
+///
+/// The header grows with the metadata chunks written into it, so the
+/// duration is read from it rather than comparing the file size with a
+/// fixed header size.
+///
+/// # Arguments
+///
+/// * `output_file` - Path to the WAV file
+///
+/// # Returns
+///
+/// `true` if audio was written to the file.
+pub(crate) fn has_recorded_audio(output_file: &str) -> bool {
+  return WavInspector::read_duration(output_file)
+    .is_ok_and(|duration| duration > 0.0);
+}
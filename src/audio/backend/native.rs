@@ -0,0 +1,402 @@
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SampleFormat, SizedSample, StreamConfig};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::backend::{
+  RecorderBackend, RecordingEnd, RecordingSegment, SegmentRequest,
+};
+use crate::audio::devices::{
+  AudioInputDevice, AudioInputDevices, find_audio_input_device,
+};
+use crate::audio::errors::{AudioError, AudioResult};
+use crate::audio::parser::SilenceEvent;
+use crate::audio::silence::{LevelSilenceDetector, SilenceTracker, level_db};
+use crate::events::Event;
+use crate::files::operations;
+use crate::vlog;
+
+/// Message from the thread that owns the input stream.
+enum CaptureMessage {
+  /// The stream started with the given sample rate
+  Started(u32),
+  /// Mono samples between -1.0 and 1.0
+  Samples(Vec<f32>),
+  /// The stream could not be started or stopped delivering audio
+  Failed(String),
+}
+
+/// Recorder backend that captures audio through the operating system's
+/// audio API with cpal, without running FFmpeg.
+///
+/// Recordings are written as 16-bit mono WAV at the device's sample rate.
+/// Silence is detected from the level of the captured audio with the same
+/// threshold and duration as FFmpeg's silencedetect filter. Call recording
+/// and provenance metadata need FFmpeg and are not supported.
+#[derive(Debug, Clone)]
+pub(crate) struct NativeBackend {}
+
+impl NativeBackend {
+  /// Creates a new NativeBackend instance.
+  ///
+  /// # Returns
+  ///
+  /// A new `NativeBackend` instance.
+  pub fn new() -> Self {
+    return Self {};
+  }
+}
+
+impl RecorderBackend for NativeBackend {
+  async fn check_available(&self) -> AudioResult<()> {
+    return Ok(());
+  }
+
+  async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
+    let devices = tokio::task::spawn_blocking(list_devices)
+      .await
+      .map_err(|e| AudioError::CaptureFailed(e.to_string()))??;
+
+    vlog!("Audio Devices Found:");
+    for device in &devices {
+      vlog!("- [{}] {}", device.get_index(), device.get_name());
+    }
+
+    return Ok(devices);
+  }
+
+  async fn select_audio_input_device(
+    &self,
+    devices: AudioInputDevices,
+    preferred_audio_input_device: String,
+  ) -> AudioInputDevice {
+    if preferred_audio_input_device.is_empty() {
+      vlog!("No preferred audio input device specified, using default device");
+      return AudioInputDevice::default();
+    }
+
+    return find_audio_input_device(devices, &preferred_audio_input_device)
+      .unwrap_or_else(|| {
+        vlog!("No preferred audio input device found, using default device");
+        return AudioInputDevice::default();
+      });
+  }
+
+  async fn record_segment(
+    &self,
+    request: SegmentRequest<'_>,
+  ) -> AudioResult<RecordingSegment> {
+    let SegmentRequest {
+      device,
+      settings,
+      metadata: _,
+      output_file,
+      deadline,
+      cancel,
+      stop,
+      events,
+    } = request;
+
+    if !settings.call_source.is_empty() {
+      return Err(AudioError::CaptureFailed(String::from(
+        "call recording needs the ffmpeg recorder backend",
+      )));
+    }
+
+    let (sender, mut receiver) = unbounded_channel();
+    let (stop_sender, stop_receiver) = mpsc::channel();
+    let capture =
+      start_capture(device.clone(), settings.channel, sender, stop_receiver);
+
+    let sample_rate = match receiver.recv().await {
+      Some(CaptureMessage::Started(sample_rate)) => sample_rate,
+      Some(CaptureMessage::Failed(message)) => {
+        return Err(AudioError::CaptureFailed(message));
+      }
+      _ => {
+        return Err(AudioError::CaptureFailed(String::from(
+          "the input stream did not start",
+        )));
+      }
+    };
+
+    let spec = hound::WavSpec {
+      channels: 1,
+      sample_rate,
+      bits_per_sample: 16,
+      sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(output_file, spec)
+      .map_err(|e| AudioError::CaptureFailed(e.to_string()))?;
+
+    events.emit(Event::DeviceSelected {
+      name: device.get_name().clone(),
+    });
+    events.emit(Event::RecordingStarted {
+      path: output_file.to_string(),
+    });
+    vlog!("Recording audio to: {}", output_file);
+    vlog!(
+      "Recording... will stop after {}s of silence",
+      settings.silence_limit
+    );
+
+    let deadline_reached = CancellationToken::new();
+    let deadline_handle = if deadline.is_active() {
+      Some(tokio::spawn(
+        deadline.watch(deadline_reached.clone(), events.clone()),
+      ))
+    } else {
+      None
+    };
+
+    let gain = 10f32.powf(settings.gain as f32 / 20.0);
+    let silence_limit = settings.silence_limit.max(0) as f64;
+    let max_duration = settings.max_recording_duration.max(0) as f64;
+    let mut detector = LevelSilenceDetector::new(
+      settings.silence_detect_noise,
+      settings.silence_limit,
+    );
+    let mut silence_tracker = SilenceTracker::new();
+    let mut silence_detected_at: Option<f64> = None;
+    let mut written: u64 = 0;
+    let mut failure = None;
+    let mut cancelled = false;
+
+    loop {
+      let message = tokio::select! {
+        message = receiver.recv() => message,
+        _ = cancel.cancelled() => {
+          vlog!("Recording cancelled. Stopping capture...");
+          cancelled = true;
+          break;
+        }
+        _ = stop.cancelled() => {
+          vlog!("Recording stopped. Stopping capture...");
+          break;
+        }
+        _ = deadline_reached.cancelled() => break,
+      };
+
+      let samples = match message {
+        Some(CaptureMessage::Samples(samples)) => samples,
+        Some(CaptureMessage::Failed(message)) => {
+          vlog!("Audio input reported an error: {}", message);
+          failure = Some(message);
+          break;
+        }
+        Some(CaptureMessage::Started(_)) => continue,
+        None => break,
+      };
+
+      let time = written as f64 / sample_rate as f64;
+      for sample in &samples {
+        let value = (sample * gain).clamp(-1.0, 1.0);
+        writer
+          .write_sample((value * i16::MAX as f32) as i16)
+          .map_err(|e| AudioError::CaptureFailed(e.to_string()))?;
+      }
+      written += samples.len() as u64;
+      let elapsed = written as f64 / sample_rate as f64;
+
+      match detector.observe(time, level_db(&samples)) {
+        Some(event @ SilenceEvent::Start(_)) => {
+          silence_tracker.record(event);
+          events.emit(Event::SilenceDetected);
+          vlog!(
+            "Possible silence detected... starting {}s countdown.",
+            silence_limit
+          );
+          silence_detected_at = Some(elapsed);
+        }
+        Some(event @ SilenceEvent::End { .. }) => {
+          silence_tracker.record(event);
+          vlog!("Sound detected. Resetting silence timer.");
+          silence_detected_at = None;
+        }
+        None => {}
+      }
+
+      if !settings.ignore_silence
+        && silence_detected_at.is_some_and(|at| elapsed - at >= silence_limit)
+      {
+        vlog!("Silence limit reached. Stopping recording...");
+        break;
+      }
+      if max_duration > 0.0 && elapsed >= max_duration {
+        vlog!("Maximum recording duration reached. Stopping recording...");
+        break;
+      }
+    }
+
+    let _ = stop_sender.send(());
+    let _ = tokio::task::spawn_blocking(move || capture.join()).await;
+    if let Some(handle) = deadline_handle {
+      handle.abort();
+    }
+    let finalized = writer.finalize();
+    vlog!("Recording ended.");
+
+    if cancelled {
+      let _ = operations::remove_file(output_file).await;
+      return Err(AudioError::Cancelled);
+    }
+    finalized.map_err(|e| AudioError::CaptureFailed(e.to_string()))?;
+
+    let end = match failure {
+      Some(_) if written > 0 => RecordingEnd::DeviceLost,
+      Some(message) => {
+        let _ = operations::remove_file(output_file).await;
+        return Err(AudioError::CaptureFailed(message));
+      }
+      None => RecordingEnd::Completed,
+    };
+
+    let duration = written as f64 / sample_rate as f64;
+    return Ok(RecordingSegment {
+      end,
+      silence: silence_tracker.total_silence(duration),
+      silences: silence_tracker.intervals(duration),
+    });
+  }
+}
+
+fn list_devices() -> AudioResult<AudioInputDevices> {
+  let host = cpal::default_host();
+  let default_name = host
+    .default_input_device()
+    .and_then(|device| device.name().ok());
+  let devices = host
+    .input_devices()
+    .map_err(|e| AudioError::CaptureFailed(e.to_string()))?;
+
+  return Ok(
+    devices
+      .filter_map(|device| device.name().ok())
+      .map(|name| {
+        let device = AudioInputDevice::new(name.clone(), name.clone());
+        if default_name.as_ref() == Some(&name) {
+          return device.into_system_default();
+        }
+        return device;
+      })
+      .collect(),
+  );
+}
+
+/// Starts the thread that owns the input stream.
+///
+/// cpal streams cannot move between threads, so the stream lives on its own
+/// thread until `stop` receives a message or is dropped.
+fn start_capture(
+  device: AudioInputDevice,
+  channel: i32,
+  sender: UnboundedSender<CaptureMessage>,
+  stop: mpsc::Receiver<()>,
+) -> JoinHandle<()> {
+  return std::thread::spawn(move || {
+    let stream = match open_stream(&device, channel, sender.clone()) {
+      Ok(stream) => stream,
+      Err(message) => {
+        let _ = sender.send(CaptureMessage::Failed(message));
+        return;
+      }
+    };
+    let _ = stop.recv();
+    drop(stream);
+  });
+}
+
+fn open_stream(
+  device: &AudioInputDevice,
+  channel: i32,
+  sender: UnboundedSender<CaptureMessage>,
+) -> Result<cpal::Stream, String> {
+  let host = cpal::default_host();
+  let input = if device.is_default() {
+    host.default_input_device()
+  } else {
+    host
+      .input_devices()
+      .map_err(|e| e.to_string())?
+      .find(|input| input.name().ok().as_ref() == Some(device.get_index()))
+  }
+  .ok_or_else(|| {
+    format!("audio input device not found: {}", device.get_name())
+  })?;
+
+  let supported = input.default_input_config().map_err(|e| e.to_string())?;
+  let format = supported.sample_format();
+  let config: StreamConfig = supported.into();
+  let channels = config.channels as usize;
+  let sample_rate = config.sample_rate.0;
+
+  let stream = match format {
+    SampleFormat::F32 => {
+      build_stream::<f32>(&input, &config, channels, channel, sender.clone())
+    }
+    SampleFormat::I16 => {
+      build_stream::<i16>(&input, &config, channels, channel, sender.clone())
+    }
+    SampleFormat::I32 => {
+      build_stream::<i32>(&input, &config, channels, channel, sender.clone())
+    }
+    SampleFormat::U16 => {
+      build_stream::<u16>(&input, &config, channels, channel, sender.clone())
+    }
+    format => return Err(format!("unsupported sample format: {}", format)),
+  }?;
+  stream.play().map_err(|e| e.to_string())?;
+
+  let _ = sender.send(CaptureMessage::Started(sample_rate));
+  return Ok(stream);
+}
+
+fn build_stream<T>(
+  input: &cpal::Device,
+  config: &StreamConfig,
+  channels: usize,
+  channel: i32,
+  sender: UnboundedSender<CaptureMessage>,
+) -> Result<cpal::Stream, String>
+where
+  T: SizedSample,
+  f32: FromSample<T>,
+{
+  let errors = sender.clone();
+  return input
+    .build_input_stream(
+      config,
+      move |data: &[T], _: &cpal::InputCallbackInfo| {
+        let samples = data
+          .chunks(channels.max(1))
+          .map(|frame| return to_mono(frame, channel))
+          .collect();
+        let _ = sender.send(CaptureMessage::Samples(samples));
+      },
+      move |error| {
+        let _ = errors.send(CaptureMessage::Failed(error.to_string()));
+      },
+      None,
+    )
+    .map_err(|e| e.to_string());
+}
+
+/// Picks the configured one-based channel of a frame, or downmixes all
+/// channels when it is 0 or out of range.
+fn to_mono<T>(frame: &[T], channel: i32) -> f32
+where
+  T: SizedSample,
+  f32: FromSample<T>,
+{
+  if channel > 0
+    && let Some(sample) = frame.get(channel as usize - 1)
+  {
+    return sample.to_sample::<f32>();
+  }
+  let sum: f32 = frame.iter().map(|sample| sample.to_sample::<f32>()).sum();
+  return sum / frame.len().max(1) as f32;
+}
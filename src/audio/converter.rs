@@ -1,10 +1,16 @@
 use std::path::Path;
 
-use crate::audio::errors::{AudioError, AudioResult};
+use crate::audio::errors::{AudioError, AudioResult, FfmpegError};
+use crate::audio::ffmpeg;
+use crate::audio::music::MusicRange;
+use crate::audio::raw::{RawAudioFormat, STDIN_INPUT};
+use crate::audio::wav::WavInspector;
 use crate::files::operations;
-use crate::process::executor::ProcessExecutor;
 use crate::vlog;
 
+const STDIN_NAME: &str = "stdin";
+const STDIN_PIPE: &str = "pipe:0";
+
 /// Handles audio format conversion for Whisper transcription.
 ///
 /// Converts various audio formats to 16kHz mono WAV format required by Whisper.
@@ -14,11 +20,23 @@ impl AudioConverter {
   /// Converts audio input file to Whisper-compatible format.
   ///
   /// Uses FFmpeg to convert any supported audio format to 16kHz mono WAV
-  /// format required by Whisper transcription service.
+  /// format required by Whisper transcription service. Metadata tags of the
+  /// input, such as those written by the recorder, are carried over. An
+  /// input file of `-` reads the audio from stdin.
+  ///
+  /// When passing through is allowed, a file that already is a 16kHz mono
+  /// PCM WAV file is not converted and its own path is returned.
   ///
   /// # Arguments
   ///
   /// * `input_file` - Path to the input audio file
+  /// * `raw_format` - Format of headerless PCM input (`None` to detect it)
+  /// * `temp_directory` - Directory for the converted file unless it is kept
+  ///   (empty for next to the input file)
+  /// * `pass_through` - Whether a Whisper-ready input is returned unchanged
+  /// * `kept` - Whether the converted file is kept after use, in which case
+  ///   it is written next to the input file, otherwise it is named as an
+  ///   intermediate file
   ///
   /// # Returns
   ///
@@ -26,19 +44,30 @@ impl AudioConverter {
   /// or an error if conversion failed.
   pub async fn convert_audio_for_whisper(
     input_file: &str,
+    raw_format: Option<&RawAudioFormat>,
+    temp_directory: &str,
+    pass_through: bool,
+    kept: bool,
   ) -> AudioResult<String> {
-    operations::validate_file_exists(input_file)
-      .await
-      .map_err(|_| AudioError::FileNotFound(input_file.to_string()))?;
+    let from_stdin = input_file == STDIN_INPUT;
+    if !from_stdin {
+      operations::validate_file_exists(input_file)
+        .await
+        .map_err(|_| AudioError::FileNotFound(input_file.to_string()))?;
+    }
 
-    let input_path = Path::new(input_file);
-    let parent_dir = input_path.parent().unwrap_or_else(|| Path::new("."));
-    let stem = input_path
-      .file_stem()
-      .and_then(|s| s.to_str())
-      .unwrap_or("audio");
-    let output_file = parent_dir.join(format!("{}_whisper.wav", stem));
-    let output_file_str = output_file.to_string_lossy();
+    if pass_through
+      && !from_stdin
+      && raw_format.is_none()
+      && WavInspector::is_whisper_ready(input_file)
+    {
+      vlog!("Audio is already in Whisper format: {}", input_file);
+      return Ok(input_file.to_string());
+    }
+
+    let input_name = if from_stdin { STDIN_NAME } else { input_file };
+    let output_file_str =
+      whisper_output_file(input_name, "", temp_directory, kept).await?;
 
     vlog!(
       "Converting audio to Whisper format: {} → {}",
@@ -46,40 +75,279 @@ impl AudioConverter {
       output_file_str
     );
 
-    convert_with_ffmpeg(input_file, &output_file_str).await?;
+    let mut input_arguments = raw_format
+      .map(|format| format.to_ffmpeg_arguments())
+      .unwrap_or_default();
+    input_arguments.push(String::from("-i"));
+    input_arguments.push(if from_stdin {
+      String::from(STDIN_PIPE)
+    } else {
+      input_file.to_string()
+    });
+
+    convert_with_ffmpeg(&input_arguments, &output_file_str, from_stdin).await?;
 
     vlog!("Audio conversion completed: {}", output_file_str);
 
-    return Ok(output_file_str.to_string());
+    return Ok(output_file_str);
   }
-}
 
-async fn convert_with_ffmpeg(
-  input_file: &str,
-  output_file: &str,
-) -> AudioResult<()> {
-  let output = ProcessExecutor::run(
-    "ffmpeg",
-    &[
-      "-i",
+  /// Converts one channel of an audio file to Whisper-compatible format.
+  ///
+  /// Used to transcribe recordings that carry a different speaker on each
+  /// channel, such as call recordings.
+  ///
+  /// # Arguments
+  ///
+  /// * `input_file` - Path to the input audio file
+  /// * `channel` - Zero-based channel to convert
+  /// * `temp_directory` - Directory for the converted file unless it is kept
+  ///   (empty for next to the input file)
+  /// * `kept` - Whether the converted file is kept after use, in which case
+  ///   it is written next to the input file, otherwise it is named as an
+  ///   intermediate file
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<String>` containing the path to the converted WAV file
+  /// or an error if conversion failed.
+  pub async fn convert_channel_for_whisper(
+    input_file: &str,
+    channel: usize,
+    temp_directory: &str,
+    kept: bool,
+  ) -> AudioResult<String> {
+    operations::validate_file_exists(input_file)
+      .await
+      .map_err(|_| AudioError::FileNotFound(input_file.to_string()))?;
+
+    let suffix = format!("_ch{}", channel + 1);
+    let output_file =
+      whisper_output_file(input_file, &suffix, temp_directory, kept).await?;
+
+    vlog!(
+      "Converting channel {} to Whisper format: {} → {}",
+      channel + 1,
       input_file,
-      "-ar",
-      "16000",
-      "-ac",
-      "1",
+      output_file
+    );
+
+    let input_arguments = [
+      String::from("-i"),
+      input_file.to_string(),
+      String::from("-af"),
+      format!("pan=mono|c0=c{}", channel),
+    ];
+    convert_with_ffmpeg(&input_arguments, &output_file, false).await?;
+
+    return Ok(output_file);
+  }
+
+  /// Appends one recording to the end of another.
+  ///
+  /// Uses FFmpeg's concat filter so that recordings captured from different
+  /// devices with different sample rates or channel layouts can be joined.
+  ///
+  /// # Arguments
+  ///
+  /// * `target_file` - Path to the recording to extend in place
+  /// * `addition_file` - Path to the recording to append
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<()>` indicating success or failure.
+  pub async fn append_audio(
+    target_file: &str,
+    addition_file: &str,
+  ) -> AudioResult<()> {
+    let target_path = Path::new(target_file);
+    let parent_dir = target_path.parent().unwrap_or_else(|| Path::new("."));
+    let stem = target_path
+      .file_stem()
+      .and_then(|s| s.to_str())
+      .unwrap_or("audio");
+    let merged_file_str =
+      private_file(parent_dir, &format!("{}_merged.wav", stem)).await?;
+
+    vlog!("Appending {} to {}", addition_file, target_file);
+
+    let output = ffmpeg::run(&[
+      "-i",
+      target_file,
+      "-i",
+      addition_file,
+      "-filter_complex",
+      "[0:a][1:a]concat=n=2:v=0:a=1",
       "-c:a",
       "pcm_s16le",
-      output_file,
+      &merged_file_str,
       "-y",
-    ],
-  )
-  .await
-  .map_err(|_| AudioError::ConversionFailed)?;
+    ])
+    .await;
+    let output = started(output, &merged_file_str).await?;
+
+    if !output.status.success() {
+      vlog!("FFmpeg concatenation error: {}", output.stderr);
+      let _ = operations::remove_file(&merged_file_str).await;
+      return Err(AudioError::ConversionFailed(FfmpegError::exited(
+        output.status,
+        &output.stderr,
+      )));
+    }
+
+    return operations::move_file(&merged_file_str, target_file)
+      .await
+      .map_err(|e| AudioError::ConversionFailed(e.into()));
+  }
+
+  /// Silences time ranges of a recording in place.
+  ///
+  /// # Arguments
+  ///
+  /// * `target_file` - Path to the recording to change
+  /// * `ranges` - Time ranges to silence
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<()>` indicating success or failure.
+  pub async fn mute_ranges(
+    target_file: &str,
+    ranges: &[MusicRange],
+  ) -> AudioResult<()> {
+    if ranges.is_empty() {
+      return Ok(());
+    }
+
+    let target_path = Path::new(target_file);
+    let parent_dir = target_path.parent().unwrap_or_else(|| Path::new("."));
+    let stem = target_path
+      .file_stem()
+      .and_then(|s| s.to_str())
+      .unwrap_or("audio");
+    let muted_file_str =
+      private_file(parent_dir, &format!("{}_muted.wav", stem)).await?;
+
+    let enable = ranges
+      .iter()
+      .map(|range| format!("between(t,{:.2},{:.2})", range.start, range.end))
+      .collect::<Vec<String>>()
+      .join("+");
+    let filter = format!("volume=enable='{}':volume=0", enable);
+
+    vlog!("Muting {} ranges of {}", ranges.len(), target_file);
+
+    let output = ffmpeg::run(&[
+      "-i",
+      target_file,
+      "-af",
+      &filter,
+      "-c:a",
+      "pcm_s16le",
+      &muted_file_str,
+      "-y",
+    ])
+    .await;
+    let output = started(output, &muted_file_str).await?;
+
+    if !output.status.success() {
+      vlog!("FFmpeg muting error: {}", output.stderr);
+      let _ = operations::remove_file(&muted_file_str).await;
+      return Err(AudioError::ConversionFailed(FfmpegError::exited(
+        output.status,
+        &output.stderr,
+      )));
+    }
+
+    return operations::move_file(&muted_file_str, target_file)
+      .await
+      .map_err(|e| AudioError::ConversionFailed(e.into()));
+  }
+}
+
+async fn whisper_output_file(
+  input_file: &str,
+  suffix: &str,
+  temp_directory: &str,
+  kept: bool,
+) -> AudioResult<String> {
+  let input_path = Path::new(input_file);
+  // The temporary directory may be a RAM disk, so files that are kept are
+  // written next to their input instead.
+  let parent_dir = if kept || temp_directory.is_empty() {
+    input_path.parent().unwrap_or_else(|| Path::new("."))
+  } else {
+    operations::create_directory_all(temp_directory)
+      .await
+      .map_err(AudioError::CouldNotCreateDirectory)?;
+    Path::new(temp_directory)
+  };
+  let stem = input_path
+    .file_stem()
+    .and_then(|s| s.to_str())
+    .unwrap_or("audio");
+  let name = format!("{}{}_whisper.wav", stem, suffix);
+  if kept {
+    return Ok(parent_dir.join(name).to_string_lossy().to_string());
+  }
+  return private_file(parent_dir, &name).await;
+}
+
+/// Creates an intermediate file for FFmpeg to overwrite, so that it never
+/// writes through a name planted in a shared directory.
+async fn private_file(parent_dir: &Path, name: &str) -> AudioResult<String> {
+  return operations::create_private_file(&parent_dir.to_string_lossy(), name)
+    .await
+    .map_err(|e| AudioError::ConversionFailed(e.into()));
+}
+
+async fn convert_with_ffmpeg(
+  input_arguments: &[String],
+  output_file: &str,
+  from_stdin: bool,
+) -> AudioResult<()> {
+  let mut args: Vec<&str> =
+    input_arguments.iter().map(|s| s.as_str()).collect();
+  args.extend([
+    "-ar",
+    "16000",
+    "-ac",
+    "1",
+    "-c:a",
+    "pcm_s16le",
+    "-write_bext",
+    "1",
+    output_file,
+    "-y",
+  ]);
+  let output = if from_stdin {
+    ffmpeg::run_with_stdin(&args).await
+  } else {
+    ffmpeg::run(&args).await
+  };
+  let output = started(output, output_file).await?;
 
   if !output.status.success() {
     vlog!("FFmpeg conversion error: {}", output.stderr);
-    return Err(AudioError::ConversionFailed);
+    let _ = operations::remove_file(output_file).await;
+    return Err(AudioError::ConversionFailed(FfmpegError::exited(
+      output.status,
+      &output.stderr,
+    )));
   }
 
   return Ok(());
 }
+
+/// Removes the output file of an FFmpeg run that could not be started.
+async fn started<T>(
+  result: Result<T, impl Into<FfmpegError>>,
+  output_file: &str,
+) -> AudioResult<T> {
+  return match result {
+    Ok(output) => Ok(output),
+    Err(e) => {
+      let _ = operations::remove_file(output_file).await;
+      Err(AudioError::ConversionFailed(e.into()))
+    }
+  };
+}
@@ -0,0 +1,122 @@
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+use tokio::sync::Mutex;
+
+use crate::audio::converter::AudioConverter;
+use crate::testing::FfmpegShim;
+
+/// The ffmpeg shim replaces ffmpeg for the whole process, so tests using it
+/// run one at a time.
+static FFMPEG: Mutex<()> = Mutex::const_new(());
+
+fn write_segment(name: &str, content: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!(
+    "lumine-segment-{}-{}.wav",
+    name,
+    std::process::id()
+  ));
+  std::fs::write(&path, content).unwrap();
+  return path;
+}
+
+fn merged_files(target: &std::path::Path) -> Vec<PathBuf> {
+  let stem = target.file_stem().unwrap().to_string_lossy();
+  let suffix = format!("_{}_merged.wav", stem);
+  return std::fs::read_dir(target.parent().unwrap())
+    .unwrap()
+    .map(|entry| return entry.unwrap().path())
+    .filter(|path| {
+      let name = path.file_name().unwrap().to_string_lossy();
+      return name.starts_with("lumine-tmp-") && name.ends_with(&suffix);
+    })
+    .collect();
+}
+
+#[tokio::test]
+async fn test_append_audio_replaces_target_with_joined_segments() {
+  let _guard = FFMPEG.lock().await;
+  let ffmpeg = FfmpegShim::new().unwrap();
+  let target = write_segment("append-target", "first");
+  let addition = write_segment("append-addition", "second");
+
+  AudioConverter::append_audio(
+    target.to_str().unwrap(),
+    addition.to_str().unwrap(),
+  )
+  .await
+  .unwrap();
+
+  let invocation = ffmpeg.invocations().join("\n");
+  let inputs = format!(
+    "-i {} -i {} -filter_complex [0:a][1:a]concat=n=2:v=0:a=1",
+    target.display(),
+    addition.display()
+  );
+  assert!(invocation.contains(&inputs), "{}", invocation);
+  // The shim copies the last input, standing in for the joined audio.
+  assert_eq!(std::fs::read_to_string(&target).unwrap(), "second");
+  assert!(merged_files(&target).is_empty());
+
+  let _ = std::fs::remove_file(&target);
+  let _ = std::fs::remove_file(&addition);
+}
+
+#[tokio::test]
+async fn test_append_audio_failure_keeps_target() {
+  let _guard = FFMPEG.lock().await;
+  let _ffmpeg = FfmpegShim::failing().unwrap();
+  let target = write_segment("append-failed-target", "first");
+  let addition = write_segment("append-failed-addition", "second");
+
+  let result = AudioConverter::append_audio(
+    target.to_str().unwrap(),
+    addition.to_str().unwrap(),
+  )
+  .await;
+
+  assert!(result.is_err());
+  assert_eq!(std::fs::read_to_string(&target).unwrap(), "first");
+  assert!(merged_files(&target).is_empty());
+
+  let _ = std::fs::remove_file(&target);
+  let _ = std::fs::remove_file(&addition);
+}
+
+#[tokio::test]
+async fn test_convert_audio_uses_private_intermediate_file() {
+  let _guard = FFMPEG.lock().await;
+  let _ffmpeg = FfmpegShim::new().unwrap();
+  let input = write_segment("private-input", "audio");
+  let temp_directory = std::env::temp_dir()
+    .join(format!("lumine-private-conversion-{}", std::process::id()));
+  let temp_directory = temp_directory.to_str().unwrap();
+
+  let first = AudioConverter::convert_audio_for_whisper(
+    input.to_str().unwrap(),
+    None,
+    temp_directory,
+    false,
+    false,
+  )
+  .await
+  .unwrap();
+  let second = AudioConverter::convert_audio_for_whisper(
+    input.to_str().unwrap(),
+    None,
+    temp_directory,
+    false,
+    false,
+  )
+  .await
+  .unwrap();
+
+  assert_ne!(first, second);
+  assert!(first.starts_with(temp_directory));
+  let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+  assert_eq!(mode & 0o777, 0o600);
+  assert_eq!(std::fs::read_to_string(&first).unwrap(), "audio");
+
+  let _ = std::fs::remove_dir_all(temp_directory);
+  let _ = std::fs::remove_file(&input);
+}
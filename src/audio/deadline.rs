@@ -0,0 +1,202 @@
+use std::io::{BufRead, IsTerminal};
+use std::sync::{Mutex, MutexGuard, Once};
+use std::time::Duration;
+
+use tokio::sync::mpsc::{
+  UnboundedReceiver, UnboundedSender, unbounded_channel,
+};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+use crate::events::{Event, EventBus};
+use crate::tr;
+use crate::vlog;
+
+/// Maximum recording duration watcher.
+///
+/// Warns the user shortly before the maximum recording duration is reached
+/// and, when extension is enabled and stdin is a terminal, lets them extend
+/// the recording by pressing Enter instead of having it cut off mid-sentence.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordingDeadline {
+  max_recording_duration: i32,
+  warning: i32,
+  extension: i32,
+}
+
+impl RecordingDeadline {
+  /// Creates a new RecordingDeadline.
+  ///
+  /// # Arguments
+  ///
+  /// * `max_recording_duration` - Maximum recording duration in seconds (0 for unlimited)
+  /// * `warning` - Seconds before the cutoff at which to warn (0 to disable)
+  /// * `extension` - Seconds added per extension (0 to disable)
+  ///
+  /// # Returns
+  ///
+  /// A new `RecordingDeadline` instance.
+  pub fn new(
+    max_recording_duration: i32,
+    warning: i32,
+    extension: i32,
+  ) -> Self {
+    return Self {
+      max_recording_duration,
+      warning,
+      extension,
+    };
+  }
+
+  /// Checks whether the recording can be extended interactively.
+  ///
+  /// When extendable, the deadline is enforced by this watcher instead of
+  /// the recorder backend, so that it can be moved while recording.
+  ///
+  /// # Returns
+  ///
+  /// `true` if the maximum duration may be extended, `false` otherwise.
+  pub fn is_extendable(&self) -> bool {
+    return self.allows_extension() && std::io::stdin().is_terminal();
+  }
+
+  /// Checks whether the settings allow extending the recording.
+  ///
+  /// # Returns
+  ///
+  /// `true` if a limit, a warning and an extension are all configured.
+  pub(crate) fn allows_extension(&self) -> bool {
+    return self.max_recording_duration > 0
+      && self.warning > 0
+      && self.extension > 0;
+  }
+
+  /// Checks whether the watcher has anything to do.
+  ///
+  /// # Returns
+  ///
+  /// `true` if a warning should be shown or the deadline enforced.
+  pub fn is_active(&self) -> bool {
+    return self.max_recording_duration > 0 && self.warning > 0;
+  }
+
+  /// Watches the recording until the maximum duration is reached.
+  ///
+  /// Sends a notice before the cutoff and, if extendable, waits for Enter
+  /// to push the deadline back. Cancels `reached` once an extendable
+  /// deadline passes, which tells the recorder backend to stop.
+  ///
+  /// # Arguments
+  ///
+  /// * `reached` - Token cancelled when the deadline passes
+  /// * `events` - Bus that receives the warning and extension notices
+  pub async fn watch(self, reached: CancellationToken, events: EventBus) {
+    let listen = self.is_extendable().then_some(listen_for_enter);
+    return self.watch_with(reached, events, listen).await;
+  }
+
+  /// Watches the recording with a given source of extension requests.
+  ///
+  /// # Arguments
+  ///
+  /// * `reached` - Token cancelled when the deadline passes
+  /// * `events` - Bus that receives the warning and extension notices
+  /// * `listen` - Starts listening for extension requests once the warning
+  ///   is shown, or `None` if the recording cannot be extended
+  pub(crate) async fn watch_with<F>(
+    self,
+    reached: CancellationToken,
+    events: EventBus,
+    mut listen: Option<F>,
+  ) where
+    F: FnOnce() -> UnboundedReceiver<()>,
+  {
+    let start = Instant::now();
+    let warning = seconds(self.warning);
+    let mut deadline = start + seconds(self.max_recording_duration);
+    let mut input: Option<UnboundedReceiver<()>> = None;
+
+    loop {
+      let warn_at = deadline.checked_sub(warning).unwrap_or(start);
+      tokio::time::sleep_until(warn_at).await;
+
+      let remaining = deadline.saturating_duration_since(Instant::now());
+      if input.is_none()
+        && let Some(listen) = listen.take()
+      {
+        input = Some(listen());
+      }
+      let Some(receiver) = &mut input else {
+        events.emit(Event::Notice {
+          message: tr!("recording-stops-soon", seconds = remaining.as_secs()),
+        });
+        return;
+      };
+
+      events.emit(Event::Notice {
+        message: tr!(
+          "recording-stops-soon-extendable",
+          seconds = remaining.as_secs(),
+          extension = self.extension
+        ),
+      });
+
+      while receiver.try_recv().is_ok() {}
+
+      tokio::select! {
+        Some(()) = receiver.recv() => {
+          deadline += seconds(self.extension);
+          events.emit(Event::Notice {
+            message: tr!("recording-extended", extension = self.extension),
+          });
+        }
+        _ = tokio::time::sleep_until(deadline) => {
+          vlog!("Maximum recording duration reached. Stopping recording...");
+          reached.cancel();
+          return;
+        }
+      }
+    }
+  }
+}
+
+fn seconds(value: i32) -> Duration {
+  return Duration::from_secs(value.max(0) as u64);
+}
+
+/// Sender of the recording currently listening for Enter, if any.
+static ENTER_SUBSCRIBER: Mutex<Option<UnboundedSender<()>>> = Mutex::new(None);
+
+static ENTER_LISTENER: Once = Once::new();
+
+/// Listens for Enter on behalf of the calling recording.
+///
+/// Stdin is read by a single thread for the whole process, started by the
+/// first recording that listens, which passes each line on to the latest
+/// recording. A plain thread is used instead of Tokio's stdin so that a
+/// pending read does not keep the runtime from shutting down once
+/// recording ends.
+fn listen_for_enter() -> UnboundedReceiver<()> {
+  let (sender, receiver) = unbounded_channel();
+  *lock_subscriber() = Some(sender);
+  ENTER_LISTENER.call_once(|| {
+    std::thread::spawn(|| {
+      for line in std::io::stdin().lock().lines() {
+        if line.is_err() {
+          return;
+        }
+        let mut subscriber = lock_subscriber();
+        if let Some(sender) = subscriber.as_ref()
+          && sender.send(()).is_err()
+        {
+          *subscriber = None;
+        }
+      }
+    });
+  });
+  return receiver;
+}
+
+fn lock_subscriber() -> MutexGuard<'static, Option<UnboundedSender<()>>> {
+  return ENTER_SUBSCRIBER.lock().unwrap_or_else(|e| e.into_inner());
+}
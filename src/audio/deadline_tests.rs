@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::deadline::RecordingDeadline;
+use crate::events::{Event, EventBus};
+
+type Listen = fn() -> UnboundedReceiver<()>;
+
+#[test]
+fn test_is_active() {
+  assert!(RecordingDeadline::new(60, 10, 0).is_active());
+  assert!(!RecordingDeadline::new(0, 10, 30).is_active());
+  assert!(!RecordingDeadline::new(60, 0, 30).is_active());
+}
+
+#[test]
+fn test_allows_extension() {
+  assert!(RecordingDeadline::new(60, 10, 30).allows_extension());
+  assert!(!RecordingDeadline::new(0, 10, 30).allows_extension());
+  assert!(!RecordingDeadline::new(60, 0, 30).allows_extension());
+  assert!(!RecordingDeadline::new(60, 10, 0).allows_extension());
+}
+
+#[test]
+fn test_is_extendable_requires_extension_settings() {
+  assert!(!RecordingDeadline::new(0, 10, 30).is_extendable());
+  assert!(!RecordingDeadline::new(60, 0, 30).is_extendable());
+  assert!(!RecordingDeadline::new(60, 10, 0).is_extendable());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_warns_before_deadline() {
+  let reached = CancellationToken::new();
+  let start = Instant::now();
+  RecordingDeadline::new(60, 10, 0)
+    .watch_with(reached.clone(), EventBus::new(), None::<Listen>)
+    .await;
+  assert_eq!(start.elapsed(), Duration::from_secs(50));
+  assert!(!reached.is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_sends_warning_as_notice() {
+  let events = EventBus::new();
+  let mut receiver = events.subscribe();
+  RecordingDeadline::new(60, 10, 0)
+    .watch_with(CancellationToken::new(), events, None::<Listen>)
+    .await;
+  let event = receiver.try_recv().unwrap();
+  assert!(matches!(event, Event::Notice { .. }));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_warns_immediately_when_warning_exceeds_limit() {
+  let reached = CancellationToken::new();
+  let start = Instant::now();
+  RecordingDeadline::new(5, 10, 0)
+    .watch_with(reached.clone(), EventBus::new(), None::<Listen>)
+    .await;
+  assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_listens_only_after_warning() {
+  let (sender, receiver) = unbounded_channel();
+  let start = Instant::now();
+  let listened_at = std::sync::Mutex::new(None);
+  RecordingDeadline::new(60, 10, 30)
+    .watch_with(
+      CancellationToken::new(),
+      EventBus::new(),
+      Some(|| {
+        *listened_at.lock().unwrap() = Some(start.elapsed());
+        return receiver;
+      }),
+    )
+    .await;
+  drop(sender);
+  assert_eq!(*listened_at.lock().unwrap(), Some(Duration::from_secs(50)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_stops_at_deadline_without_extension() {
+  let (_sender, receiver) = unbounded_channel();
+  let reached = CancellationToken::new();
+  let start = Instant::now();
+  RecordingDeadline::new(60, 10, 30)
+    .watch_with(reached.clone(), EventBus::new(), Some(|| receiver))
+    .await;
+  assert_eq!(start.elapsed(), Duration::from_secs(60));
+  assert!(reached.is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_extends_deadline() {
+  let (sender, receiver) = unbounded_channel();
+  let reached = CancellationToken::new();
+  let start = Instant::now();
+  tokio::spawn(async move {
+    tokio::time::sleep(Duration::from_secs(55)).await;
+    sender.send(()).unwrap();
+    // Keep listening open so the deadline is not cut short.
+    tokio::time::sleep(Duration::from_secs(3600)).await;
+  });
+  RecordingDeadline::new(60, 10, 30)
+    .watch_with(reached.clone(), EventBus::new(), Some(|| receiver))
+    .await;
+  assert_eq!(start.elapsed(), Duration::from_secs(90));
+  assert!(reached.is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_ignores_input_before_warning() {
+  let (sender, receiver) = unbounded_channel();
+  let reached = CancellationToken::new();
+  let start = Instant::now();
+  sender.send(()).unwrap();
+  RecordingDeadline::new(60, 10, 30)
+    .watch_with(reached.clone(), EventBus::new(), Some(|| receiver))
+    .await;
+  assert_eq!(start.elapsed(), Duration::from_secs(60));
+  assert!(reached.is_cancelled());
+}
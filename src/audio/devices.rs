@@ -5,6 +5,7 @@
 pub struct AudioInputDevice {
   index: String,
   name: String,
+  is_system_default: bool,
 }
 
 impl AudioInputDevice {
@@ -19,7 +20,39 @@ impl AudioInputDevice {
   ///
   /// A new `AudioInputDevice` instance.
   pub fn new(index: String, name: String) -> Self {
-    return AudioInputDevice { index, name };
+    return AudioInputDevice {
+      index,
+      name,
+      is_system_default: false,
+    };
+  }
+
+  /// Marks the device as the audio system's current default input.
+  ///
+  /// # Returns
+  ///
+  /// The same `AudioInputDevice` marked as the system default.
+  pub fn into_system_default(mut self) -> Self {
+    self.is_system_default = true;
+    return self;
+  }
+
+  /// Checks whether this is the placeholder default device.
+  ///
+  /// # Returns
+  ///
+  /// `true` if the device lets the audio system pick the input.
+  pub fn is_default(&self) -> bool {
+    return self.index == "default";
+  }
+
+  /// Checks whether the audio system reports this as its default input.
+  ///
+  /// # Returns
+  ///
+  /// `true` if the device is the system default input.
+  pub fn is_system_default(&self) -> bool {
+    return self.is_system_default;
   }
 
   /// Gets the device index.
@@ -32,6 +65,7 @@ impl AudioInputDevice {
   pub fn get_index(&self) -> &String {
     return &self.index;
   }
+
   /// Gets the device name.
   ///
   /// Returns the human-readable name of the audio device.
@@ -55,3 +89,32 @@ impl Default for AudioInputDevice {
 
 /// Type alias for a collection of audio input devices.
 pub type AudioInputDevices = Vec<AudioInputDevice>;
+
+/// Finds an audio input device by index or name.
+///
+/// An exact match on the device index wins, otherwise the first device whose
+/// name contains the query, ignoring case, is returned.
+///
+/// # Arguments
+///
+/// * `devices` - List of available audio input devices
+/// * `query` - Device index or part of the device name
+///
+/// # Returns
+///
+/// An `Option<AudioInputDevice>` containing the matching device, if any.
+pub fn find_audio_input_device(
+  devices: AudioInputDevices,
+  query: &str,
+) -> Option<AudioInputDevice> {
+  if let Some(device) =
+    devices.iter().find(|device| device.get_index() == query)
+  {
+    return Some(device.clone());
+  }
+
+  let query = query.to_lowercase();
+  return devices
+    .into_iter()
+    .find(|device| device.get_name().to_lowercase().contains(&query));
+}
@@ -0,0 +1,35 @@
+use crate::audio::devices::{AudioInputDevice, find_audio_input_device};
+
+fn create_devices() -> Vec<AudioInputDevice> {
+  return vec![
+    AudioInputDevice::new(
+      String::from("0"),
+      String::from("MacBook Microphone"),
+    ),
+    AudioInputDevice::new(String::from("1"), String::from("USB Microphone 2")),
+    AudioInputDevice::new(String::from("2"), String::from("Studio Display")),
+  ];
+}
+
+#[test]
+fn test_find_audio_input_device_by_index() {
+  let device = find_audio_input_device(create_devices(), "0").unwrap();
+  assert_eq!(device.get_name(), "MacBook Microphone");
+}
+
+#[test]
+fn test_find_audio_input_device_by_name() {
+  let device = find_audio_input_device(create_devices(), "usb").unwrap();
+  assert_eq!(device.get_index(), "1");
+}
+
+#[test]
+fn test_find_audio_input_device_prefers_index_over_name() {
+  let device = find_audio_input_device(create_devices(), "2").unwrap();
+  assert_eq!(device.get_index(), "2");
+}
+
+#[test]
+fn test_find_audio_input_device_no_match() {
+  assert!(find_audio_input_device(create_devices(), "Headset").is_none());
+}
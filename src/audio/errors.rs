@@ -1,37 +1,169 @@
+use std::process::ExitStatus;
+
 use thiserror::Error;
 
+use crate::files::errors::FileError;
+use crate::process::errors::ProcessError;
+
+/// Number of FFmpeg error output lines kept in [`FfmpegError::Exited`].
+pub(crate) const FFMPEG_OUTPUT_LINES: usize = 5;
+
 /// Audio-related errors.
 ///
 /// Represents errors that can occur during audio recording and conversion operations.
 #[derive(Error, Debug)]
 pub enum AudioError {
-  #[error("Audio file not found: '{0}'. Please check the file path.")]
+  #[error(
+    "[LUM-AUD-001] Audio file not found: '{0}'. Please check the file path."
+  )]
   FileNotFound(String),
 
   #[error(
-    "Failed to convert audio to Whisper format. Please check FFmpeg installation and file permissions."
+    "[LUM-AUD-002] Failed to convert audio to Whisper format. Please check FFmpeg installation and file permissions."
   )]
-  ConversionFailed,
+  ConversionFailed(#[source] FfmpegError),
 
   #[error(
-    "FFmpeg not found. Please install FFmpeg and ensure it's in your PATH."
+    "[LUM-AUD-003] FFmpeg not found. Please install FFmpeg and ensure it's in your PATH."
   )]
-  FFMPEGNotFound,
+  FFMPEGNotFound(#[source] FfmpegError),
 
   #[error(
-    "Failed to run FFmpeg. Please check if FFmpeg is properly installed and has permission to access audio devices."
+    "[LUM-AUD-004] Failed to run FFmpeg. Please check if FFmpeg is properly installed and has permission to access audio devices."
   )]
-  CouldNotExecuteFFMPEG,
+  CouldNotExecuteFFMPEG(#[source] FfmpegError),
 
   #[error(
-    "Unable to read FFmpeg output. This might be due to permission issues or corrupted FFmpeg installation."
+    "[LUM-AUD-005] Unable to read FFmpeg output. This might be due to permission issues or corrupted FFmpeg installation."
   )]
   CouldNotReadFFMPEGOutput,
 
   #[error(
-    "Cannot create recordings directory. Please check file permissions and available disk space."
+    "[LUM-AUD-006] Cannot create recordings directory. Please check file permissions and available disk space."
+  )]
+  CouldNotCreateDirectory(#[source] FileError),
+
+  #[error(
+    "[LUM-AUD-007] Cannot read WAV file: '{0}'. The file may be missing or not a valid WAV file."
+  )]
+  CouldNotReadWav(String, #[source] std::io::Error),
+
+  #[error(
+    "[LUM-AUD-008] Recording was discarded because it lasted {0:.1}s, which is shorter than the minimum of {1}s."
+  )]
+  RecordingTooShort(f64, i32),
+
+  #[error("[LUM-AUD-009] No microphone is available inside the sandbox. {0}")]
+  SandboxMicrophoneAccess(String),
+
+  #[error(
+    "[LUM-AUD-010] Cannot open stream: '{0}'. Please check the URL and that the stream is live."
+  )]
+  CouldNotOpenStream(String),
+
+  #[error("[LUM-AUD-011] Recording cancelled.")]
+  Cancelled,
+
+  #[error(
+    "[LUM-AUD-012] Unknown recorder backend: '{0}'. Please use ffmpeg, native or gstreamer."
+  )]
+  UnknownRecorderBackend(String),
+
+  #[error(
+    "[LUM-AUD-013] The '{0}' recorder backend is not included in this build. Please rebuild Lumine with its feature enabled."
+  )]
+  RecorderBackendNotBuilt(String),
+
+  #[error("[LUM-AUD-014] Audio capture failed: {0}")]
+  CaptureFailed(String),
+
+  #[error(
+    "[LUM-AUD-015] GStreamer not found. Please install gst-launch-1.0 with the base and good plugins and ensure it's in your PATH."
   )]
-  CouldNotCreateDirectory,
+  GStreamerNotFound(#[source] ProcessError),
+
+  #[error(
+    "[LUM-AUD-016] Could not play the recording. Please check that ffplay, which comes with FFmpeg, is installed and an output device is available."
+  )]
+  PlaybackFailed(#[source] FfmpegError),
+}
+
+impl AudioError {
+  /// Returns the stable code of this error, such as `LUM-AUD-001`.
+  pub fn code(&self) -> &'static str {
+    return match self {
+      AudioError::FileNotFound(..) => "LUM-AUD-001",
+      AudioError::ConversionFailed(..) => "LUM-AUD-002",
+      AudioError::FFMPEGNotFound(..) => "LUM-AUD-003",
+      AudioError::CouldNotExecuteFFMPEG(..) => "LUM-AUD-004",
+      AudioError::CouldNotReadFFMPEGOutput => "LUM-AUD-005",
+      AudioError::CouldNotCreateDirectory(..) => "LUM-AUD-006",
+      AudioError::CouldNotReadWav(..) => "LUM-AUD-007",
+      AudioError::RecordingTooShort(..) => "LUM-AUD-008",
+      AudioError::SandboxMicrophoneAccess(..) => "LUM-AUD-009",
+      AudioError::CouldNotOpenStream(..) => "LUM-AUD-010",
+      AudioError::Cancelled => "LUM-AUD-011",
+      AudioError::UnknownRecorderBackend(..) => "LUM-AUD-012",
+      AudioError::RecorderBackendNotBuilt(..) => "LUM-AUD-013",
+      AudioError::CaptureFailed(..) => "LUM-AUD-014",
+      AudioError::GStreamerNotFound(..) => "LUM-AUD-015",
+      AudioError::PlaybackFailed(..) => "LUM-AUD-016",
+    };
+  }
+}
+
+/// Failure of an FFmpeg process.
+///
+/// Kept as the source of audio errors, so verbose output shows why FFmpeg
+/// failed, such as a missing device or an unsupported filter.
+#[derive(Error, Debug)]
+pub enum FfmpegError {
+  #[error(transparent)]
+  Process(#[from] ProcessError),
+
+  #[error("FFmpeg exited with {status}{}", format_output(.output))]
+  Exited { status: ExitStatus, output: String },
+
+  #[error("FFmpeg did not report its version: '{0}'")]
+  UnknownVersion(String),
+
+  #[error(transparent)]
+  File(#[from] FileError),
+}
+
+impl FfmpegError {
+  /// Creates the error for an FFmpeg process that exited unsuccessfully.
+  ///
+  /// Only the last lines of the error output are kept, since FFmpeg prints
+  /// its banner and stream details before the actual error.
+  ///
+  /// # Arguments
+  ///
+  /// * `status` - Exit status of the process
+  /// * `stderr` - Error output of the process
+  ///
+  /// # Returns
+  ///
+  /// A new `FfmpegError::Exited`.
+  pub fn exited(status: ExitStatus, stderr: &str) -> Self {
+    let lines: Vec<&str> = stderr
+      .lines()
+      .map(|line| line.trim())
+      .filter(|line| !line.is_empty())
+      .collect();
+    let start = lines.len().saturating_sub(FFMPEG_OUTPUT_LINES);
+    return FfmpegError::Exited {
+      status,
+      output: lines[start..].join("; "),
+    };
+  }
+}
+
+fn format_output(output: &str) -> String {
+  if output.is_empty() {
+    return String::new();
+  }
+  return format!(": {}", output);
 }
 
 /// Result type for audio operations.
@@ -0,0 +1,25 @@
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+use crate::audio::FfmpegError;
+
+#[test]
+fn test_ffmpeg_exited_keeps_last_lines() {
+  let stderr = "ffmpeg version 7.1\n  built with gcc\n\nInput #0, pulse\n\
+    line 1\nline 2\nline 3\n[pulse @ 0x1] Connection refused\n\
+    default: Input/output error\n";
+  let error = FfmpegError::exited(ExitStatus::from_raw(256), stderr);
+
+  assert_eq!(
+    error.to_string(),
+    "FFmpeg exited with exit status: 1: line 1; line 2; line 3; \
+     [pulse @ 0x1] Connection refused; default: Input/output error"
+  );
+}
+
+#[test]
+fn test_ffmpeg_exited_without_output() {
+  let error = FfmpegError::exited(ExitStatus::from_raw(256), "\n");
+
+  assert_eq!(error.to_string(), "FFmpeg exited with exit status: 1");
+}
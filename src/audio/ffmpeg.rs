@@ -0,0 +1,102 @@
+use tokio::process::Child;
+
+use crate::process::errors::ProcessResult;
+use crate::process::executor::{CommandOutput, ProcessExecutor};
+
+const FFMPEG_COMMAND: &str = "ffmpeg";
+const FFPLAY_COMMAND: &str = "ffplay";
+/// Log level of FFmpeg unless the caller sets one; silencedetect events
+/// and device lists are logged at this level.
+const DEFAULT_LOG_LEVEL: &str = "info";
+const LOG_LEVEL_OPTIONS: [&str; 2] = ["-loglevel", "-v"];
+
+/// Runs FFmpeg and captures its output.
+///
+/// # Arguments
+///
+/// * `args` - Arguments to pass to FFmpeg
+///
+/// # Returns
+///
+/// A `ProcessResult<CommandOutput>` containing the output of FFmpeg or an
+/// error if it could not be started.
+pub(crate) async fn run(args: &[&str]) -> ProcessResult<CommandOutput> {
+  return ProcessExecutor::run(FFMPEG_COMMAND, &ffmpeg_arguments(args)).await;
+}
+
+/// Runs FFmpeg reading the standard input of this process.
+///
+/// # Arguments
+///
+/// * `args` - Arguments to pass to FFmpeg
+///
+/// # Returns
+///
+/// A `ProcessResult<CommandOutput>` containing the output of FFmpeg or an
+/// error if it could not be started.
+pub(crate) async fn run_with_stdin(
+  args: &[&str],
+) -> ProcessResult<CommandOutput> {
+  return ProcessExecutor::run_with_stdin(
+    FFMPEG_COMMAND,
+    &ffmpeg_arguments(args),
+  )
+  .await;
+}
+
+/// Starts FFmpeg with its error output piped, for reading events while it
+/// runs.
+///
+/// # Arguments
+///
+/// * `args` - Arguments to pass to FFmpeg
+///
+/// # Returns
+///
+/// A `ProcessResult<Child>` containing the running process or an error if
+/// it could not be started.
+pub(crate) async fn spawn(args: &[&str]) -> ProcessResult<Child> {
+  return ProcessExecutor::spawn_with_stderr_piped(
+    FFMPEG_COMMAND,
+    &ffmpeg_arguments(args),
+  )
+  .await;
+}
+
+/// Plays an audio file with ffplay, which comes with FFmpeg, and waits
+/// until it finished.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to the audio file
+///
+/// # Returns
+///
+/// A `ProcessResult<CommandOutput>` containing the output of ffplay or an
+/// error if it could not be started.
+pub(crate) async fn play(file_path: &str) -> ProcessResult<CommandOutput> {
+  let args = ["-nodisp", "-autoexit", "-loglevel", "error", file_path];
+  return ProcessExecutor::run(FFPLAY_COMMAND, &ffmpeg_arguments(&args)).await;
+}
+
+/// Prepends the arguments every FFmpeg invocation shares.
+///
+/// The banner is hidden, since it only adds noise to the output that is
+/// parsed, and the log level is pinned to the level of the lines Lumine
+/// reads, unless the invocation sets its own.
+///
+/// # Arguments
+///
+/// * `args` - Arguments of the invocation
+///
+/// # Returns
+///
+/// The arguments with the shared ones in front.
+pub(crate) fn ffmpeg_arguments<'a>(args: &[&'a str]) -> Vec<&'a str> {
+  let mut arguments = vec!["-hide_banner"];
+  if !args.iter().any(|arg| LOG_LEVEL_OPTIONS.contains(arg)) {
+    arguments.extend(["-loglevel", DEFAULT_LOG_LEVEL]);
+  }
+  arguments.extend_from_slice(args);
+  return arguments;
+}
@@ -0,0 +1,205 @@
+use crate::audio::errors::AudioResult;
+use crate::audio::wav::WavInspector;
+
+/// Length of the frames whose energies are compared, in seconds.
+const FRAME_SECONDS: f64 = 0.1;
+/// Largest shift between two fingerprints that is still compared, in frames.
+const MAX_OFFSET_FRAMES: i64 = 3;
+/// Smallest number of compared bits for a meaningful similarity.
+const MIN_OVERLAP_BITS: usize = 10;
+/// Similarity from which two fingerprints are considered the same audio.
+const MATCH_SIMILARITY: f64 = 0.9;
+/// Largest duration difference of the same audio, in seconds.
+const DURATION_TOLERANCE: f64 = 1.0;
+
+/// Cheap fingerprint of audio content.
+///
+/// Stores whether the energy rises from one 100 ms frame to the next. The
+/// pattern survives re-encoding, resampling and volume changes, so files
+/// with the same content but different bytes get similar fingerprints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioFingerprint {
+  duration: f64,
+  bits: Vec<bool>,
+}
+
+impl AudioFingerprint {
+  /// Computes the fingerprint of a 16-bit PCM WAV file.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the WAV file
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<AudioFingerprint>` containing the fingerprint or an
+  /// error if the file could not be read.
+  pub fn from_wav(file_path: &str) -> AudioResult<Self> {
+    let mut samples = WavInspector::stream_samples(file_path)?;
+    let mut builder = FingerprintBuilder::new(samples.sample_rate());
+    while let Some(chunk) = samples.next_chunk() {
+      builder.push(&chunk);
+    }
+    return Ok(builder.finish());
+  }
+
+  /// Restores a fingerprint from its encoded form.
+  ///
+  /// # Arguments
+  ///
+  /// * `duration` - Duration of the audio in seconds
+  /// * `encoded` - Fingerprint encoded by [`AudioFingerprint::encode`]
+  ///
+  /// # Returns
+  ///
+  /// The decoded `AudioFingerprint`, or `None` if the encoding is invalid.
+  pub fn decode(duration: f64, encoded: &str) -> Option<Self> {
+    let (length, hex) = encoded.split_once(':')?;
+    let length: usize = length.parse().ok()?;
+    let mut bits = Vec::with_capacity(length);
+    for digit in hex.chars() {
+      let value = digit.to_digit(16)?;
+      for shift in (0..4).rev() {
+        bits.push(value >> shift & 1 == 1);
+      }
+    }
+    if bits.len() < length || bits.len() - length >= 4 {
+      return None;
+    }
+    bits.truncate(length);
+    return Some(AudioFingerprint { duration, bits });
+  }
+
+  /// Encodes the fingerprint bits as text.
+  ///
+  /// # Returns
+  ///
+  /// A `String` with the number of bits and the bits in hexadecimal.
+  pub fn encode(&self) -> String {
+    let hex: String = self
+      .bits
+      .chunks(4)
+      .map(|chunk| {
+        let value = chunk
+          .iter()
+          .chain(std::iter::repeat(&false))
+          .take(4)
+          .fold(0, |value, bit| value << 1 | *bit as u32);
+        return char::from_digit(value, 16).unwrap_or('0');
+      })
+      .collect();
+    return format!("{}:{}", self.bits.len(), hex);
+  }
+
+  /// Gets the duration of the fingerprinted audio.
+  ///
+  /// # Returns
+  ///
+  /// The duration in seconds.
+  pub fn duration(&self) -> f64 {
+    return self.duration;
+  }
+
+  /// Measures how similar two fingerprints are.
+  ///
+  /// Small shifts, such as encoder delays, are tolerated by comparing the
+  /// fingerprints at a few offsets and keeping the best match.
+  ///
+  /// # Arguments
+  ///
+  /// * `other` - The fingerprint to compare with
+  ///
+  /// # Returns
+  ///
+  /// The share of matching bits, between 0.0 and 1.0.
+  pub fn similarity(&self, other: &AudioFingerprint) -> f64 {
+    let mut best: f64 = 0.0;
+    for offset in -MAX_OFFSET_FRAMES..=MAX_OFFSET_FRAMES {
+      let pairs: Vec<(bool, bool)> = self
+        .bits
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bit)| {
+          let other_index = usize::try_from(index as i64 + offset).ok()?;
+          return Some((*bit, *other.bits.get(other_index)?));
+        })
+        .collect();
+      if pairs.len() < MIN_OVERLAP_BITS {
+        continue;
+      }
+      let matching = pairs.iter().filter(|(a, b)| a == b).count();
+      best = best.max(matching as f64 / pairs.len() as f64);
+    }
+    return best;
+  }
+
+  /// Checks whether two fingerprints belong to the same audio content.
+  ///
+  /// # Arguments
+  ///
+  /// * `other` - The fingerprint to compare with
+  ///
+  /// # Returns
+  ///
+  /// A `bool` indicating whether the durations and fingerprints match.
+  pub fn matches(&self, other: &AudioFingerprint) -> bool {
+    return (self.duration - other.duration).abs() <= DURATION_TOLERANCE
+      && self.similarity(other) >= MATCH_SIMILARITY;
+  }
+}
+
+/// Computes a fingerprint from samples pushed in chunks of any size.
+struct FingerprintBuilder {
+  sample_rate: u32,
+  frame_size: usize,
+  frame_energy: f64,
+  frame_samples: usize,
+  samples: usize,
+  previous_energy: Option<f64>,
+  bits: Vec<bool>,
+}
+
+impl FingerprintBuilder {
+  fn new(sample_rate: u32) -> Self {
+    return FingerprintBuilder {
+      sample_rate: sample_rate.max(1),
+      frame_size: ((sample_rate as f64 * FRAME_SECONDS) as usize).max(1),
+      frame_energy: 0.0,
+      frame_samples: 0,
+      samples: 0,
+      previous_energy: None,
+      bits: Vec::new(),
+    };
+  }
+
+  fn push(&mut self, samples: &[i16]) {
+    for sample in samples {
+      self.frame_energy += (*sample as f64).powi(2);
+      self.frame_samples += 1;
+      if self.frame_samples == self.frame_size {
+        self.end_frame();
+      }
+    }
+    self.samples += samples.len();
+  }
+
+  fn end_frame(&mut self) {
+    let energy = self.frame_energy / self.frame_samples as f64;
+    if let Some(previous) = self.previous_energy {
+      self.bits.push(energy > previous);
+    }
+    self.previous_energy = Some(energy);
+    self.frame_energy = 0.0;
+    self.frame_samples = 0;
+  }
+
+  fn finish(mut self) -> AudioFingerprint {
+    if self.frame_samples > 0 {
+      self.end_frame();
+    }
+    return AudioFingerprint {
+      duration: self.samples as f64 / self.sample_rate as f64,
+      bits: self.bits,
+    };
+  }
+}
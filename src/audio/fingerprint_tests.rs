@@ -0,0 +1,88 @@
+use crate::audio::fingerprint::AudioFingerprint;
+
+const SAMPLE_RATE: u32 = 16000;
+
+fn create_samples(seconds: usize, seed: u64, gain: f64) -> Vec<i16> {
+  let mut state = seed;
+  let mut samples = Vec::new();
+  for _ in 0..seconds * 10 {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+    let level = (state >> 33) as f64 / u32::MAX as f64 * 20000.0;
+    for index in 0..SAMPLE_RATE as usize / 10 {
+      let sign = if index % 2 == 0 { 1.0 } else { -1.0 };
+      samples.push((level * gain * sign) as i16);
+    }
+  }
+  return samples;
+}
+
+fn fingerprint(name: &str, samples: &[i16]) -> AudioFingerprint {
+  let file_path = std::env::temp_dir().join(name);
+  let spec = hound::WavSpec {
+    channels: 1,
+    sample_rate: SAMPLE_RATE,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&file_path, spec).unwrap();
+  for sample in samples {
+    writer.write_sample(*sample).unwrap();
+  }
+  writer.finalize().unwrap();
+
+  let fingerprint =
+    AudioFingerprint::from_wav(&file_path.to_string_lossy()).unwrap();
+  std::fs::remove_file(&file_path).unwrap();
+  return fingerprint;
+}
+
+#[test]
+fn test_fingerprint_matches_quieter_copy() {
+  let original =
+    fingerprint("test_fp_original.wav", &create_samples(30, 7, 1.0));
+  let quieter = fingerprint("test_fp_quieter.wav", &create_samples(30, 7, 0.5));
+
+  assert!((original.duration() - 30.0).abs() < f64::EPSILON);
+  assert!(original.matches(&quieter));
+}
+
+#[test]
+fn test_fingerprint_tolerates_small_shift() {
+  let samples = create_samples(30, 7, 1.0);
+  let original = fingerprint("test_fp_shift_original.wav", &samples);
+  let shifted = fingerprint("test_fp_shifted.wav", &samples[3200..]);
+
+  assert!(original.matches(&shifted));
+}
+
+#[test]
+fn test_fingerprint_rejects_other_audio() {
+  let first = fingerprint("test_fp_first.wav", &create_samples(30, 7, 1.0));
+  let second = fingerprint("test_fp_second.wav", &create_samples(30, 8, 1.0));
+  let longer = fingerprint("test_fp_longer.wav", &create_samples(40, 7, 1.0));
+
+  assert!(!first.matches(&second));
+  assert!(!first.matches(&longer));
+}
+
+#[test]
+fn test_fingerprint_encoding_round_trip() {
+  let fingerprint =
+    fingerprint("test_fp_encoding.wav", &create_samples(3, 7, 1.0));
+  let encoded = fingerprint.encode();
+
+  assert!(encoded.starts_with("29:"));
+  assert_eq!(
+    AudioFingerprint::decode(fingerprint.duration(), &encoded),
+    Some(fingerprint)
+  );
+  assert!(AudioFingerprint::decode(3.0, "29:zz").is_none());
+  assert!(AudioFingerprint::decode(3.0, "2:ff").is_none());
+}
+
+#[test]
+fn test_fingerprint_of_sample_file() {
+  let fingerprint = AudioFingerprint::from_wav("sample/jfk.wav").unwrap();
+  assert!(fingerprint.duration() > 10.0 && fingerprint.duration() < 12.0);
+  assert!(fingerprint.matches(&fingerprint));
+}
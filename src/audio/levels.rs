@@ -0,0 +1,105 @@
+use crate::audio::errors::AudioResult;
+use crate::audio::silence::MIN_LEVEL_DB;
+use crate::audio::wav::WavInspector;
+
+/// Peak level below which a recording holds no signal, in dBFS.
+const SILENT_PEAK_DB: f64 = -80.0;
+/// Peak level from which a recording is considered clipped, in dBFS.
+const CLIPPING_PEAK_DB: f64 = -0.1;
+/// Full scale of 16-bit samples.
+const FULL_SCALE: f64 = 32768.0;
+
+/// Peak and RMS levels of a recording.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct AudioLevels {
+  /// Level of the loudest sample in decibels below full scale
+  pub peak_db: f64,
+  /// Root mean square level in decibels below full scale
+  pub rms_db: f64,
+}
+
+/// Verdict on whether an input device records usable audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceCheck {
+  /// The device records audio at a usable level
+  Works,
+  /// The device records no signal, as when muted or disconnected
+  Silent,
+  /// The device records audio below the silence threshold
+  TooQuiet,
+  /// The device records audio that reaches full scale
+  Clipping,
+}
+
+impl AudioLevels {
+  /// Measures the levels of a 16-bit PCM WAV file.
+  ///
+  /// Only the first channel is measured.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the WAV file
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<AudioLevels>` containing the levels or an error if the
+  /// file could not be read.
+  pub fn from_wav(file_path: &str) -> AudioResult<Self> {
+    let mut samples = WavInspector::stream_samples(file_path)?;
+    let mut peak: f64 = 0.0;
+    let mut power = 0.0;
+    let mut count: u64 = 0;
+    while let Some(chunk) = samples.next_chunk() {
+      count += chunk.len() as u64;
+      for sample in chunk {
+        let value = sample as f64 / FULL_SCALE;
+        peak = peak.max(value.abs());
+        power += value * value;
+      }
+    }
+
+    let rms = if count > 0 {
+      (power / count as f64).sqrt()
+    } else {
+      0.0
+    };
+    return Ok(Self {
+      peak_db: to_db(peak),
+      rms_db: to_db(rms),
+    });
+  }
+
+  /// Checks whether the levels show a working input device.
+  ///
+  /// Silence detection compares each sample with the noise threshold, so a
+  /// recording whose peak stays below it is silence as a whole.
+  ///
+  /// # Arguments
+  ///
+  /// * `silence_detect_noise` - Noise threshold in dB below which audio
+  ///   counts as silence
+  ///
+  /// # Returns
+  ///
+  /// The `DeviceCheck` verdict for the levels.
+  pub fn check(&self, silence_detect_noise: i32) -> DeviceCheck {
+    if self.peak_db < SILENT_PEAK_DB {
+      return DeviceCheck::Silent;
+    }
+    if self.peak_db >= CLIPPING_PEAK_DB {
+      return DeviceCheck::Clipping;
+    }
+    if self.peak_db < -(silence_detect_noise.abs() as f64) {
+      return DeviceCheck::TooQuiet;
+    }
+    return DeviceCheck::Works;
+  }
+}
+
+fn to_db(amplitude: f64) -> f64 {
+  if amplitude <= 0.0 {
+    return MIN_LEVEL_DB;
+  }
+  return (20.0 * amplitude.log10()).clamp(MIN_LEVEL_DB, 0.0);
+}
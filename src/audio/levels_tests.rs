@@ -0,0 +1,57 @@
+use crate::audio::levels::{AudioLevels, DeviceCheck};
+
+fn levels(name: &str, samples: &[i16]) -> AudioLevels {
+  let file_path = std::env::temp_dir().join(name);
+  let spec = hound::WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&file_path, spec).unwrap();
+  for sample in samples {
+    writer.write_sample(*sample).unwrap();
+  }
+  writer.finalize().unwrap();
+
+  let levels = AudioLevels::from_wav(&file_path.to_string_lossy()).unwrap();
+  std::fs::remove_file(&file_path).unwrap();
+  return levels;
+}
+
+#[test]
+fn test_levels_of_square_wave() {
+  let samples: Vec<i16> = (0..16000)
+    .map(|index| if index % 2 == 0 { 16384 } else { -16384 })
+    .collect();
+
+  let levels = levels("test_levels_square.wav", &samples);
+
+  assert!((levels.peak_db - -6.0206).abs() < 1e-3);
+  assert!((levels.rms_db - -6.0206).abs() < 1e-3);
+}
+
+#[test]
+fn test_levels_of_digital_silence() {
+  let levels = levels("test_levels_silence.wav", &[0; 1600]);
+
+  assert_eq!(levels.peak_db, -100.0);
+  assert_eq!(levels.rms_db, -100.0);
+}
+
+#[test]
+fn test_levels_of_missing_file() {
+  assert!(AudioLevels::from_wav("missing_levels.wav").is_err());
+}
+
+#[test]
+fn test_check_levels() {
+  let check = |peak_db, rms_db| {
+    return AudioLevels { peak_db, rms_db }.check(40);
+  };
+
+  assert_eq!(check(-12.0, -30.0), DeviceCheck::Works);
+  assert_eq!(check(-100.0, -100.0), DeviceCheck::Silent);
+  assert_eq!(check(-45.0, -60.0), DeviceCheck::TooQuiet);
+  assert_eq!(check(0.0, -10.0), DeviceCheck::Clipping);
+}
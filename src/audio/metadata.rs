@@ -0,0 +1,75 @@
+use chrono::{DateTime, Local};
+
+const ORIGINATOR_MAX_LENGTH: usize = 32;
+
+/// Provenance metadata written into recorded WAV files.
+///
+/// Stored as a RIFF `LIST/INFO` chunk and a Broadcast WAV `bext` chunk so
+/// that archived recordings carry more than their filename.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordingMetadata {
+  title: String,
+  created_at: DateTime<Local>,
+  device: String,
+}
+
+impl RecordingMetadata {
+  /// Creates new recording metadata.
+  ///
+  /// # Arguments
+  ///
+  /// * `title` - Title of the recording
+  /// * `created_at` - Time the recording started
+  /// * `device` - Name of the audio input device
+  ///
+  /// # Returns
+  ///
+  /// A new `RecordingMetadata` instance.
+  pub fn new(
+    title: String,
+    created_at: DateTime<Local>,
+    device: String,
+  ) -> Self {
+    return Self {
+      title,
+      created_at,
+      device,
+    };
+  }
+
+  /// Builds FFmpeg output options that write the metadata.
+  ///
+  /// # Returns
+  ///
+  /// Vector of FFmpeg arguments to place before the output file.
+  pub fn to_ffmpeg_arguments(&self) -> Vec<String> {
+    let originator: String = format!("Lumine {}", env!("CARGO_PKG_VERSION"))
+      .chars()
+      .take(ORIGINATOR_MAX_LENGTH)
+      .collect();
+    let comment = format!("Recorded with {} from {}", originator, self.device);
+
+    let entries = [
+      ("title", self.title.clone()),
+      ("date", self.created_at.format("%Y-%m-%d").to_string()),
+      ("comment", comment.clone()),
+      ("description", comment),
+      ("originator", originator),
+      (
+        "origination_date",
+        self.created_at.format("%Y-%m-%d").to_string(),
+      ),
+      (
+        "origination_time",
+        self.created_at.format("%H:%M:%S").to_string(),
+      ),
+    ];
+
+    let mut args = vec!["-write_bext".to_string(), "1".to_string()];
+    for (key, value) in entries {
+      args.push("-metadata".to_string());
+      args.push(format!("{}={}", key, value));
+    }
+    return args;
+  }
+}
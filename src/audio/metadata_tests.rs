@@ -0,0 +1,26 @@
+use chrono::{Local, TimeZone};
+
+use crate::audio::metadata::RecordingMetadata;
+
+#[test]
+fn test_to_ffmpeg_arguments() {
+  let created_at = Local.with_ymd_and_hms(2025, 3, 14, 9, 26, 53).unwrap();
+  let metadata = RecordingMetadata::new(
+    String::from("audiocapture_2025-03-14_09-26-53"),
+    created_at,
+    String::from("USB Microphone"),
+  );
+
+  let args = metadata.to_ffmpeg_arguments();
+  assert_eq!(args[0..2], ["-write_bext", "1"]);
+  assert!(
+    args.contains(&String::from("title=audiocapture_2025-03-14_09-26-53"))
+  );
+  assert!(args.contains(&String::from("date=2025-03-14")));
+  assert!(args.contains(&String::from("origination_time=09:26:53")));
+  assert!(
+    args
+      .iter()
+      .any(|arg| arg.starts_with("comment=") && arg.ends_with("USB Microphone"))
+  );
+}
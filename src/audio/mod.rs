@@ -7,27 +7,110 @@
 //! ## Module Structure
 //!
 //! - [`Audio`]: Main coordinator for recording and conversion operations
-//! - [`AudioRecorder`]: Platform-specific audio recording implementation
+//! - [`RecorderSettings`]: Recording parameters resolved from configuration
+//! - [`AudioRecorder`]: Device selection and resuming shared by all backends
+//! - [`RecorderBackend`]: Capture backend trait (FFmpeg, native with cpal,
+//!   GStreamer)
+//! - [`SilenceTracker`]: Measures silence from FFmpeg silencedetect output
+//! - [`parser`]: Parses device lists, versions and silencedetect events from
+//!   FFmpeg output, which always runs in the C locale
+//! - [`AudioInterval`]: Time interval of silence or speech in a recording
+//! - [`RecordingDeadline`]: Maximum duration warning and extension handling
 //! - [`AudioConverter`]: Audio format conversion to Whisper-compatible format
+//! - [`RawAudioFormat`]: Format of headerless PCM input from FIFOs or stdin
+//! - [`StreamCapture`]: Live capture of network streams in Whisper-ready chunks
+//! - [`WavInspector`]: Reads properties such as duration from WAV files
+//! - [`AudioFingerprint`]: Cheap fingerprint for spotting re-encoded duplicates
+//! - [`detect_music`]: Finds long music-only regions such as podcast intros
+//! - [`AudioLevels`]: Peak and RMS levels used to check input devices
+//! - [`RecordingResult`]: Recorded file with its device, duration and speech ratio
+//! - [`RecordingMetadata`]: Provenance tags written into recorded WAV files
 //! - [`AudioInputDevice`]: Represents available audio input devices
 //! - [`AudioPlatform`]: Platform abstraction trait (macOS/Linux)
+//! - [`Sandbox`]: Flatpak and Snap detection for microphone access on Linux
 //!
 //! ## Platform Support
 //!
 //! - **macOS**: Uses AVFoundation framework via FFmpeg
-//! - **Linux**: Uses PulseAudio via FFmpeg
+//! - **Linux**: Uses PulseAudio via FFmpeg, which also works with PipeWire and
+//!   inside Flatpak and Snap sandboxes that grant access to the audio socket
 //! - **Windows**: Not supported (compile-time error)
 
+mod backend;
 mod converter;
+mod deadline;
 mod devices;
 mod errors;
+mod ffmpeg;
+mod fingerprint;
+mod levels;
+mod metadata;
+mod music;
+mod parser;
 mod platform;
+mod raw;
 mod recorder;
+mod results;
+#[cfg(target_os = "linux")]
+mod sandbox;
+mod settings;
+mod silence;
+mod stream;
+mod wav;
 
+#[cfg(test)]
+mod converter_tests;
+#[cfg(test)]
+mod deadline_tests;
+#[cfg(test)]
+mod devices_tests;
+#[cfg(test)]
+mod errors_tests;
+#[cfg(test)]
+mod fingerprint_tests;
+#[cfg(test)]
+mod levels_tests;
+#[cfg(test)]
+mod metadata_tests;
+#[cfg(test)]
+mod music_tests;
+#[cfg(test)]
+mod parser_tests;
+#[cfg(test)]
+mod raw_tests;
+#[cfg(test)]
+mod recorder_tests;
+#[cfg(all(test, target_os = "linux"))]
+mod sandbox_tests;
+#[cfg(test)]
+mod settings_tests;
+#[cfg(test)]
+mod silence_tests;
+#[cfg(test)]
+mod stream_tests;
+#[cfg(test)]
+mod wav_tests;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::backend::{RecorderBackend, create_backend};
 use crate::audio::converter::AudioConverter;
 use crate::audio::errors::AudioResult;
-use crate::audio::platform::get_platform;
 use crate::audio::recorder::AudioRecorder;
+use crate::events::EventBus;
+
+pub use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+pub use crate::audio::errors::{AudioError, FfmpegError};
+pub use crate::audio::fingerprint::AudioFingerprint;
+pub use crate::audio::levels::{AudioLevels, DeviceCheck};
+pub use crate::audio::music::{MusicRange, detect_music};
+pub use crate::audio::raw::{RawAudioFormat, STDIN_INPUT};
+pub use crate::audio::results::RecordingResult;
+pub use crate::audio::settings::{DeviceOverrides, RecorderSettings};
+pub use crate::audio::silence::AudioInterval;
+pub use crate::audio::stream::{StreamCapture, is_stream_url};
+#[cfg(feature = "cli")]
+pub(crate) use crate::audio::wav::WavInspector;
 
 /// Main audio recording and conversion coordinator.
 ///
@@ -35,11 +118,10 @@ use crate::audio::recorder::AudioRecorder;
 /// implementations and configured settings.
 #[derive(Debug, Clone)]
 pub struct Audio {
-  recordings_directory: String,
-  silence_limit: i32,
-  silence_detect_noise: i32,
-  preferred_audio_input_device: String,
-  max_recording_duration: i32,
+  settings: RecorderSettings,
+  cancel: CancellationToken,
+  stop: CancellationToken,
+  events: EventBus,
 }
 
 impl Audio {
@@ -47,52 +129,146 @@ impl Audio {
   ///
   /// # Arguments
   ///
-  /// * `recordings_directory` - Directory path to save audio recordings
-  /// * `silence_limit` - Seconds of silence before stopping recording
-  /// * `silence_detect_noise` - Noise threshold in decibels for silence detection
-  /// * `preferred_audio_input_device` - Name of preferred audio input device
-  /// * `max_recording_duration` - Maximum recording duration in seconds (0 for unlimited)
+  /// * `settings` - Recorder settings resolved from configuration
+  /// * `cancel` - Token that stops and discards a recording when cancelled
+  /// * `events` - Bus that receives recording progress events
   ///
   /// # Returns
   ///
   /// A new `Audio` instance configured with the provided settings.
   pub fn new(
-    recordings_directory: String,
-    silence_limit: i32,
-    silence_detect_noise: i32,
-    preferred_audio_input_device: String,
-    max_recording_duration: i32,
+    settings: RecorderSettings,
+    cancel: CancellationToken,
+    events: EventBus,
   ) -> Self {
     return Audio {
-      recordings_directory,
-      silence_limit,
-      silence_detect_noise,
-      preferred_audio_input_device,
-      max_recording_duration,
+      settings,
+      cancel,
+      stop: CancellationToken::new(),
+      events,
     };
   }
 
+  /// Sets the token that ends recordings early.
+  ///
+  /// Unlike cancelling, stopping keeps what was recorded so far, as if
+  /// silence detection had ended the recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `stop` - Token that stops and keeps a recording when cancelled
+  pub fn set_stop_token(&mut self, stop: CancellationToken) {
+    self.stop = stop;
+  }
+
+  /// Sets whether silence ends recordings.
+  ///
+  /// # Arguments
+  ///
+  /// * `ignore_silence` - Whether to keep recording through silence until
+  ///   stopped or the maximum duration is reached
+  pub fn set_ignore_silence(&mut self, ignore_silence: bool) {
+    self.settings.ignore_silence = ignore_silence;
+  }
+
   /// Records audio using configured settings and platform implementation.
   ///
-  /// Delegates to a platform-specific AudioRecorder for actual recording
-  /// with silence detection and device management.
+  /// Delegates to an AudioRecorder on the configured recorder backend for
+  /// actual recording with silence detection and device management.
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<RecordingResult>` containing the recorded audio file
+  /// and device or an error if recording failed.
+  pub async fn record_audio(&self) -> AudioResult<RecordingResult> {
+    let recorder = AudioRecorder::new(
+      self.settings.clone(),
+      create_backend(&self.settings.backend)?,
+      self.cancel.clone(),
+      self.stop.clone(),
+      self.events.clone(),
+    );
+    return recorder.record_audio().await;
+  }
+
+  /// Records both sides of a call into the two channels of one recording.
+  ///
+  /// The input device is recorded on the left channel and the call source,
+  /// such as the monitor of the softphone's output, on the right channel.
+  /// Recording stops when both sides are silent for the silence limit.
+  ///
+  /// # Arguments
+  ///
+  /// * `call_source` - Source with the other side of the call
+  /// * `silence_limit` - Seconds of silence on both sides that end the call
   ///
   /// # Returns
   ///
-  /// An `AudioResult<String>` containing the path to the recorded audio file
+  /// An `AudioResult<RecordingResult>` containing the two-channel recording
   /// or an error if recording failed.
-  pub async fn record_audio(&self) -> AudioResult<String> {
+  pub async fn record_call(
+    &self,
+    call_source: &str,
+    silence_limit: i32,
+  ) -> AudioResult<RecordingResult> {
+    let mut settings = self.settings.clone();
+    settings.call_source = call_source.to_string();
+    settings.silence_limit = silence_limit;
+    settings.channel = 0;
+    let backend = create_backend(&settings.backend)?;
     let recorder = AudioRecorder::new(
-      self.recordings_directory.clone(),
-      self.silence_limit,
-      self.silence_detect_noise,
-      self.preferred_audio_input_device.clone(),
-      self.max_recording_duration,
-      get_platform(),
+      settings,
+      backend,
+      self.cancel.clone(),
+      self.stop.clone(),
+      self.events.clone(),
     );
     return recorder.record_audio().await;
   }
 
+  /// Lists the available audio input devices.
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<AudioInputDevices>` containing the devices reported by
+  /// the audio system or an error if they could not be listed.
+  pub async fn list_input_devices(&self) -> AudioResult<AudioInputDevices> {
+    return create_backend(&self.settings.backend)?
+      .get_audio_input_devices()
+      .await;
+  }
+
+  /// Sets the devices to use instead of listing them before recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `devices` - Devices listed by an earlier recording
+  pub fn set_cached_devices(&mut self, devices: AudioInputDevices) {
+    self.settings.cached_devices = devices;
+  }
+
+  /// Plays a recording back on the default output device.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the recording
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<()>` indicating whether the recording was played.
+  pub async fn play(&self, file_path: &str) -> AudioResult<()> {
+    let output = ffmpeg::play(file_path)
+      .await
+      .map_err(|e| AudioError::PlaybackFailed(e.into()))?;
+    if !output.status.success() {
+      return Err(AudioError::PlaybackFailed(FfmpegError::exited(
+        output.status,
+        &output.stderr,
+      )));
+    }
+    return Ok(());
+  }
+
   /// Converts audio input file to Whisper-compatible format.
   ///
   /// Delegates to AudioConverter to transform input audio to 16kHz mono WAV
@@ -101,12 +277,141 @@ impl Audio {
   /// # Arguments
   ///
   /// * `input_file` - Path to the audio file to convert
+  /// * `pass_through` - Whether an input already in Whisper format is used
+  ///   as is, in which case its own path is returned
+  /// * `kept` - Whether the converted file is kept after use
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<String>` containing the path to the converted audio file
+  /// or an error if conversion failed.
+  pub async fn convert_audio(
+    &self,
+    input_file: &str,
+    pass_through: bool,
+    kept: bool,
+  ) -> AudioResult<String> {
+    return AudioConverter::convert_audio_for_whisper(
+      input_file,
+      None,
+      &self.settings.temp_directory,
+      pass_through,
+      kept,
+    )
+    .await;
+  }
+
+  /// Converts one channel of an audio file to Whisper-compatible format.
+  ///
+  /// # Arguments
+  ///
+  /// * `input_file` - Path to the audio file to convert
+  /// * `channel` - Zero-based channel to convert
+  /// * `kept` - Whether the converted file is kept after use
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<String>` containing the path to the converted audio file
+  /// or an error if conversion failed.
+  pub async fn convert_channel(
+    &self,
+    input_file: &str,
+    channel: usize,
+    kept: bool,
+  ) -> AudioResult<String> {
+    return AudioConverter::convert_channel_for_whisper(
+      input_file,
+      channel,
+      &self.settings.temp_directory,
+      kept,
+    )
+    .await;
+  }
+
+  /// Silences regions of a converted file so they are not transcribed.
+  ///
+  /// The regions are muted rather than cut, so timestamps of the transcript
+  /// still match the original audio.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the converted audio file, changed in place
+  /// * `ranges` - Regions to silence
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<()>` indicating success or failure.
+  pub async fn mute_ranges(
+    &self,
+    file_path: &str,
+    ranges: &[MusicRange],
+  ) -> AudioResult<()> {
+    return AudioConverter::mute_ranges(file_path, ranges).await;
+  }
+
+  /// Starts capturing a network stream in Whisper-ready chunks.
+  ///
+  /// Chunks are written to a private directory inside the configured
+  /// temporary directory, or the system one if none is configured.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - URL of the stream, such as `rtsp://` or an HLS playlist
+  /// * `chunk_duration` - Length of each chunk in seconds
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<StreamCapture>` containing the running capture or an
+  /// error if it could not be started.
+  pub async fn capture_stream(
+    &self,
+    url: &str,
+    chunk_duration: i32,
+  ) -> AudioResult<StreamCapture> {
+    let temp_directory = if self.settings.temp_directory.is_empty() {
+      std::env::temp_dir().to_string_lossy().to_string()
+    } else {
+      self.settings.temp_directory.clone()
+    };
+    let directory = format!(
+      "{}/lumine-stream-{}",
+      temp_directory.trim_end_matches('/'),
+      std::process::id()
+    );
+    return StreamCapture::start(
+      url,
+      &directory,
+      chunk_duration,
+      self.cancel.clone(),
+    )
+    .await;
+  }
+
+  /// Converts headerless PCM input to Whisper-compatible format.
+  ///
+  /// # Arguments
+  ///
+  /// * `input_file` - Path to the PCM stream, such as a FIFO, or `-` for stdin
+  /// * `raw_format` - Sample format, rate and channels of the stream
+  /// * `kept` - Whether the converted file is kept after use
   ///
   /// # Returns
   ///
   /// An `AudioResult<String>` containing the path to the converted audio file
   /// or an error if conversion failed.
-  pub async fn convert_audio(&self, input_file: &str) -> AudioResult<String> {
-    return AudioConverter::convert_audio_for_whisper(input_file).await;
+  pub async fn convert_raw_audio(
+    &self,
+    input_file: &str,
+    raw_format: &RawAudioFormat,
+    kept: bool,
+  ) -> AudioResult<String> {
+    return AudioConverter::convert_audio_for_whisper(
+      input_file,
+      Some(raw_format),
+      &self.settings.temp_directory,
+      false,
+      kept,
+    )
+    .await;
   }
 }
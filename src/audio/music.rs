@@ -0,0 +1,141 @@
+use std::fmt;
+
+use crate::audio::errors::AudioResult;
+use crate::audio::wav::WavInspector;
+
+/// Length of the frames whose energies are measured, in seconds.
+const FRAME_SECONDS: f64 = 0.05;
+/// Number of frames in a classified window of one second.
+const WINDOW_FRAMES: usize = 20;
+/// Share of the window's mean energy below which a frame is a low-energy frame.
+const LOW_ENERGY_RATIO: f64 = 0.5;
+/// Largest share of low-energy frames in a window of music.
+const MUSIC_MAX_LOW_ENERGY_SHARE: f64 = 0.1;
+/// Root mean square amplitude below which a window is silence, not music.
+const SILENCE_RMS: f64 = 300.0;
+
+/// Time range of a recording that contains only music.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MusicRange {
+  /// Start of the range in seconds
+  pub start: f64,
+  /// End of the range in seconds
+  pub end: f64,
+}
+
+impl fmt::Display for MusicRange {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    return write!(
+      f,
+      "{}–{}",
+      format_position(self.start),
+      format_position(self.end)
+    );
+  }
+}
+
+/// Finds long music-only regions, such as podcast intros and jingles.
+///
+/// Speech alternates between syllables and short pauses, so many of its
+/// 50 ms frames carry much less energy than their surroundings. Music keeps a
+/// steady energy, so one-second windows with almost no low-energy frames and
+/// a level above silence are classified as music.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to the 16-bit PCM WAV file
+/// * `min_duration` - Shortest music region that is reported, in seconds
+///
+/// # Returns
+///
+/// An `AudioResult<Vec<MusicRange>>` containing the music regions in order
+/// or an error if the file could not be read.
+pub fn detect_music(
+  file_path: &str,
+  min_duration: f64,
+) -> AudioResult<Vec<MusicRange>> {
+  let mut samples = WavInspector::stream_samples(file_path)?;
+  let mut detector = MusicDetector::new(samples.sample_rate());
+  while let Some(chunk) = samples.next_chunk() {
+    detector.push(&chunk);
+  }
+  return Ok(detector.finish(min_duration));
+}
+
+/// Classifies one-second windows from samples pushed in chunks of any size.
+struct MusicDetector {
+  frame_size: usize,
+  frame_energy: f64,
+  frame_samples: usize,
+  window: Vec<f64>,
+  windows: Vec<bool>,
+}
+
+impl MusicDetector {
+  fn new(sample_rate: u32) -> Self {
+    return MusicDetector {
+      frame_size: ((sample_rate as f64 * FRAME_SECONDS) as usize).max(1),
+      frame_energy: 0.0,
+      frame_samples: 0,
+      window: Vec::with_capacity(WINDOW_FRAMES),
+      windows: Vec::new(),
+    };
+  }
+
+  fn push(&mut self, samples: &[i16]) {
+    for sample in samples {
+      self.frame_energy += (*sample as f64).powi(2);
+      self.frame_samples += 1;
+      if self.frame_samples == self.frame_size {
+        self.window.push(self.frame_energy / self.frame_size as f64);
+        self.frame_energy = 0.0;
+        self.frame_samples = 0;
+        if self.window.len() == WINDOW_FRAMES {
+          self.windows.push(is_music(&self.window));
+          self.window.clear();
+        }
+      }
+    }
+  }
+
+  fn finish(self, min_duration: f64) -> Vec<MusicRange> {
+    let window_seconds = FRAME_SECONDS * WINDOW_FRAMES as f64;
+    let mut ranges: Vec<MusicRange> = Vec::new();
+    let mut start: Option<usize> = None;
+    for (index, music) in self.windows.iter().chain([&false]).enumerate() {
+      match (start, *music) {
+        (None, true) => start = Some(index),
+        (Some(first), false) => {
+          let range = MusicRange {
+            start: first as f64 * window_seconds,
+            end: index as f64 * window_seconds,
+          };
+          if range.end - range.start >= min_duration {
+            ranges.push(range);
+          }
+          start = None;
+        }
+        _ => {}
+      }
+    }
+    return ranges;
+  }
+}
+
+fn is_music(frames: &[f64]) -> bool {
+  let mean = frames.iter().sum::<f64>() / frames.len() as f64;
+  if mean.sqrt() < SILENCE_RMS {
+    return false;
+  }
+  let low_energy = frames
+    .iter()
+    .filter(|energy| **energy < mean * LOW_ENERGY_RATIO)
+    .count();
+  return (low_energy as f64 / frames.len() as f64)
+    <= MUSIC_MAX_LOW_ENERGY_SHARE;
+}
+
+fn format_position(seconds: f64) -> String {
+  let seconds = seconds.max(0.0).round() as u64;
+  return format!("{}:{:02}", seconds / 60, seconds % 60);
+}
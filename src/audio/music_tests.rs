@@ -0,0 +1,65 @@
+use crate::audio::music::{MusicRange, detect_music};
+
+fn write_wav(name: &str, samples: impl Iterator<Item = i16>) -> String {
+  let file_path = std::env::temp_dir().join(name);
+  let spec = hound::WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&file_path, spec).unwrap();
+  for sample in samples {
+    writer.write_sample(sample).unwrap();
+  }
+  writer.finalize().unwrap();
+  return file_path.to_string_lossy().to_string();
+}
+
+fn tone(index: usize) -> i16 {
+  let t = index as f64 / 16000.0;
+  return (8000.0 * (2.0 * std::f64::consts::PI * 440.0 * t).sin()) as i16;
+}
+
+fn syllables(index: usize) -> i16 {
+  let t = index as f64 / 16000.0;
+  if (t * 4.0).fract() < 0.5 {
+    return tone(index);
+  }
+  return 0;
+}
+
+#[test]
+fn test_detect_music_intro() {
+  let intro = (0..12 * 16000).map(tone);
+  let speech = (12 * 16000..20 * 16000).map(syllables);
+  let file_path = write_wav("test_music_intro.wav", intro.chain(speech));
+
+  let ranges = detect_music(&file_path, 10.0).unwrap();
+  assert_eq!(
+    ranges,
+    vec![MusicRange {
+      start: 0.0,
+      end: 12.0
+    }]
+  );
+  assert_eq!(ranges[0].to_string(), "0:00–0:12");
+
+  let _ = std::fs::remove_file(file_path);
+}
+
+#[test]
+fn test_detect_music_ignores_short_music_and_silence() {
+  let jingle = (0..5 * 16000).map(tone);
+  let silence = (0..15 * 16000).map(|_| 0);
+  let file_path = write_wav("test_music_short.wav", jingle.chain(silence));
+
+  assert!(detect_music(&file_path, 10.0).unwrap().is_empty());
+
+  let _ = std::fs::remove_file(file_path);
+}
+
+#[test]
+fn test_detect_music_in_speech_sample() {
+  assert!(detect_music("sample/jfk.wav", 3.0).unwrap().is_empty());
+}
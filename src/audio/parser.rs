@@ -0,0 +1,278 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+
+const SILENCE_START_KEY: &str = "silence_start:";
+const SILENCE_END_KEY: &str = "silence_end:";
+const SILENCE_DURATION_KEY: &str = "silence_duration:";
+const PULSE_SOURCES_HEADER: &str = "Auto-detected sources for pulse";
+const PULSE_INPUT_MARKER: &str = "_input";
+const VERSION_PREFIX: &str = "ffmpeg version ";
+
+/// A device line of `ffmpeg -sources pulse`, such as
+/// `* alsa_input.usb-mic [USB Microphone] (none)`. FFmpeg 6.1 and later
+/// append the media types of the device.
+static PULSE_SOURCE: LazyLock<Regex> = LazyLock::new(|| {
+  return Regex::new(r"^\s*(\*\s+)?(\S+)\s+\[(.*)\]").unwrap();
+});
+
+/// A device line of the AVFoundation device list, such as
+/// `[AVFoundation indev @ 0x7f8] [0] MacBook Pro Microphone`.
+#[cfg(any(target_os = "macos", test))]
+static AVFOUNDATION_DEVICE: LazyLock<Regex> = LazyLock::new(|| {
+  return Regex::new(r"\[(\d+)\]\s+(.*)").unwrap();
+});
+
+/// A `key = value` line of a device's properties in the output of
+/// `gst-device-monitor-1.0`.
+#[cfg(any(feature = "gstreamer", test))]
+static GSTREAMER_PROPERTY: LazyLock<Regex> = LazyLock::new(|| {
+  return Regex::new(r"^\s*([\w.-]+)\s*[:=]\s*(.*?)\s*$").unwrap();
+});
+
+/// The RMS levels of a GStreamer `level` message, printed as
+/// `rms=(double){ -48.6, -48.6 }` by older and as
+/// `rms=(GValueArray)< -48.6, -48.6 >` by newer versions.
+#[cfg(any(feature = "gstreamer", test))]
+static GSTREAMER_RMS: LazyLock<Regex> = LazyLock::new(|| {
+  return Regex::new(r"rms=\((?:double|GValueArray)\)[{<]([^}>]*)[}>]")
+    .unwrap();
+});
+
+/// Level of a block of audio reported by GStreamer's `level` element.
+#[cfg(any(feature = "gstreamer", test))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct LevelMessage {
+  /// Start of the block in seconds
+  pub start: f64,
+  /// End of the block in seconds
+  pub end: f64,
+  /// Loudest RMS level of all channels in decibels below full scale
+  pub rms_db: f64,
+}
+
+/// Event reported by FFmpeg's silencedetect filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SilenceEvent {
+  /// Silence started at the given second
+  Start(f64),
+  /// Silence ended at the given second, after the given duration if
+  /// reported
+  End { end: f64, duration: Option<f64> },
+}
+
+/// Parses a silencedetect event from a line of FFmpeg output.
+///
+/// Timestamps with a decimal comma are accepted, in case FFmpeg runs in a
+/// locale that uses one.
+///
+/// # Arguments
+///
+/// * `line` - A line of FFmpeg stderr output
+///
+/// # Returns
+///
+/// An `Option<SilenceEvent>` containing the event, or `None` if the line is
+/// no silencedetect event.
+pub(crate) fn parse_silence_event(line: &str) -> Option<SilenceEvent> {
+  if let Some(start) = parse_value(line, SILENCE_START_KEY) {
+    return Some(SilenceEvent::Start(start));
+  }
+  let end = parse_value(line, SILENCE_END_KEY)?;
+  return Some(SilenceEvent::End {
+    end,
+    duration: parse_value(line, SILENCE_DURATION_KEY),
+  });
+}
+
+/// Parses the PulseAudio input devices listed by `ffmpeg -sources pulse`.
+///
+/// Monitors of output devices are skipped. Where FFmpeg prints the list
+/// differs between versions, so callers pass both stdout and stderr.
+///
+/// # Arguments
+///
+/// * `output` - Output of FFmpeg
+///
+/// # Returns
+///
+/// The listed input devices, with the system default marked.
+pub(crate) fn parse_pulse_sources(output: &str) -> AudioInputDevices {
+  let mut devices: AudioInputDevices = Vec::new();
+  let mut in_list = false;
+  for line in output.lines() {
+    if line.contains(PULSE_SOURCES_HEADER) {
+      in_list = true;
+      continue;
+    }
+    if !in_list || !line.contains(PULSE_INPUT_MARKER) {
+      continue;
+    }
+    let Some(caps) = PULSE_SOURCE.captures(line) else {
+      continue;
+    };
+    let device =
+      AudioInputDevice::new(caps[2].to_string(), caps[3].trim().to_string());
+    if caps.get(1).is_some() {
+      devices.push(device.into_system_default());
+    } else {
+      devices.push(device);
+    }
+  }
+  return devices;
+}
+
+/// Parses the audio devices listed by FFmpeg's AVFoundation input.
+///
+/// Video devices, listed first, are skipped.
+///
+/// # Arguments
+///
+/// * `output` - Output of FFmpeg
+///
+/// # Returns
+///
+/// The listed audio input devices.
+#[cfg(any(target_os = "macos", test))]
+pub(crate) fn parse_avfoundation_devices(output: &str) -> AudioInputDevices {
+  let mut devices: AudioInputDevices = Vec::new();
+  let mut in_audio_list = false;
+  for line in output.lines() {
+    if line.contains("AVFoundation audio devices") {
+      in_audio_list = true;
+      continue;
+    }
+    if line.contains("AVFoundation video devices") {
+      in_audio_list = false;
+      continue;
+    }
+    if in_audio_list && let Some(caps) = AVFOUNDATION_DEVICE.captures(line) {
+      devices.push(AudioInputDevice::new(
+        caps[1].to_string(),
+        caps[2].trim().to_string(),
+      ));
+    }
+  }
+  return devices;
+}
+
+/// Parses the version from the output of `ffmpeg -version`.
+///
+/// # Arguments
+///
+/// * `output` - Output of FFmpeg
+///
+/// # Returns
+///
+/// An `Option<String>` containing the version, such as `7.1` or
+/// `n6.1.1`, or `None` if the output names no FFmpeg version.
+pub(crate) fn parse_version(output: &str) -> Option<String> {
+  return output.lines().find_map(|line| {
+    let index = line.find(VERSION_PREFIX)?;
+    let version = line[index + VERSION_PREFIX.len()..]
+      .split_whitespace()
+      .next()?;
+    return Some(version.to_string());
+  });
+}
+
+/// Parses the audio sources listed by `gst-device-monitor-1.0`.
+///
+/// Monitors of output devices are skipped. The index of each device is the
+/// source element that records it, such as
+/// `pulsesrc device=alsa_input.usb-mic`, taken from the example pipeline
+/// printed for the device.
+///
+/// # Arguments
+///
+/// * `output` - Output of `gst-device-monitor-1.0 Audio/Source`
+///
+/// # Returns
+///
+/// The listed input devices, with the system default marked.
+#[cfg(any(feature = "gstreamer", test))]
+pub(crate) fn parse_gstreamer_devices(output: &str) -> AudioInputDevices {
+  let mut devices: AudioInputDevices = Vec::new();
+  for block in output.split("Device found:").skip(1) {
+    let mut name = None;
+    let mut source = None;
+    let mut is_audio_source = false;
+    let mut is_monitor = false;
+    let mut is_default = false;
+    for line in block.lines() {
+      if let Some(pipeline) = line.trim().strip_prefix("gst-launch-1.0 ") {
+        source = pipeline.split(" ! ").next().map(|s| s.trim().to_string());
+        continue;
+      }
+      let Some(caps) = GSTREAMER_PROPERTY.captures(line) else {
+        continue;
+      };
+      let value = &caps[2];
+      match &caps[1] {
+        "name" => name = Some(value.to_string()),
+        "class" => is_audio_source = value == "Audio/Source",
+        "device.class" => is_monitor = value == "monitor",
+        "is-default" => is_default = value == "true",
+        _ => {}
+      }
+    }
+    if let (Some(name), Some(source)) = (name, source)
+      && is_audio_source
+      && !is_monitor
+    {
+      let device = AudioInputDevice::new(source, name);
+      if is_default {
+        devices.push(device.into_system_default());
+      } else {
+        devices.push(device);
+      }
+    }
+  }
+  return devices;
+}
+
+/// Parses a `level` message printed by `gst-launch-1.0 -m`.
+///
+/// # Arguments
+///
+/// * `line` - A line of `gst-launch-1.0` output
+///
+/// # Returns
+///
+/// An `Option<LevelMessage>` containing the level, or `None` if the line is
+/// no `level` message.
+#[cfg(any(feature = "gstreamer", test))]
+pub(crate) fn parse_level_message(line: &str) -> Option<LevelMessage> {
+  if !line.contains("level, ") {
+    return None;
+  }
+  let start = parse_nanoseconds(line, "timestamp=(guint64)")?;
+  let end = parse_nanoseconds(line, "endtime=(guint64)")?;
+  let levels = GSTREAMER_RMS.captures(line)?;
+  let rms_db = levels[1]
+    .split(',')
+    .filter_map(|value| value.trim().parse::<f64>().ok())
+    .reduce(f64::max)?;
+  return Some(LevelMessage { start, end, rms_db });
+}
+
+#[cfg(any(feature = "gstreamer", test))]
+fn parse_nanoseconds(line: &str, key: &str) -> Option<f64> {
+  let index = line.find(key)?;
+  let digits: String = line[index + key.len()..]
+    .chars()
+    .take_while(|c| c.is_ascii_digit())
+    .collect();
+  return Some(digits.parse::<u64>().ok()? as f64 / 1e9);
+}
+
+fn parse_value(line: &str, key: &str) -> Option<f64> {
+  let index = line.find(key)?;
+  let value = line[index + key.len()..].split_whitespace().next()?;
+  return value
+    .parse::<f64>()
+    .or_else(|_| value.replace(',', ".").parse::<f64>())
+    .ok();
+}
@@ -0,0 +1,238 @@
+use crate::audio::ffmpeg::ffmpeg_arguments;
+use crate::audio::parser::{
+  LevelMessage, SilenceEvent, parse_avfoundation_devices,
+  parse_gstreamer_devices, parse_level_message, parse_pulse_sources,
+  parse_silence_event, parse_version,
+};
+
+fn silence_events(output: &str) -> Vec<SilenceEvent> {
+  return output.lines().filter_map(parse_silence_event).collect();
+}
+
+#[test]
+fn test_parse_silence_events_ffmpeg_4() {
+  let events = silence_events(include_str!("fixtures/silencedetect_4.4.txt"));
+
+  assert_eq!(
+    events,
+    vec![
+      SilenceEvent::Start(1.50354),
+      SilenceEvent::End {
+        end: 3.01,
+        duration: Some(1.50646),
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_parse_silence_events_ffmpeg_7() {
+  let events = silence_events(include_str!("fixtures/silencedetect_7.1.txt"));
+
+  assert_eq!(
+    events,
+    vec![
+      SilenceEvent::Start(1.503542),
+      SilenceEvent::End {
+        end: 3.01,
+        duration: Some(1.506458),
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_parse_silence_events_decimal_comma() {
+  let events =
+    silence_events(include_str!("fixtures/silencedetect_decimal_comma.txt"));
+
+  assert_eq!(
+    events,
+    vec![
+      SilenceEvent::Start(1.50354),
+      SilenceEvent::End {
+        end: 3.01,
+        duration: Some(1.50646),
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_parse_silence_event_without_duration() {
+  assert_eq!(
+    parse_silence_event("[silencedetect @ 0x1] silence_end: 4.5"),
+    Some(SilenceEvent::End {
+      end: 4.5,
+      duration: None,
+    })
+  );
+  assert_eq!(
+    parse_silence_event("size=     256kB time=00:00:05.00"),
+    None
+  );
+}
+
+#[test]
+fn test_parse_pulse_sources_across_versions() {
+  for output in [
+    include_str!("fixtures/pulse_sources_4.4.txt"),
+    include_str!("fixtures/pulse_sources_6.1.txt"),
+  ] {
+    let devices = parse_pulse_sources(output);
+
+    assert_eq!(devices.len(), 2);
+    assert_eq!(
+      devices[0].get_index(),
+      "alsa_input.pci-0000_00_1f.3.analog-stereo"
+    );
+    assert_eq!(devices[0].get_name(), "Built-in Audio Analog Stereo");
+    assert!(devices[0].is_system_default());
+    assert_eq!(devices[1].get_name(), "HD Pro Webcam C920 Analog Stereo");
+    assert!(!devices[1].is_system_default());
+  }
+}
+
+#[test]
+fn test_parse_pulse_sources_ffmpeg_7() {
+  let devices =
+    parse_pulse_sources(include_str!("fixtures/pulse_sources_7.1.txt"));
+
+  assert_eq!(devices.len(), 2);
+  assert_eq!(
+    devices[0].get_name(),
+    "Yeti Stereo Microphone [REV8] Analog Stereo"
+  );
+  assert!(!devices[0].is_system_default());
+  assert_eq!(devices[1].get_name(), "Built-in Audio Analog Stereo");
+  assert!(devices[1].is_system_default());
+}
+
+#[test]
+fn test_parse_pulse_sources_without_list() {
+  assert!(parse_pulse_sources("Unknown input format: 'pulse'").is_empty());
+}
+
+#[test]
+fn test_parse_avfoundation_devices() {
+  let devices = parse_avfoundation_devices(include_str!(
+    "fixtures/avfoundation_devices.txt"
+  ));
+
+  assert_eq!(devices.len(), 2);
+  assert_eq!(devices[0].get_index(), "0");
+  assert_eq!(devices[0].get_name(), "MacBook Pro Microphone");
+  assert_eq!(devices[1].get_index(), "1");
+  assert_eq!(devices[1].get_name(), "ZoomAudioDevice");
+}
+
+#[test]
+fn test_parse_gstreamer_devices_pulse() {
+  let devices =
+    parse_gstreamer_devices(include_str!("fixtures/gst_devices_pulse.txt"));
+
+  assert_eq!(devices.len(), 2);
+  assert_eq!(
+    devices[0].get_index(),
+    "pulsesrc device=alsa_input.pci-0000_00_1f.3.analog-stereo"
+  );
+  assert_eq!(devices[0].get_name(), "Built-in Audio Analog Stereo");
+  assert!(devices[0].is_system_default());
+  assert_eq!(devices[1].get_name(), "HD Pro Webcam C920 Analog Stereo");
+  assert!(!devices[1].is_system_default());
+}
+
+#[test]
+fn test_parse_gstreamer_devices_pipewire() {
+  let devices =
+    parse_gstreamer_devices(include_str!("fixtures/gst_devices_pipewire.txt"));
+
+  assert_eq!(devices.len(), 2);
+  assert_eq!(
+    devices[0].get_index(),
+    "pipewiresrc target-object=alsa_input.usb-Blue_Microphones_Yeti_Stereo_Microphone_REV8-00.analog-stereo"
+  );
+  assert_eq!(
+    devices[0].get_name(),
+    "Yeti Stereo Microphone Analog Stereo"
+  );
+  assert_eq!(devices[1].get_name(), "Built-in Audio Analog Stereo");
+  assert!(!devices[1].is_system_default());
+}
+
+#[test]
+fn test_parse_gstreamer_devices_without_devices() {
+  assert!(parse_gstreamer_devices("Probing devices...").is_empty());
+}
+
+#[test]
+fn test_parse_level_messages_across_versions() {
+  for output in [
+    include_str!("fixtures/gst_level_1.16.txt"),
+    include_str!("fixtures/gst_level_1.22.txt"),
+  ] {
+    let levels: Vec<LevelMessage> =
+      output.lines().filter_map(parse_level_message).collect();
+
+    assert_eq!(
+      levels,
+      vec![
+        LevelMessage {
+          start: 0.0,
+          end: 0.1,
+          rms_db: -21.473129431390382,
+        },
+        LevelMessage {
+          start: 0.1,
+          end: 0.2,
+          rms_db: -62.5043022112369,
+        },
+      ]
+    );
+  }
+}
+
+#[test]
+fn test_parse_level_message_uses_loudest_channel() {
+  let line = "Got message #7 from element \"level0\" (element): level, \
+    endtime=(guint64)300000000, timestamp=(guint64)200000000, \
+    rms=(GValueArray)< -70.5, -30.25 >, peak=(GValueArray)< -60, -20 >;";
+
+  let level = parse_level_message(line).unwrap();
+
+  assert_eq!(level.rms_db, -30.25);
+}
+
+#[test]
+fn test_parse_level_message_ignores_other_lines() {
+  assert!(parse_level_message("Setting pipeline to PLAYING ...").is_none());
+}
+
+#[test]
+fn test_parse_version() {
+  assert_eq!(
+    parse_version(include_str!("fixtures/version_4.4.txt")),
+    Some("4.4.2-0ubuntu0.22.04.1".to_string())
+  );
+  assert_eq!(
+    parse_version(include_str!("fixtures/version_6.1.txt")),
+    Some("n6.1.1".to_string())
+  );
+  assert_eq!(
+    parse_version(include_str!("fixtures/version_7.1.txt")),
+    Some("7.1-static".to_string())
+  );
+  assert_eq!(parse_version("avconv version 12.3"), None);
+}
+
+#[test]
+fn test_ffmpeg_arguments_pin_log_level() {
+  assert_eq!(
+    ffmpeg_arguments(&["-version"]),
+    vec!["-hide_banner", "-loglevel", "info", "-version"]
+  );
+  assert_eq!(
+    ffmpeg_arguments(&["-loglevel", "error", "-i", "input.wav"]),
+    vec!["-hide_banner", "-loglevel", "error", "-i", "input.wav"]
+  );
+}
@@ -1,9 +1,15 @@
-use regex::Regex;
-
-use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::audio::devices::{
+  AudioInputDevice, AudioInputDevices, find_audio_input_device,
+};
 use crate::audio::errors::{AudioError, AudioResult};
-use crate::audio::platform::AudioPlatform;
-use crate::process::executor::ProcessExecutor;
+use crate::audio::ffmpeg;
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::parser::parse_pulse_sources;
+use crate::audio::platform::{
+  AudioPlatform, build_audio_filters, build_output_format_arguments,
+};
+use crate::audio::sandbox::Sandbox;
+use crate::audio::settings::RecorderSettings;
 use crate::vlog;
 
 /// Linux implementation of AudioPlatform trait.
@@ -22,37 +28,24 @@ impl LinuxPlatform {
 
 impl AudioPlatform for LinuxPlatform {
   async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
-    let output = ProcessExecutor::run("ffmpeg", &["-sources", "pulse"])
+    let output = ffmpeg::run(&["-sources", "pulse"])
       .await
-      .map_err(|_| AudioError::CouldNotExecuteFFMPEG)?;
-
-    let mut audio_section = false;
-    let mut devices: AudioInputDevices = Vec::new();
-
-    let regex = Regex::new(r"^\s*(?:\*\s)?([^\s]+)\s+\[([^\]]+)\]").unwrap();
-
-    for line in output.stderr.lines() {
-      if line.contains("Auto-detected sources for pulse") {
-        audio_section = true;
-        continue;
-      }
-
-      if audio_section
-        && line.contains("_input")
-        && let Some(caps) = regex.captures(line)
-      {
-        let index = caps.get(1).unwrap().as_str();
-        let name = caps.get(2).unwrap().as_str();
-        devices.push(AudioInputDevice::new(
-          String::from(index),
-          String::from(name),
-        ));
-      }
+      .map_err(|e| AudioError::CouldNotExecuteFFMPEG(e.into()))?;
+
+    let devices = parse_pulse_sources(&output.combined());
+
+    if devices.is_empty()
+      && let Some(sandbox) = Sandbox::detect()
+    {
+      vlog!("Running inside {:?} without audio input devices", sandbox);
+      return Err(AudioError::SandboxMicrophoneAccess(
+        sandbox.microphone_instructions(),
+      ));
     }
 
     vlog!("Audio Devices Found:");
     for device in &devices {
-      vlog!("- {}", device.get_name());
+      vlog!("- [{}] {}", device.get_index(), device.get_name());
     }
 
     return Ok(devices);
@@ -70,14 +63,14 @@ impl AudioPlatform for LinuxPlatform {
       return default_device;
     }
 
-    for device in devices {
-      if device.get_name().contains(&preferred_audio_input_device) {
-        vlog!(
-          "Selected preferred audio input device: {}",
-          device.get_name()
-        );
-        return device;
-      }
+    if let Some(device) =
+      find_audio_input_device(devices, &preferred_audio_input_device)
+    {
+      vlog!(
+        "Selected preferred audio input device: {}",
+        device.get_name()
+      );
+      return device;
     }
 
     vlog!("No preferred audio input device found, using default device");
@@ -88,35 +81,48 @@ impl AudioPlatform for LinuxPlatform {
   fn build_ffmpeg_recording_arguments(
     &self,
     device_index: String,
-    silence_limit: i32,
-    silence_detect_noise: i32,
-    max_recording_duration: i32,
+    settings: &RecorderSettings,
+    metadata: &RecordingMetadata,
     output_file: String,
   ) -> Vec<String> {
-    let mut args = vec![
-      "-f".to_string(),
-      "pulse".to_string(),
-      "-i".to_string(),
-      format!(":{}", device_index),
-    ];
-
-    if max_recording_duration > 0 {
+    let mut args = vec!["-f".to_string(), "pulse".to_string()];
+    let call = !settings.call_source.is_empty();
+
+    if call {
+      args.extend(vec!["-channels".to_string(), "1".to_string()]);
+    } else if settings.channel > 2 {
+      args.push("-channels".to_string());
+      args.push(format!("{}", settings.channel));
+    }
+
+    args.extend(vec!["-i".to_string(), format!(":{}", device_index)]);
+
+    if call {
+      args.extend(vec![
+        "-f".to_string(),
+        "pulse".to_string(),
+        "-channels".to_string(),
+        "1".to_string(),
+        "-i".to_string(),
+        settings.call_source.clone(),
+      ]);
+    }
+
+    if settings.max_recording_duration > 0 {
       args.push("-t".to_string());
-      args.push(format!("{}", max_recording_duration));
+      args.push(format!("{}", settings.max_recording_duration));
     }
 
+    args.extend(build_output_format_arguments(settings));
+    let filter_option = if call { "-filter_complex" } else { "-af" };
     args.extend(vec![
-      "-acodec".to_string(),
-      "pcm_s16le".to_string(),
-      "-af".to_string(),
-      format!(
-        "silencedetect=n=-{}dB:d={}",
-        silence_detect_noise, silence_limit,
-      ),
-      output_file,
-      "-y".to_string(),
+      filter_option.to_string(),
+      build_audio_filters(settings),
     ]);
 
+    args.extend(metadata.to_ffmpeg_arguments());
+    args.extend(vec![output_file, "-y".to_string()]);
+
     return args;
   }
 }
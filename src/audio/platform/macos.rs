@@ -1,14 +1,23 @@
-use regex::Regex;
-
-use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::audio::devices::{
+  AudioInputDevice, AudioInputDevices, find_audio_input_device,
+};
 use crate::audio::errors::{AudioError, AudioResult};
-use crate::audio::platform::AudioPlatform;
-use crate::process::executor::ProcessExecutor;
+use crate::audio::ffmpeg;
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::parser::parse_avfoundation_devices;
+use crate::audio::platform::{
+  AudioPlatform, build_audio_filters, build_output_format_arguments,
+};
+use crate::audio::settings::RecorderSettings;
 use crate::vlog;
 
 /// macOS implementation of AudioPlatform trait.
 ///
 /// Provides macOS-specific audio device enumeration and recording using AVFoundation framework.
+///
+/// AVFoundation has no option for the number of input channels, so devices
+/// are recorded with the channels they deliver by default. Selecting a
+/// channel beyond those makes FFmpeg fail.
 pub(crate) struct MacOSPlatform {}
 
 impl MacOSPlatform {
@@ -24,40 +33,16 @@ impl MacOSPlatform {
 
 impl AudioPlatform for MacOSPlatform {
   async fn get_audio_input_devices(&self) -> AudioResult<AudioInputDevices> {
-    let output = ProcessExecutor::run(
-      "ffmpeg",
-      &["-f", "avfoundation", "-list_devices", "true", "-i", ""],
-    )
-    .await
-    .map_err(|_| AudioError::CouldNotExecuteFFMPEG)?;
-
-    let mut audio_section = false;
-    let mut devices: AudioInputDevices = Vec::new();
-
-    let regex = Regex::new(r"\[(\d+)\]\s+(.*)").unwrap();
-
-    for line in output.stderr.lines() {
-      if line.contains("AVFoundation audio devices") {
-        audio_section = true;
-        continue;
-      }
-
-      if audio_section
-        && let Some(caps) = regex.captures(line)
-        && caps.len() >= 3
-      {
-        let index = &caps[1];
-        let name = &caps[2];
-        devices.push(AudioInputDevice::new(
-          String::from(index),
-          String::from(name),
-        ));
-      }
-    }
+    let output =
+      ffmpeg::run(&["-f", "avfoundation", "-list_devices", "true", "-i", ""])
+        .await
+        .map_err(|e| AudioError::CouldNotExecuteFFMPEG(e.into()))?;
+
+    let devices = parse_avfoundation_devices(&output.combined());
 
     vlog!("Audio Devices Found:");
     for device in &devices {
-      vlog!("- {}", device.get_name());
+      vlog!("- [{}] {}", device.get_index(), device.get_name());
     }
 
     return Ok(devices);
@@ -75,14 +60,14 @@ impl AudioPlatform for MacOSPlatform {
       return default_device;
     }
 
-    for device in devices {
-      if device.get_name().contains(&preferred_audio_input_device) {
-        vlog!(
-          "Selected preferred audio input device: {}",
-          device.get_name()
-        );
-        return device;
-      }
+    if let Some(device) =
+      find_audio_input_device(devices, &preferred_audio_input_device)
+    {
+      vlog!(
+        "Selected preferred audio input device: {}",
+        device.get_name()
+      );
+      return device;
     }
 
     vlog!("No preferred audio input device found, using default device");
@@ -93,9 +78,8 @@ impl AudioPlatform for MacOSPlatform {
   fn build_ffmpeg_recording_arguments(
     &self,
     device_index: String,
-    silence_limit: i32,
-    silence_detect_noise: i32,
-    max_recording_duration: i32,
+    settings: &RecorderSettings,
+    metadata: &RecordingMetadata,
     output_file: String,
   ) -> Vec<String> {
     let mut args = vec![
@@ -104,24 +88,32 @@ impl AudioPlatform for MacOSPlatform {
       "-i".to_string(),
       format!(":{}", device_index),
     ];
+    let call = !settings.call_source.is_empty();
+
+    if call {
+      args.extend(vec![
+        "-f".to_string(),
+        "avfoundation".to_string(),
+        "-i".to_string(),
+        format!(":{}", settings.call_source),
+      ]);
+    }
 
-    if max_recording_duration > 0 {
+    if settings.max_recording_duration > 0 {
       args.push("-t".to_string());
-      args.push(format!("{}", max_recording_duration));
+      args.push(format!("{}", settings.max_recording_duration));
     }
 
+    args.extend(build_output_format_arguments(settings));
+    let filter_option = if call { "-filter_complex" } else { "-af" };
     args.extend(vec![
-      "-acodec".to_string(),
-      "pcm_s16le".to_string(),
-      "-af".to_string(),
-      format!(
-        "silencedetect=n=-{}dB:d={}",
-        silence_detect_noise, silence_limit,
-      ),
-      output_file,
-      "-y".to_string(),
+      filter_option.to_string(),
+      build_audio_filters(settings),
     ]);
 
+    args.extend(metadata.to_ffmpeg_arguments());
+    args.extend(vec![output_file, "-y".to_string()]);
+
     return args;
   }
 }
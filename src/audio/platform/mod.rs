@@ -1,5 +1,7 @@
 use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
 use crate::audio::errors::AudioResult;
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::settings::RecorderSettings;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -20,7 +22,7 @@ pub trait AudioPlatform {
   /// # Arguments
   ///
   /// * `devices` - List of available audio input devices
-  /// * `preferred_audio_input_device` - Preferred device name
+  /// * `preferred_audio_input_device` - Preferred device index or name
   ///
   /// # Returns
   ///
@@ -36,9 +38,8 @@ pub trait AudioPlatform {
   /// # Arguments
   ///
   /// * `device_index` - Platform-specific device identifier
-  /// * `silence_limit` - Seconds of silence before stopping
-  /// * `silence_detect_noise` - Noise threshold in dB for silence detection
-  /// * `max_recording_duration` - Maximum recording duration in seconds (0 = unlimited)
+  /// * `settings` - Recorder settings resolved for the selected device
+  /// * `metadata` - Provenance metadata to write into the recording
   /// * `output_file` - Path to output audio file
   ///
   /// # Returns
@@ -47,19 +48,81 @@ pub trait AudioPlatform {
   fn build_ffmpeg_recording_arguments(
     &self,
     device_index: String,
-    silence_limit: i32,
-    silence_detect_noise: i32,
-    max_recording_duration: i32,
+    settings: &RecorderSettings,
+    metadata: &RecordingMetadata,
     output_file: String,
   ) -> Vec<String>;
 }
 
+/// Build the FFmpeg output format arguments for recording
+///
+/// # Arguments
+///
+/// * `settings` - Recorder settings resolved for the selected device
+///
+/// # Returns
+///
+/// FFmpeg arguments for 16-bit PCM output, resampled to 16kHz mono when the
+/// recording should be ready for Whisper. Call recordings keep both channels.
+pub fn build_output_format_arguments(
+  settings: &RecorderSettings,
+) -> Vec<String> {
+  let mut args = vec!["-acodec".to_string(), "pcm_s16le".to_string()];
+  if settings.whisper_format {
+    args.extend(vec!["-ar".to_string(), "16000".to_string()]);
+    if settings.call_source.is_empty() {
+      args.extend(vec!["-ac".to_string(), "1".to_string()]);
+    }
+  }
+  return args;
+}
+
+/// Build the FFmpeg audio filter chain for recording
+///
+/// # Arguments
+///
+/// * `settings` - Recorder settings resolved for the selected device
+///
+/// # Returns
+///
+/// Comma-separated FFmpeg filter chain ending with silence detection. Call
+/// recordings start by merging the input device and the call source into
+/// the left and right channel.
+pub fn build_audio_filters(settings: &RecorderSettings) -> String {
+  let mut filters: Vec<String> = Vec::new();
+
+  if !settings.call_source.is_empty() {
+    filters.push("[0:a][1:a]amerge=inputs=2".to_string());
+  } else if settings.channel > 0 {
+    filters.push(format!("pan=mono|c0=c{}", settings.channel - 1));
+  }
+
+  if settings.gain != 0.0 {
+    filters.push(format!("volume={}dB", settings.gain));
+  }
+
+  filters.push(format!(
+    "silencedetect=n=-{}dB:d={}",
+    settings.silence_detect_noise, settings.silence_limit,
+  ));
+
+  return filters.join(",");
+}
+
+/// Audio platform implementation of the current compilation target
+#[cfg(target_os = "macos")]
+pub(crate) type CurrentPlatform = macos::MacOSPlatform;
+
+/// Audio platform implementation of the current compilation target
+#[cfg(target_os = "linux")]
+pub(crate) type CurrentPlatform = linux::LinuxPlatform;
+
 /// Get platform-specific audio platform implementation
 ///
 /// # Returns
 ///
 /// Concrete platform type for current compilation target
-pub fn get_platform() -> impl AudioPlatform {
+pub(crate) fn get_platform() -> CurrentPlatform {
   #[cfg(target_os = "macos")]
   {
     return macos::MacOSPlatform::new();
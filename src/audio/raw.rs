@@ -0,0 +1,34 @@
+/// Stdin is read when this is given as the input file.
+pub const STDIN_INPUT: &str = "-";
+
+/// Format of headerless PCM input, such as a FIFO fed by another capture
+/// tool.
+///
+/// Raw PCM carries no header, so FFmpeg has to be told how to read it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawAudioFormat {
+  /// FFmpeg sample format, such as `s16le` or `f32le`
+  pub sample_format: String,
+  /// Sample rate in Hz
+  pub sample_rate: i32,
+  /// Number of interleaved channels
+  pub channels: i32,
+}
+
+impl RawAudioFormat {
+  /// Builds the FFmpeg options describing the input.
+  ///
+  /// # Returns
+  ///
+  /// A `Vec<String>` of FFmpeg options to place before the input file.
+  pub fn to_ffmpeg_arguments(&self) -> Vec<String> {
+    return vec![
+      String::from("-f"),
+      self.sample_format.clone(),
+      String::from("-ar"),
+      self.sample_rate.to_string(),
+      String::from("-ac"),
+      self.channels.to_string(),
+    ];
+  }
+}
@@ -0,0 +1,14 @@
+use crate::audio::raw::RawAudioFormat;
+
+#[test]
+fn test_raw_format_ffmpeg_arguments() {
+  let format = RawAudioFormat {
+    sample_format: String::from("s16le"),
+    sample_rate: 48000,
+    channels: 2,
+  };
+  assert_eq!(
+    format.to_ffmpeg_arguments(),
+    vec!["-f", "s16le", "-ar", "48000", "-ac", "2"]
+  );
+}
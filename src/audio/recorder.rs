@@ -1,188 +1,406 @@
-use std::os::unix::process::ExitStatusExt;
-use std::sync::Arc;
-use std::time::Duration;
+use std::path::Path;
+use std::time::Instant;
 
-use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::sync::Mutex;
-use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
-use crate::audio::devices::AudioInputDevice;
+use crate::audio::backend::{
+  RecorderBackend, RecordingEnd, RecordingSegment, SegmentRequest,
+};
+use crate::audio::converter::AudioConverter;
+use crate::audio::deadline::RecordingDeadline;
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
 use crate::audio::errors::{AudioError, AudioResult};
-use crate::audio::platform::AudioPlatform;
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::results::RecordingResult;
+use crate::audio::settings::RecorderSettings;
+use crate::audio::silence::{AudioInterval, speech_ratio};
+use crate::audio::wav::WavInspector;
+use crate::events::{Event, EventBus};
 use crate::files::operations;
-use crate::process::executor::ProcessExecutor;
+use crate::files::sweep::intermediate_name;
+use crate::tr;
 use crate::vlog;
 
-/// Generic audio recorder with platform-specific implementation.
+const MAX_RESUME_ATTEMPTS: i32 = 3;
+
+/// Generic audio recorder on top of a recorder backend.
 ///
-/// Records audio using FFmpeg with silence detection and device management
-/// through platform-specific AudioPlatform implementations.
-#[derive(Debug, Clone)]
-pub(crate) struct AudioRecorder<P: AudioPlatform> {
-  recordings_directory: String,
-  silence_limit: i32,
-  silence_detect_noise: i32,
-  preferred_audio_input_device: String,
-  max_recording_duration: i32,
-  platform: P,
+/// Selects the input device, records it through the RecorderBackend and
+/// handles what is shared by all backends, such as device caching, resuming
+/// after device loss and discarding recordings that are too short.
+pub(crate) struct AudioRecorder<B: RecorderBackend> {
+  settings: RecorderSettings,
+  backend: B,
+  cancel: CancellationToken,
+  stop: CancellationToken,
+  events: EventBus,
 }
 
-impl<P: AudioPlatform> AudioRecorder<P> {
-  /// Creates a new AudioRecorder with configuration and platform implementation.
+impl<B: RecorderBackend> AudioRecorder<B> {
+  /// Creates a new AudioRecorder with configuration and recorder backend.
   ///
   /// # Arguments
   ///
-  /// * `recordings_directory` - Directory path to save audio recordings
-  /// * `silence_limit` - Seconds of silence before stopping recording
-  /// * `silence_detect_noise` - Noise threshold in decibels for silence detection
-  /// * `preferred_audio_input_device` - Name of preferred audio input device
-  /// * `max_recording_duration` - Maximum recording duration in seconds (0 for unlimited)
-  /// * `platform` - Platform-specific implementation for audio operations
+  /// * `settings` - Recorder settings resolved from configuration
+  /// * `backend` - Backend that captures the audio
+  /// * `cancel` - Token that stops and discards the recording when cancelled
+  /// * `stop` - Token that stops and keeps the recording when cancelled
+  /// * `events` - Bus that receives recording progress events
   ///
   /// # Returns
   ///
-  /// A new `AudioRecorder<P>` instance.
+  /// A new `AudioRecorder<B>` instance.
   pub fn new(
-    recordings_directory: String,
-    silence_limit: i32,
-    silence_detect_noise: i32,
-    preferred_audio_input_device: String,
-    max_recording_duration: i32,
-    platform: P,
+    settings: RecorderSettings,
+    backend: B,
+    cancel: CancellationToken,
+    stop: CancellationToken,
+    events: EventBus,
   ) -> Self {
     return Self {
-      recordings_directory,
-      silence_limit,
-      silence_detect_noise,
-      preferred_audio_input_device,
-      max_recording_duration,
-      platform,
+      settings,
+      backend,
+      cancel,
+      stop,
+      events,
     };
   }
 
-  /// Records audio with silence detection using platform-specific implementation.
+  /// Records audio with silence detection using the recorder backend.
   ///
-  /// Validates FFmpeg availability, selects appropriate audio device, and records
-  /// audio with automatic silence detection based on configured thresholds.
+  /// Validates that the backend is available, selects appropriate audio
+  /// device, and records audio with automatic silence detection based on
+  /// configured thresholds.
+  /// A pinned device is used as is, without listing the available devices.
   ///
   /// # Returns
   ///
-  /// An `AudioResult<String>` containing the path to the recorded audio file
-  /// or an error if recording failed.
-  pub async fn record_audio(&self) -> AudioResult<String> {
-    self.check_ffmpeg().await?;
-    let devices = self.platform.get_audio_input_devices().await?;
-    let device = self
-      .platform
-      .select_audio_input_device(
-        devices,
-        self.preferred_audio_input_device.clone(),
-      )
-      .await;
-    return self.record_audio_with_device(device).await;
-  }
+  /// An `AudioResult<RecordingResult>` containing the recorded audio file
+  /// and device or an error if recording failed.
+  pub async fn record_audio(&self) -> AudioResult<RecordingResult> {
+    let (mut device, mut listed_devices) = self.select_device(true).await?;
+    let from_cache = listed_devices.is_none() && !self.is_device_pinned();
 
-  async fn check_ffmpeg(&self) -> AudioResult<bool> {
-    let output = ProcessExecutor::run("ffmpeg", &["-version"])
+    operations::create_directory_all(&self.settings.recordings_directory)
       .await
-      .map_err(|_| AudioError::FFMPEGNotFound)?;
+      .map_err(AudioError::CouldNotCreateDirectory)?;
 
-    for line in output.stdout.lines() {
-      if line.contains("ffmpeg version") {
-        vlog!("Found ffmpeg: {}", line);
-        return Ok(true);
+    let output_file = self.get_output_file().await;
+
+    let started = Instant::now();
+    let max_recording_duration = self.settings.max_recording_duration;
+    let mut segment = match self
+      .record_audio_with_device(&device, &output_file, max_recording_duration)
+      .await
+    {
+      Err(
+        AudioError::CouldNotExecuteFFMPEG(_) | AudioError::CaptureFailed(_),
+      ) if from_cache => {
+        vlog!("Could not record on cached audio input device, listing again");
+        (device, listed_devices) = self.select_device(false).await?;
+        self
+          .record_audio_with_device(
+            &device,
+            &output_file,
+            max_recording_duration,
+          )
+          .await?
       }
+      result => result?,
+    };
+
+    let mut interrupted = false;
+    let mut attempts = 0;
+    let mut silence = segment.silence;
+    let mut silences = segment.silences.clone();
+    while segment.end == RecordingEnd::DeviceLost {
+      interrupted = true;
+      self.events.emit(Event::Warning {
+        message: tr!(
+          "recording-interrupted",
+          device = device.get_name(),
+          path = output_file
+        ),
+      });
+
+      if !self.settings.resume_on_device_loss || attempts >= MAX_RESUME_ATTEMPTS
+      {
+        break;
+      }
+      attempts += 1;
+
+      let remaining =
+        max_recording_duration - started.elapsed().as_secs() as i32;
+      if max_recording_duration > 0 && remaining <= 0 {
+        break;
+      }
+
+      let devices = self.backend.get_audio_input_devices().await?;
+      let next_device = select_resume_device(&device, &devices);
+      self.events.emit(Event::Notice {
+        message: tr!("recording-resumed", device = next_device.get_name()),
+      });
+
+      let continuation_file = continuation_file(&output_file, attempts);
+      let remaining = if max_recording_duration > 0 {
+        remaining
+      } else {
+        0
+      };
+      segment = match self
+        .record_audio_with_device(&next_device, &continuation_file, remaining)
+        .await
+      {
+        Err(AudioError::Cancelled) => {
+          let _ = operations::remove_file(&output_file).await;
+          return Err(AudioError::Cancelled);
+        }
+        result => result?,
+      };
+      silence += segment.silence;
+      let offset = WavInspector::read_duration(&output_file).unwrap_or(0.0);
+      silences.extend(segment.silences.iter().map(|interval| {
+        return AudioInterval {
+          start: interval.start + offset,
+          end: interval.end + offset,
+        };
+      }));
+
+      AudioConverter::append_audio(&output_file, &continuation_file).await?;
+      let _ = operations::remove_file(&continuation_file).await;
+      device = next_device;
     }
-    return Err(AudioError::FFMPEGNotFound);
+
+    if self.cancel.is_cancelled() {
+      vlog!("Recording cancelled, discarding {}", output_file);
+      let _ = operations::remove_file(&output_file).await;
+      return Err(AudioError::Cancelled);
+    }
+
+    self.discard_if_too_short(&output_file).await?;
+
+    let duration = WavInspector::read_duration(&output_file)?;
+    let speech_ratio = speech_ratio(duration, silence);
+
+    vlog!("Recording saved to {}", output_file);
+    vlog!(
+      "Recorded {:.1}s, {:.0}% speech",
+      duration,
+      speech_ratio * 100.0
+    );
+
+    self.events.emit(Event::RecordingStopped {
+      path: output_file.clone(),
+      duration,
+    });
+    return Ok(RecordingResult {
+      path: output_file,
+      device,
+      listed_devices,
+      interrupted,
+      duration,
+      speech_ratio,
+      silences,
+    });
   }
 
-  async fn record_audio_with_device(
-    &self,
-    device: AudioInputDevice,
-  ) -> AudioResult<String> {
-    operations::create_directory_all(&self.recordings_directory)
-      .await
-      .map_err(|_| AudioError::CouldNotCreateDirectory)?;
+  async fn get_output_file(&self) -> String {
+    let directory = &self.settings.recordings_directory;
+    let now = chrono::Local::now();
+    let session_name = &self.settings.session_name;
+    if session_name.is_empty() {
+      return format!(
+        "{}/audiocapture_{}.wav",
+        directory,
+        now.format("%Y-%m-%d_%H-%M-%S")
+      );
+    }
 
-    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
-    let output_file = format!(
-      "{}/audiocapture_{}.wav",
-      self.recordings_directory, timestamp
+    let output_file = format!("{}/{}.wav", directory, session_name);
+    if !operations::file_exists(&output_file).await {
+      return output_file;
+    }
+    return format!(
+      "{}/{} {}.wav",
+      directory,
+      session_name,
+      now.format("%H-%M-%S")
     );
+  }
+
+  async fn discard_if_too_short(&self, output_file: &str) -> AudioResult<()> {
+    let min_duration = self.settings.min_duration;
+    if min_duration <= 0 {
+      return Ok(());
+    }
+
+    let duration = WavInspector::read_duration(output_file)?;
+    if duration >= min_duration as f64 {
+      return Ok(());
+    }
 
-    let args = self.platform.build_ffmpeg_recording_arguments(
-      device.get_index().clone(),
-      self.silence_limit,
-      self.silence_detect_noise,
-      self.max_recording_duration,
-      output_file.clone(),
+    vlog!("Discarding {:.1}s recording: {}", duration, output_file);
+    let _ = operations::remove_file(output_file).await;
+    return Err(AudioError::RecordingTooShort(duration, min_duration));
+  }
+
+  fn is_device_pinned(&self) -> bool {
+    return self.settings.pin_device
+      && !self.settings.preferred_audio_input_device.is_empty();
+  }
+
+  async fn select_device(
+    &self,
+    use_cache: bool,
+  ) -> AudioResult<(AudioInputDevice, Option<AudioInputDevices>)> {
+    if self.is_device_pinned() {
+      let preferred = &self.settings.preferred_audio_input_device;
+      vlog!("Using pinned audio input device: {}", preferred);
+      let device = AudioInputDevice::new(preferred.clone(), preferred.clone());
+      return Ok((device, None));
+    }
+
+    let cached_devices = &self.settings.cached_devices;
+    if use_cache && !cached_devices.is_empty() {
+      vlog!("Using cached list of audio input devices");
+      let device = self.choose_device(cached_devices).await;
+      return Ok((device, None));
+    }
+
+    let (available, devices) = tokio::join!(
+      self.backend.check_available(),
+      self.backend.get_audio_input_devices()
     );
+    available?;
+    let devices = devices?;
+    let device = self.choose_device(&devices).await;
+    return Ok((device, Some(devices)));
+  }
 
-    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-    let mut child =
-      ProcessExecutor::spawn_with_stderr_piped("ffmpeg", &args_refs)
-        .await
-        .map_err(|_| AudioError::CouldNotExecuteFFMPEG)?;
+  async fn choose_device(
+    &self,
+    devices: &AudioInputDevices,
+  ) -> AudioInputDevice {
+    let preferred_audio_input_device =
+      self.resolve_preferred_audio_input_device(devices);
+    let device = self
+      .backend
+      .select_audio_input_device(devices.clone(), preferred_audio_input_device)
+      .await;
+    return resolve_system_default_device(device, devices);
+  }
+
+  fn resolve_preferred_audio_input_device(
+    &self,
+    devices: &AudioInputDevices,
+  ) -> String {
+    let preferred = &self.settings.preferred_audio_input_device;
+    if !preferred.is_empty() {
+      return preferred.clone();
+    }
+
+    let last = &self.settings.last_audio_input_device;
+    if last.is_empty() {
+      return String::new();
+    }
+
+    if devices.iter().any(|device| device.get_name() == last) {
+      vlog!("Preferring last used audio input device: {}", last);
+      return last.clone();
+    }
 
-    vlog!("Recording audio to: {}", output_file);
     vlog!(
-      "Recording... will stop after {}s of silence",
-      self.silence_limit
+      "Last used audio input device is no longer available: {}",
+      last
     );
-    if self.max_recording_duration > 0 {
-      vlog!(
-        "Maximum recording duration: {} seconds",
-        self.max_recording_duration
-      );
-    }
+    return String::new();
+  }
 
-    let stderr = child
-      .stderr
-      .take()
-      .ok_or(AudioError::CouldNotReadFFMPEGOutput)?;
-
-    let mut reader = BufReader::new(stderr).lines();
-
-    let silence_limit = self.silence_limit;
-    let child_mutex = Arc::new(Mutex::new(child));
-    let mut timer_handle: Option<JoinHandle<()>> = None;
-
-    while let Ok(Some(line)) = reader.next_line().await {
-      if line.contains("silence_start") {
-        vlog!(
-          "Possible silence detected... starting {}s countdown.",
-          silence_limit
-        );
-
-        let child_for_timer = Arc::clone(&child_mutex);
-        timer_handle = Some(tokio::spawn(async move {
-          tokio::time::sleep(Duration::from_secs(silence_limit as u64)).await;
-          vlog!("Silence limit reached. Stopping recording...");
-          let _ = child_for_timer.lock().await.kill().await;
-        }));
-      }
+  async fn record_audio_with_device(
+    &self,
+    device: &AudioInputDevice,
+    output_file: &str,
+    max_recording_duration: i32,
+  ) -> AudioResult<RecordingSegment> {
+    let deadline = RecordingDeadline::new(
+      max_recording_duration,
+      self.settings.max_duration_warning,
+      self.settings.max_duration_extension,
+    );
 
-      if line.contains("silence_end") {
-        vlog!("Sound detected. Resetting silence timer.");
-        if let Some(handle) = timer_handle.take() {
-          handle.abort();
-        }
-      }
+    let mut settings = self.settings.for_device(device);
+    settings.max_recording_duration = max_recording_duration;
+    if deadline.is_extendable() {
+      settings.max_recording_duration = 0;
     }
 
-    vlog!("Recording ended.");
+    let title = Path::new(output_file)
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().to_string())
+      .unwrap_or_default();
+    let metadata = RecordingMetadata::new(
+      title,
+      chrono::Local::now(),
+      device.get_name().clone(),
+    );
 
-    if let Ok(status) = child_mutex.lock().await.wait().await
-      && !status.success()
-      && status.code() != Some(255)
-      && status.signal() != Some(9)
-    {
-      vlog!("Process failed with exit code: {:?}", status.code());
-      return Err(AudioError::CouldNotExecuteFFMPEG);
+    if max_recording_duration > 0 {
+      vlog!(
+        "Maximum recording duration: {} seconds",
+        max_recording_duration
+      );
     }
 
-    vlog!("Recording saved to {}", output_file);
+    return self
+      .backend
+      .record_segment(SegmentRequest {
+        device,
+        settings: &settings,
+        metadata: &metadata,
+        output_file,
+        deadline,
+        cancel: &self.cancel,
+        stop: &self.stop,
+        events: &self.events,
+      })
+      .await;
+  }
+}
 
-    return Ok(output_file);
+pub(crate) fn select_resume_device(
+  lost_device: &AudioInputDevice,
+  devices: &AudioInputDevices,
+) -> AudioInputDevice {
+  let device =
+    resolve_system_default_device(AudioInputDevice::default(), devices);
+  if device.get_name() == lost_device.get_name() {
+    return AudioInputDevice::default();
   }
+  return device;
+}
+
+fn resolve_system_default_device(
+  device: AudioInputDevice,
+  devices: &AudioInputDevices,
+) -> AudioInputDevice {
+  if !device.is_default() {
+    return device;
+  }
+  return devices
+    .iter()
+    .find(|candidate| candidate.is_system_default())
+    .cloned()
+    .unwrap_or(device);
+}
+
+/// Gets the path of the intermediate file that continues a recording after
+/// its input device was lost.
+pub(crate) fn continuation_file(output_file: &str, attempt: i32) -> String {
+  let path = Path::new(output_file);
+  let stem = path
+    .file_stem()
+    .map(|stem| stem.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let name = intermediate_name(&format!("{}_resumed_{}.wav", stem, attempt));
+  return path.with_file_name(name).to_string_lossy().to_string();
 }
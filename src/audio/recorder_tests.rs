@@ -0,0 +1,45 @@
+use crate::audio::devices::AudioInputDevice;
+use crate::audio::recorder::{continuation_file, select_resume_device};
+
+fn create_devices() -> Vec<AudioInputDevice> {
+  return vec![
+    AudioInputDevice::new(String::from("1"), String::from("USB Microphone")),
+    AudioInputDevice::new(String::from("2"), String::from("Built-in"))
+      .into_system_default(),
+  ];
+}
+
+#[test]
+fn test_select_resume_device_uses_system_default() {
+  let lost =
+    AudioInputDevice::new(String::from("1"), String::from("USB Microphone"));
+  let device = select_resume_device(&lost, &create_devices());
+  assert_eq!(device.get_index(), "2");
+  assert_eq!(device.get_name(), "Built-in");
+}
+
+#[test]
+fn test_select_resume_device_when_system_default_was_lost() {
+  let lost = AudioInputDevice::new(String::from("2"), String::from("Built-in"));
+  let device = select_resume_device(&lost, &create_devices());
+  assert!(device.is_default());
+}
+
+#[test]
+fn test_select_resume_device_without_system_default() {
+  let lost =
+    AudioInputDevice::new(String::from("1"), String::from("USB Microphone"));
+  let devices = vec![AudioInputDevice::new(
+    String::from("3"),
+    String::from("Headset"),
+  )];
+  assert!(select_resume_device(&lost, &devices).is_default());
+}
+
+#[test]
+fn test_continuation_file() {
+  assert_eq!(
+    continuation_file("/recordings/meeting.wav", 2),
+    "/recordings/lumine-tmp-meeting_resumed_2.wav"
+  );
+}
@@ -0,0 +1,35 @@
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::audio::silence::{AudioInterval, speech_intervals};
+
+/// Result of a completed recording.
+///
+/// Describes the recorded file, the device it was captured from, and how
+/// long it is and how much and which parts of it contained speech.
+#[derive(Debug, Clone)]
+pub struct RecordingResult {
+  /// Path to the recorded audio file
+  pub path: String,
+  /// Audio input device used for the recording
+  pub device: AudioInputDevice,
+  /// Devices listed for the recording, `None` if cached or not listed
+  pub listed_devices: Option<AudioInputDevices>,
+  /// Whether the input device was lost while recording
+  pub interrupted: bool,
+  /// Duration of the recording in seconds
+  pub duration: f64,
+  /// Share of the recording that contained speech, between 0.0 and 1.0
+  pub speech_ratio: f64,
+  /// Silent intervals of the recording in chronological order
+  pub silences: Vec<AudioInterval>,
+}
+
+impl RecordingResult {
+  /// Gets the intervals of the recording that contained speech.
+  ///
+  /// # Returns
+  ///
+  /// The speech intervals between the silent ones, in chronological order.
+  pub fn speech_intervals(&self) -> Vec<AudioInterval> {
+    return speech_intervals(self.duration, &self.silences);
+  }
+}
@@ -0,0 +1,82 @@
+use std::path::Path;
+
+const FLATPAK_INFO_PATH: &str = "/.flatpak-info";
+const DEFAULT_FLATPAK_ID: &str = "io.github.mahanrahmati.lumine";
+const DEFAULT_SNAP_NAME: &str = "lumine";
+
+/// Application sandbox Lumine is running in.
+///
+/// Sandboxed applications reach the microphone through the PulseAudio or
+/// PipeWire socket only when the sandbox grants access to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sandbox {
+  /// Flatpak sandbox with the application ID
+  Flatpak(String),
+  /// Snap confinement with the snap name
+  Snap(String),
+}
+
+impl Sandbox {
+  /// Detects the sandbox of the current process.
+  ///
+  /// # Returns
+  ///
+  /// The detected `Sandbox`, or `None` when not sandboxed.
+  pub fn detect() -> Option<Sandbox> {
+    return Sandbox::from_environment(
+      Path::new(FLATPAK_INFO_PATH).exists(),
+      std::env::var("FLATPAK_ID").ok(),
+      std::env::var("SNAP_NAME").ok(),
+    );
+  }
+
+  /// Detects the sandbox from environment information.
+  ///
+  /// # Arguments
+  ///
+  /// * `flatpak_info` - Whether the Flatpak info file exists
+  /// * `flatpak_id` - Value of `FLATPAK_ID`, if set
+  /// * `snap_name` - Value of `SNAP_NAME`, if set
+  ///
+  /// # Returns
+  ///
+  /// The detected `Sandbox`, or `None` when not sandboxed.
+  pub fn from_environment(
+    flatpak_info: bool,
+    flatpak_id: Option<String>,
+    snap_name: Option<String>,
+  ) -> Option<Sandbox> {
+    let flatpak_id = flatpak_id.filter(|id| !id.is_empty());
+    if flatpak_info || flatpak_id.is_some() {
+      return Some(Sandbox::Flatpak(
+        flatpak_id.unwrap_or(String::from(DEFAULT_FLATPAK_ID)),
+      ));
+    }
+    if let Some(name) = snap_name.filter(|name| !name.is_empty()) {
+      return Some(Sandbox::Snap(name));
+    }
+    return None;
+  }
+
+  /// Gets instructions for granting microphone access to the sandbox.
+  ///
+  /// # Returns
+  ///
+  /// A `String` with the command that grants access.
+  pub fn microphone_instructions(&self) -> String {
+    return match self {
+      Sandbox::Flatpak(id) => format!(
+        "Grant audio access with 'flatpak override --user --socket=pulseaudio {}' and restart Lumine.",
+        id
+      ),
+      Sandbox::Snap(name) => format!(
+        "Connect the audio-record interface with 'snap connect {}:audio-record' and restart Lumine.",
+        if name.is_empty() {
+          DEFAULT_SNAP_NAME
+        } else {
+          name
+        }
+      ),
+    };
+  }
+}
@@ -0,0 +1,49 @@
+use crate::audio::sandbox::Sandbox;
+
+#[test]
+fn test_detect_flatpak() {
+  assert_eq!(
+    Sandbox::from_environment(
+      true,
+      Some(String::from("org.example.Lumine")),
+      None
+    ),
+    Some(Sandbox::Flatpak(String::from("org.example.Lumine")))
+  );
+  assert_eq!(
+    Sandbox::from_environment(true, None, Some(String::from("lumine"))),
+    Some(Sandbox::Flatpak(String::from(
+      "io.github.mahanrahmati.lumine"
+    )))
+  );
+}
+
+#[test]
+fn test_detect_snap() {
+  assert_eq!(
+    Sandbox::from_environment(
+      false,
+      Some(String::new()),
+      Some(String::from("lumine"))
+    ),
+    Some(Sandbox::Snap(String::from("lumine")))
+  );
+  assert_eq!(Sandbox::from_environment(false, None, None), None);
+}
+
+#[test]
+fn test_microphone_instructions() {
+  let flatpak = Sandbox::Flatpak(String::from("org.example.Lumine"));
+  assert!(
+    flatpak
+      .microphone_instructions()
+      .contains("--socket=pulseaudio org.example.Lumine")
+  );
+
+  let snap = Sandbox::Snap(String::from("lumine"));
+  assert!(
+    snap
+      .microphone_instructions()
+      .contains("snap connect lumine:audio-record")
+  );
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::audio::devices::{AudioInputDevice, AudioInputDevices};
+use crate::vlog;
+
+/// Settings that control how audio is recorded.
+///
+/// Groups the recorder parameters resolved from configuration so they can be
+/// passed to the recorder as a single value.
+#[derive(Debug, Clone, Default)]
+pub struct RecorderSettings {
+  /// Name of the backend that captures audio (`ffmpeg` or `native`)
+  pub backend: String,
+  /// Directory path to save audio recordings
+  pub recordings_directory: String,
+  /// Name of the recording session, used as the file name (empty for a timestamp)
+  pub session_name: String,
+  /// Directory for converted intermediate files (empty for next to the input)
+  pub temp_directory: String,
+  /// Seconds of silence before stopping recording
+  pub silence_limit: i32,
+  /// Whether silence is detected without stopping the recording, which then
+  /// only ends when stopped or at the maximum duration
+  pub ignore_silence: bool,
+  /// Noise threshold in decibels for silence detection
+  pub silence_detect_noise: i32,
+  /// Name of preferred audio input device
+  pub preferred_audio_input_device: String,
+  /// Whether the preferred device is used as the device index without listing devices
+  pub pin_device: bool,
+  /// Devices listed by an earlier recording (empty to list them again)
+  pub cached_devices: AudioInputDevices,
+  /// Name of the last successfully used audio input device
+  pub last_audio_input_device: String,
+  /// Maximum recording duration in seconds (0 for unlimited)
+  pub max_recording_duration: i32,
+  /// Minimum recording duration in seconds, shorter recordings are discarded (0 to disable)
+  pub min_duration: i32,
+  /// Seconds before the maximum duration at which a warning is shown (0 to disable)
+  pub max_duration_warning: i32,
+  /// Seconds added to the maximum duration when extending (0 to disable)
+  pub max_duration_extension: i32,
+  /// Whether to continue on another device when the input device is lost
+  pub resume_on_device_loss: bool,
+  /// Whether to record 16kHz mono audio ready for Whisper
+  pub whisper_format: bool,
+  /// Input gain in decibels (0 for none)
+  pub gain: f64,
+  /// One-based input channel to record (0 to downmix all channels)
+  pub channel: i32,
+  /// Source with the other side of a call, recorded as a second channel
+  /// (empty to record only the input device)
+  pub call_source: String,
+  /// Settings overrides keyed by device name
+  pub device_overrides: HashMap<String, DeviceOverrides>,
+}
+
+/// Recorder settings overrides for a specific audio input device.
+///
+/// Unset fields keep the value from the general recorder settings.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceOverrides {
+  /// Seconds of silence before stopping recording
+  pub silence_limit: Option<i32>,
+  /// Noise threshold in decibels for silence detection
+  pub silence_detect_noise: Option<i32>,
+  /// Input gain in decibels
+  pub gain: Option<f64>,
+  /// One-based input channel to record (0 or negative to downmix all
+  /// channels)
+  pub channel: Option<i32>,
+}
+
+impl RecorderSettings {
+  /// Resolves the settings to use for the given device.
+  ///
+  /// Applies the overrides whose key matches the device name exactly or, if
+  /// there is no exact match, the longest key contained in the device name.
+  ///
+  /// # Arguments
+  ///
+  /// * `device` - The selected audio input device
+  ///
+  /// # Returns
+  ///
+  /// A `RecorderSettings` with any matching device overrides applied.
+  pub fn for_device(&self, device: &AudioInputDevice) -> RecorderSettings {
+    let mut settings = self.clone();
+    let name = device.get_name();
+
+    let overrides = self.device_overrides.get(name).or_else(|| {
+      self
+        .device_overrides
+        .iter()
+        .filter(|(key, _)| !key.is_empty() && name.contains(key.as_str()))
+        .max_by_key(|(key, _)| key.len())
+        .map(|(_, overrides)| overrides)
+    });
+
+    if let Some(overrides) = overrides {
+      vlog!("Applying recorder overrides for device: {}", name);
+      if let Some(silence_limit) = overrides.silence_limit {
+        settings.silence_limit = silence_limit;
+      }
+      if let Some(silence_detect_noise) = overrides.silence_detect_noise {
+        settings.silence_detect_noise = silence_detect_noise;
+      }
+      if let Some(gain) = overrides.gain {
+        settings.gain = gain;
+      }
+      if let Some(channel) = overrides.channel {
+        settings.channel = channel.max(0);
+      }
+    }
+
+    return settings;
+  }
+}
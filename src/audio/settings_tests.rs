@@ -0,0 +1,160 @@
+use chrono::Local;
+
+use crate::audio::devices::AudioInputDevice;
+use crate::audio::metadata::RecordingMetadata;
+use crate::audio::platform::{
+  AudioPlatform, build_audio_filters, build_output_format_arguments,
+  get_platform,
+};
+use crate::audio::settings::{DeviceOverrides, RecorderSettings};
+
+fn create_settings() -> RecorderSettings {
+  let mut settings = RecorderSettings {
+    silence_limit: 2,
+    silence_detect_noise: 40,
+    ..Default::default()
+  };
+  settings.device_overrides.insert(
+    String::from("USB"),
+    DeviceOverrides {
+      silence_detect_noise: Some(30),
+      ..Default::default()
+    },
+  );
+  settings.device_overrides.insert(
+    String::from("USB Microphone"),
+    DeviceOverrides {
+      silence_detect_noise: Some(35),
+      gain: Some(6.0),
+      ..Default::default()
+    },
+  );
+  return settings;
+}
+
+#[test]
+fn test_for_device_applies_most_specific_override() {
+  let settings = create_settings();
+  let device = AudioInputDevice::new(
+    String::from("1"),
+    String::from("Blue USB Microphone"),
+  );
+
+  let resolved = settings.for_device(&device);
+  assert_eq!(resolved.silence_detect_noise, 35);
+  assert_eq!(resolved.silence_limit, 2);
+  assert_eq!(resolved.gain, 6.0);
+}
+
+#[test]
+fn test_for_device_without_match_keeps_settings() {
+  let settings = create_settings();
+  let resolved = settings.for_device(&AudioInputDevice::default());
+
+  assert_eq!(resolved.silence_detect_noise, 40);
+  assert_eq!(resolved.gain, 0.0);
+}
+
+#[test]
+fn test_build_audio_filters() {
+  let mut settings = create_settings();
+  assert_eq!(build_audio_filters(&settings), "silencedetect=n=-40dB:d=2");
+
+  settings.gain = 6.0;
+  assert_eq!(
+    build_audio_filters(&settings),
+    "volume=6dB,silencedetect=n=-40dB:d=2"
+  );
+}
+
+#[test]
+fn test_build_audio_filters_with_channel() {
+  let mut settings = create_settings();
+  settings.channel = 2;
+  settings.gain = -3.0;
+  assert_eq!(
+    build_audio_filters(&settings),
+    "pan=mono|c0=c1,volume=-3dB,silencedetect=n=-40dB:d=2"
+  );
+}
+
+#[test]
+fn test_for_device_clamps_negative_channel() {
+  let mut settings = create_settings();
+  settings.device_overrides.insert(
+    String::from("Interface"),
+    DeviceOverrides {
+      channel: Some(-2),
+      ..Default::default()
+    },
+  );
+  let device =
+    AudioInputDevice::new(String::from("2"), String::from("Interface"));
+
+  let resolved = settings.for_device(&device);
+  assert_eq!(resolved.channel, 0);
+  assert_eq!(build_audio_filters(&resolved), "silencedetect=n=-40dB:d=2");
+}
+
+#[test]
+fn test_recording_arguments_with_channel() {
+  let mut settings = create_settings();
+  settings.channel = 4;
+  let metadata = RecordingMetadata::new(
+    String::from("recording"),
+    Local::now(),
+    String::from("Interface"),
+  );
+  let args = get_platform().build_ffmpeg_recording_arguments(
+    String::from("2"),
+    &settings,
+    &metadata,
+    String::from("recording.wav"),
+  );
+
+  let channels = args
+    .iter()
+    .position(|arg| arg == "-channels")
+    .map(|index| args[index + 1].as_str());
+  if cfg!(target_os = "linux") {
+    // PulseAudio records two channels unless asked for more.
+    assert_eq!(channels, Some("4"));
+  } else {
+    // AVFoundation records the channels the device delivers.
+    assert_eq!(channels, None);
+  }
+  assert!(
+    args.contains(&String::from("pan=mono|c0=c3,silencedetect=n=-40dB:d=2"))
+  );
+}
+
+#[test]
+fn test_build_output_format_arguments() {
+  let mut settings = create_settings();
+  assert_eq!(
+    build_output_format_arguments(&settings),
+    vec!["-acodec", "pcm_s16le"]
+  );
+
+  settings.whisper_format = true;
+  assert_eq!(
+    build_output_format_arguments(&settings),
+    vec!["-acodec", "pcm_s16le", "-ar", "16000", "-ac", "1"]
+  );
+}
+
+#[test]
+fn test_build_call_filters() {
+  let mut settings = create_settings();
+  settings.call_source = String::from("softphone.monitor");
+  settings.channel = 2;
+  settings.whisper_format = true;
+  assert_eq!(
+    build_audio_filters(&settings),
+    "[0:a][1:a]amerge=inputs=2,silencedetect=n=-40dB:d=2"
+  );
+  assert_eq!(
+    build_output_format_arguments(&settings),
+    vec!["-acodec", "pcm_s16le", "-ar", "16000"]
+  );
+}
@@ -0,0 +1,265 @@
+use crate::audio::parser::{SilenceEvent, parse_silence_event};
+
+/// Level reported for digital silence.
+pub(crate) const MIN_LEVEL_DB: f64 = -100.0;
+
+/// Time interval of a recording, such as a span of silence or speech.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct AudioInterval {
+  /// Start of the interval in seconds
+  pub start: f64,
+  /// End of the interval in seconds
+  pub end: f64,
+}
+
+/// Accumulates silence reported by FFmpeg's silencedetect filter.
+///
+/// Parses `silence_start` and `silence_end` lines from FFmpeg's stderr to
+/// measure how much of a recording was silent and when.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SilenceTracker {
+  total_silence: f64,
+  open_silence_start: Option<f64>,
+  intervals: Vec<AudioInterval>,
+}
+
+impl SilenceTracker {
+  /// Creates a new, empty SilenceTracker.
+  ///
+  /// # Returns
+  ///
+  /// A new `SilenceTracker` instance.
+  pub fn new() -> Self {
+    return Self::default();
+  }
+
+  /// Records a line of FFmpeg output.
+  ///
+  /// Lines that are not silencedetect events are ignored.
+  ///
+  /// # Arguments
+  ///
+  /// * `line` - A line of FFmpeg stderr output
+  ///
+  /// # Returns
+  ///
+  /// An `Option<SilenceEvent>` containing the event of the line, or `None`
+  /// if it is no silencedetect event.
+  pub fn observe(&mut self, line: &str) -> Option<SilenceEvent> {
+    let event = parse_silence_event(line)?;
+    self.record(event);
+    return Some(event);
+  }
+
+  /// Records a silence event.
+  ///
+  /// # Arguments
+  ///
+  /// * `event` - Event reported by FFmpeg or a level-based detector
+  pub fn record(&mut self, event: SilenceEvent) {
+    match event {
+      SilenceEvent::Start(start) => {
+        self.open_silence_start = Some(start);
+      }
+      SilenceEvent::End { end, duration } => {
+        if let Some(duration) = duration {
+          self.total_silence += duration;
+        } else if let Some(start) = self.open_silence_start {
+          self.total_silence += (end - start).max(0.0);
+        }
+        let start = self
+          .open_silence_start
+          .or_else(|| return duration.map(|duration| end - duration))
+          .unwrap_or(end)
+          .max(0.0);
+        self.intervals.push(AudioInterval { start, end });
+        self.open_silence_start = None;
+      }
+    }
+  }
+
+  /// Gets the silent intervals in a recording of the given duration.
+  ///
+  /// Silence that started but never ended lasts until the end of the
+  /// recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `duration` - Duration of the recording in seconds
+  ///
+  /// # Returns
+  ///
+  /// The silent intervals in chronological order.
+  pub fn intervals(&self, duration: f64) -> Vec<AudioInterval> {
+    let mut intervals = self.intervals.clone();
+    if let Some(start) = self.open_silence_start
+      && start < duration
+    {
+      intervals.push(AudioInterval {
+        start,
+        end: duration,
+      });
+    }
+    return intervals;
+  }
+
+  /// Gets the total silence in a recording of the given duration.
+  ///
+  /// Silence that started but never ended is counted up to the end of the
+  /// recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `duration` - Duration of the recording in seconds
+  ///
+  /// # Returns
+  ///
+  /// The total silence in seconds.
+  pub fn total_silence(&self, duration: f64) -> f64 {
+    let trailing = self
+      .open_silence_start
+      .map(|start| (duration - start).max(0.0))
+      .unwrap_or(0.0);
+    return (self.total_silence + trailing).min(duration.max(0.0));
+  }
+}
+
+/// Detects silence from audio levels like FFmpeg's silencedetect filter.
+///
+/// Used by backends that measure levels themselves instead of running
+/// silencedetect. Silence is reported once the level stayed below the noise
+/// threshold for the minimum duration, starting when it first dropped.
+#[cfg(any(feature = "cpal", feature = "gstreamer", test))]
+#[derive(Debug, Clone)]
+pub(crate) struct LevelSilenceDetector {
+  noise_db: f64,
+  min_duration: f64,
+  quiet_since: Option<f64>,
+  reported: bool,
+}
+
+#[cfg(any(feature = "cpal", feature = "gstreamer", test))]
+impl LevelSilenceDetector {
+  /// Creates a new LevelSilenceDetector.
+  ///
+  /// # Arguments
+  ///
+  /// * `silence_detect_noise` - Noise threshold in decibels below full scale
+  /// * `min_duration` - Seconds the level must stay below the threshold
+  ///
+  /// # Returns
+  ///
+  /// A new `LevelSilenceDetector` instance.
+  pub fn new(silence_detect_noise: i32, min_duration: i32) -> Self {
+    return Self {
+      noise_db: -(silence_detect_noise.abs() as f64),
+      min_duration: min_duration.max(0) as f64,
+      quiet_since: None,
+      reported: false,
+    };
+  }
+
+  /// Records the level of a block of audio.
+  ///
+  /// # Arguments
+  ///
+  /// * `time` - Start of the block in seconds
+  /// * `level_db` - Level of the block in decibels below full scale
+  ///
+  /// # Returns
+  ///
+  /// An `Option<SilenceEvent>` containing the event the block caused, if
+  /// any.
+  pub fn observe(&mut self, time: f64, level_db: f64) -> Option<SilenceEvent> {
+    if level_db < self.noise_db {
+      let since = *self.quiet_since.get_or_insert(time);
+      if !self.reported && time - since >= self.min_duration {
+        self.reported = true;
+        return Some(SilenceEvent::Start(since));
+      }
+      return None;
+    }
+
+    let since = self.quiet_since.take()?;
+    if !std::mem::take(&mut self.reported) {
+      return None;
+    }
+    return Some(SilenceEvent::End {
+      end: time,
+      duration: Some(time - since),
+    });
+  }
+}
+
+/// Computes the RMS level of samples in decibels below full scale.
+///
+/// # Arguments
+///
+/// * `samples` - Samples between -1.0 and 1.0
+///
+/// # Returns
+///
+/// The level in decibels, at most 0.0 and at least -100.0.
+#[cfg(any(feature = "cpal", test))]
+pub(crate) fn level_db(samples: &[f32]) -> f64 {
+  if samples.is_empty() {
+    return MIN_LEVEL_DB;
+  }
+  let power = samples
+    .iter()
+    .map(|sample| (*sample as f64).powi(2))
+    .sum::<f64>()
+    / samples.len() as f64;
+  return (10.0 * power.log10()).clamp(MIN_LEVEL_DB, 0.0);
+}
+
+/// Computes the share of a recording that contained speech.
+///
+/// # Arguments
+///
+/// * `duration` - Duration of the recording in seconds
+/// * `silence` - Total silence in the recording in seconds
+///
+/// # Returns
+///
+/// The speech ratio between 0.0 and 1.0, or 0.0 for empty recordings.
+pub(crate) fn speech_ratio(duration: f64, silence: f64) -> f64 {
+  if duration <= 0.0 {
+    return 0.0;
+  }
+  return ((duration - silence) / duration).clamp(0.0, 1.0);
+}
+
+/// Computes the intervals of a recording that contained speech.
+///
+/// Speech is everything between the silent intervals.
+///
+/// # Arguments
+///
+/// * `duration` - Duration of the recording in seconds
+/// * `silences` - Silent intervals in chronological order
+///
+/// # Returns
+///
+/// The speech intervals in chronological order.
+pub(crate) fn speech_intervals(
+  duration: f64,
+  silences: &[AudioInterval],
+) -> Vec<AudioInterval> {
+  let mut intervals = Vec::new();
+  let mut start = 0.0;
+  for silence in silences {
+    let end = silence.start.min(duration);
+    if end > start {
+      intervals.push(AudioInterval { start, end });
+    }
+    start = f64::max(start, silence.end);
+  }
+  if duration > start {
+    intervals.push(AudioInterval {
+      start,
+      end: duration,
+    });
+  }
+  return intervals;
+}
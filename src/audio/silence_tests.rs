@@ -0,0 +1,128 @@
+use crate::audio::parser::SilenceEvent;
+use crate::audio::silence::{
+  AudioInterval, LevelSilenceDetector, SilenceTracker, level_db,
+  speech_intervals, speech_ratio,
+};
+
+#[test]
+fn test_silence_tracker_sums_closed_silences() {
+  let mut tracker = SilenceTracker::new();
+  tracker.observe("[silencedetect @ 0x1] silence_start: 1.5");
+  tracker
+    .observe("[silencedetect @ 0x1] silence_end: 3 | silence_duration: 1.5");
+  tracker.observe("size=     256kB time=00:00:05.00 bitrate= 419.4kbits/s");
+  tracker.observe("[silencedetect @ 0x1] silence_start: 6");
+  tracker
+    .observe("[silencedetect @ 0x1] silence_end: 6.5 | silence_duration: 0.5");
+
+  assert_eq!(tracker.total_silence(10.0), 2.0);
+}
+
+#[test]
+fn test_silence_tracker_counts_trailing_silence() {
+  let mut tracker = SilenceTracker::new();
+  tracker.observe("[silencedetect @ 0x1] silence_start: 8");
+
+  assert_eq!(tracker.total_silence(10.0), 2.0);
+}
+
+#[test]
+fn test_speech_ratio() {
+  assert_eq!(speech_ratio(10.0, 2.5), 0.75);
+  assert_eq!(speech_ratio(10.0, 12.0), 0.0);
+  assert_eq!(speech_ratio(0.0, 0.0), 0.0);
+}
+
+#[test]
+fn test_silence_tracker_intervals() {
+  let mut tracker = SilenceTracker::new();
+  tracker.observe("[silencedetect @ 0x1] silence_start: 1.5");
+  tracker
+    .observe("[silencedetect @ 0x1] silence_end: 3 | silence_duration: 1.5");
+  tracker.observe("[silencedetect @ 0x1] silence_start: 8");
+
+  assert_eq!(
+    tracker.intervals(10.0),
+    vec![
+      AudioInterval {
+        start: 1.5,
+        end: 3.0
+      },
+      AudioInterval {
+        start: 8.0,
+        end: 10.0
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_speech_intervals() {
+  let silences = vec![
+    AudioInterval {
+      start: 0.0,
+      end: 1.0,
+    },
+    AudioInterval {
+      start: 4.0,
+      end: 6.0,
+    },
+  ];
+
+  assert_eq!(
+    speech_intervals(8.0, &silences),
+    vec![
+      AudioInterval {
+        start: 1.0,
+        end: 4.0
+      },
+      AudioInterval {
+        start: 6.0,
+        end: 8.0
+      },
+    ]
+  );
+  assert_eq!(
+    speech_intervals(2.0, &[]),
+    vec![AudioInterval {
+      start: 0.0,
+      end: 2.0
+    }]
+  );
+}
+
+#[test]
+fn test_level_silence_detector_reports_like_silencedetect() {
+  let mut detector = LevelSilenceDetector::new(40, 2);
+
+  assert_eq!(detector.observe(0.0, -20.0), None);
+  assert_eq!(detector.observe(1.0, -60.0), None);
+  assert_eq!(detector.observe(2.0, -60.0), None);
+  assert_eq!(detector.observe(3.0, -60.0), Some(SilenceEvent::Start(1.0)));
+  assert_eq!(detector.observe(4.0, -60.0), None);
+  assert_eq!(
+    detector.observe(4.5, -10.0),
+    Some(SilenceEvent::End {
+      end: 4.5,
+      duration: Some(3.5),
+    })
+  );
+}
+
+#[test]
+fn test_level_silence_detector_ignores_short_pauses() {
+  let mut detector = LevelSilenceDetector::new(40, 2);
+
+  assert_eq!(detector.observe(0.0, -60.0), None);
+  assert_eq!(detector.observe(1.0, -60.0), None);
+  assert_eq!(detector.observe(1.5, -10.0), None);
+  assert_eq!(detector.observe(2.5, -60.0), None);
+}
+
+#[test]
+fn test_level_db() {
+  assert_eq!(level_db(&[1.0, -1.0]), 0.0);
+  assert!((level_db(&[0.1, -0.1]) - -20.0).abs() < 1e-6);
+  assert_eq!(level_db(&[0.0; 16]), -100.0);
+  assert_eq!(level_db(&[]), -100.0);
+}
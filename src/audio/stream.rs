@@ -0,0 +1,177 @@
+use std::process::ExitStatus;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Child;
+use tokio_util::sync::CancellationToken;
+
+use crate::audio::errors::{AudioError, AudioResult};
+use crate::audio::ffmpeg;
+use crate::files::operations;
+use crate::vlog;
+
+const STREAM_SCHEMES: [&str; 9] = [
+  "rtsp", "rtsps", "rtmp", "rtmps", "http", "https", "srt", "udp", "rtp",
+];
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Checks whether an input names a network stream rather than a file.
+///
+/// # Arguments
+///
+/// * `input` - File path or URL given as the input
+///
+/// # Returns
+///
+/// `true` if the input is a URL with a streaming scheme such as `rtsp`,
+/// `rtmp` or `https` (for HLS playlists and internet radio).
+pub fn is_stream_url(input: &str) -> bool {
+  return input.split_once("://").is_some_and(|(scheme, _)| {
+    return STREAM_SCHEMES.contains(&scheme.to_lowercase().as_str());
+  });
+}
+
+/// Live capture of a network stream in Whisper-ready chunks.
+///
+/// FFmpeg pulls the stream and writes consecutive 16kHz mono WAV chunks of
+/// a fixed duration into a private directory, which is removed when the
+/// capture finishes.
+#[derive(Debug)]
+pub struct StreamCapture {
+  url: String,
+  directory: String,
+  child: Child,
+  next_index: usize,
+  cancel: CancellationToken,
+}
+
+impl StreamCapture {
+  /// Starts capturing a stream.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - URL of the stream
+  /// * `directory` - Directory for the chunks, created if missing
+  /// * `chunk_duration` - Length of each chunk in seconds
+  /// * `cancel` - Token that stops the capture when cancelled
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<StreamCapture>` containing the running capture or an
+  /// error if FFmpeg could not be started.
+  pub async fn start(
+    url: &str,
+    directory: &str,
+    chunk_duration: i32,
+    cancel: CancellationToken,
+  ) -> AudioResult<StreamCapture> {
+    operations::create_directory_all(directory)
+      .await
+      .map_err(AudioError::CouldNotCreateDirectory)?;
+
+    let pattern = format!("{}/chunk_%05d.wav", directory);
+    let chunk_duration = chunk_duration.to_string();
+    let args = [
+      "-nostats",
+      "-loglevel",
+      "error",
+      "-i",
+      url,
+      "-vn",
+      "-ar",
+      "16000",
+      "-ac",
+      "1",
+      "-c:a",
+      "pcm_s16le",
+      "-f",
+      "segment",
+      "-segment_time",
+      &chunk_duration,
+      "-reset_timestamps",
+      "1",
+      &pattern,
+      "-y",
+    ];
+    let mut child = ffmpeg::spawn(&args)
+      .await
+      .map_err(|e| AudioError::CouldNotExecuteFFMPEG(e.into()))?;
+
+    if let Some(stderr) = child.stderr.take() {
+      tokio::spawn(async move {
+        let mut lines = BufReader::new(stderr).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+          vlog!("FFmpeg: {}", line);
+        }
+      });
+    }
+    vlog!("Capturing stream {} into {}", url, directory);
+
+    return Ok(StreamCapture {
+      url: url.to_string(),
+      directory: directory.to_string(),
+      child,
+      next_index: 0,
+      cancel,
+    });
+  }
+
+  /// Waits for the next complete chunk.
+  ///
+  /// A chunk is complete once FFmpeg starts the following one or the stream
+  /// ends. Cancelling stops FFmpeg and discards the chunk in progress.
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<Option<String>>` containing the path of the chunk,
+  /// `None` once the stream ended or the capture was cancelled, or an error
+  /// if the stream could not be opened.
+  pub async fn next_chunk(&mut self) -> AudioResult<Option<String>> {
+    loop {
+      let current = self.chunk_path(self.next_index);
+      if operations::file_exists(&self.chunk_path(self.next_index + 1)).await {
+        self.next_index += 1;
+        return Ok(Some(current));
+      }
+
+      if let Ok(Some(status)) = self.child.try_wait() {
+        return self.last_chunk(current, status).await;
+      }
+
+      tokio::select! {
+        _ = tokio::time::sleep(POLL_INTERVAL) => {}
+        _ = self.cancel.cancelled() => {
+          vlog!("Stream capture cancelled. Stopping FFmpeg...");
+          let _ = self.child.kill().await;
+          return Ok(None);
+        }
+      }
+    }
+  }
+
+  /// Stops FFmpeg and removes the chunk directory.
+  pub async fn finish(mut self) {
+    let _ = self.child.kill().await;
+    let _ = tokio::fs::remove_dir_all(&self.directory).await;
+  }
+
+  async fn last_chunk(
+    &mut self,
+    current: String,
+    status: ExitStatus,
+  ) -> AudioResult<Option<String>> {
+    if operations::file_exists(&current).await {
+      self.next_index += 1;
+      return Ok(Some(current));
+    }
+    if self.next_index == 0 && !status.success() {
+      return Err(AudioError::CouldNotOpenStream(self.url.clone()));
+    }
+    vlog!("Stream ended.");
+    return Ok(None);
+  }
+
+  fn chunk_path(&self, index: usize) -> String {
+    return format!("{}/chunk_{:05}.wav", self.directory, index);
+  }
+}
@@ -0,0 +1,11 @@
+use crate::audio::stream::is_stream_url;
+
+#[test]
+fn test_is_stream_url() {
+  assert!(is_stream_url("rtsp://camera.local/live"));
+  assert!(is_stream_url("RTMP://example.com/app/key"));
+  assert!(is_stream_url("https://radio.example.com/stream.m3u8"));
+  assert!(!is_stream_url("recordings/talk.wav"));
+  assert!(!is_stream_url("file:///tmp/talk.wav"));
+  assert!(!is_stream_url("-"));
+}
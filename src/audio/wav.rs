@@ -0,0 +1,233 @@
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read, Seek, SeekFrom, Take};
+
+use crate::audio::errors::{AudioError, AudioResult};
+
+const RIFF_HEADER_SIZE: usize = 12;
+const CHUNK_HEADER_SIZE: usize = 8;
+const MAX_HEADER_SIZE: u64 = 64 * 1024;
+const CHUNK_FRAMES: usize = 16 * 1024;
+const PCM_FORMAT: u64 = 1;
+const WHISPER_SAMPLE_RATE: u64 = 16000;
+
+/// Reads properties of WAV files produced by the recorder and converter.
+pub(crate) struct WavInspector;
+
+/// Streams the samples of a 16-bit PCM WAV file in chunks.
+///
+/// Only one chunk is held in memory at a time, so long recordings can be
+/// processed without loading them completely.
+pub(crate) struct WavSamples {
+  reader: BufReader<Take<File>>,
+  channels: usize,
+  sample_rate: u32,
+  buffer: Vec<u8>,
+}
+
+impl WavSamples {
+  /// Gets the sample rate of the audio.
+  ///
+  /// # Returns
+  ///
+  /// The sample rate in Hz.
+  pub fn sample_rate(&self) -> u32 {
+    return self.sample_rate;
+  }
+
+  /// Reads the next chunk of samples of the first channel.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<Vec<i16>>` containing the samples, or `None` at the end of
+  /// the audio data or when it can no longer be read.
+  pub fn next_chunk(&mut self) -> Option<Vec<i16>> {
+    let frame_size = self.channels * 2;
+    let mut filled = 0;
+    while filled < self.buffer.len() {
+      match self.reader.read(&mut self.buffer[filled..]) {
+        Ok(0) | Err(_) => break,
+        Ok(read) => filled += read,
+      }
+    }
+    let samples: Vec<i16> = self.buffer[..filled - filled % frame_size]
+      .chunks_exact(frame_size)
+      .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+      .collect();
+    if samples.is_empty() {
+      return None;
+    }
+    return Some(samples);
+  }
+}
+
+/// Format information read from a WAV header.
+#[derive(Debug, Clone, Copy)]
+struct WavHeader {
+  audio_format: u64,
+  channels: u64,
+  sample_rate: u64,
+  bits_per_sample: u64,
+  data_offset: u64,
+  data_size: u64,
+}
+
+impl WavInspector {
+  /// Reads the duration of a WAV file from its header.
+  ///
+  /// Recordings stopped by killing FFmpeg keep placeholder sizes in their
+  /// header, so the duration is capped by what the file size can hold.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the WAV file
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<f64>` containing the duration in seconds
+  /// or an error if the file could not be read.
+  pub fn read_duration(file_path: &str) -> AudioResult<f64> {
+    let error = |e| AudioError::CouldNotReadWav(file_path.to_string(), e);
+
+    let file = std::fs::File::open(file_path).map_err(error)?;
+    let file_size = file.metadata().map_err(error)?.len();
+
+    let mut bytes = Vec::new();
+    file
+      .take(MAX_HEADER_SIZE)
+      .read_to_end(&mut bytes)
+      .map_err(error)?;
+
+    let header = parse_header(&bytes)
+      .ok_or_else(|| error(invalid_data("missing WAV header")))?;
+    let frame_size = header.channels * header.bits_per_sample.div_ceil(8);
+    if header.sample_rate == 0 || frame_size == 0 {
+      return Err(error(invalid_data("empty WAV format")));
+    }
+
+    let available = file_size.saturating_sub(header.data_offset);
+    let data_size = header.data_size.min(available);
+    return Ok((data_size / frame_size) as f64 / header.sample_rate as f64);
+  }
+
+  /// Checks whether a file is already in the format Whisper expects.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the file
+  ///
+  /// # Returns
+  ///
+  /// `true` if the file is a 16 kHz mono 16-bit PCM WAV file, `false`
+  /// otherwise or if it could not be read.
+  pub fn is_whisper_ready(file_path: &str) -> bool {
+    let Ok(file) = File::open(file_path) else {
+      return false;
+    };
+    let mut bytes = Vec::new();
+    if file.take(MAX_HEADER_SIZE).read_to_end(&mut bytes).is_err() {
+      return false;
+    }
+    return parse_header(&bytes).is_some_and(|header| {
+      return header.audio_format == PCM_FORMAT
+        && header.channels == 1
+        && header.sample_rate == WHISPER_SAMPLE_RATE
+        && header.bits_per_sample == 16;
+    });
+  }
+
+  /// Opens a 16-bit PCM WAV file for streaming its samples.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the WAV file
+  ///
+  /// # Returns
+  ///
+  /// An `AudioResult<WavSamples>` streaming the samples of the first channel
+  /// or an error if the file could not be read.
+  pub fn stream_samples(file_path: &str) -> AudioResult<WavSamples> {
+    let error = |e| AudioError::CouldNotReadWav(file_path.to_string(), e);
+
+    let mut file = File::open(file_path).map_err(error)?;
+    let mut bytes = Vec::new();
+    (&mut file)
+      .take(MAX_HEADER_SIZE)
+      .read_to_end(&mut bytes)
+      .map_err(error)?;
+
+    let header = parse_header(&bytes)
+      .ok_or_else(|| error(invalid_data("missing WAV header")))?;
+    if header.bits_per_sample != 16 || header.channels == 0 {
+      return Err(error(invalid_data("not 16-bit PCM audio")));
+    }
+    let sample_rate = u32::try_from(header.sample_rate)
+      .map_err(|_| error(invalid_data("unsupported sample rate")))?;
+    let channels = usize::try_from(header.channels)
+      .map_err(|_| error(invalid_data("unsupported channel count")))?;
+
+    file
+      .seek(SeekFrom::Start(header.data_offset))
+      .map_err(error)?;
+    return Ok(WavSamples {
+      reader: BufReader::new(file.take(header.data_size)),
+      channels,
+      sample_rate,
+      buffer: vec![0; CHUNK_FRAMES * channels * 2],
+    });
+  }
+}
+
+fn parse_header(bytes: &[u8]) -> Option<WavHeader> {
+  if bytes.len() < RIFF_HEADER_SIZE
+    || &bytes[0..4] != b"RIFF"
+    || &bytes[8..12] != b"WAVE"
+  {
+    return None;
+  }
+
+  let mut format: Option<(u64, u64, u64, u64)> = None;
+  let mut offset = RIFF_HEADER_SIZE;
+
+  while offset + CHUNK_HEADER_SIZE <= bytes.len() {
+    let id = &bytes[offset..offset + 4];
+    let size = read_u32(bytes, offset + 4)? as u64;
+    let body = offset + CHUNK_HEADER_SIZE;
+
+    if id == b"fmt " {
+      format = Some((
+        read_u16(bytes, body)? as u64,
+        read_u16(bytes, body + 2)? as u64,
+        read_u32(bytes, body + 4)? as u64,
+        read_u16(bytes, body + 14)? as u64,
+      ));
+    } else if id == b"data" {
+      let (audio_format, channels, sample_rate, bits_per_sample) = format?;
+      return Some(WavHeader {
+        audio_format,
+        channels,
+        sample_rate,
+        bits_per_sample,
+        data_offset: body as u64,
+        data_size: size,
+      });
+    }
+
+    offset = body.checked_add(usize::try_from(size + size % 2).ok()?)?;
+  }
+
+  return None;
+}
+
+fn invalid_data(reason: &str) -> std::io::Error {
+  return std::io::Error::new(ErrorKind::InvalidData, reason);
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+  let slice = bytes.get(offset..offset + 2)?;
+  return Some(u16::from_le_bytes([slice[0], slice[1]]));
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+  let slice = bytes.get(offset..offset + 4)?;
+  return Some(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]));
+}
@@ -0,0 +1,84 @@
+use crate::audio::errors::AudioError;
+use crate::audio::wav::WavInspector;
+
+#[test]
+fn test_read_duration_of_sample() {
+  let duration = WavInspector::read_duration("sample/jfk.wav").unwrap();
+  assert!(duration > 10.0 && duration < 12.0);
+}
+
+#[test]
+fn test_read_duration_missing_file() {
+  let result = WavInspector::read_duration("nonexistent_file.wav");
+  match result.unwrap_err() {
+    AudioError::CouldNotReadWav(_, e) => {
+      assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+    }
+    _ => panic!("Expected CouldNotReadWav error"),
+  }
+}
+
+#[test]
+fn test_read_duration_with_unfinished_header() {
+  let file_path = std::env::temp_dir().join("test_unfinished_header.wav");
+  let spec = hound::WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&file_path, spec).unwrap();
+  for _ in 0..16000 {
+    writer.write_sample(0i16).unwrap();
+  }
+  writer.finalize().unwrap();
+
+  let mut bytes = std::fs::read(&file_path).unwrap();
+  bytes[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+  std::fs::write(&file_path, bytes).unwrap();
+
+  let duration =
+    WavInspector::read_duration(&file_path.to_string_lossy()).unwrap();
+  assert!(duration > 0.99 && duration < 1.01);
+
+  std::fs::remove_file(&file_path).unwrap();
+}
+
+#[test]
+fn test_stream_samples_of_sample() {
+  let mut samples = WavInspector::stream_samples("sample/jfk.wav").unwrap();
+  assert_eq!(samples.sample_rate(), 16000);
+
+  let mut count = 0;
+  let mut chunks = 0;
+  while let Some(chunk) = samples.next_chunk() {
+    count += chunk.len();
+    chunks += 1;
+  }
+  let duration = count as f64 / 16000.0;
+  assert!(duration > 10.0 && duration < 12.0);
+  assert!(chunks > 1);
+  assert!(WavInspector::stream_samples("nonexistent_file.wav").is_err());
+}
+
+#[test]
+fn test_is_whisper_ready() {
+  assert!(WavInspector::is_whisper_ready("sample/jfk.wav"));
+  assert!(!WavInspector::is_whisper_ready("nonexistent_file.wav"));
+
+  let file_path = std::env::temp_dir().join("test_is_whisper_ready.wav");
+  let spec = hound::WavSpec {
+    channels: 2,
+    sample_rate: 44100,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&file_path, spec).unwrap();
+  writer.write_sample(0i16).unwrap();
+  writer.write_sample(0i16).unwrap();
+  writer.finalize().unwrap();
+
+  assert!(!WavInspector::is_whisper_ready(file_path.to_str().unwrap()));
+
+  std::fs::remove_file(&file_path).unwrap();
+}
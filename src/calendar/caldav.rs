@@ -0,0 +1,116 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use regex::Regex;
+use tokio_util::sync::CancellationToken;
+
+use crate::calendar::errors::{CalendarError, CalendarResult};
+use crate::network::HttpClient;
+
+const QUERY_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const QUERY_RANGE_HOURS: i64 = 24;
+const CALENDAR_DATA_PATTERN: &str = r"(?s)<(?:[\w-]+:)?calendar-data(?:\s[^>]*[^/>])?>(.*?)</(?:[\w-]+:)?calendar-data\s*>";
+const XML_ENTITY_PATTERN: &str =
+  r"&(#x[0-9a-fA-F]+|#[0-9]+|lt|gt|amp|quot|apos);";
+
+/// Fetches the events around the current time from a CalDAV calendar.
+///
+/// Sends a `calendar-query` REPORT for events within a day of now, so
+/// recurring events that started long ago are returned as well. Credentials
+/// in the URL are sent with basic authentication.
+///
+/// # Arguments
+///
+/// * `url` - URL of the calendar collection
+/// * `cancel` - Token that aborts the request when cancelled
+///
+/// # Returns
+///
+/// A `CalendarResult<String>` containing the iCalendar data of the events or
+/// an error.
+pub(crate) async fn fetch(
+  url: &str,
+  cancel: &CancellationToken,
+) -> CalendarResult<String> {
+  let now = Utc::now();
+  let range = TimeDelta::hours(QUERY_RANGE_HOURS);
+  let response = HttpClient::new(url.to_string())
+    .with_cancellation(cancel.clone())
+    .report(calendar_query(now - range, now + range))
+    .await
+    .map_err(|e| CalendarError::Fetch(e.to_string()))?;
+  return Ok(calendar_data(&response));
+}
+
+/// Builds a `calendar-query` REPORT body for events overlapping a range.
+fn calendar_query(start: DateTime<Utc>, end: DateTime<Utc>) -> String {
+  return format!(
+    r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <c:calendar-data/>
+  </d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VEVENT">
+        <c:time-range start="{}" end="{}"/>
+      </c:comp-filter>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#,
+    start.format(QUERY_TIME_FORMAT),
+    end.format(QUERY_TIME_FORMAT)
+  );
+}
+
+/// Extracts the iCalendar data from a CalDAV multistatus response.
+///
+/// # Arguments
+///
+/// * `response` - Body of the REPORT response
+///
+/// # Returns
+///
+/// A `String` containing the iCalendar data of all returned events, one
+/// calendar after another.
+pub(crate) fn calendar_data(response: &str) -> String {
+  let regex = Regex::new(CALENDAR_DATA_PATTERN).unwrap();
+  return regex
+    .captures_iter(response)
+    .map(|captures| unescape_xml(&captures[1]))
+    .collect::<Vec<String>>()
+    .join("\n");
+}
+
+fn unescape_xml(text: &str) -> String {
+  if let Some(data) = text
+    .trim()
+    .strip_prefix("<![CDATA[")
+    .and_then(|data| data.strip_suffix("]]>"))
+  {
+    return data.to_string();
+  }
+
+  let regex = Regex::new(XML_ENTITY_PATTERN).unwrap();
+  return regex
+    .replace_all(text, |captures: &regex::Captures| {
+      let entity = &captures[1];
+      let code = if let Some(hex) = entity.strip_prefix("#x") {
+        u32::from_str_radix(hex, 16).ok()
+      } else if let Some(decimal) = entity.strip_prefix('#') {
+        decimal.parse().ok()
+      } else {
+        None
+      };
+      if let Some(code) = code {
+        return char::from_u32(code).map(String::from).unwrap_or_default();
+      }
+      return match entity {
+        "lt" => "<",
+        "gt" => ">",
+        "amp" => "&",
+        "quot" => "\"",
+        _ => "'",
+      }
+      .to_string();
+    })
+    .to_string();
+}
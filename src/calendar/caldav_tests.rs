@@ -0,0 +1,68 @@
+use crate::calendar::Calendar;
+use crate::calendar::caldav::calendar_data;
+
+const RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response>
+    <d:href>/calendars/me/work/review.ics</d:href>
+    <d:propstat>
+      <d:prop>
+        <cal:calendar-data content-type="text/calendar">BEGIN:VCALENDAR&#13;
+BEGIN:VEVENT&#13;
+SUMMARY:Design &amp; review&#13;
+DTSTART:20240502T100000Z&#13;
+DTEND:20240502T110000Z&#13;
+END:VEVENT&#13;
+END:VCALENDAR&#13;
+</cal:calendar-data>
+      </d:prop>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/me/work/lunch.ics</d:href>
+    <d:propstat>
+      <d:prop>
+        <calendar-data xmlns="urn:ietf:params:xml:ns:caldav"><![CDATA[BEGIN:VCALENDAR
+BEGIN:VEVENT
+SUMMARY:Lunch <team>
+DTSTART:20240502T120000Z
+DTEND:20240502T130000Z
+END:VEVENT
+END:VCALENDAR
+]]></calendar-data>
+      </d:prop>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/me/work/missing.ics</d:href>
+    <d:propstat>
+      <d:prop>
+        <cal:calendar-data/>
+      </d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+</d:multistatus>
+"#;
+
+#[test]
+fn test_calendar_data() {
+  let data = calendar_data(RESPONSE);
+  assert!(data.contains("SUMMARY:Design & review\r\n"));
+  assert!(data.contains("SUMMARY:Lunch <team>\n"));
+  assert!(!data.contains("calendar-data"));
+}
+
+#[test]
+fn test_calendar_data_is_parsed() {
+  let calendar = Calendar::parse(&calendar_data(RESPONSE));
+  let now = chrono::DateTime::parse_from_rfc3339("2024-05-02T12:30:00Z")
+    .unwrap()
+    .with_timezone(&chrono::Local);
+  assert_eq!(calendar.current_event(now).unwrap().summary, "Lunch <team>");
+}
+
+#[test]
+fn test_calendar_data_without_events() {
+  assert_eq!(calendar_data("<d:multistatus xmlns:d=\"DAV:\"/>"), "");
+}
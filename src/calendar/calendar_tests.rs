@@ -0,0 +1,248 @@
+use chrono::{Local, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+
+use crate::calendar::{Calendar, CalendarEvent, session_name};
+
+const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+SUMMARY:Design review\r
+DTSTART;TZID=Europe/Berlin:20240502T100000\r
+DTEND;TZID=Europe/Berlin:20240502T110000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Sync with design\\, product and\r
+  engineering\r
+DTSTART;TZID=\"Europe/Berlin\":20240502T103000\r
+DTEND;TZID=\"Europe/Berlin\":20240502T104500\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Planning\r
+DTSTART:20240502T150000\r
+DTEND:20240502T160000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Conference\r
+DTSTART;VALUE=DATE:20240502\r
+DTEND;VALUE=DATE:20240503\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Lunch\r
+DTSTART:20240502T120000Z\r
+DTEND:20240502T130000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Customer call\r
+DTSTART;TZID=Europe/Berlin:20240503T200000\r
+DURATION:PT1H15M\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+const RECURRING_CALENDAR: &str = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:standup\r
+SUMMARY:Standup\r
+DTSTART;TZID=Europe/Berlin:20240318T093000\r
+DTEND;TZID=Europe/Berlin:20240318T094500\r
+RRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;COUNT=20\r
+EXDATE;TZID=Europe/Berlin:20240320T093000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:standup\r
+RECURRENCE-ID;TZID=Europe/Berlin:20240321T093000\r
+SUMMARY:Standup (moved)\r
+DTSTART;TZID=Europe/Berlin:20240321T110000\r
+DTEND;TZID=Europe/Berlin:20240321T111500\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:review\r
+SUMMARY:Review\r
+DTSTART;TZID=Europe/Berlin:20240318T140000\r
+DTEND;TZID=Europe/Berlin:20240318T150000\r
+RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;UNTIL=20240430T000000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:report\r
+SUMMARY:Monthly report\r
+DTSTART:20240301T120000Z\r
+DTEND:20240301T130000Z\r
+RRULE:FREQ=MONTHLY;BYMONTHDAY=1\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+fn local(hour: u32, minute: u32) -> chrono::DateTime<Local> {
+  return Local.with_ymd_and_hms(2024, 5, 2, hour, minute, 0).unwrap();
+}
+
+fn berlin(
+  month: u32,
+  day: u32,
+  hour: u32,
+  minute: u32,
+) -> chrono::DateTime<Local> {
+  return Berlin
+    .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+    .unwrap()
+    .with_timezone(&Local);
+}
+
+fn current_summary(
+  calendar: &Calendar,
+  now: chrono::DateTime<Local>,
+) -> Option<String> {
+  return calendar.current_event(now).map(|event| event.summary);
+}
+
+#[test]
+fn test_parse_skips_all_day_events() {
+  let calendar = Calendar::parse(
+    "BEGIN:VEVENT\r
+SUMMARY:Conference\r
+DTSTART;VALUE=DATE:20240502\r
+DTEND;VALUE=DATE:20240503\r
+END:VEVENT\r
+",
+  );
+  assert!(calendar.current_event(local(9, 0)).is_none());
+}
+
+#[test]
+fn test_current_event() {
+  let calendar = Calendar::parse(CALENDAR);
+  let event = calendar.current_event(berlin(5, 2, 10, 15)).unwrap();
+  assert_eq!(event.summary, "Design review");
+  assert_eq!(event.start, berlin(5, 2, 10, 0));
+  assert!(calendar.current_event(berlin(5, 2, 11, 0)).is_none());
+}
+
+#[test]
+fn test_parse_duration_instead_of_end() {
+  let calendar = Calendar::parse(CALENDAR);
+  let event = calendar.current_event(berlin(5, 3, 20, 30)).unwrap();
+  assert_eq!(event.summary, "Customer call");
+  assert_eq!(event.start, berlin(5, 3, 20, 0));
+  assert_eq!(event.end, berlin(5, 3, 21, 15));
+  assert!(calendar.current_event(berlin(5, 3, 21, 15)).is_none());
+}
+
+#[test]
+fn test_day_durations_follow_the_wall_clock() {
+  let calendar = Calendar::parse(
+    "BEGIN:VEVENT\r
+SUMMARY:Offsite\r
+DTSTART;TZID=Europe/Berlin:20240330T120000\r
+DURATION:P1D\r
+END:VEVENT\r
+",
+  );
+  let event = calendar.current_event(berlin(3, 31, 11, 30)).unwrap();
+  assert_eq!(event.end, berlin(3, 31, 12, 0));
+  assert!(calendar.current_event(berlin(3, 31, 12, 0)).is_none());
+}
+
+#[test]
+fn test_current_event_prefers_latest_start() {
+  let calendar = Calendar::parse(CALENDAR);
+  let event = calendar.current_event(berlin(5, 2, 10, 35)).unwrap();
+  assert_eq!(event.summary, "Sync with design, product and engineering");
+}
+
+#[test]
+fn test_parse_floating_times() {
+  let calendar = Calendar::parse(CALENDAR);
+  let event = calendar.current_event(local(15, 30)).unwrap();
+  assert_eq!(event.summary, "Planning");
+  assert_eq!(event.start, local(15, 0));
+}
+
+#[test]
+fn test_daily_recurrence() {
+  let calendar = Calendar::parse(RECURRING_CALENDAR);
+  let event = calendar.current_event(berlin(3, 19, 9, 40)).unwrap();
+  assert_eq!(event.summary, "Standup");
+  assert_eq!(event.start, berlin(3, 19, 9, 30));
+  assert_eq!(event.end, berlin(3, 19, 9, 45));
+
+  // Weekends are left out and the time stays the same across the daylight
+  // saving change on March 31.
+  assert_eq!(current_summary(&calendar, berlin(3, 23, 9, 40)), None);
+  assert_eq!(
+    current_summary(&calendar, berlin(4, 2, 9, 40)).as_deref(),
+    Some("Standup")
+  );
+  // The 20th weekday is April 12.
+  assert_eq!(
+    current_summary(&calendar, berlin(4, 12, 9, 40)).as_deref(),
+    Some("Standup")
+  );
+  assert_eq!(current_summary(&calendar, berlin(4, 15, 9, 40)), None);
+}
+
+#[test]
+fn test_recurrence_exceptions() {
+  let calendar = Calendar::parse(RECURRING_CALENDAR);
+  assert_eq!(current_summary(&calendar, berlin(3, 20, 9, 40)), None);
+  assert_eq!(current_summary(&calendar, berlin(3, 21, 9, 40)), None);
+  assert_eq!(
+    current_summary(&calendar, berlin(3, 21, 11, 5)).as_deref(),
+    Some("Standup (moved)")
+  );
+}
+
+#[test]
+fn test_weekly_recurrence() {
+  let calendar = Calendar::parse(RECURRING_CALENDAR);
+  for (month, day) in [(3, 18), (3, 21), (4, 1), (4, 4), (4, 29)] {
+    let event = calendar.current_event(berlin(month, day, 14, 30)).unwrap();
+    assert_eq!(event.summary, "Review");
+    assert_eq!(event.start, berlin(month, day, 14, 0));
+  }
+  // Every other week, on Mondays and Thursdays only, until April 30.
+  for (month, day) in [(3, 25), (3, 28), (3, 19), (5, 13)] {
+    assert_eq!(current_summary(&calendar, berlin(month, day, 14, 30)), None);
+  }
+}
+
+#[test]
+fn test_unsupported_recurrence_matches_first_occurrence() {
+  let calendar = Calendar::parse(RECURRING_CALENDAR);
+  let first = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+  let next = Utc.with_ymd_and_hms(2024, 4, 2, 12, 30, 0).unwrap();
+  assert_eq!(
+    current_summary(&calendar, first.with_timezone(&Local)).as_deref(),
+    Some("Monthly report")
+  );
+  assert_eq!(current_summary(&calendar, next.with_timezone(&Local)), None);
+}
+
+#[test]
+fn test_parse_utc_times() {
+  let calendar = Calendar::parse(CALENDAR);
+  let now = Utc
+    .with_ymd_and_hms(2024, 5, 2, 12, 30, 0)
+    .unwrap()
+    .with_timezone(&Local);
+  let event = calendar.current_event(now).unwrap();
+  assert_eq!(event.summary, "Lunch");
+}
+
+#[test]
+fn test_session_name() {
+  let event = CalendarEvent {
+    summary: String::from(" Design / review:  Q2 "),
+    start: local(10, 0),
+    end: local(11, 0),
+  };
+  assert_eq!(
+    session_name(&event, local(10, 15)),
+    "2024-05-02 Design review Q2"
+  );
+
+  let event = CalendarEvent {
+    summary: String::from("///"),
+    ..event
+  };
+  assert_eq!(session_name(&event, local(10, 15)), "");
+}
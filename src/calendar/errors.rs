@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+/// Calendar errors.
+///
+/// Represents errors that can occur while loading a calendar.
+#[derive(Error, Debug)]
+pub enum CalendarError {
+  #[error(
+    "Cannot read calendar file: '{0}'. Please check the calendar path in your configuration file."
+  )]
+  FileRead(String),
+
+  #[error(
+    "Cannot fetch calendar: '{0}'. Please check the calendar URL in your configuration file."
+  )]
+  Fetch(String),
+
+  #[error(
+    "Calendar did not respond within {0} seconds. Please check the calendar URL in your configuration file."
+  )]
+  Timeout(u64),
+}
+
+/// Result type for calendar operations.
+pub type CalendarResult<T> = Result<T, CalendarError>;
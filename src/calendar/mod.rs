@@ -0,0 +1,502 @@
+//! Calendar lookup for naming recordings after the current meeting.
+//!
+//! Reads events from an iCalendar (ICS) file or URL, or from a CalDAV
+//! calendar, and finds the event happening at a given time, so recordings
+//! can be named after it instead of a bare timestamp.
+//!
+//! ## Main Components
+//!
+//! - [`Calendar`]: Events loaded from an iCalendar source
+//! - [`CalendarEvent`]: A single timed event
+//! - [`CalendarError`]: Error types for calendar loading
+//! - [`CalendarResult<T>`]: Result type alias for calendar operations
+//!
+//! ## Limitations
+//!
+//! All-day events are ignored. Only daily and weekly recurrence rules are
+//! expanded; events with other rules only match their first occurrence.
+//! Times with a `TZID` that is not an IANA time zone name are interpreted in
+//! the local time zone.
+
+mod caldav;
+pub mod errors;
+mod recurrence;
+
+#[cfg(test)]
+mod caldav_tests;
+#[cfg(test)]
+mod calendar_tests;
+
+use std::time::Duration;
+
+use chrono::{
+  DateTime, Days, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc,
+};
+use chrono_tz::Tz;
+use tokio_util::sync::CancellationToken;
+
+use crate::calendar::errors::{CalendarError, CalendarResult};
+use crate::calendar::recurrence::Recurrence;
+use crate::files::operations;
+use crate::network::HttpClient;
+use crate::vlog;
+
+const ICS_DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+const CALDAV_PREFIX: &str = "caldav+";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// A timed calendar event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+  /// Title of the event
+  pub summary: String,
+  /// Start of the event
+  pub start: DateTime<Local>,
+  /// End of the event
+  pub end: DateTime<Local>,
+}
+
+/// Time zone the times of an event are written in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EventZone {
+  Utc,
+  Local,
+  Named(Tz),
+}
+
+impl EventZone {
+  /// Resolves a wall-clock time in this time zone to an instant.
+  ///
+  /// Ambiguous times resolve to their earlier instant, and times skipped by
+  /// a daylight saving change to `None`.
+  fn resolve(&self, time: &NaiveDateTime) -> Option<DateTime<Utc>> {
+    return match self {
+      EventZone::Utc => Some(Utc.from_utc_datetime(time)),
+      EventZone::Local => Local
+        .from_local_datetime(time)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc)),
+      EventZone::Named(zone) => zone
+        .from_local_datetime(time)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc)),
+    };
+  }
+}
+
+/// An event as written in the calendar, before recurrences are expanded.
+#[derive(Debug, Clone)]
+struct EventDefinition {
+  uid: Option<String>,
+  summary: String,
+  start: NaiveDateTime,
+  zone: EventZone,
+  duration: TimeDelta,
+  recurrence: Option<Recurrence>,
+  exceptions: Vec<DateTime<Utc>>,
+}
+
+impl EventDefinition {
+  /// Finds the occurrence of the event in progress at a given time.
+  fn occurrence_at(&self, now: DateTime<Utc>) -> Option<CalendarEvent> {
+    let start = match &self.recurrence {
+      Some(recurrence) => {
+        recurrence.latest_start(self.start, self.zone, &self.exceptions, now)?
+      }
+      None => self.zone.resolve(&self.start)?,
+    };
+    let end = start + self.duration;
+    if now < start || now >= end {
+      return None;
+    }
+    return Some(CalendarEvent {
+      summary: self.summary.clone(),
+      start: start.with_timezone(&Local),
+      end: end.with_timezone(&Local),
+    });
+  }
+}
+
+/// Properties of the event being parsed.
+#[derive(Default)]
+struct EventProperties {
+  uid: Option<String>,
+  summary: Option<String>,
+  start: Option<(NaiveDateTime, EventZone)>,
+  end: Option<(NaiveDateTime, EventZone)>,
+  duration: Option<EventDuration>,
+  rule: Option<String>,
+  exceptions: Vec<(NaiveDateTime, EventZone)>,
+  recurrence_id: Option<(NaiveDateTime, EventZone)>,
+}
+
+/// A `DURATION` value, split into whole days, which follow the wall clock
+/// across daylight saving changes, and an exact time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct EventDuration {
+  days: u64,
+  time: TimeDelta,
+}
+
+/// A content line split into its name, time zone parameter and value.
+struct Property<'a> {
+  name: String,
+  time_zone: Option<String>,
+  value: &'a str,
+}
+
+/// Events loaded from an iCalendar source.
+#[derive(Debug, Clone, Default)]
+pub struct Calendar {
+  events: Vec<EventDefinition>,
+}
+
+impl Calendar {
+  /// Loads a calendar from an ICS file path, an `http(s)` URL or a CalDAV
+  /// calendar URL prefixed with `caldav+`.
+  ///
+  /// Fetching from the network gives up after a fixed timeout, so a slow
+  /// calendar server does not delay recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `source` - Path or URL of the iCalendar data
+  /// * `cancel` - Token that aborts fetching when cancelled
+  ///
+  /// # Returns
+  ///
+  /// A `CalendarResult<Calendar>` containing the events or an error.
+  pub async fn load(
+    source: &str,
+    cancel: &CancellationToken,
+  ) -> CalendarResult<Calendar> {
+    let content = if let Some(url) = source.strip_prefix(CALDAV_PREFIX) {
+      fetch_with_timeout(caldav::fetch(url, cancel)).await?
+    } else if source.starts_with("http://") || source.starts_with("https://") {
+      fetch_with_timeout(async {
+        return HttpClient::new(source.to_string())
+          .with_cancellation(cancel.clone())
+          .get_text()
+          .await
+          .map_err(|e| CalendarError::Fetch(e.to_string()));
+      })
+      .await?
+    } else {
+      operations::read_to_string(source)
+        .await
+        .map_err(|e| CalendarError::FileRead(e.to_string()))?
+    };
+
+    let calendar = Calendar::parse(&content);
+    vlog!("Loaded {} calendar events", calendar.events.len());
+    return Ok(calendar);
+  }
+
+  /// Parses timed events from iCalendar data.
+  ///
+  /// Events without a title, without an end or duration, or with date-only
+  /// times are skipped.
+  /// Daily and weekly recurrence rules are expanded, leaving out the
+  /// occurrences listed in `EXDATE` and the ones moved by another event
+  /// with the same `UID` and a `RECURRENCE-ID`.
+  ///
+  /// # Arguments
+  ///
+  /// * `content` - The iCalendar data
+  ///
+  /// # Returns
+  ///
+  /// A `Calendar` containing the parsed events.
+  pub fn parse(content: &str) -> Calendar {
+    let mut events = Vec::new();
+    let mut moved = Vec::new();
+    let mut properties: Option<EventProperties> = None;
+
+    for line in unfold_lines(content) {
+      let Some(property) = split_property(&line) else {
+        continue;
+      };
+      let value = property.value;
+      let time_zone = property.time_zone.as_deref();
+
+      if property.name == "BEGIN" && value == "VEVENT" {
+        properties = Some(EventProperties::default());
+        continue;
+      }
+      let Some(event) = properties.as_mut() else {
+        continue;
+      };
+      match property.name.as_str() {
+        "END" if value == "VEVENT" => {
+          let Some(event) = properties.take() else {
+            continue;
+          };
+          if let (Some(uid), Some((time, zone))) =
+            (&event.uid, event.recurrence_id)
+            && let Some(start) = zone.resolve(&time)
+          {
+            moved.push((uid.clone(), start));
+          }
+          if let Some(definition) = define_event(event) {
+            events.push(definition);
+          }
+        }
+        "UID" => event.uid = Some(value.to_string()),
+        "SUMMARY" => event.summary = Some(unescape_text(value)),
+        "DTSTART" => event.start = parse_date_time(value, time_zone),
+        "DTEND" => event.end = parse_date_time(value, time_zone),
+        "DURATION" => event.duration = parse_duration(value),
+        "RRULE" => event.rule = Some(value.to_string()),
+        "EXDATE" => event.exceptions.extend(
+          value
+            .split(',')
+            .filter_map(|value| parse_date_time(value, time_zone)),
+        ),
+        "RECURRENCE-ID" => {
+          event.recurrence_id = parse_date_time(value, time_zone)
+        }
+        _ => {}
+      }
+    }
+
+    for (uid, start) in moved {
+      for event in &mut events {
+        if event.recurrence.is_some() && event.uid.as_ref() == Some(&uid) {
+          event.exceptions.push(start);
+        }
+      }
+    }
+
+    return Calendar { events };
+  }
+
+  /// Finds the event happening at a given time.
+  ///
+  /// When several events overlap, the one that started most recently wins.
+  ///
+  /// # Arguments
+  ///
+  /// * `now` - The time to look up
+  ///
+  /// # Returns
+  ///
+  /// An `Option<CalendarEvent>` containing the occurrence of the current
+  /// event, if any.
+  pub fn current_event(&self, now: DateTime<Local>) -> Option<CalendarEvent> {
+    let now = now.with_timezone(&Utc);
+    return self
+      .events
+      .iter()
+      .filter_map(|event| event.occurrence_at(now))
+      .max_by_key(|event| event.start);
+  }
+}
+
+/// Gives up fetching a calendar after [`FETCH_TIMEOUT`].
+async fn fetch_with_timeout(
+  fetch: impl Future<Output = CalendarResult<String>>,
+) -> CalendarResult<String> {
+  return tokio::time::timeout(FETCH_TIMEOUT, fetch)
+    .await
+    .map_err(|_| CalendarError::Timeout(FETCH_TIMEOUT.as_secs()))?;
+}
+
+fn define_event(event: EventProperties) -> Option<EventDefinition> {
+  let summary = event.summary?;
+  let (start, zone) = event.start?;
+  let end = match (event.end, event.duration) {
+    (Some((end, end_zone)), _) => end_zone.resolve(&end)?,
+    (None, Some(duration)) => {
+      let end = start.checked_add_days(Days::new(duration.days))?;
+      zone.resolve(&end)? + duration.time
+    }
+    (None, None) => return None,
+  };
+  let duration = end - zone.resolve(&start)?;
+
+  let recurrence = event.rule.and_then(|rule| {
+    let recurrence = Recurrence::parse(&rule, zone);
+    if recurrence.is_none() {
+      vlog!(
+        "Only the first occurrence of '{}' is used: {}",
+        summary,
+        rule
+      );
+    }
+    return recurrence;
+  });
+  let exceptions = event
+    .exceptions
+    .iter()
+    .filter_map(|(time, zone)| zone.resolve(time))
+    .collect();
+
+  return Some(EventDefinition {
+    uid: event.uid,
+    summary,
+    start,
+    zone,
+    duration,
+    recurrence,
+    exceptions,
+  });
+}
+
+/// Builds a file-safe session name for an event, such as
+/// `2024-05-02 Design review`.
+///
+/// # Arguments
+///
+/// * `event` - The current event
+/// * `now` - The time the session starts
+///
+/// # Returns
+///
+/// A `String` containing the session name, empty if the title has no usable
+/// characters.
+pub fn session_name(event: &CalendarEvent, now: DateTime<Local>) -> String {
+  let title: String = event
+    .summary
+    .chars()
+    .filter(|c| !c.is_control() && !"/\\:*?\"<>|".contains(*c))
+    .collect();
+  let title = title.split_whitespace().collect::<Vec<&str>>().join(" ");
+  if title.is_empty() {
+    return String::new();
+  }
+  return format!("{} {}", now.format("%Y-%m-%d"), title);
+}
+
+fn unfold_lines(content: &str) -> Vec<String> {
+  let mut lines: Vec<String> = Vec::new();
+  for line in content.lines() {
+    let line = line.trim_end_matches('\r');
+    match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+      (Some(continuation), Some(previous)) => previous.push_str(continuation),
+      _ => lines.push(line.to_string()),
+    }
+  }
+  return lines;
+}
+
+fn split_property(line: &str) -> Option<Property<'_>> {
+  let (key, value) = line.split_once(':')?;
+  let mut parameters = key.split(';');
+  let name = parameters.next().unwrap_or(key).to_uppercase();
+  let time_zone = parameters
+    .filter_map(|parameter| parameter.split_once('='))
+    .find(|(name, _)| name.eq_ignore_ascii_case("TZID"))
+    .map(|(_, zone)| zone.trim_matches('"').to_string());
+  return Some(Property {
+    name,
+    time_zone,
+    value: value.trim(),
+  });
+}
+
+fn parse_date_time(
+  value: &str,
+  time_zone: Option<&str>,
+) -> Option<(NaiveDateTime, EventZone)> {
+  let value = value.trim();
+  if let Some(utc) = value.strip_suffix('Z') {
+    let time = NaiveDateTime::parse_from_str(utc, ICS_DATE_TIME_FORMAT).ok()?;
+    return Some((time, EventZone::Utc));
+  }
+
+  if NaiveDate::parse_from_str(value, "%Y%m%d").is_ok() {
+    return None;
+  }
+
+  let time = NaiveDateTime::parse_from_str(value, ICS_DATE_TIME_FORMAT).ok()?;
+  let zone = match time_zone {
+    Some(name) => match name.parse::<Tz>() {
+      Ok(zone) => EventZone::Named(zone),
+      Err(_) => {
+        vlog!("Unknown time zone '{}', using the local time zone", name);
+        EventZone::Local
+      }
+    },
+    None => EventZone::Local,
+  };
+  return Some((time, zone));
+}
+
+/// Parses a `DURATION` value such as `PT1H30M`, `P1D` or `P2W`.
+///
+/// Negative durations are rejected, since an event cannot end before it
+/// starts.
+fn parse_duration(value: &str) -> Option<EventDuration> {
+  let value = value.trim();
+  let value = value.strip_prefix('+').unwrap_or(value);
+  let body = value.strip_prefix('P')?;
+  let (date, time) = match body.split_once('T') {
+    Some((date, time)) => (date, Some(time)),
+    None => (body, None),
+  };
+
+  let mut duration = EventDuration {
+    days: 0,
+    time: TimeDelta::zero(),
+  };
+  for (amount, unit) in duration_parts(date)? {
+    duration.days += match unit {
+      'W' => amount.checked_mul(7)?,
+      'D' => amount,
+      _ => return None,
+    };
+  }
+  if let Some(time) = time {
+    let parts = duration_parts(time)?;
+    if parts.is_empty() {
+      return None;
+    }
+    for (amount, unit) in parts {
+      let amount = i64::try_from(amount).ok()?;
+      duration.time += match unit {
+        'H' => TimeDelta::try_hours(amount)?,
+        'M' => TimeDelta::try_minutes(amount)?,
+        'S' => TimeDelta::try_seconds(amount)?,
+        _ => return None,
+      };
+    }
+  } else if date.is_empty() {
+    return None;
+  }
+  return Some(duration);
+}
+
+/// Splits a part of a duration such as `1H30M` into amounts and units.
+fn duration_parts(value: &str) -> Option<Vec<(u64, char)>> {
+  let mut parts = Vec::new();
+  let mut digits = String::new();
+  for c in value.chars() {
+    if c.is_ascii_digit() {
+      digits.push(c);
+      continue;
+    }
+    parts.push((digits.parse().ok()?, c.to_ascii_uppercase()));
+    digits.clear();
+  }
+  if !digits.is_empty() {
+    return None;
+  }
+  return Some(parts);
+}
+
+fn unescape_text(value: &str) -> String {
+  let mut text = String::new();
+  let mut chars = value.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      text.push(c);
+      continue;
+    }
+    match chars.next() {
+      Some('n') | Some('N') => text.push(' '),
+      Some(escaped) => text.push(escaped),
+      None => {}
+    }
+  }
+  return text.trim().to_string();
+}
@@ -0,0 +1,188 @@
+use chrono::{
+  DateTime, Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+  Weekday,
+};
+
+use crate::calendar::{EventZone, ICS_DATE_TIME_FORMAT};
+
+const MAX_OCCURRENCES: usize = 100_000;
+
+/// How often a recurring event repeats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+  Daily,
+  Weekly,
+}
+
+/// Recurrence rule (`RRULE`) of an event.
+///
+/// Only daily and weekly rules with `INTERVAL`, `COUNT`, `UNTIL`, `BYDAY`
+/// and `WKST` are supported.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Recurrence {
+  frequency: Frequency,
+  interval: u64,
+  count: Option<usize>,
+  until: Option<DateTime<Utc>>,
+  weekdays: Vec<Weekday>,
+}
+
+impl Recurrence {
+  /// Parses a recurrence rule.
+  ///
+  /// # Arguments
+  ///
+  /// * `rule` - Value of the `RRULE` property
+  /// * `zone` - Time zone of the event start, used for a floating `UNTIL`
+  ///
+  /// # Returns
+  ///
+  /// An `Option<Recurrence>` containing the rule, or `None` if it is invalid
+  /// or uses parts that are not supported.
+  pub(crate) fn parse(rule: &str, zone: EventZone) -> Option<Recurrence> {
+    let mut frequency = None;
+    let mut interval = 1;
+    let mut count = None;
+    let mut until = None;
+    let mut weekdays = Vec::new();
+
+    for part in rule.split(';') {
+      let (name, value) = part.split_once('=')?;
+      match name.to_uppercase().as_str() {
+        "FREQ" => {
+          frequency = match value.to_uppercase().as_str() {
+            "DAILY" => Some(Frequency::Daily),
+            "WEEKLY" => Some(Frequency::Weekly),
+            _ => return None,
+          };
+        }
+        "INTERVAL" => interval = value.parse().ok().filter(|i| *i > 0)?,
+        "COUNT" => count = Some(value.parse().ok()?),
+        "UNTIL" => until = Some(parse_until(value, zone)?),
+        "BYDAY" => {
+          weekdays =
+            value.split(',').map(parse_weekday).collect::<Option<_>>()?
+        }
+        "WKST" => {}
+        _ => return None,
+      }
+    }
+
+    return Some(Recurrence {
+      frequency: frequency?,
+      interval,
+      count,
+      until,
+      weekdays,
+    });
+  }
+
+  /// Finds the start of the latest occurrence at or before a given time.
+  ///
+  /// # Arguments
+  ///
+  /// * `start` - Start of the first occurrence in the event's time zone
+  /// * `zone` - Time zone of the event
+  /// * `exceptions` - Starts of occurrences that were removed or moved
+  /// * `now` - The time to look up
+  ///
+  /// # Returns
+  ///
+  /// An `Option<DateTime<Utc>>` containing the start of the occurrence, or
+  /// `None` if the event has not started yet.
+  pub(crate) fn latest_start(
+    &self,
+    start: NaiveDateTime,
+    zone: EventZone,
+    exceptions: &[DateTime<Utc>],
+    now: DateTime<Utc>,
+  ) -> Option<DateTime<Utc>> {
+    let mut latest = None;
+    let occurrences = self.occurrences(start).take(MAX_OCCURRENCES);
+    for (index, occurrence) in occurrences.enumerate() {
+      if self.count.is_some_and(|count| index >= count) {
+        break;
+      }
+      // Times skipped by a daylight saving change do not occur.
+      let Some(occurrence) = zone.resolve(&occurrence) else {
+        continue;
+      };
+      if occurrence > now || self.until.is_some_and(|until| occurrence > until)
+      {
+        break;
+      }
+      if !exceptions.contains(&occurrence) {
+        latest = Some(occurrence);
+      }
+    }
+    return latest;
+  }
+
+  /// Lists the starts of all occurrences in the event's time zone, in order.
+  fn occurrences(
+    &self,
+    start: NaiveDateTime,
+  ) -> Box<dyn Iterator<Item = NaiveDateTime>> {
+    let interval = self.interval;
+    let mut weekdays = self.weekdays.clone();
+
+    if self.frequency == Frequency::Daily {
+      return Box::new(
+        (0..)
+          .map(move |day| start + Days::new(day * interval))
+          .filter(move |time| {
+            return weekdays.is_empty() || weekdays.contains(&time.weekday());
+          }),
+      );
+    }
+
+    if weekdays.is_empty() {
+      weekdays.push(start.weekday());
+    }
+    weekdays.sort_by_key(|day| day.num_days_from_monday());
+    let week_start = start.date().week(Weekday::Mon).first_day();
+    return Box::new(
+      (0..)
+        .flat_map(move |week| {
+          return weekdays
+            .iter()
+            .map(|day| {
+              let offset =
+                week * 7 * interval + u64::from(day.num_days_from_monday());
+              return (week_start + Days::new(offset)).and_time(start.time());
+            })
+            .collect::<Vec<NaiveDateTime>>();
+        })
+        .filter(move |time| *time >= start),
+    );
+  }
+}
+
+fn parse_until(value: &str, zone: EventZone) -> Option<DateTime<Utc>> {
+  if let Some(utc) = value.strip_suffix('Z') {
+    let time = NaiveDateTime::parse_from_str(utc, ICS_DATE_TIME_FORMAT).ok()?;
+    return Some(Utc.from_utc_datetime(&time));
+  }
+
+  // A date-only end includes every occurrence on that day.
+  if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59)?;
+    return zone.resolve(&date.and_time(end_of_day));
+  }
+
+  let time = NaiveDateTime::parse_from_str(value, ICS_DATE_TIME_FORMAT).ok()?;
+  return zone.resolve(&time);
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+  return match value.trim().to_uppercase().as_str() {
+    "MO" => Some(Weekday::Mon),
+    "TU" => Some(Weekday::Tue),
+    "WE" => Some(Weekday::Wed),
+    "TH" => Some(Weekday::Thu),
+    "FR" => Some(Weekday::Fri),
+    "SA" => Some(Weekday::Sat),
+    "SU" => Some(Weekday::Sun),
+    _ => None,
+  };
+}
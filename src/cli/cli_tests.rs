@@ -1,6 +1,8 @@
 use clap::Parser;
 
-use crate::cli::{Cli, Commands};
+use crate::cli::{Cli, Commands, ConfigCommands, DeviceCommands, RecorderArgs};
+use crate::config::{Config, DeviceConfig};
+use crate::output::format::OutputFormat;
 
 #[test]
 fn test_cli_default_no_arguments() {
@@ -50,11 +52,85 @@ fn test_cli_record_command() {
   assert!(cli.is_ok());
   let parsed = cli.unwrap();
   match parsed.command {
-    Some(Commands::Record) => {}
+    Some(Commands::Record {
+      directory,
+      name,
+      output_json,
+      ..
+    }) => {
+      assert!(directory.is_none());
+      assert!(name.is_none());
+      assert!(!output_json);
+    }
+    _ => panic!("Expected Record command"),
+  }
+}
+
+#[test]
+fn test_cli_record_command_with_directory_and_name() {
+  let args = vec![
+    "lumine",
+    "record",
+    "--directory",
+    "/tmp/interviews",
+    "--name",
+    "guest",
+    "-j",
+  ];
+  let cli = Cli::try_parse_from(args);
+
+  assert!(cli.is_ok());
+  let parsed = cli.unwrap();
+  match parsed.command {
+    Some(Commands::Record {
+      directory,
+      name,
+      output_json,
+      ..
+    }) => {
+      assert_eq!(directory.as_deref(), Some("/tmp/interviews"));
+      assert_eq!(name.as_deref(), Some("guest"));
+      assert!(output_json);
+    }
+    _ => panic!("Expected Record command"),
+  }
+}
+
+#[test]
+fn test_cli_record_command_force_requires_name() {
+  let args = vec!["lumine", "record", "--force"];
+  assert!(Cli::try_parse_from(args).is_err());
+
+  let args = vec!["lumine", "record", "--name", "guest", "--force"];
+  match Cli::try_parse_from(args).unwrap().command {
+    Some(Commands::Record { force, .. }) => assert!(force),
     _ => panic!("Expected Record command"),
   }
 }
 
+#[test]
+fn test_cli_last_command() {
+  let args = vec!["lumine", "last"];
+  let cli = Cli::try_parse_from(args);
+
+  assert!(cli.is_ok());
+  assert!(matches!(
+    cli.unwrap().command,
+    Some(Commands::Last { transcript: false })
+  ));
+}
+
+#[test]
+fn test_cli_last_transcript() {
+  let args = vec!["lumine", "last", "--transcript"];
+  let cli = Cli::try_parse_from(args);
+
+  assert!(matches!(
+    cli.unwrap().command,
+    Some(Commands::Last { transcript: true })
+  ));
+}
+
 #[test]
 fn test_cli_invalid_command() {
   let args = vec!["lumine", "invalid_command"];
@@ -131,3 +207,727 @@ fn test_cli_multiple_arguments_ignored_extra() {
 
   assert!(cli.is_err());
 }
+
+#[test]
+fn test_cli_json_summary_flag_is_global() {
+  let args = vec!["lumine", "transcribe", "-f", "test.wav", "--json-summary"];
+  let cli = Cli::try_parse_from(args);
+
+  assert!(cli.is_ok());
+  assert!(cli.unwrap().json_summary);
+}
+
+#[test]
+fn test_cli_default_silence_overrides() {
+  let args = vec![
+    "lumine",
+    "--silence-limit",
+    "4",
+    "--silence-threshold",
+    "30",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+
+  assert_eq!(cli.recorder.silence_limit, Some(4));
+  assert_eq!(cli.recorder.silence_threshold, Some(30));
+}
+
+#[test]
+fn test_cli_noise_threshold_alias() {
+  let args = vec!["lumine", "record", "--noise-threshold", "35"];
+  let cli = Cli::try_parse_from(args).unwrap();
+
+  assert!(matches!(cli.command, Some(Commands::Record { .. })));
+  assert_eq!(cli.recorder.silence_threshold, Some(35));
+}
+
+#[test]
+fn test_cli_record_silence_overrides() {
+  let args = vec!["lumine", "record", "--silence-limit", "5"];
+  let cli = Cli::try_parse_from(args).unwrap();
+
+  assert!(matches!(cli.command, Some(Commands::Record { .. })));
+  assert_eq!(cli.recorder.silence_limit, Some(5));
+  assert!(cli.recorder.silence_threshold.is_none());
+}
+
+#[test]
+fn test_cli_recorder_flags_are_global() {
+  for args in [
+    vec!["lumine", "--silence-limit", "3", "record"],
+    vec!["lumine", "record", "--silence-limit", "3"],
+  ] {
+    let cli = Cli::try_parse_from(args).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Record { .. })));
+    assert_eq!(cli.recorder.silence_limit, Some(3));
+  }
+}
+
+#[test]
+fn test_cli_transcription_flags_are_global() {
+  for args in [
+    vec!["lumine", "--chapters", "transcribe", "-f", "a.wav"],
+    vec!["lumine", "transcribe", "-f", "a.wav", "--chapters"],
+  ] {
+    let cli = Cli::try_parse_from(args).unwrap();
+    assert!(cli.transcription.chapters);
+  }
+}
+
+#[test]
+fn test_cli_rejects_invalid_silence_limit() {
+  let args = vec!["lumine", "--silence-limit", "0"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_recorder_args_apply() {
+  let mut config = Config::default();
+  config.recorder.device = Some(
+    [(
+      String::from("USB Microphone"),
+      DeviceConfig {
+        silence_limit: Some(3),
+        silence_detect_noise: Some(35),
+        ..Default::default()
+      },
+    )]
+    .into(),
+  );
+
+  let overrides = RecorderArgs {
+    silence_limit: Some(6),
+    ..Default::default()
+  };
+  overrides.apply(&mut config);
+
+  assert_eq!(config.get_silence_limit(), 6);
+  assert_eq!(config.get_silence_detect_noise(), 40);
+  let device = &config.get_device_overrides()["USB Microphone"];
+  assert!(device.silence_limit.is_none());
+  assert_eq!(device.silence_detect_noise, Some(35));
+}
+
+#[test]
+fn test_cli_max_duration_overrides() {
+  let args = vec!["lumine", "record", "--max-duration", "15"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Record { .. })));
+  let mut config = Config::default();
+  cli.recorder.apply(&mut config);
+  assert_eq!(config.get_max_recording_duration(), 15);
+
+  let args = vec!["lumine", "--no-max-duration"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.recorder.apply(&mut config);
+  assert_eq!(config.get_max_recording_duration(), 0);
+}
+
+#[test]
+fn test_cli_max_duration_conflicts_with_unlimited() {
+  let args = vec!["lumine", "--max-duration", "15", "--no-max-duration"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_cli_device_override() {
+  let args = vec!["lumine", "record", "--device", "USB"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Record { .. })));
+  let mut config = Config::default();
+  cli.recorder.apply(&mut config);
+  assert_eq!(config.get_preferred_audio_input_device(), "USB");
+}
+
+#[test]
+fn test_cli_mode() {
+  let args = vec![
+    "lumine",
+    "transcribe",
+    "-f",
+    "talk.wav",
+    "--mode",
+    "archive",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert_eq!(cli.mode.as_deref(), Some("archive"));
+}
+
+#[test]
+fn test_cli_refresh_devices() {
+  let args = vec!["lumine", "--refresh-devices"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.recorder.apply(&mut config);
+  assert_eq!(config.get_device_cache_ttl(), 0);
+}
+
+#[test]
+fn test_cli_transcribe_vad_overrides() {
+  let args = vec!["lumine", "transcribe", "-f", "talk.wav", "--no-vad"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert_eq!(config.get_whisper_vad(), Some(false));
+
+  let args = vec!["lumine", "--vad"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert_eq!(config.get_whisper_vad(), Some(true));
+}
+
+#[test]
+fn test_cli_vad_conflicts_with_no_vad() {
+  let args = vec!["lumine", "--vad", "--no-vad"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_cli_two_pass_override() {
+  let args = vec!["lumine", "transcribe", "-f", "talk.wav", "--two-pass"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert!(config.get_two_pass());
+}
+
+#[test]
+fn test_cli_transcribe_lrc_format() {
+  let args = vec!["lumine", "transcribe", "-f", "song.wav", "--format", "lrc"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Transcribe { format, .. }) => {
+      assert_eq!(format, Some(OutputFormat::Lrc));
+    }
+    _ => panic!("Expected Transcribe command"),
+  }
+}
+
+#[test]
+fn test_cli_transcribe_words_formats() {
+  for (name, expected) in [
+    ("words-json", OutputFormat::WordsJson),
+    ("words-tsv", OutputFormat::WordsTsv),
+  ] {
+    let args = vec!["lumine", "transcribe", "-f", "talk.wav", "--format", name];
+    let cli = Cli::try_parse_from(args).unwrap();
+    match cli.command {
+      Some(Commands::Transcribe { format, .. }) => {
+        assert_eq!(format, Some(expected));
+      }
+      _ => panic!("Expected Transcribe command"),
+    }
+  }
+}
+
+#[test]
+fn test_cli_format_conflicts_with_json_flags() {
+  let args = vec!["lumine", "--format", "lrc", "-j"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_cli_eval_command() {
+  let args = vec![
+    "lumine",
+    "eval",
+    "-f",
+    "talk.wav",
+    "--reference",
+    "talk.txt",
+    "--keep-case",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Eval {
+      file,
+      reference,
+      keep_case,
+      keep_punctuation,
+      ..
+    }) => {
+      assert_eq!(file, "talk.wav");
+      assert_eq!(reference, "talk.txt");
+      assert!(keep_case);
+      assert!(!keep_punctuation);
+    }
+    _ => panic!("Expected Eval command"),
+  }
+}
+
+#[test]
+fn test_cli_eval_requires_reference() {
+  let args = vec!["lumine", "eval", "-f", "talk.wav"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_cli_eval_corpus_command() {
+  let args = vec!["lumine", "eval-corpus", "dataset", "-j"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::EvalCorpus {
+      directory,
+      output_json,
+      ..
+    }) => {
+      assert_eq!(directory, "dataset");
+      assert!(output_json);
+    }
+    _ => panic!("Expected EvalCorpus command"),
+  }
+}
+
+#[test]
+fn test_cli_notes_command() {
+  let args = vec!["lumine", "notes", "-o", "sync.md", "--max-duration", "30"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Notes { output }) => {
+      assert_eq!(output.as_deref(), Some("sync.md"));
+      assert_eq!(cli.recorder.max_duration, Some(30));
+    }
+    _ => panic!("Expected Notes command"),
+  }
+}
+
+#[test]
+fn test_cli_post_profile_override() {
+  let args = vec!["lumine", "--post", "meeting"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert_eq!(config.get_post_profile(), "meeting");
+}
+
+#[test]
+fn test_cli_translate_override() {
+  let args = vec![
+    "lumine",
+    "transcribe",
+    "-f",
+    "talk.wav",
+    "--translate",
+    "de",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert_eq!(config.get_translation_target_language(), "de");
+}
+
+#[test]
+fn test_cli_bilingual_requires_translate() {
+  let args = vec!["lumine", "--bilingual"];
+  assert!(Cli::try_parse_from(args).is_err());
+
+  let args = vec!["lumine", "--translate", "de", "--bilingual"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert!(config.get_bilingual_subtitles());
+}
+
+#[test]
+fn test_cli_chapters_override() {
+  let args = vec!["lumine", "transcribe", "-f", "show.wav", "--chapters"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert!(config.get_chapters_enabled());
+}
+
+#[test]
+fn test_cli_skip_duplicates_override() {
+  let args = vec!["lumine", "transcribe", "-f", "a.mp3", "--skip-duplicates"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert!(config.get_skip_duplicates());
+}
+
+#[test]
+fn test_cli_transcribe_raw_input() {
+  let args = vec![
+    "lumine",
+    "transcribe",
+    "-f",
+    "-",
+    "--input-format",
+    "s16le",
+    "--rate",
+    "48000",
+    "--channels",
+    "2",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Transcribe {
+      file,
+      input_format,
+      rate,
+      channels,
+      ..
+    }) => {
+      assert_eq!(file, "-");
+      assert_eq!(input_format.as_deref(), Some("s16le"));
+      assert_eq!(rate, Some(48000));
+      assert_eq!(channels, Some(2));
+    }
+    _ => panic!("Expected Transcribe command"),
+  }
+
+  let args = vec!["lumine", "transcribe", "-f", "-", "--input-format", "s16le"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_call_command() {
+  let args = vec![
+    "lumine",
+    "call",
+    "--source",
+    "alsa_output.softphone.monitor",
+    "--device",
+    "USB",
+  ];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Call { source }) => {
+      assert_eq!(source.as_deref(), Some("alsa_output.softphone.monitor"));
+      assert_eq!(cli.recorder.device.as_deref(), Some("USB"));
+    }
+    _ => panic!("Expected Call command"),
+  }
+}
+
+#[test]
+fn test_transcribe_split_channels() {
+  let args = vec!["lumine", "transcribe", "-f", "call.wav", "--split-channels"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Transcribe { split_channels, .. }) => {
+      assert!(split_channels);
+    }
+    _ => panic!("Expected Transcribe command"),
+  }
+
+  let args = vec![
+    "lumine",
+    "transcribe",
+    "-f",
+    "call.wav",
+    "--split-channels",
+    "--format",
+    "srt",
+  ];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_decrypt_command() {
+  let args = vec!["lumine", "decrypt", "-f", "call.wav.enc"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Decrypt {
+      file,
+      output,
+      force,
+    }) => {
+      assert_eq!(file, "call.wav.enc");
+      assert!(output.is_none());
+      assert!(!force);
+    }
+    _ => panic!("Expected Decrypt command"),
+  }
+
+  let args = vec!["lumine", "decrypt", "-f", "a.enc", "-o", "a.wav", "--force"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Decrypt { output, force, .. }) => {
+      assert_eq!(output.as_deref(), Some("a.wav"));
+      assert!(force);
+    }
+    _ => panic!("Expected Decrypt command"),
+  }
+}
+
+#[test]
+fn test_clean_command() {
+  let args = vec!["lumine", "clean", "--temp"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Clean { temp }) => assert!(temp),
+    _ => panic!("Expected Clean command"),
+  }
+
+  let args = vec!["lumine", "clean"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_self_update_command() {
+  let args = vec!["lumine", "self-update", "--check"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::SelfUpdate { check }) => assert!(check),
+    _ => panic!("Expected SelfUpdate command"),
+  }
+}
+
+#[test]
+fn test_stats_command() {
+  let args = vec!["lumine", "stats", "-j"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Stats { output_json }) => assert!(output_json),
+    _ => panic!("Expected Stats command"),
+  }
+}
+
+#[test]
+fn test_devices_command() {
+  let args = vec!["lumine", "devices"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Devices {
+      action: None,
+      output_json,
+    }) => assert!(!output_json),
+    _ => panic!("Expected Devices command"),
+  }
+
+  let args = vec!["lumine", "devices", "--json"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Devices {
+      action: None,
+      output_json,
+    }) => assert!(output_json),
+    _ => panic!("Expected Devices command"),
+  }
+}
+
+#[test]
+fn test_devices_test_command() {
+  let args = vec!["lumine", "devices", "test"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Devices {
+      action:
+        Some(DeviceCommands::Test {
+          device,
+          play,
+          output_json,
+        }),
+      ..
+    }) => {
+      assert_eq!(device, None);
+      assert!(!play);
+      assert!(!output_json);
+    }
+    _ => panic!("Expected Devices Test command"),
+  }
+
+  let args = vec!["lumine", "devices", "test", "--device", "USB", "--play"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Devices {
+      action: Some(DeviceCommands::Test { device, play, .. }),
+      ..
+    }) => {
+      assert_eq!(device, Some(String::from("USB")));
+      assert!(play);
+    }
+    _ => panic!("Expected Devices Test command"),
+  }
+}
+
+#[test]
+fn test_config_get_command() {
+  let args = vec!["lumine", "config", "get", "whisper.url"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Config {
+      action: ConfigCommands::Get { key },
+    }) => assert_eq!(key, "whisper.url"),
+    _ => panic!("Expected Config Get command"),
+  }
+}
+
+#[test]
+fn test_config_set_command() {
+  let args = vec!["lumine", "config", "set", "recorder.silence_limit", "3"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Config {
+      action: ConfigCommands::Set { key, value },
+    }) => {
+      assert_eq!(key, "recorder.silence_limit");
+      assert_eq!(value, "3");
+    }
+    _ => panic!("Expected Config Set command"),
+  }
+
+  let args = vec!["lumine", "config", "set", "recorder.silence_limit"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_config_show_command() {
+  let args = vec!["lumine", "config", "show", "--json"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Config {
+      action: ConfigCommands::Show { output_json },
+    }) => assert!(output_json),
+    _ => panic!("Expected Config Show command"),
+  }
+}
+
+#[test]
+fn test_cli_keep_intermediate() {
+  let args = vec!["lumine", "transcribe", "-f", "a.mp3", "--keep-intermediate"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  assert!(matches!(cli.command, Some(Commands::Transcribe { .. })));
+  cli.transcription.apply(&mut config);
+  assert!(config.get_keep_converted());
+}
+
+#[test]
+fn test_cli_clipboard() {
+  let args = vec!["lumine", "--clipboard"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  let mut config = Config::default();
+  cli.transcription.apply(&mut config);
+  assert!(config.get_copy_to_clipboard());
+}
+
+#[test]
+fn test_watch_command() {
+  let args = vec!["lumine", "watch", "/inbox", "--skip-music"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Watch { directory }) => {
+      assert_eq!(directory, "/inbox");
+      assert!(cli.transcription.skip_music);
+    }
+    _ => panic!("Expected Watch command"),
+  }
+
+  let args = vec!["lumine", "watch"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_record_stdout_audio() {
+  let args = vec!["lumine", "record", "--stdout-audio"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Record { stdout_audio, .. }) => assert!(stdout_audio),
+    _ => panic!("Expected Record command"),
+  }
+
+  let args = vec!["lumine", "record", "--stdout-audio", "--name", "memo"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_record_intervals() {
+  let args = vec!["lumine", "record", "--intervals"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Record { intervals, .. }) => assert!(intervals),
+    _ => panic!("Expected Record command"),
+  }
+
+  let args = vec!["lumine", "record", "--intervals", "--stdout-audio"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_dictate_command() {
+  let args = vec!["lumine", "dictate", "--hotkey", "super+f9", "--toggle"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Dictate { hotkey, toggle, .. }) => {
+      assert_eq!(hotkey.as_deref(), Some("super+f9"));
+      assert!(toggle);
+    }
+    _ => panic!("Expected Dictate command"),
+  }
+
+  let args = vec!["lumine", "dictate", "--hotkey", ""];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Dictate { hotkey, toggle, .. }) => {
+      assert_eq!(hotkey.as_deref(), Some(""));
+      assert!(!toggle);
+    }
+    _ => panic!("Expected Dictate command"),
+  }
+}
+
+#[test]
+fn test_explain_command() {
+  let args = vec!["lumine", "explain", "LUM-AUD-003"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  match cli.command {
+    Some(Commands::Explain { code }) => assert_eq!(code, "LUM-AUD-003"),
+    _ => panic!("Expected Explain command"),
+  }
+
+  let args = vec!["lumine", "explain"];
+  assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_validate_rejects_json_summary_for_plain_text_commands() {
+  for args in [
+    vec!["lumine", "call", "--json-summary"],
+    vec![
+      "lumine",
+      "transcribe",
+      "-f",
+      "c.wav",
+      "--split-channels",
+      "--json-summary",
+    ],
+    vec![
+      "lumine",
+      "--json-summary",
+      "transcribe",
+      "-f",
+      "rtmp://host/live",
+    ],
+  ] {
+    let cli = Cli::try_parse_from(args).unwrap();
+    assert!(cli.validate().is_err());
+  }
+}
+
+#[test]
+fn test_validate_rejects_format_for_streams() {
+  let args = vec!["lumine", "transcribe", "-f", "rtmp://host/live", "-j"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(cli.validate().is_err());
+}
+
+#[test]
+fn test_validate_accepts_json_summary_for_files() {
+  let args = vec!["lumine", "transcribe", "-f", "a.wav", "--json-summary"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(cli.validate().is_ok());
+
+  let args = vec!["lumine", "call", "--device", "USB"];
+  let cli = Cli::try_parse_from(args).unwrap();
+  assert!(cli.validate().is_ok());
+}
@@ -7,14 +7,38 @@
 //! ## Commands
 //!
 //! - **Default (no subcommand)**: Record audio and transcribe
-//! - `transcribe --file <path>`: Transcribe an existing audio file
-//! - `record`: Record audio and save to file only
+//! - `transcribe --file <path>`: Transcribe an existing audio file or a live stream URL
+//! - `record [--directory <dir>] [--name <name>]`: Record audio and save to file only
+//! - `notes [--output <path>]`: Take live Markdown notes of a meeting
+//! - `eval --file <path> --reference <path>`: Measure transcript accuracy
+//! - `eval-corpus <dir>`: Measure accuracy over a directory of recordings
+//! - `watch <dir>`: Transcribe audio files as they appear in a directory
+//! - `call [--source <source>]`: Record both sides of a call and transcribe them as a dialogue
+//! - `decrypt --file <path> [--output <path>] [--force]`: Decrypt a file encrypted at rest
+//! - `clean --temp`: Remove temporary files left behind by failed runs
+//! - `devices [--json]`: List the available audio input devices
+//! - `devices test [--device <device>] [--play]`: Record a short test and check the input level
+//! - `stats [--output-json]`: Show locally recorded usage statistics
+//! - `self-update [--check]`: Update Lumine to the latest release
+//! - `last [--transcript]`: Print the path of the latest kept recording or
+//!   saved transcript
 //! - `reset-config`: Reset configuration to default values
+//! - `config get <key>` / `config set <key> <value>`: Read or change a single configuration value
+//! - `config show [--json]`: Show the effective configuration and where each value comes from
+//!
+//! The default and `record` commands accept recorder overrides such as
+//! `--silence-limit` that apply to that run only.
 
 #[cfg(test)]
 mod cli_tests;
 
-use clap::{Parser, Subcommand};
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+
+use crate::audio::is_stream_url;
+use crate::config::Config;
+use crate::output::format::OutputFormat;
+use crate::tr;
 
 #[derive(Parser)]
 #[command(name = "lumine")]
@@ -45,16 +69,293 @@ pub struct Cli {
     conflicts_with = "output_json"
   )]
   pub output_json_full: bool,
+
+  /// Output format of the transcript
+  #[arg(
+    long,
+    value_enum,
+    conflicts_with_all = ["output_json", "output_json_full"]
+  )]
+  pub format: Option<OutputFormat>,
+
+  /// Print a single-line JSON summary of the run instead of the transcript
+  #[arg(long, default_value_t = false, global = true)]
+  pub json_summary: bool,
+
+  /// Quality preset: `interactive`, `archive` or one defined in the
+  /// configuration
+  #[arg(long, value_name = "MODE", global = true)]
+  pub mode: Option<String>,
+
+  /// Announce progress with short status lines for screen readers
+  #[arg(long, default_value_t = false, global = true)]
+  pub a11y: bool,
+
+  #[command(flatten)]
+  pub recorder: RecorderArgs,
+
+  #[command(flatten)]
+  pub transcription: TranscriptionArgs,
+}
+
+impl Cli {
+  /// Checks output flags that only some commands support.
+  ///
+  /// Live streams, split channels and calls print plain text as it is
+  /// transcribed, so they reject `--json-summary` and other output formats
+  /// instead of ignoring them.
+  ///
+  /// # Returns
+  ///
+  /// A `Result<(), clap::Error>` with the conflicting flag, if any.
+  pub fn validate(&self) -> Result<(), clap::Error> {
+    let (plain_text_only, format_requested) = match &self.command {
+      Some(Commands::Transcribe {
+        file,
+        split_channels,
+        output_json,
+        output_json_full,
+        format,
+        ..
+      }) => (
+        *split_channels || is_stream_url(file),
+        *output_json || *output_json_full || format.is_some(),
+      ),
+      Some(Commands::Call { .. }) => (true, false),
+      _ => (false, false),
+    };
+    if !plain_text_only {
+      return Ok(());
+    }
+
+    let flag = if self.json_summary {
+      "--json-summary"
+    } else if format_requested {
+      "--format"
+    } else {
+      return Ok(());
+    };
+    return Err(Cli::command().error(
+      ErrorKind::ArgumentConflict,
+      tr!("output-flag-unsupported", flag = flag),
+    ));
+  }
+}
+
+/// Recorder settings that override the configuration for a single run.
+///
+/// The flags are global, so they mean the same before and after the
+/// subcommand.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RecorderArgs {
+  /// Seconds of silence before stopping recording
+  #[arg(long, value_parser = clap::value_parser!(i32).range(1..), global = true)]
+  pub silence_limit: Option<i32>,
+
+  /// Noise threshold in dB below which audio counts as silence
+  #[arg(
+    long,
+    visible_alias = "noise-threshold",
+    value_parser = clap::value_parser!(i32).range(1..),
+    global = true
+  )]
+  pub silence_threshold: Option<i32>,
+
+  /// Maximum recording duration in seconds
+  #[arg(
+    long,
+    value_parser = clap::value_parser!(i32).range(1..),
+    conflicts_with = "no_max_duration",
+    global = true
+  )]
+  pub max_duration: Option<i32>,
+
+  /// Record without a maximum duration
+  #[arg(long, default_value_t = false, global = true)]
+  pub no_max_duration: bool,
+
+  /// Audio input device to record from, by index or part of its name
+  #[arg(long, global = true)]
+  pub device: Option<String>,
+
+  /// List the audio input devices again instead of using the cached list
+  #[arg(long, default_value_t = false, global = true)]
+  pub refresh_devices: bool,
+}
+
+/// Transcription settings that override the configuration for a single run.
+///
+/// Like the recorder flags, these are global.
+#[derive(Args, Debug, Clone, Default)]
+pub struct TranscriptionArgs {
+  /// Ask the Whisper service to run voice activity detection
+  #[arg(
+    long,
+    default_value_t = false,
+    conflicts_with = "no_vad",
+    global = true
+  )]
+  pub vad: bool,
+
+  /// Ask the Whisper service to skip voice activity detection
+  #[arg(long, default_value_t = false, global = true)]
+  pub no_vad: bool,
+
+  /// Print a quick draft from the draft Whisper service before the final
+  /// transcript
+  #[arg(long, default_value_t = false, global = true)]
+  pub two_pass: bool,
+
+  /// Post-processing profile applied to the transcript, such as `meeting`
+  #[arg(long, value_name = "PROFILE", global = true)]
+  pub post: Option<String>,
+
+  /// Translate the transcript to a language, such as `de`
+  #[arg(long, value_name = "LANGUAGE", global = true)]
+  pub translate: Option<String>,
+
+  /// Show the original and translated line in each subtitle cue
+  #[arg(long, requires = "translate", global = true)]
+  pub bilingual: bool,
+
+  /// Split the transcript into chapters and add a chapter list
+  #[arg(long, global = true)]
+  pub chapters: bool,
+
+  /// Skip files whose audio was already transcribed and reuse its transcript
+  #[arg(long, global = true)]
+  pub skip_duplicates: bool,
+
+  /// Skip long music-only regions, such as podcast intros, when transcribing
+  #[arg(long, global = true)]
+  pub skip_music: bool,
+
+  /// Keep the converted audio files sent to Whisper
+  #[arg(long, global = true)]
+  pub keep_intermediate: bool,
+
+  /// Copy the finished transcript to the clipboard as well
+  #[arg(long, global = true)]
+  pub clipboard: bool,
+}
+
+impl TranscriptionArgs {
+  /// Applies the overrides to a configuration.
+  ///
+  /// # Arguments
+  ///
+  /// * `config` - The configuration to override
+  pub fn apply(&self, config: &mut Config) {
+    if self.vad {
+      config.whisper.vad = Some(true);
+    }
+    if self.no_vad {
+      config.whisper.vad = Some(false);
+    }
+    if self.two_pass {
+      config.whisper.two_pass = Some(true);
+    }
+    if let Some(profile) = &self.post {
+      config.llm.profile = Some(profile.clone());
+    }
+    if let Some(language) = &self.translate {
+      config.translation.target_language = Some(language.clone());
+    }
+    if self.bilingual {
+      config.translation.bilingual_subtitles = Some(true);
+    }
+    if self.chapters {
+      config.chapters.enabled = Some(true);
+    }
+    if self.skip_duplicates {
+      config.general.skip_duplicates = Some(true);
+    }
+    if self.skip_music {
+      config.general.skip_music = Some(true);
+    }
+    if self.keep_intermediate {
+      config.general.keep_converted = Some(true);
+    }
+    if self.clipboard {
+      config.general.copy_to_clipboard = Some(true);
+    }
+  }
+}
+
+impl RecorderArgs {
+  /// Applies the overrides to a configuration.
+  ///
+  /// Overridden values also replace the matching per-device overrides, so
+  /// the command-line value is used whichever device is selected.
+  ///
+  /// # Arguments
+  ///
+  /// * `config` - The configuration to override
+  pub fn apply(&self, config: &mut Config) {
+    let devices = config
+      .recorder
+      .device
+      .iter_mut()
+      .flat_map(|d| d.values_mut());
+    for device in devices {
+      if self.silence_limit.is_some() {
+        device.silence_limit = None;
+      }
+      if self.silence_threshold.is_some() {
+        device.silence_detect_noise = None;
+      }
+    }
+
+    if let Some(silence_limit) = self.silence_limit {
+      config.recorder.silence_limit = Some(silence_limit);
+    }
+    if let Some(silence_threshold) = self.silence_threshold {
+      config.recorder.silence_detect_noise = Some(silence_threshold);
+    }
+    if let Some(max_duration) = self.max_duration {
+      config.recorder.max_recording_duration = Some(max_duration);
+    }
+    if self.no_max_duration {
+      config.recorder.max_recording_duration = Some(0);
+    }
+    if let Some(device) = &self.device {
+      config.recorder.preferred_audio_input_device = Some(device.clone());
+    }
+    if self.refresh_devices {
+      config.recorder.device_cache_ttl = Some(0);
+    }
+  }
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
   /// Transcribe an existing audio file
   Transcribe {
-    /// Path to the audio file to transcribe
+    /// Path to the audio file to transcribe, `-` to read stdin, or the URL
+    /// of a live stream
     #[arg(short, long)]
     file: String,
 
+    /// Sample format of headerless PCM input, such as `s16le` or `f32le`
+    #[arg(long, requires_all = ["rate", "channels"])]
+    input_format: Option<String>,
+
+    /// Sample rate of PCM input in Hz
+    #[arg(
+      long,
+      requires = "input_format",
+      value_parser = clap::value_parser!(i32).range(1..)
+    )]
+    rate: Option<i32>,
+
+    /// Number of channels of PCM input
+    #[arg(
+      long,
+      requires = "input_format",
+      value_parser = clap::value_parser!(i32).range(1..)
+    )]
+    channels: Option<i32>,
+
     /// Output result in JSON format
     #[arg(
       short = 'j',
@@ -72,11 +373,259 @@ pub enum Commands {
       conflicts_with = "output_json"
     )]
     output_json_full: bool,
+
+    /// Output format of the transcript
+    #[arg(
+      long,
+      value_enum,
+      conflicts_with_all = ["output_json", "output_json_full"]
+    )]
+    format: Option<OutputFormat>,
+
+    /// Transcribe the left and right channel separately and print them as a
+    /// dialogue between Speaker A and Speaker B
+    #[arg(
+      long,
+      default_value_t = false,
+      conflicts_with_all = ["format", "output_json", "output_json_full"]
+    )]
+    split_channels: bool,
   },
 
   /// Record audio and save it to a file
-  Record,
+  Record {
+    /// Directory to save the recording in, overriding the configuration
+    #[arg(short, long)]
+    directory: Option<String>,
+
+    /// File name for the recording
+    #[arg(short, long)]
+    name: Option<String>,
+
+    /// Overwrite an existing file with the same name
+    #[arg(long, default_value_t = false, requires = "name")]
+    force: bool,
+
+    /// Output result in JSON format
+    #[arg(short = 'j', long, default_value_t = false)]
+    output_json: bool,
+
+    /// Output JSON including the detected speech and silence intervals
+    #[arg(long, default_value_t = false)]
+    intervals: bool,
+
+    /// Write the recorded 16 kHz mono WAV audio to stdout instead of
+    /// keeping a file
+    #[arg(
+      long,
+      default_value_t = false,
+      conflicts_with_all = [
+        "directory",
+        "name",
+        "force",
+        "output_json",
+        "intervals"
+      ]
+    )]
+    stdout_audio: bool,
+  },
+
+  /// Take live Markdown notes of a meeting until stopped with Ctrl+C
+  Notes {
+    /// Path to the notes file, overriding the default in the recordings directory
+    #[arg(short, long)]
+    output: Option<String>,
+  },
+
+  /// Transcribe an audio file and compare it with a reference transcript
+  Eval {
+    /// Path to the audio file to transcribe
+    #[arg(short, long)]
+    file: String,
+
+    /// Path to the text file with the reference transcript
+    #[arg(short, long)]
+    reference: String,
+
+    /// Treat differences in letter case as errors
+    #[arg(long, default_value_t = false)]
+    keep_case: bool,
+
+    /// Treat differences in punctuation as errors
+    #[arg(long, default_value_t = false)]
+    keep_punctuation: bool,
+
+    /// Output result in JSON format
+    #[arg(short = 'j', long, default_value_t = false)]
+    output_json: bool,
+  },
+
+  /// Evaluate every recording of a directory against its `.txt` reference
+  EvalCorpus {
+    /// Directory with audio files and reference transcripts
+    directory: String,
+
+    /// Treat differences in letter case as errors
+    #[arg(long, default_value_t = false)]
+    keep_case: bool,
+
+    /// Treat differences in punctuation as errors
+    #[arg(long, default_value_t = false)]
+    keep_punctuation: bool,
+
+    /// Output the report in JSON format instead of CSV
+    #[arg(short = 'j', long, default_value_t = false)]
+    output_json: bool,
+  },
+
+  /// Transcribe audio files as they appear in a directory, writing `.txt`
+  /// and `.srt` files next to them
+  Watch {
+    /// Directory to watch for new audio files
+    directory: String,
+  },
+
+  /// Stay running and record while a global hotkey is held, transcribing
+  /// each recording on release
+  Dictate {
+    /// Hotkey that controls recording, such as `ctrl+alt+space`, overriding
+    /// the configuration (empty to only use the SIGUSR1 signal)
+    #[arg(long)]
+    hotkey: Option<String>,
+
+    /// Start and stop recording with separate presses instead of holding
+    /// the hotkey
+    #[arg(long, default_value_t = false)]
+    toggle: bool,
+
+    /// Output each result in JSON format
+    #[arg(short = 'j', long, default_value_t = false)]
+    output_json: bool,
+  },
+
+  /// Record both sides of a call until they fall silent and transcribe them
+  /// as a labeled dialogue
+  Call {
+    /// Source with the other side of the call, such as the monitor of the
+    /// softphone's output, overriding the configuration
+    #[arg(short, long)]
+    source: Option<String>,
+  },
+
+  /// Decrypt a recording, transcript or notes file encrypted at rest
+  Decrypt {
+    /// Path to the encrypted file
+    #[arg(short, long)]
+    file: String,
+
+    /// Path of the decrypted file, by default the encrypted path without its
+    /// `.enc` extension
+    #[arg(short, long)]
+    output: Option<String>,
+
+    /// Overwrite an existing file at the output path
+    #[arg(long, default_value_t = false)]
+    force: bool,
+  },
+
+  /// Remove files left behind by failed or killed runs
+  Clean {
+    /// Remove intermediate recordings, converted files and stream chunks
+    /// from the recordings and temporary directories
+    #[arg(long, required = true)]
+    temp: bool,
+  },
+
+  /// List the available audio input devices
+  Devices {
+    #[command(subcommand)]
+    action: Option<DeviceCommands>,
+
+    /// Output result in JSON format
+    #[arg(short = 'j', long, alias = "json", default_value_t = false)]
+    output_json: bool,
+  },
+
+  /// Show locally recorded usage statistics
+  Stats {
+    /// Output result in JSON format
+    #[arg(short = 'j', long, default_value_t = false)]
+    output_json: bool,
+  },
+
+  /// Update Lumine to the latest release
+  SelfUpdate {
+    /// Only check whether a newer release is available
+    #[arg(long, default_value_t = false)]
+    check: bool,
+  },
+
+  /// Print the path of the latest kept recording
+  Last {
+    /// Print the path of the latest saved transcript instead
+    #[arg(long, default_value_t = false)]
+    transcript: bool,
+  },
 
   /// Reset configuration to default values
   ResetConfig,
+
+  /// Explain the causes and fixes of an error code, such as `LUM-AUD-003`
+  Explain {
+    /// Error code shown at the start of an error message
+    code: String,
+  },
+
+  /// Read, change or show configuration values
+  Config {
+    #[command(subcommand)]
+    action: ConfigCommands,
+  },
+}
+
+/// Subcommands of `lumine devices`.
+#[derive(Subcommand)]
+pub enum DeviceCommands {
+  /// Record a few seconds, measure the input level and report whether the
+  /// device appears to work
+  Test {
+    /// Audio input device to test, by index or part of its name, instead of
+    /// the configured one
+    #[arg(long)]
+    device: Option<String>,
+
+    /// Play the test recording back afterwards
+    #[arg(long, default_value_t = false)]
+    play: bool,
+
+    /// Output result in JSON format
+    #[arg(short = 'j', long, alias = "json", default_value_t = false)]
+    output_json: bool,
+  },
+}
+
+/// Subcommands of `lumine config`.
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+  /// Print a configuration value, such as `whisper.url`
+  Get {
+    /// Dotted key of the value
+    key: String,
+  },
+
+  /// Change a configuration value, such as `recorder.silence_limit 3`
+  Set {
+    /// Dotted key of the value
+    key: String,
+
+    /// New value
+    value: String,
+  },
+
+  /// Show the effective configuration and where each value comes from
+  Show {
+    /// Output result in JSON format
+    #[arg(short = 'j', long, alias = "json", default_value_t = false)]
+    output_json: bool,
+  },
 }
@@ -0,0 +1,76 @@
+use crate::config::Config;
+use crate::config::errors::{ConfigError, ConfigResult};
+use crate::vlog;
+
+const VARIABLE_PREFIX: &str = "LUMINE_";
+
+impl Config {
+  /// Applies configuration overrides from environment variables.
+  ///
+  /// Variables are named after the dotted key in upper case, with dots and
+  /// the `LUMINE_` prefix, such as `LUMINE_WHISPER_URL` for `whisper.url`
+  /// or `LUMINE_RECORDER_SILENCE_LIMIT` for `recorder.silence_limit`.
+  /// Variables that match no key are ignored.
+  ///
+  /// # Returns
+  ///
+  /// A `ConfigResult<()>` indicating success, or an error if a variable has
+  /// a value of the wrong type.
+  pub fn apply_environment(&mut self) -> ConfigResult<()> {
+    let variables = std::env::vars_os().filter_map(|(name, value)| {
+      return match (name.into_string(), value.into_string()) {
+        (Ok(name), Ok(value)) => Some((name, value)),
+        _ => None,
+      };
+    });
+    return self.apply_variables(variables);
+  }
+
+  pub(crate) fn apply_variables<I>(&mut self, variables: I) -> ConfigResult<()>
+  where
+    I: IntoIterator<Item = (String, String)>,
+  {
+    for (name, value) in variables {
+      let Some(key) = name.strip_prefix(VARIABLE_PREFIX) else {
+        continue;
+      };
+      if !self.apply_variable(&key.to_lowercase(), &value)? {
+        vlog!("Ignoring unknown configuration variable: {}", name);
+      }
+    }
+    return Ok(());
+  }
+
+  fn apply_variable(&mut self, name: &str, value: &str) -> ConfigResult<bool> {
+    for key in candidate_keys(name) {
+      match self.set_value(&key, value) {
+        Ok(()) => return Ok(true),
+        Err(ConfigError::UnknownKey(_)) => {}
+        Err(e) => return Err(e),
+      }
+    }
+    return Ok(false);
+  }
+}
+
+/// Lists the dotted keys a lower-case variable name may stand for.
+///
+/// Section names contain no underscores, so the first underscore always
+/// separates the section. Field names may, so each later underscore is also
+/// tried as the separator of a nested table such as `whisper.decoding`.
+fn candidate_keys(name: &str) -> Vec<String> {
+  let parts: Vec<&str> = name.split('_').collect();
+  if parts.len() < 2 {
+    return Vec::new();
+  }
+  let mut keys = vec![format!("{}.{}", parts[0], parts[1..].join("_"))];
+  for split in 2..parts.len() {
+    keys.push(format!(
+      "{}.{}.{}",
+      parts[0],
+      parts[1..split].join("_"),
+      parts[split..].join("_")
+    ));
+  }
+  return keys;
+}
@@ -0,0 +1,54 @@
+use crate::config::Config;
+use crate::config::errors::ConfigError;
+
+fn variables(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+  return pairs
+    .iter()
+    .map(|(name, value)| (name.to_string(), value.to_string()))
+    .collect();
+}
+
+#[test]
+fn test_apply_variables() {
+  let mut config = Config::default();
+  config
+    .apply_variables(variables(&[
+      ("LUMINE_WHISPER_URL", "http://whisper:9090"),
+      ("LUMINE_RECORDER_SILENCE_LIMIT", "3"),
+      ("LUMINE_GENERAL_REMOVE_AFTER_TRANSCRIPT", "false"),
+    ]))
+    .unwrap();
+  assert_eq!(config.get_whisper_url(), "http://whisper:9090");
+  assert_eq!(config.get_silence_limit(), 3);
+  assert!(!config.get_remove_after_transcript());
+}
+
+#[test]
+fn test_apply_nested_variable() {
+  let mut config = Config::default();
+  config
+    .apply_variables(variables(&[("LUMINE_WHISPER_DECODING_BEAM_SIZE", "5")]))
+    .unwrap();
+  assert_eq!(config.get_decoding().beam_size, Some(5));
+}
+
+#[test]
+fn test_ignore_unrelated_variables() {
+  let mut config = Config::default();
+  config
+    .apply_variables(variables(&[
+      ("LUMINE_PASSPHRASE", "secret"),
+      ("LUMINE_RECORDER_VOLUME", "3"),
+      ("WHISPER_URL", "http://whisper:9090"),
+    ]))
+    .unwrap();
+  assert_eq!(config.get_whisper_url(), "http://127.0.0.1:9090");
+}
+
+#[test]
+fn test_invalid_variable_value() {
+  let mut config = Config::default();
+  let result = config
+    .apply_variables(variables(&[("LUMINE_RECORDER_SILENCE_LIMIT", "long")]));
+  assert!(matches!(result, Err(ConfigError::InvalidValue(..))));
+}
@@ -1,19 +1,42 @@
 use thiserror::Error;
 
+use crate::tr;
+
 /// Configuration-related errors.
 ///
 /// Represents errors that can occur during configuration loading and parsing.
 #[derive(Error, Debug)]
 pub enum ConfigError {
-  #[error(
-    "Cannot read configuration file: '{0}'. Please check file permissions and ensure the file exists."
-  )]
+  #[error("[LUM-CFG-001] {}", tr!("error-config-file-read", error = .0))]
   FileRead(String),
 
+  #[error("[LUM-CFG-002] {}", tr!("error-config-parse", error = .0))]
+  Parse(String),
+
+  #[error("[LUM-CFG-003] {}", tr!("error-config-unknown-mode", mode = .0))]
+  UnknownMode(String),
+
+  #[error("[LUM-CFG-004] {}", tr!("error-config-unknown-key", key = .0))]
+  UnknownKey(String),
+
   #[error(
-    "Configuration file is invalid: '{0}'. Please check the syntax and ensure all required fields are present."
+    "[LUM-CFG-005] {}",
+    tr!("error-config-invalid-value", key = .0, value = .1, error = .2)
   )]
-  Parse(String),
+  InvalidValue(String, String, String),
+}
+
+impl ConfigError {
+  /// Returns the stable code of this error, such as `LUM-CFG-001`.
+  pub fn code(&self) -> &'static str {
+    return match self {
+      ConfigError::FileRead(..) => "LUM-CFG-001",
+      ConfigError::Parse(..) => "LUM-CFG-002",
+      ConfigError::UnknownMode(..) => "LUM-CFG-003",
+      ConfigError::UnknownKey(..) => "LUM-CFG-004",
+      ConfigError::InvalidValue(..) => "LUM-CFG-005",
+    };
+  }
 }
 
 /// Result type for configuration operations.
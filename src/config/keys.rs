@@ -0,0 +1,147 @@
+use toml::{Table, Value};
+
+use crate::config::Config;
+use crate::config::errors::{ConfigError, ConfigResult};
+
+impl Config {
+  /// Gets a configuration value by its dotted key.
+  ///
+  /// Keys name a section and a field, such as `recorder.silence_limit`.
+  /// Unset fields report their default value.
+  ///
+  /// # Arguments
+  ///
+  /// * `key` - The dotted key of the value
+  ///
+  /// # Returns
+  ///
+  /// A `ConfigResult<String>` containing the value as it would be written in
+  /// the configuration file, or an error if the key is unknown or unset.
+  pub fn get_value(&self, key: &str) -> ConfigResult<String> {
+    let path = split_key(key)?;
+    let current = to_value(self)?;
+    let defaults = to_value(&Config::default())?;
+    let value = lookup(&current, &path)
+      .or_else(|| lookup(&defaults, &path))
+      .ok_or_else(|| ConfigError::UnknownKey(key.to_string()))?;
+    return match value {
+      Value::String(text) => Ok(text.clone()),
+      Value::Table(table) => toml::to_string_pretty(table)
+        .map(|text| text.trim_end().to_string())
+        .map_err(|e| ConfigError::Parse(e.to_string())),
+      _ => Ok(value.to_string()),
+    };
+  }
+
+  /// Sets a configuration value by its dotted key.
+  ///
+  /// The value is read as a TOML value when possible, so `3` sets an
+  /// integer and `true` a boolean, and as plain text otherwise. The change
+  /// is only applied when the key exists and the value has the right type.
+  ///
+  /// # Arguments
+  ///
+  /// * `key` - The dotted key of the value
+  /// * `value` - The new value
+  ///
+  /// # Returns
+  ///
+  /// A `ConfigResult<()>` indicating success or failure.
+  pub fn set_value(&mut self, key: &str, value: &str) -> ConfigResult<()> {
+    let path = split_key(key)?;
+    let mut candidates = Vec::new();
+    if let Some(parsed) = parse_value(value) {
+      candidates.push(parsed);
+    }
+    candidates.push(Value::String(value.to_string()));
+
+    let mut error = ConfigError::UnknownKey(key.to_string());
+    for candidate in candidates {
+      match self.with_value(&path, &candidate) {
+        Ok(Some(config)) => {
+          *self = config;
+          return Ok(());
+        }
+        Ok(None) => {}
+        Err(e) => {
+          error = ConfigError::InvalidValue(
+            key.to_string(),
+            value.to_string(),
+            e.message().to_string(),
+          );
+        }
+      }
+    }
+    return Err(error);
+  }
+
+  fn with_value(
+    &self,
+    path: &[&str],
+    value: &Value,
+  ) -> Result<Option<Config>, toml::de::Error> {
+    let Ok(mut current) = to_value(self) else {
+      return Ok(None);
+    };
+    if insert(&mut current, path, value.clone()).is_none() {
+      return Ok(None);
+    }
+    let config: Config = current.try_into()?;
+
+    // Unknown fields are ignored when deserializing, so a key only exists
+    // when the value survives the round trip.
+    let Ok(written) = to_value(&config) else {
+      return Ok(None);
+    };
+    if !lookup(&written, path).is_some_and(|stored| same_value(stored, value)) {
+      return Ok(None);
+    }
+    return Ok(Some(config));
+  }
+}
+
+fn split_key(key: &str) -> ConfigResult<Vec<&str>> {
+  let path: Vec<&str> = key.split('.').collect();
+  if path.len() < 2 || path.iter().any(|part| part.is_empty()) {
+    return Err(ConfigError::UnknownKey(key.to_string()));
+  }
+  return Ok(path);
+}
+
+pub(crate) fn to_value(config: &Config) -> ConfigResult<Value> {
+  return Value::try_from(config)
+    .map_err(|e| ConfigError::Parse(e.to_string()));
+}
+
+fn parse_value(value: &str) -> Option<Value> {
+  let document = format!("value = {}", value);
+  return toml::from_str::<Table>(&document)
+    .ok()
+    .and_then(|mut table| table.remove("value"));
+}
+
+pub(crate) fn lookup<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+  return path.iter().try_fold(value, |current, part| {
+    return current.as_table().and_then(|table| table.get(*part));
+  });
+}
+
+fn insert(root: &mut Value, path: &[&str], value: Value) -> Option<()> {
+  let (last, parents) = path.split_last()?;
+  let mut current = root;
+  for part in parents {
+    current = current
+      .as_table_mut()?
+      .entry(part.to_string())
+      .or_insert_with(|| Value::Table(Table::new()));
+  }
+  current.as_table_mut()?.insert(last.to_string(), value);
+  return Some(());
+}
+
+fn same_value(stored: &Value, value: &Value) -> bool {
+  return match (stored, value) {
+    (Value::Float(stored), Value::Integer(value)) => *stored == *value as f64,
+    _ => stored == value,
+  };
+}
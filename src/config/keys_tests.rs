@@ -0,0 +1,99 @@
+use crate::config::Config;
+use crate::config::errors::ConfigError;
+
+#[test]
+fn test_get_value() {
+  let config = Config::default();
+  assert_eq!(
+    config.get_value("whisper.url").unwrap(),
+    "http://127.0.0.1:9090"
+  );
+  assert_eq!(config.get_value("recorder.silence_limit").unwrap(), "2");
+  assert_eq!(config.get_value("usage.enabled").unwrap(), "false");
+}
+
+#[test]
+fn test_get_unknown_value() {
+  let config = Config::default();
+  assert!(matches!(
+    config.get_value("recorder.volume"),
+    Err(ConfigError::UnknownKey(_))
+  ));
+  assert!(matches!(
+    config.get_value("whisper"),
+    Err(ConfigError::UnknownKey(_))
+  ));
+}
+
+#[test]
+fn test_set_value() {
+  let mut config = Config::default();
+  config.set_value("recorder.silence_limit", "3").unwrap();
+  assert_eq!(config.get_silence_limit(), 3);
+
+  config
+    .set_value("whisper.url", "http://localhost:8080")
+    .unwrap();
+  assert_eq!(config.get_whisper_url(), "http://localhost:8080");
+
+  config
+    .set_value("general.remove_after_transcript", "false")
+    .unwrap();
+  assert!(!config.get_remove_after_transcript());
+
+  config.set_value("usage.cost_per_minute", "1").unwrap();
+  assert_eq!(config.get_usage_cost_per_minute(), 1.0);
+
+  config.set_value("whisper.decoding.beam_size", "5").unwrap();
+  assert_eq!(config.get_decoding().beam_size, Some(5));
+}
+
+#[test]
+fn test_set_numeric_text() {
+  let mut config = Config::default();
+  config
+    .set_value("recorder.preferred_audio_input_device", "2")
+    .unwrap();
+  assert_eq!(config.get_preferred_audio_input_device(), "2");
+}
+
+#[test]
+fn test_set_invalid_value() {
+  let mut config = Config::default();
+  assert!(matches!(
+    config.set_value("recorder.silence_limit", "long"),
+    Err(ConfigError::InvalidValue(..))
+  ));
+  assert_eq!(config.get_silence_limit(), 2);
+}
+
+#[test]
+fn test_set_unknown_value() {
+  let mut config = Config::default();
+  assert!(matches!(
+    config.set_value("recorder.volume", "3"),
+    Err(ConfigError::UnknownKey(_))
+  ));
+  assert!(matches!(
+    config.set_value("recorder..silence_limit", "3"),
+    Err(ConfigError::UnknownKey(_))
+  ));
+}
+
+#[tokio::test]
+async fn test_set_value_round_trip() {
+  let temp_dir = std::env::temp_dir().join("test_set_value_round_trip");
+  let _ = std::fs::create_dir_all(&temp_dir);
+  let config_path = temp_dir.join("config.toml");
+
+  let mut config = Config::default();
+  config.set_value("recorder.silence_limit", "7").unwrap();
+  Config::save_to_path(config, config_path.clone())
+    .await
+    .unwrap();
+
+  let config = Config::load_from_path(config_path).await.unwrap();
+  assert_eq!(config.get_value("recorder.silence_limit").unwrap(), "7");
+
+  let _ = std::fs::remove_dir_all(&temp_dir);
+}
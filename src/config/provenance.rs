@@ -0,0 +1,204 @@
+use toml::Value;
+
+use crate::config::Config;
+use crate::config::errors::{ConfigError, ConfigResult};
+use crate::config::keys::{lookup, to_value};
+use crate::output::format::OutputFormat;
+use crate::tr;
+
+/// Keys whose whole value is a credential.
+const SECRET_KEYS: [&str; 4] = [
+  "chat.webhook_url",
+  "llm.api_key",
+  "obs.password",
+  "translation.deepl_api_key",
+];
+
+/// Replacement shown instead of a secret.
+const MASK: &str = "***";
+
+/// Where an effective configuration value comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueSource {
+  /// Built-in default value
+  Default,
+  /// The configuration file
+  File,
+  /// A `LUMINE_*` environment variable
+  Environment,
+  /// A quality preset selected with `--mode`
+  Mode,
+  /// A command-line flag
+  Flag,
+}
+
+impl ValueSource {
+  fn label(&self) -> String {
+    return match self {
+      ValueSource::Default => tr!("config-source-default"),
+      ValueSource::File => tr!("config-source-file"),
+      ValueSource::Environment => tr!("config-source-environment"),
+      ValueSource::Mode => tr!("config-source-mode"),
+      ValueSource::Flag => tr!("config-source-flag"),
+    };
+  }
+}
+
+/// An effective configuration value and where it comes from.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedValue {
+  /// Dotted key of the value
+  pub key: String,
+  /// The value as written in the configuration file
+  pub value: String,
+  /// Where the value comes from
+  pub source: ValueSource,
+}
+
+/// Snapshots of the configuration as each source is applied.
+///
+/// Record a snapshot after loading the configuration file and after
+/// applying each later source. A value is attributed to the last source
+/// that changed it.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+  layers: Vec<(ValueSource, Value)>,
+}
+
+impl ConfigSources {
+  /// Creates an empty set of snapshots.
+  ///
+  /// # Returns
+  ///
+  /// A new `ConfigSources` instance.
+  pub fn new() -> Self {
+    return ConfigSources::default();
+  }
+
+  /// Records the configuration after a source has been applied.
+  ///
+  /// # Arguments
+  ///
+  /// * `source` - The source that was applied last
+  /// * `config` - The configuration with the source applied
+  pub fn record(&mut self, source: ValueSource, config: &Config) {
+    if let Ok(value) = to_value(config) {
+      self.layers.push((source, value));
+    }
+  }
+
+  /// Resolves every effective value and its source.
+  ///
+  /// Credentials, and the user information of URLs, are masked so that the
+  /// output can be shared.
+  ///
+  /// # Returns
+  ///
+  /// A `ConfigResult<Vec<ResolvedValue>>` containing the values sorted by
+  /// section or an error.
+  pub fn resolve(&self) -> ConfigResult<Vec<ResolvedValue>> {
+    let defaults = to_value(&Config::default())?;
+    let mut keys = Vec::new();
+    collect_keys(&defaults, "", &mut keys);
+    for (_, layer) in &self.layers {
+      collect_keys(layer, "", &mut keys);
+    }
+
+    let mut values = Vec::new();
+    for key in keys {
+      let path: Vec<&str> = key.split('.').collect();
+      let mut source = ValueSource::Default;
+      let mut previous = None;
+      for (layer_source, layer) in &self.layers {
+        let current = lookup(layer, &path);
+        if current.is_some() && current != previous {
+          source = *layer_source;
+        }
+        previous = current;
+      }
+      let Some(value) = previous.or_else(|| lookup(&defaults, &path)) else {
+        continue;
+      };
+      let value = mask_secret(&key, value);
+      values.push(ResolvedValue { key, value, source });
+    }
+    return Ok(values);
+  }
+
+  /// Formats the effective configuration with the source of each value.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The desired output format
+  ///
+  /// # Returns
+  ///
+  /// A `ConfigResult<String>` containing the formatted configuration or an
+  /// error.
+  pub fn format(&self, format: OutputFormat) -> ConfigResult<String> {
+    let values = self.resolve()?;
+    if matches!(format, OutputFormat::Json | OutputFormat::FullJson) {
+      return serde_json::to_string_pretty(&values)
+        .map_err(|e| ConfigError::Parse(e.to_string()));
+    }
+    let lines = values
+      .iter()
+      .map(|resolved| {
+        return format!(
+          "{} = {}  # {}",
+          resolved.key,
+          resolved.value,
+          resolved.source.label()
+        );
+      })
+      .collect::<Vec<String>>();
+    return Ok(lines.join("\n"));
+  }
+}
+
+fn mask_secret(key: &str, value: &Value) -> String {
+  if SECRET_KEYS.contains(&key) {
+    return Value::String(MASK.to_string()).to_string();
+  }
+  if let Some(text) = value.as_str() {
+    return Value::String(mask_userinfo(text)).to_string();
+  }
+  return value.to_string();
+}
+
+fn mask_userinfo(text: &str) -> String {
+  let Some(scheme_end) = text.find("://") else {
+    return text.to_string();
+  };
+  let authority_start = scheme_end + 3;
+  let authority_end = text[authority_start..]
+    .find(['/', '?', '#'])
+    .map_or(text.len(), |end| authority_start + end);
+  let Some(at) = text[authority_start..authority_end].rfind('@') else {
+    return text.to_string();
+  };
+  return format!(
+    "{}{}{}",
+    &text[..authority_start],
+    MASK,
+    &text[authority_start + at..]
+  );
+}
+
+fn collect_keys(value: &Value, prefix: &str, keys: &mut Vec<String>) {
+  let Some(table) = value.as_table() else {
+    if !keys.iter().any(|key| key == prefix) {
+      keys.push(prefix.to_string());
+    }
+    return;
+  };
+  for (name, child) in table {
+    let key = if prefix.is_empty() {
+      name.clone()
+    } else {
+      format!("{}.{}", prefix, name)
+    };
+    collect_keys(child, &key, keys);
+  }
+}
@@ -0,0 +1,534 @@
+use std::io::{BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::stream::{DecryptorBE32, EncryptorBE32};
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::Sha256;
+
+use crate::encryption::errors::{EncryptionError, EncryptionResult};
+use crate::files::errors::FileError;
+use crate::files::operations;
+use crate::process::executor::ProcessExecutor;
+use crate::vlog;
+
+/// Extension appended to the names of encrypted files.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"LUMINE-ENC2\n";
+// Data sealed as a single message by earlier versions, still decrypted.
+const LEGACY_MAGIC: &[u8] = b"LUMINE-ENC1\n";
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+// The STREAM construction takes 5 of the 12 nonce bytes for the chunk
+// counter and the last chunk flag.
+const STREAM_NONCE_SIZE: usize = 7;
+const HEADER_SIZE: usize = MAGIC.len() + SALT_SIZE + STREAM_NONCE_SIZE;
+const CHUNK_SIZE: usize = 64 * 1024;
+const TAG_SIZE: usize = 16;
+const KEY_SIZE: usize = 32;
+// Unit tests derive many keys in debug builds, where full-strength PBKDF2
+// takes over a minute per test.
+pub(crate) const KDF_ROUNDS: u32 = if cfg!(test) { 1_000 } else { 600_000 };
+const PASSPHRASE_VARIABLE: &str = "LUMINE_PASSPHRASE";
+
+type Salt = [u8; SALT_SIZE];
+type DerivedKeys = Vec<(Salt, Key<Aes256Gcm>)>;
+
+/// Encrypts and decrypts data with a passphrase.
+///
+/// Encrypted data starts with a marker, followed by the salt of the key
+/// derivation and a nonce prefix. The data is sealed with AES-256-GCM in
+/// chunks of 64 KiB using the STREAM construction, so files of any length
+/// are encrypted and decrypted without holding them in memory, and
+/// reordered, dropped or truncated chunks are detected. Data sealed as a
+/// single message by earlier versions is still decrypted.
+///
+/// Deriving a key takes a noticeable moment, so derived keys are kept for
+/// the lifetime of the encryptor and its clones. Everything it encrypts
+/// shares one salt and key, told apart by a fresh nonce per message.
+#[derive(Clone)]
+pub struct Encryptor {
+  passphrase: String,
+  keys: Arc<Mutex<DerivedKeys>>,
+}
+
+impl std::fmt::Debug for Encryptor {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return f.write_str("Encryptor");
+  }
+}
+
+impl Encryptor {
+  /// Creates an encryptor for a passphrase.
+  ///
+  /// # Arguments
+  ///
+  /// * `passphrase` - The passphrase keys are derived from
+  ///
+  /// # Returns
+  ///
+  /// A new `Encryptor` instance.
+  pub fn new(passphrase: String) -> Self {
+    return Encryptor {
+      passphrase,
+      keys: Arc::new(Mutex::new(Vec::new())),
+    };
+  }
+
+  /// Resolves the passphrase and creates an encryptor.
+  ///
+  /// The `LUMINE_PASSPHRASE` environment variable takes precedence over the
+  /// key command, whose first output line is used as the passphrase. A key
+  /// command such as `secret-tool lookup service lumine` or
+  /// `security find-generic-password -s lumine -w` reads it from the system
+  /// keyring.
+  ///
+  /// # Arguments
+  ///
+  /// * `key_command` - Shell command printing the passphrase (empty for none)
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<Option<Encryptor>>` containing the encryptor,
+  /// `None` if no passphrase is configured, or an error if the key command
+  /// failed.
+  pub async fn from_environment(
+    key_command: &str,
+  ) -> EncryptionResult<Option<Encryptor>> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_VARIABLE)
+      && !passphrase.is_empty()
+    {
+      return Ok(Some(Encryptor::new(passphrase)));
+    }
+    if key_command.is_empty() {
+      return Ok(None);
+    }
+
+    vlog!("Reading passphrase with key command");
+    let output = ProcessExecutor::run("sh", &["-c", key_command])
+      .await
+      .map_err(|e| EncryptionError::KeyCommand(e.to_string()))?;
+    if !output.status.success() {
+      return Err(EncryptionError::KeyCommand(
+        output.stderr.trim().to_string(),
+      ));
+    }
+    let passphrase = output.stdout.lines().next().unwrap_or_default();
+    if passphrase.is_empty() {
+      return Err(EncryptionError::NoPassphrase);
+    }
+    return Ok(Some(Encryptor::new(passphrase.to_string())));
+  }
+
+  /// Encrypts data.
+  ///
+  /// # Arguments
+  ///
+  /// * `plaintext` - The data to encrypt
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<Vec<u8>>` containing the encrypted data.
+  pub fn encrypt(&self, plaintext: &[u8]) -> EncryptionResult<Vec<u8>> {
+    let chunks = plaintext.len() / CHUNK_SIZE + 1;
+    let mut data =
+      Vec::with_capacity(HEADER_SIZE + plaintext.len() + chunks * TAG_SIZE);
+    self.encrypt_stream(&mut &plaintext[..], &mut data)?;
+    return Ok(data);
+  }
+
+  /// Decrypts data written by [`Encryptor::encrypt`].
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The encrypted data
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<Vec<u8>>` containing the plaintext or an error if
+  /// the passphrase is wrong or the data was changed.
+  pub fn decrypt(&self, data: &[u8]) -> EncryptionResult<Vec<u8>> {
+    let mut plaintext = Vec::with_capacity(data.len());
+    self.decrypt_stream(&mut &data[..], &mut plaintext)?;
+    return Ok(plaintext);
+  }
+
+  /// Encrypts data on a blocking thread.
+  ///
+  /// Deriving the key can take a noticeable moment, so async callers use
+  /// this instead of [`Encryptor::encrypt`] to keep the runtime responsive.
+  ///
+  /// # Arguments
+  ///
+  /// * `plaintext` - The data to encrypt
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<Vec<u8>>` containing the encrypted data.
+  pub async fn encrypt_async(
+    &self,
+    plaintext: Vec<u8>,
+  ) -> EncryptionResult<Vec<u8>> {
+    let encryptor = self.clone();
+    return run_blocking(move || encryptor.encrypt(&plaintext)).await;
+  }
+
+  /// Decrypts data on a blocking thread.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The encrypted data
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<Vec<u8>>` containing the plaintext or an error if
+  /// the passphrase is wrong or the data was changed.
+  pub async fn decrypt_async(
+    &self,
+    data: Vec<u8>,
+  ) -> EncryptionResult<Vec<u8>> {
+    let encryptor = self.clone();
+    return run_blocking(move || encryptor.decrypt(&data)).await;
+  }
+
+  /// Encrypts a file and removes the plaintext.
+  ///
+  /// The file is encrypted chunk by chunk, so long recordings are never
+  /// held in memory. The plaintext is overwritten before it is removed,
+  /// whether or not secure delete is enabled.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the file to encrypt
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<String>` containing the path of the encrypted
+  /// file, the original path with `.enc` appended.
+  pub async fn encrypt_file(
+    &self,
+    file_path: &str,
+  ) -> EncryptionResult<String> {
+    let encrypted_path = encrypted_name(file_path);
+    let partial_path = stage(&encrypted_path).await?;
+    let encryptor = self.clone();
+    let input = file_path.to_string();
+    let output = partial_path.clone();
+    let written = run_blocking(move || {
+      let mut reader = std::fs::File::open(&input)
+        .map_err(|e| FileError::FileRead(input.clone(), e))?;
+      let mut writer =
+        BufWriter::new(operations::open_private_file(&output, true)?);
+      return encryptor.encrypt_stream(&mut reader, &mut writer);
+    })
+    .await;
+    publish(&partial_path, &encrypted_path, written).await?;
+    operations::shred_file(file_path).await?;
+    vlog!("Encrypted {} → {}", file_path, encrypted_path);
+    return Ok(encrypted_path);
+  }
+
+  /// Encrypts data into the encrypted file of a path, without writing the
+  /// plaintext to disk.
+  ///
+  /// The data is written to a private, uniquely named intermediate file
+  /// first and then moved over the encrypted file, so an interrupted write
+  /// keeps the previous version and concurrent writes do not mix.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path of the plaintext file, which is not created
+  /// * `plaintext` - The data to encrypt
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<String>` containing the path of the encrypted
+  /// file, the given path with `.enc` appended.
+  pub async fn write_file(
+    &self,
+    file_path: &str,
+    plaintext: Vec<u8>,
+  ) -> EncryptionResult<String> {
+    let data = self.encrypt_async(plaintext).await?;
+    let encrypted_path = encrypted_name(file_path);
+    let partial_path = stage(&encrypted_path).await?;
+    let written = operations::write_private_file(&partial_path, &data, true)
+      .await
+      .map_err(EncryptionError::from);
+    publish(&partial_path, &encrypted_path, written).await?;
+    return Ok(encrypted_path);
+  }
+
+  /// Decrypts a file into another file.
+  ///
+  /// The file is decrypted chunk by chunk into a file only the current user
+  /// can read, which is removed again if decryption fails.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - Path to the encrypted file
+  /// * `output_path` - Path the plaintext is written to
+  /// * `overwrite` - Whether an existing file at `output_path` may be
+  ///   replaced
+  ///
+  /// # Returns
+  ///
+  /// An `EncryptionResult<()>` indicating success or failure.
+  pub async fn decrypt_file(
+    &self,
+    file_path: &str,
+    output_path: &str,
+    overwrite: bool,
+  ) -> EncryptionResult<()> {
+    let encryptor = self.clone();
+    let input = file_path.to_string();
+    let output = output_path.to_string();
+    run_blocking(move || {
+      let mut reader = std::fs::File::open(&input)
+        .map_err(|e| FileError::FileRead(input.clone(), e))?;
+      let mut writer =
+        BufWriter::new(operations::open_private_file(&output, overwrite)?);
+      let decrypted = encryptor.decrypt_stream(&mut reader, &mut writer);
+      if decrypted.is_err() {
+        let _ = std::fs::remove_file(&output);
+      }
+      return decrypted;
+    })
+    .await?;
+    vlog!("Decrypted {} → {}", file_path, output_path);
+    return Ok(());
+  }
+
+  /// Encrypts everything a reader returns into a writer.
+  fn encrypt_stream(
+    &self,
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+  ) -> EncryptionResult<()> {
+    let (salt, cipher) = self.encryption_cipher();
+    let mut nonce = [0u8; STREAM_NONCE_SIZE];
+    OsRng.fill_bytes(&mut nonce);
+    let mut stream =
+      EncryptorBE32::from_aead(cipher, GenericArray::from_slice(&nonce));
+    write_all(writer, MAGIC)?;
+    write_all(writer, &salt)?;
+    write_all(writer, &nonce)?;
+
+    // Every chunk but the last is full, so a full chunk followed by the end
+    // of the data is sealed as an empty last chunk.
+    let mut chunk = vec![0u8; CHUNK_SIZE];
+    loop {
+      let length = read_chunk(reader, &mut chunk)?;
+      if length < CHUNK_SIZE {
+        let sealed = stream
+          .encrypt_last(&chunk[..length])
+          .map_err(|_| EncryptionError::Encrypt)?;
+        write_all(writer, &sealed)?;
+        return writer.flush().map_err(EncryptionError::Write);
+      }
+      let sealed = stream
+        .encrypt_next(chunk.as_slice())
+        .map_err(|_| EncryptionError::Encrypt)?;
+      write_all(writer, &sealed)?;
+    }
+  }
+
+  /// Decrypts the encrypted data a reader returns into a writer.
+  fn decrypt_stream(
+    &self,
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+  ) -> EncryptionResult<()> {
+    let mut magic = [0u8; MAGIC.len()];
+    read_header(reader, &mut magic)?;
+    if magic == LEGACY_MAGIC {
+      let mut body = Vec::new();
+      reader
+        .read_to_end(&mut body)
+        .map_err(EncryptionError::Read)?;
+      write_all(writer, &self.decrypt_legacy(&body)?)?;
+      return writer.flush().map_err(EncryptionError::Write);
+    }
+    if magic != MAGIC {
+      return Err(EncryptionError::Decrypt);
+    }
+
+    let mut salt = [0u8; SALT_SIZE];
+    read_header(reader, &mut salt)?;
+    let mut nonce = [0u8; STREAM_NONCE_SIZE];
+    read_header(reader, &mut nonce)?;
+    let mut stream = DecryptorBE32::from_aead(
+      self.cipher(&salt),
+      GenericArray::from_slice(&nonce),
+    );
+
+    let mut chunk = vec![0u8; CHUNK_SIZE + TAG_SIZE];
+    loop {
+      let length = read_chunk(reader, &mut chunk)?;
+      if length < chunk.len() {
+        let plaintext = stream
+          .decrypt_last(&chunk[..length])
+          .map_err(|_| EncryptionError::Decrypt)?;
+        write_all(writer, &plaintext)?;
+        return writer.flush().map_err(EncryptionError::Write);
+      }
+      let plaintext = stream
+        .decrypt_next(chunk.as_slice())
+        .map_err(|_| EncryptionError::Decrypt)?;
+      write_all(writer, &plaintext)?;
+    }
+  }
+
+  /// Decrypts data sealed as a single message, after its marker.
+  fn decrypt_legacy(&self, body: &[u8]) -> EncryptionResult<Vec<u8>> {
+    if body.len() < SALT_SIZE + NONCE_SIZE {
+      return Err(EncryptionError::Decrypt);
+    }
+    let (salt, body) = body.split_at(SALT_SIZE);
+    let (nonce, ciphertext) = body.split_at(NONCE_SIZE);
+    let salt = Salt::try_from(salt).map_err(|_| EncryptionError::Decrypt)?;
+    return self
+      .cipher(&salt)
+      .decrypt(Nonce::from_slice(nonce), ciphertext)
+      .map_err(|_| EncryptionError::Decrypt);
+  }
+
+  /// Gets the salt and cipher used for encrypting.
+  ///
+  /// Reuses the first salt seen, so that data decrypted and written back,
+  /// such as the state file, needs no further key derivation.
+  fn encryption_cipher(&self) -> (Salt, Aes256Gcm) {
+    let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((salt, key)) = keys.first() {
+      return (*salt, Aes256Gcm::new(key));
+    }
+
+    let mut salt = [0u8; SALT_SIZE];
+    OsRng.fill_bytes(&mut salt);
+    let key = self.derive_key(&salt);
+    keys.push((salt, key));
+    return (salt, Aes256Gcm::new(&key));
+  }
+
+  fn cipher(&self, salt: &Salt) -> Aes256Gcm {
+    let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, key)) = keys.iter().find(|(known, _)| known == salt) {
+      return Aes256Gcm::new(key);
+    }
+
+    let key = self.derive_key(salt);
+    keys.push((*salt, key));
+    return Aes256Gcm::new(&key);
+  }
+
+  fn derive_key(&self, salt: &Salt) -> Key<Aes256Gcm> {
+    let mut key = [0u8; KEY_SIZE];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+      self.passphrase.as_bytes(),
+      salt,
+      KDF_ROUNDS,
+      &mut key,
+    );
+    return *Key::<Aes256Gcm>::from_slice(&key);
+  }
+}
+
+/// Checks whether data was written by an [`Encryptor`].
+///
+/// # Arguments
+///
+/// * `data` - The data, or at least its first bytes
+///
+/// # Returns
+///
+/// A `bool` indicating whether the data is encrypted.
+pub fn is_encrypted(data: &[u8]) -> bool {
+  return data.starts_with(MAGIC) || data.starts_with(LEGACY_MAGIC);
+}
+
+fn encrypted_name(file_path: &str) -> String {
+  return format!("{}.{}", file_path, ENCRYPTED_EXTENSION);
+}
+
+/// Creates the private, uniquely named intermediate file an encrypted file
+/// is written to before it is moved into place.
+async fn stage(encrypted_path: &str) -> EncryptionResult<String> {
+  let path = Path::new(encrypted_path);
+  let directory = match path.parent() {
+    Some(parent) if !parent.as_os_str().is_empty() => {
+      parent.to_string_lossy().to_string()
+    }
+    _ => String::from("."),
+  };
+  let name = path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .unwrap_or_default();
+  return Ok(operations::create_private_file(&directory, &name).await?);
+}
+
+/// Moves a written intermediate file over the encrypted file, or removes
+/// it if writing failed.
+async fn publish(
+  partial_path: &str,
+  encrypted_path: &str,
+  written: EncryptionResult<()>,
+) -> EncryptionResult<()> {
+  let published = match written {
+    Ok(()) => operations::move_file(partial_path, encrypted_path)
+      .await
+      .map_err(EncryptionError::from),
+    Err(e) => Err(e),
+  };
+  if published.is_err() {
+    let _ = operations::remove_file(partial_path).await;
+  }
+  return published;
+}
+
+/// Reads until the buffer is full or the data ends.
+fn read_chunk(
+  reader: &mut impl Read,
+  buffer: &mut [u8],
+) -> EncryptionResult<usize> {
+  let mut length = 0;
+  while length < buffer.len() {
+    match reader.read(&mut buffer[length..]) {
+      Ok(0) => break,
+      Ok(read) => length += read,
+      Err(e) if e.kind() == ErrorKind::Interrupted => {}
+      Err(e) => return Err(EncryptionError::Read(e)),
+    }
+  }
+  return Ok(length);
+}
+
+/// Reads a part of the header, which is missing from data that is not
+/// encrypted.
+fn read_header(
+  reader: &mut impl Read,
+  buffer: &mut [u8],
+) -> EncryptionResult<()> {
+  return reader.read_exact(buffer).map_err(|e| {
+    if e.kind() == ErrorKind::UnexpectedEof {
+      return EncryptionError::Decrypt;
+    }
+    return EncryptionError::Read(e);
+  });
+}
+
+fn write_all(writer: &mut impl Write, data: &[u8]) -> EncryptionResult<()> {
+  return writer.write_all(data).map_err(EncryptionError::Write);
+}
+
+async fn run_blocking<T: Send + 'static>(
+  operation: impl FnOnce() -> EncryptionResult<T> + Send + 'static,
+) -> EncryptionResult<T> {
+  return tokio::task::spawn_blocking(operation)
+    .await
+    .map_err(EncryptionError::Interrupted)?;
+}
@@ -0,0 +1,272 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::Sha256;
+
+use crate::encryption::encryptor::{Encryptor, KDF_ROUNDS, is_encrypted};
+use crate::encryption::errors::EncryptionError;
+
+/// Length of the marker and salt at the start of encrypted data.
+const SALT_END: usize = 28;
+/// Length of the marker, salt and nonce prefix of encrypted data.
+const HEADER_END: usize = 35;
+const CHUNK_SIZE: usize = 64 * 1024;
+const TAG_SIZE: usize = 16;
+
+fn sample(length: usize) -> Vec<u8> {
+  return (0..length).map(|index| (index % 251) as u8).collect();
+}
+
+#[test]
+fn test_encrypt_and_decrypt() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let data = encryptor
+    .encrypt(b"Patient reports mild headache.")
+    .unwrap();
+
+  assert!(is_encrypted(&data));
+  assert!(!data.windows(7).any(|window| window == b"Patient"));
+  assert_eq!(
+    encryptor.decrypt(&data).unwrap(),
+    b"Patient reports mild headache."
+  );
+}
+
+#[test]
+fn test_encrypt_and_decrypt_chunks() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  for length in [
+    0,
+    1,
+    CHUNK_SIZE - 1,
+    CHUNK_SIZE,
+    CHUNK_SIZE + 1,
+    3 * CHUNK_SIZE + 7,
+  ] {
+    let plaintext = sample(length);
+    let data = encryptor.encrypt(&plaintext).unwrap();
+    let chunks = length / CHUNK_SIZE + 1;
+    assert_eq!(data.len(), HEADER_END + length + chunks * TAG_SIZE);
+    assert_eq!(encryptor.decrypt(&data).unwrap(), plaintext);
+  }
+}
+
+#[test]
+fn test_decrypt_detects_truncated_chunks() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let data = encryptor.encrypt(&sample(2 * CHUNK_SIZE + 10)).unwrap();
+
+  let first_chunk = HEADER_END + CHUNK_SIZE + TAG_SIZE;
+  for length in [first_chunk, first_chunk * 2 - HEADER_END, data.len() - 1] {
+    let result = encryptor.decrypt(&data[..length]);
+    assert!(matches!(result, Err(EncryptionError::Decrypt)));
+  }
+}
+
+#[test]
+fn test_decrypt_single_message_data() {
+  let salt = [7u8; 16];
+  let mut key = [0u8; 32];
+  pbkdf2::pbkdf2_hmac::<Sha256>(b"correct horse", &salt, KDF_ROUNDS, &mut key);
+  let nonce = [9u8; 12];
+  let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+    .encrypt(Nonce::from_slice(&nonce), b"old state".as_slice())
+    .unwrap();
+  let mut data = b"LUMINE-ENC1\n".to_vec();
+  data.extend_from_slice(&salt);
+  data.extend_from_slice(&nonce);
+  data.extend_from_slice(&ciphertext);
+
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  assert!(is_encrypted(&data));
+  assert_eq!(encryptor.decrypt(&data).unwrap(), b"old state");
+}
+
+#[test]
+fn test_encrypt_uses_fresh_nonce() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let first = encryptor.encrypt(b"same").unwrap();
+  let second = encryptor.encrypt(b"same").unwrap();
+  assert_ne!(first, second);
+}
+
+#[test]
+fn test_encrypt_reuses_salt() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let first = encryptor.encrypt(b"same").unwrap();
+  let second = encryptor.clone().encrypt(b"same").unwrap();
+  assert_eq!(first[..SALT_END], second[..SALT_END]);
+
+  let other = Encryptor::new(String::from("correct horse"))
+    .encrypt(b"same")
+    .unwrap();
+  assert_ne!(first[..SALT_END], other[..SALT_END]);
+}
+
+#[test]
+fn test_encrypt_reuses_salt_of_decrypted_data() {
+  let data = Encryptor::new(String::from("correct horse"))
+    .encrypt(b"state")
+    .unwrap();
+
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  assert_eq!(encryptor.decrypt(&data).unwrap(), b"state");
+  let written = encryptor.encrypt(b"state").unwrap();
+  assert_eq!(written[..SALT_END], data[..SALT_END]);
+}
+
+#[test]
+fn test_decrypt_with_wrong_passphrase() {
+  let data = Encryptor::new(String::from("correct horse"))
+    .encrypt(b"secret")
+    .unwrap();
+  let result = Encryptor::new(String::from("battery staple")).decrypt(&data);
+  assert!(matches!(result, Err(EncryptionError::Decrypt)));
+}
+
+#[test]
+fn test_decrypt_plaintext() {
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  assert!(!is_encrypted(b"last_audio_input_device = \"USB\""));
+  assert!(matches!(
+    encryptor.decrypt(b"plain"),
+    Err(EncryptionError::Decrypt)
+  ));
+}
+
+#[tokio::test]
+async fn test_encrypt_file_round_trip() {
+  use std::os::unix::fs::PermissionsExt;
+
+  let directory = std::env::temp_dir();
+  let file_path = directory.join("test_encrypt_file.txt");
+  let file_path = file_path.to_string_lossy().to_string();
+  let output_path = directory.join("test_encrypt_file_decrypted.txt");
+  let output_path = output_path.to_string_lossy().to_string();
+  let _ = std::fs::remove_file(&output_path);
+  std::fs::write(&file_path, "dictation").unwrap();
+
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let encrypted_path = encryptor.encrypt_file(&file_path).await.unwrap();
+  assert_eq!(encrypted_path, format!("{}.enc", file_path));
+  assert!(!std::path::Path::new(&file_path).exists());
+
+  encryptor
+    .decrypt_file(&encrypted_path, &output_path, false)
+    .await
+    .unwrap();
+  assert_eq!(std::fs::read_to_string(&output_path).unwrap(), "dictation");
+  let mode = std::fs::metadata(&output_path)
+    .unwrap()
+    .permissions()
+    .mode();
+  assert_eq!(mode & 0o777, 0o600);
+
+  let result = encryptor
+    .decrypt_file(&encrypted_path, &output_path, false)
+    .await;
+  assert!(matches!(result, Err(EncryptionError::File(..))));
+  encryptor
+    .decrypt_file(&encrypted_path, &output_path, true)
+    .await
+    .unwrap();
+
+  let _ = std::fs::remove_file(encrypted_path);
+  let _ = std::fs::remove_file(output_path);
+}
+
+#[tokio::test]
+async fn test_encrypt_file_in_chunks() {
+  let directory = std::env::temp_dir();
+  let file_path = directory.join("test_encrypt_file_chunks.wav");
+  let file_path = file_path.to_string_lossy().to_string();
+  let output_path = directory.join("test_encrypt_file_chunks_decrypted.wav");
+  let output_path = output_path.to_string_lossy().to_string();
+  let plaintext = sample(5 * CHUNK_SIZE + 3);
+  std::fs::write(&file_path, &plaintext).unwrap();
+
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let encrypted_path = encryptor.encrypt_file(&file_path).await.unwrap();
+  let data = std::fs::read(&encrypted_path).unwrap();
+  assert_eq!(encryptor.decrypt(&data).unwrap(), plaintext);
+
+  encryptor
+    .decrypt_file(&encrypted_path, &output_path, true)
+    .await
+    .unwrap();
+  assert_eq!(std::fs::read(&output_path).unwrap(), plaintext);
+
+  let _ = std::fs::remove_file(encrypted_path);
+  let _ = std::fs::remove_file(output_path);
+}
+
+#[tokio::test]
+async fn test_decrypt_file_removes_output_on_failure() {
+  let directory = std::env::temp_dir();
+  let file_path = directory.join("test_decrypt_damaged.wav.enc");
+  let file_path = file_path.to_string_lossy().to_string();
+  let output_path = directory.join("test_decrypt_damaged.wav");
+  let output_path = output_path.to_string_lossy().to_string();
+  let _ = std::fs::remove_file(&output_path);
+
+  let encryptor = Encryptor::new(String::from("correct horse"));
+  let mut data = encryptor.encrypt(&sample(2 * CHUNK_SIZE)).unwrap();
+  let last = data.len() - 1;
+  data[last] ^= 1;
+  std::fs::write(&file_path, &data).unwrap();
+
+  let result = encryptor
+    .decrypt_file(&file_path, &output_path, false)
+    .await;
+  assert!(matches!(result, Err(EncryptionError::Decrypt)));
+  assert!(!std::path::Path::new(&output_path).exists());
+
+  let _ = std::fs::remove_file(file_path);
+}
+
+#[tokio::test]
+async fn test_write_file_keeps_plaintext_off_disk() {
+  let directory = std::env::temp_dir();
+  let file_path = directory.join("test_write_encrypted_notes.md");
+  let file_path = file_path.to_string_lossy().to_string();
+  let encryptor = Encryptor::new(String::from("correct horse"));
+
+  encryptor
+    .write_file(&file_path, b"first note".to_vec())
+    .await
+    .unwrap();
+  let encrypted_path = encryptor
+    .write_file(&file_path, b"first note, second note".to_vec())
+    .await
+    .unwrap();
+
+  assert_eq!(encrypted_path, format!("{}.enc", file_path));
+  assert!(!std::path::Path::new(&file_path).exists());
+  let data = std::fs::read(&encrypted_path).unwrap();
+  assert_eq!(
+    encryptor.decrypt(&data).unwrap(),
+    b"first note, second note"
+  );
+
+  let _ = std::fs::remove_file(encrypted_path);
+}
+
+#[tokio::test]
+async fn test_concurrent_write_file() {
+  let directory = std::env::temp_dir();
+  let file_path = directory.join("test_concurrent_encrypted_notes.md");
+  let file_path = file_path.to_string_lossy().to_string();
+  let encryptor = Encryptor::new(String::from("correct horse"));
+
+  let (first, second) = tokio::join!(
+    encryptor.write_file(&file_path, b"first".to_vec()),
+    encryptor.write_file(&file_path, b"second".to_vec()),
+  );
+
+  let encrypted_path = first.unwrap();
+  assert_eq!(second.unwrap(), encrypted_path);
+  let data = std::fs::read(&encrypted_path).unwrap();
+  let plaintext = encryptor.decrypt(&data).unwrap();
+  assert!(plaintext == b"first" || plaintext == b"second");
+
+  let _ = std::fs::remove_file(encrypted_path);
+}
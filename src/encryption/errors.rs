@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+use crate::files::errors::FileError;
+
+/// Encryption-related errors.
+///
+/// Represents errors that can occur while resolving the passphrase or
+/// encrypting and decrypting data.
+#[derive(Error, Debug)]
+pub enum EncryptionError {
+  #[error(
+    "No passphrase available. Please set LUMINE_PASSPHRASE or `key_command` in the [encryption] section."
+  )]
+  NoPassphrase,
+
+  #[error("Failed to get the passphrase from the key command: {0}")]
+  KeyCommand(String),
+
+  #[error("Failed to encrypt data.")]
+  Encrypt,
+
+  #[error(
+    "Failed to decrypt data. The passphrase may be wrong or the file damaged."
+  )]
+  Decrypt,
+
+  #[error("Failed to read the data to encrypt or decrypt.")]
+  Read(#[source] std::io::Error),
+
+  #[error("Failed to write the encrypted or decrypted data.")]
+  Write(#[source] std::io::Error),
+
+  #[error(transparent)]
+  File(#[from] FileError),
+
+  #[error("Encryption was interrupted.")]
+  Interrupted(#[source] tokio::task::JoinError),
+}
+
+/// Result type for encryption operations.
+pub type EncryptionResult<T> = Result<T, EncryptionError>;
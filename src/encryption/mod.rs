@@ -0,0 +1,23 @@
+//! Encryption of recordings, transcripts and state at rest.
+//!
+//! Files are encrypted in chunks with AES-256-GCM using a key derived from a
+//! passphrase with PBKDF2-HMAC-SHA256 and a random salt. The key is derived
+//! once per run and shared by the files written in it. The passphrase comes
+//! from the `LUMINE_PASSPHRASE` environment variable or from a command such
+//! as a keyring lookup.
+//!
+//! ## Main Components
+//!
+//! - [`Encryptor`]: Encrypts and decrypts data and files with a passphrase
+//! - [`is_encrypted`]: Recognizes data written by the encryptor
+//! - [`EncryptionError`]: Error types for encryption operations
+//! - [`EncryptionResult<T>`]: Result type alias for encryption operations
+
+pub mod encryptor;
+pub mod errors;
+
+#[cfg(test)]
+mod encryptor_tests;
+
+pub use encryptor::{ENCRYPTED_EXTENSION, Encryptor, is_encrypted};
+pub use errors::EncryptionError;
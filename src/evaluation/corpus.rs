@@ -0,0 +1,163 @@
+//! Accuracy evaluation over a corpus of recordings.
+//!
+//! A corpus is a directory of audio files, each with a reference transcript
+//! next to it that has the same name and a `.txt` extension.
+
+use std::path::Path;
+
+use crate::evaluation::Evaluation;
+
+const REFERENCE_EXTENSION: &str = "txt";
+
+/// An audio file of a corpus and its reference transcript.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorpusPair {
+  /// Path to the audio file
+  pub audio: String,
+  /// Path to the reference transcript
+  pub reference: String,
+}
+
+/// Evaluation of a single corpus file.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CorpusEntry {
+  /// Path to the audio file
+  pub file: String,
+  /// Evaluation of the file's transcript
+  #[serde(flatten)]
+  pub evaluation: Evaluation,
+}
+
+/// Evaluation of a whole corpus.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CorpusReport {
+  /// Word error rate over all files
+  pub wer: f64,
+  /// Character error rate over all files
+  pub cer: f64,
+  /// Evaluations of the individual files
+  pub files: Vec<CorpusEntry>,
+}
+
+/// Finds the audio files that have a reference transcript.
+///
+/// # Arguments
+///
+/// * `files` - Paths of the files in the corpus directory
+///
+/// # Returns
+///
+/// A `Vec<CorpusPair>` with one pair per audio file with a reference.
+pub fn find_pairs(files: &[String]) -> Vec<CorpusPair> {
+  return files
+    .iter()
+    .filter(|file| !is_reference(file))
+    .filter_map(|audio| {
+      let reference = Path::new(audio)
+        .with_extension(REFERENCE_EXTENSION)
+        .to_string_lossy()
+        .to_string();
+      if !files.contains(&reference) {
+        return None;
+      }
+      return Some(CorpusPair {
+        audio: audio.clone(),
+        reference,
+      });
+    })
+    .collect();
+}
+
+fn is_reference(file: &str) -> bool {
+  return Path::new(file)
+    .extension()
+    .is_some_and(|ext| ext.eq_ignore_ascii_case(REFERENCE_EXTENSION));
+}
+
+impl CorpusReport {
+  /// Aggregates file evaluations into a corpus report.
+  ///
+  /// Overall error rates weigh each file by the length of its reference.
+  ///
+  /// # Arguments
+  ///
+  /// * `files` - Evaluations of the individual files
+  ///
+  /// # Returns
+  ///
+  /// A new `CorpusReport`.
+  pub fn new(files: Vec<CorpusEntry>) -> Self {
+    let sum = |value: fn(&Evaluation) -> usize| {
+      return files
+        .iter()
+        .map(|entry| value(&entry.evaluation))
+        .sum::<usize>();
+    };
+    let word_errors = sum(|e| e.substitutions + e.insertions + e.deletions);
+    let words = sum(|e| e.reference_words);
+    let character_errors = sum(|e| e.character_errors);
+    let characters = sum(|e| e.reference_characters);
+
+    return CorpusReport {
+      wer: ratio(word_errors, words),
+      cer: ratio(character_errors, characters),
+      files,
+    };
+  }
+
+  /// Formats the report as CSV with one row per file and an overall row.
+  ///
+  /// # Returns
+  ///
+  /// A `String` containing the CSV report.
+  pub fn to_csv(&self) -> String {
+    let mut csv = String::from(
+      "file,wer,cer,reference_words,substitutions,insertions,deletions",
+    );
+    for entry in &self.files {
+      let evaluation = &entry.evaluation;
+      csv.push_str(&format!(
+        "\n{},{:.4},{:.4},{},{},{},{}",
+        escape_csv(&entry.file),
+        evaluation.wer,
+        evaluation.cer,
+        evaluation.reference_words,
+        evaluation.substitutions,
+        evaluation.insertions,
+        evaluation.deletions
+      ));
+    }
+
+    let total = |value: fn(&Evaluation) -> usize| {
+      return self
+        .files
+        .iter()
+        .map(|entry| value(&entry.evaluation))
+        .sum::<usize>();
+    };
+    csv.push_str(&format!(
+      "\noverall,{:.4},{:.4},{},{},{},{}",
+      self.wer,
+      self.cer,
+      total(|e| e.reference_words),
+      total(|e| e.substitutions),
+      total(|e| e.insertions),
+      total(|e| e.deletions)
+    ));
+    return csv;
+  }
+}
+
+fn ratio(errors: usize, total: usize) -> f64 {
+  if total == 0 {
+    return 0.0;
+  }
+  return errors as f64 / total as f64;
+}
+
+fn escape_csv(value: &str) -> String {
+  if value.contains([',', '"', '\n']) {
+    return format!("\"{}\"", value.replace('"', "\"\""));
+  }
+  return value.to_string();
+}
@@ -0,0 +1,49 @@
+use crate::evaluation::corpus::{
+  CorpusEntry, CorpusPair, CorpusReport, find_pairs,
+};
+use crate::evaluation::{Evaluation, Normalization};
+
+#[test]
+fn test_find_pairs() {
+  let files = vec![
+    String::from("corpus/a.wav"),
+    String::from("corpus/a.txt"),
+    String::from("corpus/b.mp3"),
+    String::from("corpus/notes.txt"),
+  ];
+  assert_eq!(
+    find_pairs(&files),
+    vec![CorpusPair {
+      audio: String::from("corpus/a.wav"),
+      reference: String::from("corpus/a.txt"),
+    }]
+  );
+}
+
+#[test]
+fn test_corpus_report_weighs_files_by_length() {
+  let normalization = Normalization::default();
+  let report = CorpusReport::new(vec![
+    CorpusEntry {
+      file: String::from("a.wav"),
+      evaluation: Evaluation::new("ask not", "ask", normalization),
+    },
+    CorpusEntry {
+      file: String::from("b, c.wav"),
+      evaluation: Evaluation::new(
+        "my fellow americans",
+        "my fellow americans",
+        normalization,
+      ),
+    },
+  ]);
+
+  assert_eq!(report.wer, 0.2);
+  assert_eq!(
+    report.to_csv(),
+    "file,wer,cer,reference_words,substitutions,insertions,deletions\n\
+     a.wav,0.5000,0.5714,2,0,0,1\n\
+     \"b, c.wav\",0.0000,0.0000,3,0,0,0\n\
+     overall,0.2000,0.1538,5,0,0,1"
+  );
+}
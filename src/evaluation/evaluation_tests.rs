@@ -0,0 +1,80 @@
+use crate::evaluation::{Edit, Evaluation, Normalization, normalize};
+
+#[test]
+fn test_normalize_ignores_case_and_punctuation() {
+  let text = " And so, my fellow Americans: ask not!";
+  assert_eq!(
+    normalize(text, Normalization::default()),
+    "and so my fellow americans ask not"
+  );
+}
+
+#[test]
+fn test_normalize_keeps_contractions() {
+  let text = "Don't say 'never'.";
+  assert_eq!(normalize(text, Normalization::default()), "don't say never");
+}
+
+#[test]
+fn test_normalize_keeping_case_and_punctuation() {
+  let normalization = Normalization {
+    keep_case: true,
+    keep_punctuation: true,
+  };
+  assert_eq!(normalize("  Ask   not! ", normalization), "Ask not!");
+}
+
+#[test]
+fn test_evaluation_of_identical_texts() {
+  let evaluation =
+    Evaluation::new("Ask not.", " ask not", Normalization::default());
+
+  assert_eq!(evaluation.wer, 0.0);
+  assert_eq!(evaluation.cer, 0.0);
+  assert_eq!(evaluation.reference_words, 2);
+}
+
+#[test]
+fn test_evaluation_counts_edits() {
+  let evaluation = Evaluation::new(
+    "and so my fellow americans ask not",
+    "and so my follow americans ask not what",
+    Normalization::default(),
+  );
+  assert_eq!(evaluation.substitutions, 1);
+  assert_eq!(evaluation.insertions, 1);
+  assert_eq!(evaluation.deletions, 0);
+  assert!((evaluation.wer - 2.0 / 7.0).abs() < 1e-9);
+
+  let evaluation = Evaluation::new(
+    "ask not what your country",
+    "ask what your country",
+    Normalization::default(),
+  );
+  assert_eq!(
+    evaluation.edits[1],
+    Edit::Deletion {
+      word: String::from("not")
+    }
+  );
+  assert_eq!(evaluation.wer, 0.2);
+}
+
+#[test]
+fn test_evaluation_report() {
+  let evaluation =
+    Evaluation::new("my fellow", "my follow", Normalization::default());
+  let report = evaluation.to_text();
+
+  assert!(report.starts_with("WER: 50.00% (1 substitutions"));
+  assert!(report.ends_with("~ fellow → follow"));
+}
+
+#[test]
+fn test_evaluation_with_empty_reference() {
+  let evaluation = Evaluation::new("", "", Normalization::default());
+  assert_eq!(evaluation.wer, 0.0);
+
+  let evaluation = Evaluation::new("", "hello", Normalization::default());
+  assert_eq!(evaluation.wer, 1.0);
+}
@@ -0,0 +1,275 @@
+//! Transcript accuracy evaluation.
+//!
+//! Compares a transcript with a reference transcript and computes the word
+//! error rate (WER) and character error rate (CER), along with the word
+//! alignment so differences can be reviewed.
+//!
+//! ## Main Components
+//!
+//! - [`Evaluation`]: Error rates and word differences of a transcript
+//! - [`Normalization`]: How texts are normalized before comparing
+//! - [`Edit`]: A single step of the word alignment
+//! - [`corpus`]: Evaluation over a directory of recordings and references
+
+pub mod corpus;
+
+#[cfg(test)]
+mod corpus_tests;
+#[cfg(test)]
+mod evaluation_tests;
+
+/// How texts are normalized before comparing.
+///
+/// By default, case and punctuation are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Normalization {
+  /// Whether letter case is significant
+  pub keep_case: bool,
+  /// Whether punctuation is significant
+  pub keep_punctuation: bool,
+}
+
+/// A single step of the alignment between reference and transcript words.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Edit {
+  /// The word is the same in both texts
+  Match { word: String },
+  /// A reference word was transcribed as another word
+  Substitution {
+    reference: String,
+    hypothesis: String,
+  },
+  /// The transcript contains a word missing from the reference
+  Insertion { word: String },
+  /// A reference word is missing from the transcript
+  Deletion { word: String },
+}
+
+/// Error rates and word differences of a transcript.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Evaluation {
+  /// Word error rate, between 0.0 and possibly above 1.0
+  pub wer: f64,
+  /// Character error rate, between 0.0 and possibly above 1.0
+  pub cer: f64,
+  /// Number of words in the normalized reference
+  pub reference_words: usize,
+  /// Number of substituted words
+  pub substitutions: usize,
+  /// Number of inserted words
+  pub insertions: usize,
+  /// Number of deleted words
+  pub deletions: usize,
+  /// Number of characters in the normalized reference
+  pub reference_characters: usize,
+  /// Number of substituted, inserted and deleted characters
+  pub character_errors: usize,
+  /// Alignment of reference and transcript words
+  pub edits: Vec<Edit>,
+}
+
+impl Evaluation {
+  /// Evaluates a transcript against a reference transcript.
+  ///
+  /// # Arguments
+  ///
+  /// * `reference` - The correct transcript
+  /// * `hypothesis` - The transcript to evaluate
+  /// * `normalization` - How both texts are normalized before comparing
+  ///
+  /// # Returns
+  ///
+  /// A new `Evaluation` with error rates and the word alignment.
+  pub fn new(
+    reference: &str,
+    hypothesis: &str,
+    normalization: Normalization,
+  ) -> Self {
+    let reference = normalize(reference, normalization);
+    let hypothesis = normalize(hypothesis, normalization);
+
+    let reference_words = split_words(&reference);
+    let edits = align(&reference_words, &split_words(&hypothesis));
+    let count = |matches: fn(&Edit) -> bool| {
+      return edits.iter().filter(|edit| matches(edit)).count();
+    };
+    let substitutions = count(|e| matches!(e, Edit::Substitution { .. }));
+    let insertions = count(|e| matches!(e, Edit::Insertion { .. }));
+    let deletions = count(|e| matches!(e, Edit::Deletion { .. }));
+
+    let reference_chars = split_chars(&reference);
+    let character_errors = align(&reference_chars, &split_chars(&hypothesis))
+      .iter()
+      .filter(|edit| !matches!(edit, Edit::Match { .. }))
+      .count();
+
+    return Evaluation {
+      wer: error_rate(
+        substitutions + insertions + deletions,
+        reference_words.len(),
+      ),
+      cer: error_rate(character_errors, reference_chars.len()),
+      reference_words: reference_words.len(),
+      substitutions,
+      insertions,
+      deletions,
+      reference_characters: reference_chars.len(),
+      character_errors,
+      edits,
+    };
+  }
+
+  /// Formats the evaluation as a human-readable report.
+  ///
+  /// Lists the error rates followed by each substitution (`~`), insertion
+  /// (`+`) and deletion (`-`).
+  ///
+  /// # Returns
+  ///
+  /// A `String` containing the report.
+  pub fn to_text(&self) -> String {
+    let mut report = format!(
+      "WER: {:.2}% ({} substitutions, {} insertions, {} deletions, {} words)\n\
+       CER: {:.2}%",
+      self.wer * 100.0,
+      self.substitutions,
+      self.insertions,
+      self.deletions,
+      self.reference_words,
+      self.cer * 100.0
+    );
+    for edit in &self.edits {
+      let line = match edit {
+        Edit::Match { .. } => continue,
+        Edit::Substitution {
+          reference,
+          hypothesis,
+        } => format!("~ {} → {}", reference, hypothesis),
+        Edit::Insertion { word } => format!("+ {}", word),
+        Edit::Deletion { word } => format!("- {}", word),
+      };
+      report.push('\n');
+      report.push_str(&line);
+    }
+    return report;
+  }
+}
+
+/// Normalizes a text for comparison.
+///
+/// Collapses whitespace and, unless kept, lowercases letters and removes
+/// punctuation. Apostrophes inside words are kept so contractions stay
+/// single words.
+///
+/// # Arguments
+///
+/// * `text` - The text to normalize
+/// * `normalization` - How the text is normalized
+///
+/// # Returns
+///
+/// The normalized text.
+pub fn normalize(text: &str, normalization: Normalization) -> String {
+  let text = if normalization.keep_case {
+    text.to_string()
+  } else {
+    text.to_lowercase()
+  };
+  let text: String = if normalization.keep_punctuation {
+    text
+  } else {
+    text
+      .chars()
+      .map(|c| {
+        if c.is_alphanumeric() || c == '\'' || c.is_whitespace() {
+          return c;
+        }
+        return ' ';
+      })
+      .collect()
+  };
+  return text
+    .split_whitespace()
+    .map(|word| {
+      if normalization.keep_punctuation {
+        return word;
+      }
+      return word.trim_matches('\'');
+    })
+    .filter(|word| !word.is_empty())
+    .collect::<Vec<&str>>()
+    .join(" ");
+}
+
+fn split_words(text: &str) -> Vec<String> {
+  return text.split_whitespace().map(String::from).collect();
+}
+
+fn split_chars(text: &str) -> Vec<String> {
+  return text.chars().map(String::from).collect();
+}
+
+fn error_rate(errors: usize, total: usize) -> f64 {
+  if total == 0 {
+    return if errors == 0 { 0.0 } else { 1.0 };
+  }
+  return errors as f64 / total as f64;
+}
+
+fn align(reference: &[String], hypothesis: &[String]) -> Vec<Edit> {
+  let rows = reference.len() + 1;
+  let columns = hypothesis.len() + 1;
+  let mut distances = vec![vec![0usize; columns]; rows];
+  for (i, row) in distances.iter_mut().enumerate() {
+    row[0] = i;
+  }
+  for (j, distance) in distances[0].iter_mut().enumerate() {
+    *distance = j;
+  }
+  for i in 1..rows {
+    for j in 1..columns {
+      let cost = usize::from(reference[i - 1] != hypothesis[j - 1]);
+      distances[i][j] = (distances[i - 1][j - 1] + cost)
+        .min(distances[i - 1][j] + 1)
+        .min(distances[i][j - 1] + 1);
+    }
+  }
+
+  let mut edits = Vec::new();
+  let (mut i, mut j) = (reference.len(), hypothesis.len());
+  while i > 0 || j > 0 {
+    if i > 0 && j > 0 {
+      let same = reference[i - 1] == hypothesis[j - 1];
+      let cost = usize::from(!same);
+      if distances[i][j] == distances[i - 1][j - 1] + cost {
+        edits.push(if same {
+          Edit::Match {
+            word: reference[i - 1].clone(),
+          }
+        } else {
+          Edit::Substitution {
+            reference: reference[i - 1].clone(),
+            hypothesis: hypothesis[j - 1].clone(),
+          }
+        });
+        i -= 1;
+        j -= 1;
+        continue;
+      }
+    }
+    if i > 0 && distances[i][j] == distances[i - 1][j] + 1 {
+      edits.push(Edit::Deletion {
+        word: reference[i - 1].clone(),
+      });
+      i -= 1;
+    } else {
+      edits.push(Edit::Insertion {
+        word: hypothesis[j - 1].clone(),
+      });
+      j -= 1;
+    }
+  }
+  edits.reverse();
+  return edits;
+}
@@ -0,0 +1,59 @@
+use crate::events::{Event, EventBus};
+
+#[tokio::test]
+async fn test_event_bus_delivers_to_subscribers() {
+  let bus = EventBus::new();
+  let mut first = bus.subscribe();
+  let mut second = bus.clone().subscribe();
+
+  bus.emit(Event::SilenceDetected);
+  bus.emit(Event::Completed);
+
+  assert_eq!(first.recv().await.unwrap(), Event::SilenceDetected);
+  assert_eq!(first.recv().await.unwrap(), Event::Completed);
+  assert_eq!(second.recv().await.unwrap(), Event::SilenceDetected);
+}
+
+#[tokio::test]
+async fn test_event_bus_without_subscribers() {
+  let bus = EventBus::new();
+  bus.emit(Event::Completed);
+
+  let mut late = bus.subscribe();
+  bus.emit(Event::Failed {
+    error: String::from("boom"),
+  });
+  assert_eq!(
+    late.recv().await.unwrap(),
+    Event::Failed {
+      error: String::from("boom")
+    }
+  );
+}
+
+#[test]
+fn test_describe_event() {
+  let started = Event::RecordingStarted {
+    path: String::from("/tmp/a.wav"),
+  };
+  let failed = Event::Failed {
+    error: String::from("boom"),
+  };
+
+  assert_eq!(
+    started.describe(),
+    ("recording_started", String::from("/tmp/a.wav"))
+  );
+  assert_eq!(
+    Event::TranscriptionStarted.describe(),
+    ("transcription_started", String::new())
+  );
+  assert_eq!(failed.describe(), ("failed", String::from("boom")));
+  assert_eq!(
+    Event::Warning {
+      message: String::from("Warning: no audio")
+    }
+    .describe(),
+    ("warning", String::from("Warning: no audio"))
+  );
+}
@@ -0,0 +1,130 @@
+//! Progress events emitted while Lumine records and transcribes.
+//!
+//! Lets callers observe a run as it happens, for example to drive a
+//! progress display, without parsing log output.
+//!
+//! ## Main Components
+//!
+//! - [`Event`]: Typed progress events
+//! - [`EventBus`]: Broadcasts events to any number of subscribers
+//! - [`PartialTranscript`]: Text shown while a run is still going
+
+#[cfg(test)]
+mod events_tests;
+
+use tokio::sync::broadcast;
+
+const EVENT_CAPACITY: usize = 64;
+
+/// A progress event emitted during a run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+  /// An audio input device was selected for recording
+  DeviceSelected { name: String },
+  /// FFmpeg started recording to a file
+  RecordingStarted { path: String },
+  /// Silence was detected, the recording stops if it lasts long enough
+  SilenceDetected,
+  /// Recording finished and the file was kept
+  RecordingStopped { path: String, duration: f64 },
+  /// The audio was sent to the Whisper service
+  TranscriptionStarted,
+  /// A quick draft of the transcript is available
+  DraftTranscribed { text: String },
+  /// A piece of the transcript is available
+  ChunkTranscribed { text: String },
+  /// The run finished successfully
+  Completed,
+  /// The run failed
+  Failed { error: String },
+  /// A step failed without stopping the run
+  Warning { message: String },
+  /// A message about the run for the user, such as how to stop it
+  Notice { message: String },
+}
+
+impl Event {
+  /// Describes the event for callers outside Rust.
+  ///
+  /// # Returns
+  ///
+  /// A tuple of the stable event name, such as `recording_started`, and its
+  /// detail, empty when the event has none.
+  pub fn describe(&self) -> (&'static str, String) {
+    return match self {
+      Event::DeviceSelected { name } => ("device_selected", name.clone()),
+      Event::RecordingStarted { path } => ("recording_started", path.clone()),
+      Event::SilenceDetected => ("silence_detected", String::new()),
+      Event::RecordingStopped { path, .. } => {
+        ("recording_stopped", path.clone())
+      }
+      Event::TranscriptionStarted => ("transcription_started", String::new()),
+      Event::DraftTranscribed { text } => ("draft_transcribed", text.clone()),
+      Event::ChunkTranscribed { text } => ("chunk_transcribed", text.clone()),
+      Event::Completed => ("completed", String::new()),
+      Event::Failed { error } => ("failed", error.clone()),
+      Event::Warning { message } => ("warning", message.clone()),
+      Event::Notice { message } => ("notice", message.clone()),
+    };
+  }
+}
+
+/// Text transcribed while a run is still going.
+///
+/// Unlike events, partial transcripts are sent over a channel that never
+/// drops them, so they can be printed as part of the output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialTranscript {
+  /// Quick draft of the transcript, shown before the final one
+  Draft(String),
+  /// Transcript of one chunk of a live stream
+  Chunk(String),
+  /// Transcript of one dictation, in the requested output format
+  Dictation(String),
+}
+
+/// Broadcasts events to subscribers.
+///
+/// Cloning the bus shares the same channel. Events emitted while nobody is
+/// subscribed are dropped, and slow subscribers miss the oldest events once
+/// the channel is full.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+  sender: broadcast::Sender<Event>,
+}
+
+impl EventBus {
+  /// Creates a new EventBus without subscribers.
+  ///
+  /// # Returns
+  ///
+  /// A new `EventBus` instance.
+  pub fn new() -> Self {
+    let (sender, _) = broadcast::channel(EVENT_CAPACITY);
+    return EventBus { sender };
+  }
+
+  /// Emits an event to all current subscribers.
+  ///
+  /// # Arguments
+  ///
+  /// * `event` - The event to emit
+  pub fn emit(&self, event: Event) {
+    let _ = self.sender.send(event);
+  }
+
+  /// Subscribes to events emitted from now on.
+  ///
+  /// # Returns
+  ///
+  /// A `broadcast::Receiver<Event>` receiving subsequent events.
+  pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+    return self.sender.subscribe();
+  }
+}
+
+impl Default for EventBus {
+  fn default() -> Self {
+    return EventBus::new();
+  }
+}
@@ -0,0 +1,164 @@
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+use crate::audio::{AudioError, FfmpegError};
+use crate::config::errors::ConfigError;
+use crate::explain::{EXPLANATIONS, find_explanation, format_explanation};
+use crate::files::errors::FileError;
+use crate::network::errors::{NetworkError, StatusError};
+use crate::process::errors::ProcessError;
+use crate::whisper::WhisperError;
+
+fn text() -> String {
+  return String::from("value");
+}
+
+fn ffmpeg() -> FfmpegError {
+  return FfmpegError::exited(ExitStatus::from_raw(256), "error");
+}
+
+fn io() -> std::io::Error {
+  return std::io::Error::other("error");
+}
+
+fn status() -> StatusError {
+  return StatusError {
+    status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+    body: text(),
+  };
+}
+
+/// Builds a reqwest error without sending a request.
+fn reqwest_error() -> reqwest::Error {
+  return reqwest::Client::new().get("not a url").build().unwrap_err();
+}
+
+/// One error of every variant, with its code and message.
+fn all_errors() -> Vec<(&'static str, String)> {
+  let config = [
+    ConfigError::FileRead(text()),
+    ConfigError::Parse(text()),
+    ConfigError::UnknownMode(text()),
+    ConfigError::UnknownKey(text()),
+    ConfigError::InvalidValue(text(), text(), text()),
+  ];
+  let audio = [
+    AudioError::FileNotFound(text()),
+    AudioError::ConversionFailed(ffmpeg()),
+    AudioError::FFMPEGNotFound(ffmpeg()),
+    AudioError::CouldNotExecuteFFMPEG(ffmpeg()),
+    AudioError::CouldNotReadFFMPEGOutput,
+    AudioError::CouldNotCreateDirectory(FileError::DirectoryCreate(
+      text(),
+      io(),
+    )),
+    AudioError::CouldNotReadWav(text(), io()),
+    AudioError::RecordingTooShort(0.5, 1),
+    AudioError::SandboxMicrophoneAccess(text()),
+    AudioError::CouldNotOpenStream(text()),
+    AudioError::Cancelled,
+    AudioError::UnknownRecorderBackend(text()),
+    AudioError::RecorderBackendNotBuilt(text()),
+    AudioError::CaptureFailed(text()),
+    AudioError::GStreamerNotFound(ProcessError::ExecutionFailed(text(), io())),
+    AudioError::PlaybackFailed(ffmpeg()),
+  ];
+  let whisper = [
+    WhisperError::FileNotFound(text()),
+    WhisperError::InvalidURL(text()),
+    WhisperError::RequestFailed(reqwest_error()),
+    WhisperError::ResponseError(status()),
+    WhisperError::DecodeError(text()),
+    WhisperError::Cancelled,
+    WhisperError::FileRead(text(), io()),
+  ];
+  let network = [
+    NetworkError::InvalidURL(text()),
+    NetworkError::RequestFailed(reqwest_error()),
+    NetworkError::ResponseError(status()),
+    NetworkError::DecodeError(reqwest_error()),
+    NetworkError::Cancelled,
+    NetworkError::InvalidMethod(text()),
+  ];
+  let files = [
+    FileError::DirectoryCreate(text(), io()),
+    FileError::DirectoryRead(text(), io()),
+    FileError::FileRemove(text(), io()),
+    FileError::FileRead(text(), io()),
+    FileError::FileWrite(text(), io()),
+    FileError::FileMove(text(), io()),
+    FileError::SymlinkCreate(text(), io()),
+    FileError::FileNotFound(text()),
+    FileError::DirectoryWatch(text(), io()),
+  ];
+
+  let mut errors = Vec::new();
+  errors.extend(config.iter().map(|e| (e.code(), e.to_string())));
+  errors.extend(audio.iter().map(|e| (e.code(), e.to_string())));
+  errors.extend(whisper.iter().map(|e| (e.code(), e.to_string())));
+  errors.extend(network.iter().map(|e| (e.code(), e.to_string())));
+  errors.extend(files.iter().map(|e| (e.code(), e.to_string())));
+  return errors;
+}
+
+#[test]
+fn test_error_messages_start_with_code() {
+  for (code, message) in all_errors() {
+    assert!(
+      message.starts_with(&format!("[{}] ", code)),
+      "{} does not start with its code {}",
+      message,
+      code
+    );
+  }
+}
+
+#[test]
+fn test_every_error_code_is_explained_once() {
+  let errors = all_errors();
+  let codes: Vec<&str> = errors.iter().map(|(code, _)| *code).collect();
+  let explained: Vec<&str> = EXPLANATIONS
+    .iter()
+    .map(|explanation| explanation.code)
+    .collect();
+
+  assert_eq!(codes, explained);
+}
+
+#[test]
+fn test_explanations_have_causes_and_fixes() {
+  for explanation in EXPLANATIONS {
+    assert!(!explanation.summary.is_empty(), "{}", explanation.code);
+    assert!(!explanation.causes.is_empty(), "{}", explanation.code);
+    assert!(!explanation.fixes.is_empty(), "{}", explanation.code);
+  }
+}
+
+#[test]
+fn test_find_explanation_ignores_case_and_brackets() {
+  assert_eq!(find_explanation("LUM-AUD-003").unwrap().code, "LUM-AUD-003");
+  assert_eq!(find_explanation("lum-aud-003").unwrap().code, "LUM-AUD-003");
+  assert_eq!(
+    find_explanation(" [LUM-AUD-003] ").unwrap().code,
+    "LUM-AUD-003"
+  );
+}
+
+#[test]
+fn test_find_explanation_unknown_code() {
+  assert!(find_explanation("LUM-AUD-999").is_none());
+  assert!(find_explanation("").is_none());
+}
+
+#[test]
+fn test_format_explanation() {
+  let explanation = find_explanation("LUM-AUD-003").unwrap();
+  let formatted = format_explanation(explanation);
+
+  assert!(formatted.starts_with("LUM-AUD-003: FFmpeg is not installed"));
+  assert!(formatted.contains("\n  - FFmpeg is not installed.\n"));
+  assert!(
+    formatted
+      .ends_with("  - Check that `ffmpeg -version` works in the same shell.")
+  );
+}
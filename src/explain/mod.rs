@@ -0,0 +1,524 @@
+//! Explanations of error codes.
+//!
+//! Errors of the configuration, audio, Whisper, network and file modules
+//! carry stable codes such as `LUM-AUD-003`, shown at the start of their
+//! messages. `lumine explain` looks a code up here and prints its likely
+//! causes and how to fix them, so support requests can refer to a code
+//! instead of a translated message.
+//!
+//! ## Main Components
+//!
+//! - [`ErrorExplanation`]: Causes and fixes of one error code
+//! - [`find_explanation`]: Look up the explanation of a code
+//! - [`format_explanation`]: Format an explanation for the terminal
+
+#[cfg(test)]
+mod explain_tests;
+
+use crate::tr;
+
+/// Causes and fixes of one error code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorExplanation {
+  /// Stable error code, such as `LUM-AUD-003`
+  pub code: &'static str,
+  /// One-line description of the error
+  pub summary: &'static str,
+  /// Likely causes of the error
+  pub causes: &'static [&'static str],
+  /// Steps that usually fix the error
+  pub fixes: &'static [&'static str],
+}
+
+/// Explanations of all error codes, ordered by code.
+pub const EXPLANATIONS: &[ErrorExplanation] = &[
+  ErrorExplanation {
+    code: "LUM-CFG-001",
+    summary: "The configuration file could not be read.",
+    causes: &[
+      "The file is not readable by the current user.",
+      "The file is not valid UTF-8 text.",
+    ],
+    fixes: &[
+      "Check the permissions of ~/.config/lumine/config.toml.",
+      "Run `lumine reset-config` to write a fresh configuration.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-CFG-002",
+    summary: "The configuration file is not valid TOML or has a value of the wrong type.",
+    causes: &[
+      "A value is missing quotes, such as a URL or device name.",
+      "A number or boolean was written as a string, or the other way around.",
+      "A section header is misspelled or repeated.",
+    ],
+    fixes: &[
+      "Fix the line named in the message, comparing it with the default configuration in the README.",
+      "Run `lumine reset-config` to start over with default values.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-CFG-003",
+    summary: "The requested mode does not exist.",
+    causes: &[
+      "The `--mode` flag names a mode that is neither built in nor defined in the configuration.",
+    ],
+    fixes: &[
+      "Check the spelling of the mode.",
+      "Define the mode as a `[mode.<name>]` section in the configuration.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-CFG-004",
+    summary: "The configuration key does not exist.",
+    causes: &[
+      "The key passed to `lumine config` or set in a `LUMINE_*` environment variable is misspelled.",
+      "The key is missing its section, such as `url` instead of `whisper.url`.",
+    ],
+    fixes: &[
+      "Run `lumine config show` to list all keys.",
+      "Use the dotted `section.field` form of the key.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-CFG-005",
+    summary: "A configuration value has the wrong type.",
+    causes: &[
+      "A number, boolean or list was expected but the value could not be parsed as one.",
+    ],
+    fixes: &[
+      "Use `true` or `false` for switches and plain digits for numbers.",
+      "Run `lumine config show` to see the current value and its type.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-001",
+    summary: "The audio file does not exist.",
+    causes: &[
+      "The path is misspelled or relative to another directory.",
+      "The file was moved or removed before it was processed.",
+    ],
+    fixes: &["Check the path, or pass an absolute path."],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-002",
+    summary: "FFmpeg could not convert the audio to the format Whisper needs.",
+    causes: &[
+      "The input is not an audio or video file, or it is damaged.",
+      "The temporary directory is not writable or the disk is full.",
+      "FFmpeg was built without the decoder the input needs.",
+    ],
+    fixes: &[
+      "Run with `--verbose` to see the FFmpeg error.",
+      "Try converting the file with `ffmpeg -i <file> out.wav` by hand.",
+      "Check `general.temp_directory` and the free disk space.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-003",
+    summary: "FFmpeg is not installed or not on the PATH.",
+    causes: &[
+      "FFmpeg is not installed.",
+      "FFmpeg is installed outside the directories listed in PATH.",
+    ],
+    fixes: &[
+      "Install FFmpeg with your package manager, such as `apt install ffmpeg` or `brew install ffmpeg`.",
+      "Check that `ffmpeg -version` works in the same shell.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-004",
+    summary: "FFmpeg could not be started.",
+    causes: &[
+      "The FFmpeg binary is not executable.",
+      "The audio device is busy or access to it was denied.",
+    ],
+    fixes: &[
+      "Check that `ffmpeg -version` works.",
+      "Grant microphone access to the terminal, or close other programs using the device.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-005",
+    summary: "The output of FFmpeg could not be read.",
+    causes: &[
+      "FFmpeg exited while starting.",
+      "The FFmpeg installation is broken.",
+    ],
+    fixes: &[
+      "Run with `--verbose` to see what FFmpeg printed.",
+      "Reinstall FFmpeg.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-006",
+    summary: "The recordings directory could not be created.",
+    causes: &["The parent directory is not writable.", "The disk is full."],
+    fixes: &[
+      "Check `recorder.recordings_directory` and its permissions.",
+      "Free up disk space.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-007",
+    summary: "A WAV file could not be read.",
+    causes: &[
+      "The file is not a WAV file despite its extension.",
+      "The recording was cut off and its header is incomplete.",
+    ],
+    fixes: &[
+      "Convert the file with FFmpeg and try again.",
+      "Record again if the recording was interrupted.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-008",
+    summary: "The recording was shorter than the minimum duration and was discarded.",
+    causes: &[
+      "Recording stopped right after it started, for example because of silence detection.",
+    ],
+    fixes: &[
+      "Speak longer, or lower `recorder.min_duration`.",
+      "Raise `recorder.silence_limit` if silence detection stops too early.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-009",
+    summary: "Lumine runs in a sandbox without access to a microphone.",
+    causes: &["The Flatpak or Snap package was not granted audio input."],
+    fixes: &[
+      "Grant microphone access to the sandbox as described in the message.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-010",
+    summary: "The live stream could not be opened.",
+    causes: &[
+      "The URL is wrong or the stream is offline.",
+      "The stream needs authentication or a protocol FFmpeg does not support.",
+    ],
+    fixes: &[
+      "Open the URL with `ffplay <url>` to check that it plays.",
+      "Run with `--verbose` to see the FFmpeg error.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-011",
+    summary: "The recording was cancelled.",
+    causes: &["Ctrl+C was pressed or the process was asked to stop."],
+    fixes: &["Nothing to fix; start the recording again."],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-012",
+    summary: "The configured recorder backend does not exist.",
+    causes: &["`recorder.backend` is misspelled or names a removed backend."],
+    fixes: &[
+      "Run `lumine config set recorder.backend ffmpeg` to record with FFmpeg.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-013",
+    summary: "The configured recorder backend was left out of this build.",
+    causes: &[
+      "Optional backends such as `native` and `gstreamer` are only built when their Cargo feature is enabled.",
+    ],
+    fixes: &[
+      "Rebuild with the backend's feature, such as `cargo install lumine --features cpal`.",
+      "Switch back with `lumine config set recorder.backend ffmpeg`.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-014",
+    summary: "The recorder backend could not capture audio from the device.",
+    causes: &[
+      "The device was unplugged or is used exclusively by another program.",
+      "The device offers no sample format the backend can read.",
+      "Call recording was requested from a backend that does not support it.",
+    ],
+    fixes: &[
+      "Run `lumine devices` and pick another device.",
+      "Switch to the FFmpeg backend with `lumine config set recorder.backend ffmpeg`.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-015",
+    summary: "The gstreamer recorder backend could not run `gst-launch-1.0`.",
+    causes: &[
+      "GStreamer's command line tools are not installed or not in PATH.",
+    ],
+    fixes: &[
+      "Install GStreamer with its tools and base and good plugins, such as `gstreamer1.0-tools` and `gstreamer1.0-plugins-good` on Debian and Ubuntu.",
+      "Switch to the FFmpeg backend with `lumine config set recorder.backend ffmpeg`.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-AUD-016",
+    summary: "The test recording of an audio input device could not be played back.",
+    causes: &[
+      "`ffplay` is missing, as in some minimal FFmpeg packages.",
+      "No audio output device is available.",
+    ],
+    fixes: &[
+      "Install a full FFmpeg package that includes `ffplay`.",
+      "Run `lumine devices test` without `--play` and rely on the measured levels.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-001",
+    summary: "The audio file to transcribe does not exist.",
+    causes: &[
+      "The converted file was removed before it was uploaded, for example by `lumine clean`.",
+    ],
+    fixes: &["Transcribe the original file again."],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-002",
+    summary: "The Whisper service URL is invalid or the service answered unexpectedly.",
+    causes: &[
+      "`whisper.url` is not a full URL such as `http://127.0.0.1:9090`.",
+      "Another service is listening at the URL.",
+    ],
+    fixes: &[
+      "Set `whisper.url` to the base URL of the service, without `/inference`.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-003",
+    summary: "The Whisper service could not be reached.",
+    causes: &[
+      "The Whisper server is not running.",
+      "The host or port in `whisper.url` is wrong.",
+      "A firewall or proxy blocks the connection.",
+    ],
+    fixes: &[
+      "Start the Whisper server and check its port.",
+      "Open `whisper.url` in a browser or with `curl` to check that it answers.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-004",
+    summary: "The Whisper service answered with an error.",
+    causes: &[
+      "The server failed to load its model or ran out of memory.",
+      "The server does not support a requested option.",
+    ],
+    fixes: &[
+      "Check the logs of the Whisper server.",
+      "Retry without extra transcription options.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-005",
+    summary: "The answer of the Whisper service could not be decoded.",
+    causes: &[
+      "The service is not a whisper.cpp compatible server.",
+      "The server version returns a different response format.",
+    ],
+    fixes: &[
+      "Check that `whisper.url` points to a whisper.cpp server.",
+      "Update the Whisper server.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-006",
+    summary: "The transcription was cancelled.",
+    causes: &["Ctrl+C was pressed or the process was asked to stop."],
+    fixes: &["Nothing to fix; transcribe the file again."],
+  },
+  ErrorExplanation {
+    code: "LUM-WSP-007",
+    summary: "The audio file could not be read for upload.",
+    causes: &[
+      "The file is not readable by the current user.",
+      "The file was replaced by a directory or removed while it was read.",
+    ],
+    fixes: &["Check the permissions of the file and transcribe it again."],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-001",
+    summary: "The URL of a service is invalid or the service answered unexpectedly.",
+    causes: &[
+      "A configured URL, such as a webhook or language model URL, is misspelled.",
+    ],
+    fixes: &["Check the URL in the configuration, including its scheme."],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-002",
+    summary: "A service could not be reached.",
+    causes: &[
+      "The service is down or the network is offline.",
+      "A firewall or proxy blocks the connection.",
+    ],
+    fixes: &[
+      "Check the network connection.",
+      "Open the URL with `curl` to check that it answers.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-003",
+    summary: "A service answered with an error.",
+    causes: &[
+      "The request was rejected, for example because of a missing API key.",
+      "The service had an internal error.",
+    ],
+    fixes: &[
+      "Check API keys and other credentials in the configuration.",
+      "Check the logs or status page of the service.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-004",
+    summary: "The answer of a service could not be decoded.",
+    causes: &[
+      "The URL points to a different kind of service.",
+      "The service changed its response format.",
+    ],
+    fixes: &["Check that the configured URL points to the intended API."],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-005",
+    summary: "The request was cancelled.",
+    causes: &["Ctrl+C was pressed or the process was asked to stop."],
+    fixes: &["Nothing to fix; run the command again."],
+  },
+  ErrorExplanation {
+    code: "LUM-NET-006",
+    summary: "A request used an HTTP method that could not be built.",
+    causes: &["Lumine was built with an HTTP library that rejects the method."],
+    fixes: &[
+      "Report the error together with the output of `lumine --version`.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-001",
+    summary: "A directory could not be created.",
+    causes: &["The parent directory is not writable.", "The disk is full."],
+    fixes: &[
+      "Check the permissions of the parent directory.",
+      "Free up disk space.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-002",
+    summary: "A directory could not be read.",
+    causes: &[
+      "The directory does not exist.",
+      "The directory is not readable by the current user.",
+    ],
+    fixes: &["Check the path and its permissions."],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-003",
+    summary: "A file could not be removed.",
+    causes: &[
+      "The file is in a directory that is not writable.",
+      "The file was already removed.",
+    ],
+    fixes: &["Check the permissions of the directory containing the file."],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-004",
+    summary: "A file could not be read.",
+    causes: &[
+      "The file is not readable by the current user.",
+      "The file is encrypted and no passphrase was provided.",
+    ],
+    fixes: &[
+      "Check the permissions of the file.",
+      "Set `LUMINE_PASSPHRASE` or `encryption.key_command` for encrypted files.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-005",
+    summary: "A file could not be written.",
+    causes: &["The directory is not writable.", "The disk is full."],
+    fixes: &[
+      "Check the permissions of the output directory.",
+      "Free up disk space.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-006",
+    summary: "A file could not be moved.",
+    causes: &["The destination directory does not exist or is not writable."],
+    fixes: &["Check the destination path and its permissions."],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-007",
+    summary: "The `latest.wav` link could not be created.",
+    causes: &[
+      "The recordings directory is on a file system without symbolic links.",
+      "The recordings directory is not writable.",
+    ],
+    fixes: &[
+      "Move `recorder.recordings_directory` to a file system with symbolic links.",
+    ],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-008",
+    summary: "A file does not exist.",
+    causes: &["The path is misspelled or relative to another directory."],
+    fixes: &["Check the path, or pass an absolute path."],
+  },
+  ErrorExplanation {
+    code: "LUM-FIL-009",
+    summary: "A directory could not be watched for new files.",
+    causes: &[
+      "The directory does not exist.",
+      "The system limit of watched files was reached.",
+    ],
+    fixes: &[
+      "Check the path passed to `lumine watch`.",
+      "Raise the inotify limit, such as `fs.inotify.max_user_watches` on Linux.",
+    ],
+  },
+];
+
+/// Looks up the explanation of an error code.
+///
+/// The lookup ignores case and surrounding brackets, so codes can be pasted
+/// as shown in messages.
+///
+/// # Arguments
+///
+/// * `code` - Error code such as `LUM-AUD-003`
+///
+/// # Returns
+///
+/// An `Option<&ErrorExplanation>` containing the explanation, or `None` if
+/// the code is unknown.
+pub fn find_explanation(code: &str) -> Option<&'static ErrorExplanation> {
+  let code = code.trim().trim_start_matches('[').trim_end_matches(']');
+  return EXPLANATIONS
+    .iter()
+    .find(|explanation| explanation.code.eq_ignore_ascii_case(code));
+}
+
+/// Formats an explanation for the terminal.
+///
+/// # Arguments
+///
+/// * `explanation` - The explanation to format
+///
+/// # Returns
+///
+/// A `String` with the code and summary followed by lists of causes and
+/// fixes.
+pub fn format_explanation(explanation: &ErrorExplanation) -> String {
+  let list = |items: &[&str]| {
+    return items
+      .iter()
+      .map(|item| format!("  - {}", item))
+      .collect::<Vec<String>>()
+      .join("\n");
+  };
+  return format!(
+    "{}: {}\n\n{}\n{}\n\n{}\n{}",
+    explanation.code,
+    explanation.summary,
+    tr!("explain-causes"),
+    list(explanation.causes),
+    tr!("explain-fixes"),
+    list(explanation.fixes)
+  );
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+/// C ABI errors.
+///
+/// Represents errors of calls made through the C ABI, reported by
+/// `lumine_last_error`.
+#[derive(Error, Debug)]
+pub enum FfiError {
+  #[error("Invalid argument '{0}': expected a UTF-8 C string.")]
+  InvalidArgument(&'static str),
+
+  #[error(
+    "Unknown output format: '{0}'. Use a format accepted by --format, such as 'text', 'json' or 'srt'."
+  )]
+  UnknownFormat(String),
+
+  #[error("Configuration error: {0}")]
+  Configuration(String),
+
+  #[error("{0}")]
+  Runtime(String),
+
+  #[error("Cannot start the async runtime: {0}")]
+  RuntimeStart(String),
+
+  #[error("Lumine panicked while handling the call.")]
+  Panicked,
+}
+
+/// Result type for C ABI operations.
+pub type FfiResult<T> = Result<T, FfiError>;
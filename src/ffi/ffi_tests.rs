@@ -0,0 +1,59 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use crate::ffi::*;
+
+const HEADER: &str = include_str!("../../include/lumine.h");
+const GENERATED_HEADER: &str =
+  include_str!(concat!(env!("OUT_DIR"), "/lumine.h"));
+
+fn last_error() -> String {
+  let error = lumine_last_error();
+  assert!(!error.is_null());
+  return unsafe { CStr::from_ptr(error) }
+    .to_str()
+    .unwrap()
+    .to_string();
+}
+
+#[test]
+fn test_transcribe_file_rejects_null_path() {
+  let transcript = unsafe {
+    lumine_transcribe_file(ptr::null(), ptr::null(), None, ptr::null_mut())
+  };
+
+  assert!(transcript.is_null());
+  assert!(last_error().contains("'path'"));
+}
+
+#[test]
+fn test_transcribe_file_rejects_unknown_format() {
+  let path = CString::new("sample/jfk.wav").unwrap();
+  let format = CString::new("midi").unwrap();
+
+  let transcript = unsafe {
+    lumine_transcribe_file(
+      path.as_ptr(),
+      format.as_ptr(),
+      None,
+      ptr::null_mut(),
+    )
+  };
+
+  assert!(transcript.is_null());
+  assert!(last_error().contains("'midi'"));
+}
+
+#[test]
+fn test_string_free_accepts_null() {
+  unsafe { lumine_string_free(ptr::null_mut()) };
+}
+
+#[test]
+fn test_header_is_up_to_date() {
+  assert!(
+    HEADER == GENERATED_HEADER,
+    "include/lumine.h is out of date, refresh it with: cbindgen --config \
+     cbindgen.toml --output include/lumine.h src/ffi/mod.rs"
+  );
+}
@@ -0,0 +1,293 @@
+//! C ABI for embedding Lumine in other languages.
+//!
+//! Built with the `ffi` feature and declared in `include/lumine.h`, which
+//! cbindgen generates from this file. Each call loads the configuration
+//! like the CLI does, blocks until the workflow finishes and reports
+//! progress through an optional callback. Cargo cannot switch the crate
+//! type on a feature, so the shared library is built with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib`.
+//!
+//! ## Conventions
+//!
+//! - Strings passed in are UTF-8 and stay owned by the caller
+//! - Returned strings belong to the caller, who frees them with
+//!   [`lumine_string_free`]
+//! - Failed calls return `NULL`, and [`lumine_last_error`] describes the
+//!   failure on the same thread
+//! - The progress callback runs on the calling thread before the call
+//!   returns
+//! - [`lumine_cancel`] stops every running call, from any thread
+//!
+//! ## Main Components
+//!
+//! - [`lumine_transcribe_file`]: Transcribe an audio file
+//! - [`lumine_record`]: Record from the microphone and transcribe
+//! - [`LumineProgressCallback`]: Callback receiving progress events
+//! - [`FfiError`]: Error types for calls through the C ABI
+
+pub mod errors;
+
+#[cfg(test)]
+mod ffi_tests;
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::ptr;
+use std::sync::{Mutex, OnceLock};
+
+use clap::ValueEnum;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::{App, Workflow, shared_runtime};
+use crate::config::Config;
+use crate::events::Event;
+use crate::output::format::OutputFormat;
+
+pub use crate::ffi::errors::{FfiError, FfiResult};
+
+/// Callback receiving progress events.
+///
+/// Called with the event name, such as `recording_started`, its detail,
+/// such as the recording path (empty when the event has none), and the
+/// user data passed to the call. Both strings are only valid during the
+/// callback.
+pub type LumineProgressCallback = Option<
+  unsafe extern "C" fn(
+    event: *const c_char,
+    detail: *const c_char,
+    user_data: *mut c_void,
+  ),
+>;
+
+static CANCEL: OnceLock<Mutex<CancellationToken>> = OnceLock::new();
+
+thread_local! {
+  static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Progress callback with the user data it is called with.
+struct Progress {
+  callback: unsafe extern "C" fn(*const c_char, *const c_char, *mut c_void),
+  user_data: *mut c_void,
+}
+
+impl Progress {
+  fn new(
+    callback: LumineProgressCallback,
+    user_data: *mut c_void,
+  ) -> Option<Self> {
+    return callback.map(|callback| Progress {
+      callback,
+      user_data,
+    });
+  }
+
+  fn report(&self, event: &Event) {
+    let (name, detail) = event.describe();
+    let name = CString::new(name).unwrap_or_default();
+    let detail = CString::new(detail.replace('\0', "")).unwrap_or_default();
+    // SAFETY: The caller passed a callback that accepts these arguments
+    // until the call returns.
+    unsafe { (self.callback)(name.as_ptr(), detail.as_ptr(), self.user_data) };
+  }
+}
+
+/// Transcribes an audio file.
+///
+/// # Safety
+///
+/// `path` and `format` must be `NULL` or point to NUL-terminated strings,
+/// and `callback` must be safe to call with `user_data` until the call
+/// returns.
+///
+/// # Arguments
+///
+/// * `path` - Path to the audio file to transcribe
+/// * `format` - Output format accepted by `--format`, `NULL` for text
+/// * `callback` - Callback receiving progress events, or `NULL`
+/// * `user_data` - Pointer passed to the callback unchanged
+///
+/// # Returns
+///
+/// The transcript in the requested format, or `NULL` on failure.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lumine_transcribe_file(
+  path: *const c_char,
+  format: *const c_char,
+  callback: LumineProgressCallback,
+  user_data: *mut c_void,
+) -> *mut c_char {
+  return call(|| {
+    // SAFETY: Upheld by the caller.
+    let path = unsafe { read_string(path, "path") }?
+      .ok_or(FfiError::InvalidArgument("path"))?;
+    // SAFETY: Upheld by the caller.
+    let format = unsafe { read_format(format) }?;
+    let workflow = Workflow::TranscribeFile { path, format };
+    return run(workflow, Progress::new(callback, user_data));
+  });
+}
+
+/// Records from the microphone and transcribes the recording.
+///
+/// Recording stops on silence like `lumine` without a command, or when
+/// [`lumine_cancel`] is called.
+///
+/// # Safety
+///
+/// `format` must be `NULL` or point to a NUL-terminated string, and
+/// `callback` must be safe to call with `user_data` until the call returns.
+///
+/// # Arguments
+///
+/// * `format` - Output format accepted by `--format`, `NULL` for text
+/// * `callback` - Callback receiving progress events, or `NULL`
+/// * `user_data` - Pointer passed to the callback unchanged
+///
+/// # Returns
+///
+/// The transcript in the requested format, or `NULL` on failure.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lumine_record(
+  format: *const c_char,
+  callback: LumineProgressCallback,
+  user_data: *mut c_void,
+) -> *mut c_char {
+  return call(|| {
+    // SAFETY: Upheld by the caller.
+    let format = unsafe { read_format(format) }?;
+    let workflow = Workflow::Record { format };
+    return run(workflow, Progress::new(callback, user_data));
+  });
+}
+
+/// Stops every running call.
+///
+/// The stopped calls clean up, then return `NULL`. Calls started
+/// afterwards run normally.
+#[unsafe(no_mangle)]
+pub extern "C" fn lumine_cancel() {
+  let mut cancel = cancellation().lock().unwrap_or_else(|e| e.into_inner());
+  cancel.cancel();
+  *cancel = CancellationToken::new();
+}
+
+/// Gets the error of the last failed call on this thread.
+///
+/// # Returns
+///
+/// A description of the error, valid until the next call on this thread,
+/// or `NULL` if the last call succeeded.
+#[unsafe(no_mangle)]
+pub extern "C" fn lumine_last_error() -> *const c_char {
+  return LAST_ERROR.with(|error| {
+    return match error.borrow().as_ref() {
+      Some(message) => message.as_ptr(),
+      None => ptr::null(),
+    };
+  });
+}
+
+/// Frees a string returned by Lumine.
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a string returned by Lumine that was not
+/// freed yet.
+///
+/// # Arguments
+///
+/// * `string` - The string to free
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lumine_string_free(string: *mut c_char) {
+  if string.is_null() {
+    return;
+  }
+  // SAFETY: Upheld by the caller, the string came from `CString::into_raw`.
+  drop(unsafe { CString::from_raw(string) });
+}
+
+/// Runs the body of a call, recording its error for `lumine_last_error`.
+fn call(body: impl FnOnce() -> FfiResult<String>) -> *mut c_char {
+  let result = catch_unwind(AssertUnwindSafe(body))
+    .unwrap_or(Err(FfiError::Panicked))
+    .and_then(|text| {
+      return CString::new(text)
+        .map_err(|e| FfiError::Runtime(format!("Invalid transcript: {}", e)));
+    });
+  let (output, error) = match result {
+    Ok(text) => (text.into_raw(), None),
+    Err(e) => {
+      let message = CString::new(e.to_string().replace('\0', ""));
+      (ptr::null_mut(), message.ok())
+    }
+  };
+  LAST_ERROR.with(|last| *last.borrow_mut() = error);
+  return output;
+}
+
+/// Reads a C string argument.
+///
+/// # Safety
+///
+/// `value` must be `NULL` or point to a NUL-terminated string.
+unsafe fn read_string(
+  value: *const c_char,
+  name: &'static str,
+) -> FfiResult<Option<String>> {
+  if value.is_null() {
+    return Ok(None);
+  }
+  // SAFETY: Upheld by the caller.
+  let value = unsafe { CStr::from_ptr(value) };
+  return value
+    .to_str()
+    .map(|value| Some(value.to_string()))
+    .map_err(|_| FfiError::InvalidArgument(name));
+}
+
+/// Reads the output format argument, text when it is `NULL`.
+///
+/// # Safety
+///
+/// `format` must be `NULL` or point to a NUL-terminated string.
+unsafe fn read_format(format: *const c_char) -> FfiResult<OutputFormat> {
+  // SAFETY: Upheld by the caller.
+  return match unsafe { read_string(format, "format") }? {
+    Some(name) => OutputFormat::from_str(&name, true)
+      .map_err(|_| FfiError::UnknownFormat(name)),
+    None => Ok(OutputFormat::Text),
+  };
+}
+
+fn cancellation() -> &'static Mutex<CancellationToken> {
+  return CANCEL.get_or_init(|| return Mutex::new(CancellationToken::new()));
+}
+
+/// Runs a workflow to completion, reporting its progress.
+fn run(workflow: Workflow, progress: Option<Progress>) -> FfiResult<String> {
+  let cancel = cancellation()
+    .lock()
+    .unwrap_or_else(|e| e.into_inner())
+    .child_token();
+  let runtime = shared_runtime().map_err(FfiError::RuntimeStart)?;
+  return runtime.block_on(async {
+    let config = Config::load()
+      .await
+      .map_err(|e| FfiError::Configuration(e.to_string()))?;
+    let app = App::bootstrap(config)
+      .await
+      .map_err(|e| FfiError::Runtime(e.to_string()))?
+      .with_cancellation(cancel);
+    let report = |event: &Event| {
+      if let Some(progress) = &progress {
+        progress.report(event);
+      }
+    };
+    return app
+      .run_workflow(workflow, report)
+      .await
+      .map_err(|e| FfiError::Runtime(e.to_string()));
+  });
+}
@@ -0,0 +1,17 @@
+use sha2::{Digest, Sha256};
+
+/// Computes the SHA-256 checksum of data.
+///
+/// # Arguments
+///
+/// * `data` - The data to hash
+///
+/// # Returns
+///
+/// A `String` containing the lowercase hex checksum.
+pub fn sha256_hex(data: &[u8]) -> String {
+  return Sha256::digest(data)
+    .iter()
+    .map(|byte| format!("{:02x}", byte))
+    .collect();
+}
@@ -0,0 +1,9 @@
+use crate::files::digest::sha256_hex;
+
+#[test]
+fn test_sha256_hex() {
+  assert_eq!(
+    sha256_hex(b"abc"),
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+  );
+}
@@ -5,21 +5,65 @@ use thiserror::Error;
 /// Represents errors that can occur during file and directory operations.
 #[derive(Error, Debug)]
 pub enum FileError {
-  #[error("Cannot create directory '{0}'. Please check permissions.")]
-  DirectoryCreate(String),
+  #[error(
+    "[LUM-FIL-001] Cannot create directory '{0}'. Please check permissions."
+  )]
+  DirectoryCreate(String, #[source] std::io::Error),
+
+  #[error(
+    "[LUM-FIL-002] Cannot read directory '{0}'. Please check permissions."
+  )]
+  DirectoryRead(String, #[source] std::io::Error),
 
   #[error(
-    "Cannot remove file '{0}'. Please check if the file exists and you have permission to delete it."
+    "[LUM-FIL-003] Cannot remove file '{0}'. Please check if the file exists and you have permission to delete it."
   )]
-  FileRemove(String),
+  FileRemove(String, #[source] std::io::Error),
 
   #[error(
-    "Cannot read file '{0}'. Please check if the file exists and you have permission to access it."
+    "[LUM-FIL-004] Cannot read file '{0}'. Please check if the file exists and you have permission to access it."
   )]
-  FileRead(String),
+  FileRead(String, #[source] std::io::Error),
 
-  #[error("File not found: '{0}'. Please verify the file path and try again.")]
+  #[error("[LUM-FIL-005] Cannot write file '{0}'. Please check permissions.")]
+  FileWrite(String, #[source] std::io::Error),
+
+  #[error(
+    "[LUM-FIL-006] Cannot move file '{0}'. Please check the destination path and permissions."
+  )]
+  FileMove(String, #[source] std::io::Error),
+
+  #[error(
+    "[LUM-FIL-007] Cannot create symbolic link: '{0}'. Please check permissions."
+  )]
+  SymlinkCreate(String, #[source] std::io::Error),
+
+  #[error(
+    "[LUM-FIL-008] File not found: '{0}'. Please verify the file path and try again."
+  )]
   FileNotFound(String),
+
+  #[error(
+    "[LUM-FIL-009] Cannot watch directory: '{0}'. Please check the path and permissions."
+  )]
+  DirectoryWatch(String, #[source] std::io::Error),
+}
+
+impl FileError {
+  /// Returns the stable code of this error, such as `LUM-FIL-001`.
+  pub fn code(&self) -> &'static str {
+    return match self {
+      FileError::DirectoryCreate(..) => "LUM-FIL-001",
+      FileError::DirectoryRead(..) => "LUM-FIL-002",
+      FileError::FileRemove(..) => "LUM-FIL-003",
+      FileError::FileRead(..) => "LUM-FIL-004",
+      FileError::FileWrite(..) => "LUM-FIL-005",
+      FileError::FileMove(..) => "LUM-FIL-006",
+      FileError::SymlinkCreate(..) => "LUM-FIL-007",
+      FileError::FileNotFound(..) => "LUM-FIL-008",
+      FileError::DirectoryWatch(..) => "LUM-FIL-009",
+    };
+  }
 }
 
 /// Result type for file operations.
@@ -26,11 +26,43 @@ async fn test_remove_nonexistent_file() {
   let result = remove_file(&nonexistent_file.to_string_lossy()).await;
   assert!(result.is_err());
   match result.unwrap_err() {
-    FileError::FileRemove(_) => (),
+    FileError::FileRemove(path, e) => {
+      assert_eq!(path, nonexistent_file.to_string_lossy());
+      assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+    }
     _ => panic!("Expected FileRemove error"),
   }
 }
 
+#[tokio::test]
+async fn test_overwrite_file() {
+  let temp_dir = std::env::temp_dir();
+  let test_file = temp_dir.join("test_overwrite_file.txt");
+
+  fs::write(&test_file, TEST_FILE_CONTENT).unwrap();
+
+  let result = overwrite_file(&test_file.to_string_lossy()).await;
+  assert!(result.is_ok());
+  let content = fs::read(&test_file).unwrap();
+  assert_eq!(content.len(), TEST_FILE_CONTENT.len());
+  assert!(content.iter().all(|byte| *byte == 0));
+
+  fs::remove_file(&test_file).unwrap();
+}
+
+#[test]
+fn test_remove_file_blocking() {
+  let temp_dir = std::env::temp_dir();
+  let test_file = temp_dir.join("test_remove_file_blocking.txt");
+
+  fs::write(&test_file, TEST_FILE_CONTENT).unwrap();
+
+  let result = remove_file_blocking(&test_file.to_string_lossy());
+  assert!(result.is_ok());
+  assert!(!test_file.exists());
+  assert!(remove_file_blocking(&test_file.to_string_lossy()).is_err());
+}
+
 #[tokio::test]
 async fn test_create_directory_all() {
   let temp_dir = std::env::temp_dir();
@@ -46,6 +78,26 @@ async fn test_create_directory_all() {
   fs::remove_dir_all(temp_dir.join("test_create_directory_all")).unwrap();
 }
 
+#[tokio::test]
+async fn test_create_private_file() {
+  use std::os::unix::fs::PermissionsExt;
+
+  let temp_dir = std::env::temp_dir().to_string_lossy().to_string();
+  let first = create_private_file(&temp_dir, "memo.wav").await.unwrap();
+  let second = create_private_file(&temp_dir, "memo.wav").await.unwrap();
+
+  assert_ne!(first, second);
+  for path in [&first, &second] {
+    let name = std::path::Path::new(path).file_name().unwrap();
+    let name = name.to_string_lossy();
+    assert!(name.starts_with("lumine-tmp-"));
+    assert!(name.ends_with("_memo.wav"));
+    let mode = fs::metadata(path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    fs::remove_file(path).unwrap();
+  }
+}
+
 #[tokio::test]
 async fn test_create_directory_invalid_path() {
   let invalid_path = "/root/nonexistent/invalid/path";
@@ -53,7 +105,7 @@ async fn test_create_directory_invalid_path() {
   let result = create_directory_all(invalid_path).await;
   assert!(result.is_err());
   match result.unwrap_err() {
-    FileError::DirectoryCreate(_) => (),
+    FileError::DirectoryCreate(..) => (),
     _ => panic!("Expected DirectoryCreate error"),
   }
 }
@@ -103,3 +155,127 @@ async fn test_read_to_string() {
   fs::remove_file(&test_file).unwrap();
   assert!(read_to_string(&test_file.to_string_lossy()).await.is_err());
 }
+
+#[tokio::test]
+async fn test_read_bytes() {
+  let temp_dir = std::env::temp_dir();
+  let test_file = temp_dir.join("test_read_bytes.txt");
+
+  fs::write(&test_file, TEST_FILE_CONTENT).unwrap();
+  let result = read_bytes(&test_file.to_string_lossy()).await;
+  assert_eq!(result.unwrap(), TEST_FILE_CONTENT.as_bytes());
+
+  fs::remove_file(&test_file).unwrap();
+  assert!(read_bytes(&test_file.to_string_lossy()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_append_to_file() {
+  let temp_dir = std::env::temp_dir();
+  let test_file = temp_dir.join("test_append_to_file.md");
+  let path = test_file.to_string_lossy().to_string();
+  let _ = fs::remove_file(&test_file);
+
+  write_file(&path, "Replaced\n").await.unwrap();
+  write_file(&path, "# Notes\n").await.unwrap();
+  append_to_file(&path, "First\n").await.unwrap();
+  fs::remove_file(&test_file).unwrap();
+
+  append_to_file(&path, "# Notes\n").await.unwrap();
+  append_to_file(&path, "First\n").await.unwrap();
+  assert_eq!(fs::read_to_string(&test_file).unwrap(), "# Notes\nFirst\n");
+
+  fs::remove_file(&test_file).unwrap();
+}
+
+#[tokio::test]
+async fn test_list_files() {
+  let temp_dir = std::env::temp_dir().join("test_list_files");
+  fs::create_dir_all(temp_dir.join("nested")).unwrap();
+  fs::write(temp_dir.join("b.txt"), TEST_FILE_CONTENT).unwrap();
+  fs::write(temp_dir.join("a.wav"), TEST_FILE_CONTENT).unwrap();
+
+  let files = list_files(&temp_dir.to_string_lossy()).await.unwrap();
+  let names: Vec<String> = files
+    .iter()
+    .map(|file| file.rsplit('/').next().unwrap().to_string())
+    .collect();
+  assert_eq!(names, vec!["a.wav", "b.txt"]);
+
+  fs::remove_dir_all(&temp_dir).unwrap();
+  assert!(list_files(&temp_dir.to_string_lossy()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_file_size() {
+  let temp_dir = std::env::temp_dir();
+  let test_file = temp_dir.join("test_file_size.txt");
+
+  fs::write(&test_file, TEST_FILE_CONTENT).unwrap();
+  let result = file_size(&test_file.to_string_lossy()).await;
+  assert_eq!(result.unwrap(), TEST_FILE_CONTENT.len() as u64);
+
+  fs::remove_file(&test_file).unwrap();
+  assert!(file_size(&test_file.to_string_lossy()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_move_file() {
+  let temp_dir = std::env::temp_dir();
+  let source = temp_dir.join("test_move_file_source.txt");
+  let destination = temp_dir.join("test_move_file_destination.txt");
+
+  fs::write(&source, TEST_FILE_CONTENT).unwrap();
+  let result =
+    move_file(&source.to_string_lossy(), &destination.to_string_lossy()).await;
+  assert!(result.is_ok());
+  assert!(!source.exists());
+  assert!(destination.exists());
+
+  fs::remove_file(&destination).unwrap();
+}
+
+#[tokio::test]
+async fn test_move_file_across_file_systems() {
+  let ram_disk = std::path::Path::new("/dev/shm");
+  if !ram_disk.is_dir() {
+    return;
+  }
+  let source = ram_disk.join("test_move_file_across_file_systems.txt");
+  let destination = std::env::temp_dir()
+    .join("test_move_file_across_file_systems_destination.txt");
+  fs::write(&source, "content").unwrap();
+
+  let result =
+    move_file(&source.to_string_lossy(), &destination.to_string_lossy()).await;
+  assert!(result.is_ok());
+  assert!(!source.exists());
+  assert_eq!(fs::read_to_string(&destination).unwrap(), "content");
+  fs::remove_file(&destination).unwrap();
+}
+
+#[tokio::test]
+async fn test_update_and_read_symlink() {
+  let temp_dir = std::env::temp_dir();
+  let first = temp_dir.join("test_symlink_first.txt");
+  let second = temp_dir.join("test_symlink_second.txt");
+  let link = temp_dir.join("test_symlink_latest.txt");
+
+  fs::write(&first, TEST_FILE_CONTENT).unwrap();
+  fs::write(&second, TEST_FILE_CONTENT).unwrap();
+
+  let link_path = link.to_string_lossy().to_string();
+  update_symlink(&first.to_string_lossy(), &link_path)
+    .await
+    .unwrap();
+  update_symlink(&second.to_string_lossy(), &link_path)
+    .await
+    .unwrap();
+
+  let target = read_symlink(&link_path).await.unwrap();
+  assert_eq!(target, second.to_string_lossy());
+
+  fs::remove_file(&link).unwrap();
+  fs::remove_file(&first).unwrap();
+  fs::remove_file(&second).unwrap();
+}
@@ -0,0 +1,34 @@
+use std::path::{Component, Path};
+
+/// Moves a path from one directory tree into another.
+///
+/// Paths inside `source_root` keep their location relative to it, so the
+/// target tree mirrors the source tree. Paths outside of it are placed
+/// directly in `target_root`.
+///
+/// # Arguments
+///
+/// * `path` - The path to relocate
+/// * `source_root` - Root of the tree the path usually lives in
+/// * `target_root` - Root of the tree the path is moved to
+///
+/// # Returns
+///
+/// A `String` containing the relocated path.
+pub fn relocate(path: &str, source_root: &str, target_root: &str) -> String {
+  let path = Path::new(path);
+  let relative = path
+    .strip_prefix(source_root)
+    .ok()
+    .filter(|relative| {
+      return relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    })
+    .or_else(|| path.file_name().map(Path::new))
+    .unwrap_or(path);
+  return Path::new(target_root)
+    .join(relative)
+    .to_string_lossy()
+    .to_string();
+}
@@ -0,0 +1,29 @@
+use crate::files::layout::relocate;
+
+#[test]
+fn test_relocate_mirrors_layout() {
+  assert_eq!(
+    relocate(
+      "/data/recordings/2024/standup.summary.md",
+      "/data/recordings",
+      "/data/transcripts"
+    ),
+    "/data/transcripts/2024/standup.summary.md"
+  );
+}
+
+#[test]
+fn test_relocate_outside_source_root() {
+  assert_eq!(
+    relocate(
+      "/tmp/podcast.de.srt",
+      "/data/recordings",
+      "/data/transcripts"
+    ),
+    "/data/transcripts/podcast.de.srt"
+  );
+  assert_eq!(
+    relocate("talk.summary.md", "/data/recordings", "/data/transcripts"),
+    "/data/transcripts/talk.summary.md"
+  );
+}
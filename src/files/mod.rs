@@ -7,7 +7,11 @@
 //! ## Submodules
 //!
 //! - [`operations`]: Core file system operations (read, write, delete, etc.)
+//! - [`digest`]: Checksums of file contents
+//! - [`layout`]: Relocation of paths between mirrored directory trees
+//! - [`sweep`]: Removal of intermediate files left behind by failed runs
 //! - [`temporary`]: RAII temporary file management with automatic cleanup
+//! - [`watch`]: Notifications about new audio files in a directory
 //! - [`errors`]: Error types for file operations
 //!
 //! ## Features
@@ -17,11 +21,25 @@
 //! - XDG directory compliance helpers
 //! - Comprehensive error handling with context
 
+#[cfg(feature = "cli")]
+pub mod digest;
 pub mod errors;
+pub mod layout;
 pub mod operations;
+pub mod sweep;
 pub mod temporary;
+#[cfg(feature = "cli")]
+pub mod watch;
 
+#[cfg(all(test, feature = "cli"))]
+mod digest_tests;
 #[cfg(test)]
 mod files_tests;
 #[cfg(test)]
+mod layout_tests;
+#[cfg(test)]
+mod sweep_tests;
+#[cfg(test)]
 mod temporary_tests;
+#[cfg(all(test, feature = "cli"))]
+mod watch_tests;
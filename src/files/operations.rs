@@ -1,9 +1,39 @@
+use std::collections::hash_map::RandomState;
+use std::fs::Permissions;
+use std::hash::BuildHasher;
+use std::io::{ErrorKind, Write};
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::io::AsyncWriteExt;
 
 use crate::files::errors::{FileError, FileResult};
+use crate::files::sweep::intermediate_name;
+use crate::vlog;
+
+const OVERWRITE_CHUNK_SIZE: usize = 64 * 1024;
+const PRIVATE_FILE_MODE: u32 = 0o600;
+const PRIVATE_FILE_ATTEMPTS: usize = 16;
+
+static SECURE_DELETE: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether removed files are overwritten before they are unlinked.
+///
+/// This should be called once at application startup from the
+/// configuration.
+///
+/// # Arguments
+///
+/// * `value` - Whether to overwrite files before removing them
+pub fn set_secure_delete(value: bool) {
+  SECURE_DELETE.store(value, Ordering::Relaxed);
+}
 
 /// Removes a file from the filesystem.
 ///
+/// With secure delete enabled, the file is overwritten with zeros first.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the file to be removed
@@ -12,10 +42,99 @@ use crate::files::errors::{FileError, FileResult};
 ///
 /// A `FileResult<()>` indicating success or failure.
 pub async fn remove_file(file_path: &str) -> FileResult<()> {
-  let path = Path::new(file_path);
-  return tokio::fs::remove_file(path)
+  if SECURE_DELETE.load(Ordering::Relaxed) {
+    return shred_file(file_path).await;
+  }
+  return tokio::fs::remove_file(file_path)
+    .await
+    .map_err(|e| FileError::FileRemove(file_path.to_string(), e));
+}
+
+/// Overwrites a file with zeros and removes it, whether or not secure
+/// delete is enabled.
+///
+/// Used for plaintext that was just encrypted, which should not stay
+/// readable on disk.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to be removed
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or failure.
+pub async fn shred_file(file_path: &str) -> FileResult<()> {
+  if let Err(e) = overwrite_file(file_path).await {
+    vlog!(
+      "Could not overwrite {} before removing it: {}",
+      file_path,
+      e
+    );
+  }
+  return tokio::fs::remove_file(file_path)
     .await
-    .map_err(|e| FileError::FileRemove(e.to_string()));
+    .map_err(|e| FileError::FileRemove(file_path.to_string(), e));
+}
+
+/// Removes a file from the filesystem without yielding to the runtime.
+///
+/// Used where awaiting is not possible, such as in `Drop` implementations,
+/// so that the file is gone even if the process exits right after.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to be removed
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or failure.
+pub fn remove_file_blocking(file_path: &str) -> FileResult<()> {
+  if SECURE_DELETE.load(Ordering::Relaxed)
+    && let Err(e) = overwrite_contents(Path::new(file_path))
+  {
+    vlog!(
+      "Could not overwrite {} before removing it: {}",
+      file_path,
+      e
+    );
+  }
+  return std::fs::remove_file(file_path)
+    .map_err(|e| FileError::FileRemove(file_path.to_string(), e));
+}
+
+/// Overwrites the contents of a file with zeros, keeping its length.
+///
+/// This is best effort: copy-on-write and journaling file systems, snapshots
+/// and SSD wear leveling can keep copies of the old blocks elsewhere.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to overwrite
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or failure.
+pub async fn overwrite_file(file_path: &str) -> FileResult<()> {
+  let path = file_path.to_string();
+  return tokio::task::spawn_blocking(move || {
+    return overwrite_contents(Path::new(&path));
+  })
+  .await
+  .map_err(std::io::Error::other)
+  .and_then(|result| return result)
+  .map_err(|e| FileError::FileWrite(file_path.to_string(), e));
+}
+
+fn overwrite_contents(path: &Path) -> std::io::Result<()> {
+  let mut file = std::fs::OpenOptions::new().write(true).open(path)?;
+  let mut remaining = file.metadata()?.len();
+  let zeros = vec![0u8; OVERWRITE_CHUNK_SIZE];
+  while remaining > 0 {
+    let length = remaining.min(OVERWRITE_CHUNK_SIZE as u64) as usize;
+    file.write_all(&zeros[..length])?;
+    remaining -= length as u64;
+  }
+  return file.sync_all();
 }
 
 /// Creates a directory and all parent directories if they don't exist.
@@ -30,7 +149,112 @@ pub async fn remove_file(file_path: &str) -> FileResult<()> {
 pub async fn create_directory_all(dir_path: &str) -> FileResult<()> {
   return tokio::fs::create_dir_all(dir_path)
     .await
-    .map_err(|e| FileError::DirectoryCreate(e.to_string()));
+    .map_err(|e| FileError::DirectoryCreate(dir_path.to_string(), e));
+}
+
+/// Creates an empty intermediate file that only the current user can read.
+///
+/// The name gets a random part, so runs on inputs with the same name do not
+/// collide, and the file must not exist yet, so a link planted in a shared
+/// directory such as `/tmp` is never followed. The file carries the
+/// intermediate prefix, so `clean --temp` removes it if a run is
+/// interrupted.
+///
+/// # Arguments
+///
+/// * `dir_path` - The directory to create the file in
+/// * `name` - The file name to make unique
+///
+/// # Returns
+///
+/// A `FileResult<String>` containing the path of the new file or an error.
+pub async fn create_private_file(
+  dir_path: &str,
+  name: &str,
+) -> FileResult<String> {
+  let mut last_error = None;
+  for _ in 0..PRIVATE_FILE_ATTEMPTS {
+    let unique = RandomState::new().hash_one(name) as u32;
+    let file_name = intermediate_name(&format!("{:08x}_{}", unique, name));
+    let path = Path::new(dir_path)
+      .join(file_name)
+      .to_string_lossy()
+      .to_string();
+    let created = tokio::fs::OpenOptions::new()
+      .write(true)
+      .create_new(true)
+      .mode(PRIVATE_FILE_MODE)
+      .open(&path)
+      .await;
+    match created {
+      Ok(_) => return Ok(path),
+      Err(e) if e.kind() == ErrorKind::AlreadyExists => last_error = Some(e),
+      Err(e) => return Err(FileError::FileWrite(path, e)),
+    }
+  }
+  let error = last_error
+    .unwrap_or_else(|| std::io::Error::from(ErrorKind::AlreadyExists));
+  return Err(FileError::FileWrite(dir_path.to_string(), error));
+}
+
+/// Opens a file for writing that only the current user can read.
+///
+/// Without `overwrite` the file must not exist yet, so an existing file is
+/// never replaced and a planted link is never followed. With `overwrite` an
+/// existing file is truncated and its permissions narrowed.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to open
+/// * `overwrite` - Whether an existing file may be replaced
+///
+/// # Returns
+///
+/// A `FileResult<std::fs::File>` containing the opened file or an error.
+pub fn open_private_file(
+  file_path: &str,
+  overwrite: bool,
+) -> FileResult<std::fs::File> {
+  let write_error = |e| FileError::FileWrite(file_path.to_string(), e);
+  let mut options = std::fs::OpenOptions::new();
+  options.write(true).mode(PRIVATE_FILE_MODE);
+  if overwrite {
+    options.create(true).truncate(true);
+  } else {
+    options.create_new(true);
+  }
+  let file = options.open(file_path).map_err(write_error)?;
+  if overwrite {
+    file
+      .set_permissions(Permissions::from_mode(PRIVATE_FILE_MODE))
+      .map_err(write_error)?;
+  }
+  return Ok(file);
+}
+
+/// Writes data to a file that only the current user can read.
+///
+/// The file is opened like with [`open_private_file`].
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to write
+/// * `content` - The data to write
+/// * `overwrite` - Whether an existing file may be replaced
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or an error.
+pub async fn write_private_file(
+  file_path: &str,
+  content: &[u8],
+  overwrite: bool,
+) -> FileResult<()> {
+  let write_error = |e| FileError::FileWrite(file_path.to_string(), e);
+  let mut file =
+    tokio::fs::File::from_std(open_private_file(file_path, overwrite)?);
+  file.write_all(content).await.map_err(write_error)?;
+  return file.flush().await.map_err(write_error);
 }
 
 /// Validates that a file exists, returning an error if it doesn't.
@@ -74,5 +298,202 @@ pub async fn file_exists(file_path: &str) -> bool {
 pub async fn read_to_string(file_path: &str) -> FileResult<String> {
   return tokio::fs::read_to_string(file_path)
     .await
-    .map_err(|e| FileError::FileRead(e.to_string()));
+    .map_err(|e| FileError::FileRead(file_path.to_string(), e));
+}
+
+/// Reads the entire contents of a file into a byte vector.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to read
+///
+/// # Returns
+///
+/// A `FileResult<Vec<u8>>` containing the file contents or an error.
+pub async fn read_bytes(file_path: &str) -> FileResult<Vec<u8>> {
+  return tokio::fs::read(file_path)
+    .await
+    .map_err(|e| FileError::FileRead(file_path.to_string(), e));
+}
+
+/// Writes content to a file, replacing any existing content.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to write
+/// * `content` - The content to write
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or an error.
+pub async fn write_file(file_path: &str, content: &str) -> FileResult<()> {
+  return tokio::fs::write(file_path, content)
+    .await
+    .map_err(|e| FileError::FileWrite(file_path.to_string(), e));
+}
+
+/// Appends content to a file, creating the file if it does not exist.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file to append to
+/// * `content` - The content to append
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or an error.
+pub async fn append_to_file(file_path: &str, content: &str) -> FileResult<()> {
+  let mut file = tokio::fs::OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(file_path)
+    .await
+    .map_err(|e| FileError::FileWrite(file_path.to_string(), e))?;
+  file
+    .write_all(content.as_bytes())
+    .await
+    .map_err(|e| FileError::FileWrite(file_path.to_string(), e))?;
+  return file
+    .flush()
+    .await
+    .map_err(|e| FileError::FileWrite(file_path.to_string(), e));
+}
+
+/// Lists the files in a directory, sorted by path.
+///
+/// Subdirectories are skipped.
+///
+/// # Arguments
+///
+/// * `dir_path` - The path to the directory to list
+///
+/// # Returns
+///
+/// A `FileResult<Vec<String>>` containing the file paths or an error.
+pub async fn list_files(dir_path: &str) -> FileResult<Vec<String>> {
+  let mut entries = tokio::fs::read_dir(dir_path)
+    .await
+    .map_err(|e| FileError::DirectoryRead(dir_path.to_string(), e))?;
+
+  let mut files = Vec::new();
+  while let Some(entry) = entries
+    .next_entry()
+    .await
+    .map_err(|e| FileError::DirectoryRead(dir_path.to_string(), e))?
+  {
+    let is_file = entry
+      .file_type()
+      .await
+      .is_ok_and(|file_type| file_type.is_file());
+    if is_file {
+      files.push(entry.path().to_string_lossy().to_string());
+    }
+  }
+  files.sort();
+  return Ok(files);
+}
+
+/// Gets the size of a file in bytes.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file
+///
+/// # Returns
+///
+/// A `FileResult<u64>` containing the file size or an error.
+pub async fn file_size(file_path: &str) -> FileResult<u64> {
+  return tokio::fs::metadata(file_path)
+    .await
+    .map(|metadata| metadata.len())
+    .map_err(|e| FileError::FileRead(file_path.to_string(), e));
+}
+
+/// Gets the last modification time of a file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file
+///
+/// # Returns
+///
+/// A `FileResult<i64>` containing the modification time as a Unix timestamp
+/// in seconds or an error.
+pub async fn modified_time(file_path: &str) -> FileResult<i64> {
+  let modified = tokio::fs::metadata(file_path)
+    .await
+    .and_then(|metadata| metadata.modified())
+    .map_err(|e| FileError::FileRead(file_path.to_string(), e))?;
+  let timestamp = modified
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|duration| duration.as_secs() as i64)
+    .unwrap_or_default();
+  return Ok(timestamp);
+}
+
+/// Moves a file to a new location, replacing any existing file.
+///
+/// Files cannot be renamed across file systems, such as from a RAM disk to
+/// the home directory, so they are copied and the source is removed then.
+///
+/// # Arguments
+///
+/// * `source` - The path to the file to move
+/// * `destination` - The new path for the file
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or failure.
+pub async fn move_file(source: &str, destination: &str) -> FileResult<()> {
+  match tokio::fs::rename(source, destination).await {
+    Ok(()) => return Ok(()),
+    Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {}
+    Err(e) => return Err(FileError::FileMove(source.to_string(), e)),
+  }
+
+  vlog!("Copying {} to {} across file systems", source, destination);
+  if let Err(e) = tokio::fs::copy(source, destination).await {
+    let _ = tokio::fs::remove_file(destination).await;
+    return Err(FileError::FileMove(source.to_string(), e));
+  }
+  return remove_file(source).await;
+}
+
+/// Points a symbolic link at a target, replacing any existing link.
+///
+/// # Arguments
+///
+/// * `target` - The path the link should point to
+/// * `link_path` - The path of the symbolic link
+///
+/// # Returns
+///
+/// A `FileResult<()>` indicating success or failure.
+pub async fn update_symlink(target: &str, link_path: &str) -> FileResult<()> {
+  let target = std::path::absolute(target)
+    .map_err(|e| FileError::SymlinkCreate(link_path.to_string(), e))?;
+  if tokio::fs::symlink_metadata(link_path).await.is_ok() {
+    tokio::fs::remove_file(link_path)
+      .await
+      .map_err(|e| FileError::SymlinkCreate(link_path.to_string(), e))?;
+  }
+  return tokio::fs::symlink(target, link_path)
+    .await
+    .map_err(|e| FileError::SymlinkCreate(link_path.to_string(), e));
+}
+
+/// Reads the target of a symbolic link.
+///
+/// # Arguments
+///
+/// * `link_path` - The path of the symbolic link
+///
+/// # Returns
+///
+/// A `FileResult<String>` containing the link target or an error.
+pub async fn read_symlink(link_path: &str) -> FileResult<String> {
+  return tokio::fs::read_link(link_path)
+    .await
+    .map(|path| path.to_string_lossy().to_string())
+    .map_err(|e| FileError::FileRead(link_path.to_string(), e));
 }
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use crate::files::errors::{FileError, FileResult};
+use crate::files::operations;
+
+const INTERMEDIATE_PREFIX: &str = "lumine-tmp-";
+const STREAM_DIRECTORY_PREFIX: &str = "lumine-stream-";
+
+/// Gets the file name of an intermediate file of a run.
+///
+/// Intermediate files, such as converted files that are removed after
+/// transcription or segments of resumed recordings, carry a prefix of their
+/// own. Only files with it are swept, so kept recordings and files of other
+/// programs are never removed.
+///
+/// # Arguments
+///
+/// * `name` - The file name without the prefix
+///
+/// # Returns
+///
+/// The file name with the intermediate prefix, added only once.
+pub fn intermediate_name(name: &str) -> String {
+  if name.starts_with(INTERMEDIATE_PREFIX) {
+    return name.to_string();
+  }
+  return format!("{}{}", INTERMEDIATE_PREFIX, name);
+}
+
+/// Checks whether a file name belongs to an intermediate file of a run.
+///
+/// # Arguments
+///
+/// * `name` - The file name to check
+///
+/// # Returns
+///
+/// `true` if the file was named by [`intermediate_name`], `false` otherwise.
+pub fn is_intermediate(name: &str) -> bool {
+  return name.starts_with(INTERMEDIATE_PREFIX);
+}
+
+/// Removes intermediate files and stream chunk directories left in a
+/// directory by interrupted or failed runs.
+///
+/// A missing directory is treated as empty. Files are removed with
+/// [`operations::remove_file`], so secure delete applies to them.
+///
+/// # Arguments
+///
+/// * `directory` - The directory to sweep
+///
+/// # Returns
+///
+/// A `FileResult<Vec<String>>` containing the removed paths or an error.
+pub async fn sweep_directory(directory: &str) -> FileResult<Vec<String>> {
+  if !Path::new(directory).is_dir() {
+    return Ok(Vec::new());
+  }
+  let mut entries = tokio::fs::read_dir(directory)
+    .await
+    .map_err(|e| FileError::DirectoryRead(directory.to_string(), e))?;
+
+  let mut removed = Vec::new();
+  while let Some(entry) = entries
+    .next_entry()
+    .await
+    .map_err(|e| FileError::DirectoryRead(directory.to_string(), e))?
+  {
+    let path = entry.path().to_string_lossy().to_string();
+    let name = entry.file_name().to_string_lossy().to_string();
+    let Ok(file_type) = entry.file_type().await else {
+      continue;
+    };
+    if file_type.is_dir() && name.starts_with(STREAM_DIRECTORY_PREFIX) {
+      tokio::fs::remove_dir_all(&path)
+        .await
+        .map_err(|e| FileError::FileRemove(path.clone(), e))?;
+      removed.push(path);
+    } else if file_type.is_file() && is_intermediate(&name) {
+      operations::remove_file(&path).await?;
+      removed.push(path);
+    }
+  }
+  removed.sort();
+  return Ok(removed);
+}
@@ -0,0 +1,62 @@
+use std::fs;
+
+use crate::files::sweep::{
+  intermediate_name, is_intermediate, sweep_directory,
+};
+
+#[test]
+fn test_intermediate_name() {
+  assert_eq!(
+    intermediate_name("standup_merged.wav"),
+    "lumine-tmp-standup_merged.wav"
+  );
+  assert_eq!(
+    intermediate_name("lumine-tmp-standup_merged.wav"),
+    "lumine-tmp-standup_merged.wav"
+  );
+}
+
+#[test]
+fn test_is_intermediate() {
+  assert!(is_intermediate("lumine-tmp-standup_merged.wav"));
+  assert!(is_intermediate("lumine-tmp-standup_resumed_2.wav"));
+  assert!(is_intermediate("lumine-tmp-call_ch1_whisper.wav"));
+  assert!(!is_intermediate("audiocapture_2024-01-01_whisper.wav"));
+  assert!(!is_intermediate("standup_merged.wav"));
+  assert!(!is_intermediate("standup_muted.wav"));
+  assert!(!is_intermediate("standup.wav"));
+}
+
+#[tokio::test]
+async fn test_sweep_directory() {
+  let directory = std::env::temp_dir().join("test_sweep_directory");
+  let _ = fs::remove_dir_all(&directory);
+  fs::create_dir_all(directory.join("lumine-stream-42")).unwrap();
+  for name in [
+    "lumine-tmp-a_whisper.wav",
+    "lumine-tmp-a_muted.wav",
+    "b_whisper.wav",
+    "other_merged.wav",
+    "a.wav",
+  ] {
+    fs::write(directory.join(name), "audio").unwrap();
+  }
+
+  let removed = sweep_directory(&directory.to_string_lossy()).await.unwrap();
+  assert_eq!(removed.len(), 3);
+  assert!(!directory.join("lumine-tmp-a_whisper.wav").exists());
+  assert!(!directory.join("lumine-tmp-a_muted.wav").exists());
+  assert!(!directory.join("lumine-stream-42").exists());
+  assert!(directory.join("b_whisper.wav").exists());
+  assert!(directory.join("other_merged.wav").exists());
+  assert!(directory.join("a.wav").exists());
+
+  fs::remove_dir_all(&directory).unwrap();
+}
+
+#[tokio::test]
+async fn test_sweep_missing_directory() {
+  let directory = std::env::temp_dir().join("test_sweep_missing_directory");
+  let removed = sweep_directory(&directory.to_string_lossy()).await.unwrap();
+  assert!(removed.is_empty());
+}
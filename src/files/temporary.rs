@@ -1,5 +1,6 @@
 use crate::files::errors::FileResult;
 use crate::files::operations;
+use crate::vlog;
 
 /// RAII-style temporary file management.
 ///
@@ -57,13 +58,10 @@ impl TemporaryFile {
 
 impl Drop for TemporaryFile {
   fn drop(&mut self) {
-    if self.should_cleanup {
-      let path = self.path.clone();
-      tokio::spawn(async move {
-        if let Err(e) = operations::remove_file(&path).await {
-          eprintln!("Failed to cleanup temporary file '{}': {}", path, e);
-        }
-      });
+    if self.should_cleanup
+      && let Err(e) = operations::remove_file_blocking(&self.path)
+    {
+      vlog!("Failed to clean up temporary file {}: {}", self.path, e);
     }
   }
 }
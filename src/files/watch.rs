@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+use crate::files::errors::{FileError, FileResult};
+use crate::files::operations;
+use crate::files::sweep::is_intermediate;
+
+const CONVERTED_SUFFIX: &str = "_whisper.wav";
+const AUDIO_EXTENSIONS: &[&str] = &[
+  "aac", "aiff", "flac", "m4a", "mkv", "mov", "mp3", "mp4", "oga", "ogg",
+  "opus", "wav", "webm", "wma",
+];
+
+/// Checks whether a file is an audio file that watch mode transcribes.
+///
+/// Hidden files, intermediate files and converted `_whisper.wav` files
+/// written by Lumine itself are skipped.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file
+///
+/// # Returns
+///
+/// `true` if the file should be transcribed, `false` otherwise.
+pub fn is_watched_audio(file_path: &str) -> bool {
+  let path = Path::new(file_path);
+  let Some(name) = path.file_name().map(|name| name.to_string_lossy()) else {
+    return false;
+  };
+  if name.starts_with('.')
+    || is_intermediate(&name)
+    || name.ends_with(CONVERTED_SUFFIX)
+  {
+    return false;
+  }
+  return path
+    .extension()
+    .map(|extension| extension.to_string_lossy().to_lowercase())
+    .is_some_and(|extension| AUDIO_EXTENSIONS.contains(&extension.as_str()));
+}
+
+/// Reports files that are created or changed in a directory.
+///
+/// The directory is watched until the watcher is dropped.
+pub struct DirectoryWatcher {
+  _watcher: RecommendedWatcher,
+  paths: mpsc::UnboundedReceiver<String>,
+}
+
+impl DirectoryWatcher {
+  /// Starts watching a directory.
+  ///
+  /// # Arguments
+  ///
+  /// * `directory` - The directory to watch, without its subdirectories
+  ///
+  /// # Returns
+  ///
+  /// A `FileResult<DirectoryWatcher>` containing the watcher or an error.
+  pub fn new(directory: &str) -> FileResult<Self> {
+    let (sender, paths) = mpsc::unbounded_channel();
+    let mut watcher =
+      notify::recommended_watcher(move |result: notify::Result<Event>| {
+        let Ok(event) = result else {
+          return;
+        };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+          return;
+        }
+        for path in event.paths {
+          let _ = sender.send(path.to_string_lossy().to_string());
+        }
+      })
+      .map_err(|e| watch_error(directory, e))?;
+    watcher
+      .watch(Path::new(directory), RecursiveMode::NonRecursive)
+      .map_err(|e| watch_error(directory, e))?;
+    return Ok(DirectoryWatcher {
+      _watcher: watcher,
+      paths,
+    });
+  }
+
+  /// Waits for the next created or changed file.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<String>` containing the path of the file, or `None` if the
+  /// watcher stopped.
+  pub async fn next(&mut self) -> Option<String> {
+    return self.paths.recv().await;
+  }
+}
+
+/// Files that are waiting to be completely written.
+///
+/// A file counts as complete once its size is unchanged between two checks,
+/// so files that are still being copied or recorded are not picked up early.
+#[derive(Debug, Clone, Default)]
+pub struct PendingFiles {
+  sizes: HashMap<String, Option<u64>>,
+}
+
+impl PendingFiles {
+  /// Adds a file to wait for.
+  ///
+  /// Adding a file again restarts the wait.
+  ///
+  /// # Arguments
+  ///
+  /// * `file_path` - The path to the file
+  pub fn add(&mut self, file_path: String) {
+    self.sizes.insert(file_path, None);
+  }
+
+  /// Checks whether no files are waiting.
+  ///
+  /// # Returns
+  ///
+  /// `true` if no files are waiting, `false` otherwise.
+  pub fn is_empty(&self) -> bool {
+    return self.sizes.is_empty();
+  }
+
+  /// Takes the files whose size did not change since the last check.
+  ///
+  /// Files that no longer exist are forgotten.
+  ///
+  /// # Returns
+  ///
+  /// A `Vec<String>` containing the complete files, sorted by path.
+  pub async fn take_settled(&mut self) -> Vec<String> {
+    let mut settled = Vec::new();
+    let mut waiting = HashMap::new();
+    for (path, previous) in self.sizes.drain() {
+      let Ok(size) = operations::file_size(&path).await else {
+        continue;
+      };
+      if size > 0 && previous == Some(size) {
+        settled.push(path);
+      } else {
+        waiting.insert(path, Some(size));
+      }
+    }
+    self.sizes = waiting;
+    settled.sort();
+    return settled;
+  }
+}
+
+/// Wraps an error of the watcher, keeping the I/O error when there is one.
+fn watch_error(directory: &str, error: notify::Error) -> FileError {
+  let source = match error.kind {
+    notify::ErrorKind::Io(e) => e,
+    _ => std::io::Error::other(error),
+  };
+  return FileError::DirectoryWatch(directory.to_string(), source);
+}
@@ -0,0 +1,45 @@
+use std::fs;
+
+use crate::files::watch::{PendingFiles, is_watched_audio};
+
+#[test]
+fn test_is_watched_audio() {
+  assert!(is_watched_audio("/inbox/interview.mp3"));
+  assert!(is_watched_audio("/inbox/Memo.M4A"));
+  assert!(!is_watched_audio("/inbox/interview.txt"));
+  assert!(!is_watched_audio("/inbox/interview.srt"));
+  assert!(!is_watched_audio("/inbox/interview_whisper.wav"));
+  assert!(!is_watched_audio("/inbox/lumine-tmp-interview_merged.wav"));
+  assert!(!is_watched_audio("/inbox/.interview.mp3"));
+  assert!(!is_watched_audio("/inbox/interview"));
+}
+
+#[tokio::test]
+async fn test_pending_files_settle() {
+  let directory = std::env::temp_dir().join("test_pending_files_settle");
+  let _ = fs::remove_dir_all(&directory);
+  fs::create_dir_all(&directory).unwrap();
+  let file = directory.join("memo.wav");
+  fs::write(&file, "audio").unwrap();
+  let path = file.to_string_lossy().to_string();
+
+  let mut pending = PendingFiles::default();
+  pending.add(path.clone());
+  assert!(pending.take_settled().await.is_empty());
+
+  fs::write(&file, "more audio").unwrap();
+  assert!(pending.take_settled().await.is_empty());
+
+  assert_eq!(pending.take_settled().await, vec![path]);
+  assert!(pending.is_empty());
+
+  fs::remove_dir_all(&directory).unwrap();
+}
+
+#[tokio::test]
+async fn test_pending_files_forget_removed() {
+  let mut pending = PendingFiles::default();
+  pending.add(String::from("/nonexistent/lumine/memo.wav"));
+  assert!(pending.take_settled().await.is_empty());
+  assert!(pending.is_empty());
+}
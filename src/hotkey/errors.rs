@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+/// Hotkey errors.
+///
+/// Represents errors that can occur while registering the dictation hotkey.
+#[derive(Error, Debug)]
+pub enum HotkeyError {
+  #[error(
+    "Invalid hotkey: '{0}'. Please use modifiers followed by one key, such as 'ctrl+alt+space'."
+  )]
+  Invalid(String),
+
+  #[error(
+    "Cannot register hotkey: {0}. Global hotkeys need an X11 session, set an empty hotkey to toggle dictation with SIGUSR1 instead."
+  )]
+  Registration(String),
+
+  #[error(
+    "Global hotkeys are not supported on macOS. Set an empty hotkey and bind a shortcut that runs 'pkill -USR1 lumine' to toggle dictation instead."
+  )]
+  Unsupported,
+
+  #[error("Cannot listen for signals: {0}")]
+  Signal(String),
+}
+
+/// Result type for hotkey operations.
+pub type HotkeyResult<T> = Result<T, HotkeyError>;
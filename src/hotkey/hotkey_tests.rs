@@ -0,0 +1,59 @@
+use crate::hotkey::errors::HotkeyError;
+use crate::hotkey::{HotkeyEvent, HotkeyListener, parse_hotkey};
+
+#[test]
+fn test_parse_hotkey() {
+  assert!(parse_hotkey("ctrl+alt+space").is_ok());
+  assert!(parse_hotkey("super+F9").is_ok());
+
+  for hotkey in ["ctrl+", "ctrl+alt", "space+a+b"] {
+    match parse_hotkey(hotkey).unwrap_err() {
+      HotkeyError::Invalid(text) => assert_eq!(text, hotkey),
+      _ => panic!("Expected Invalid error"),
+    }
+  }
+}
+
+#[test]
+fn test_hotkey_event_while_held() {
+  assert!(HotkeyEvent::Pressed.starts_recording());
+  assert!(!HotkeyEvent::Released.starts_recording());
+  assert!(!HotkeyEvent::Pressed.stops_recording(false));
+  assert!(HotkeyEvent::Released.stops_recording(false));
+}
+
+#[test]
+fn test_hotkey_event_holds_recording() {
+  assert!(HotkeyEvent::Pressed.holds_recording(false));
+  assert!(!HotkeyEvent::Pressed.holds_recording(true));
+  assert!(!HotkeyEvent::Toggled.holds_recording(false));
+}
+
+#[tokio::test]
+async fn test_discard_pending_events() {
+  let mut listener = HotkeyListener::new("").unwrap();
+  listener._sender.send(HotkeyEvent::Pressed).unwrap();
+  listener._sender.send(HotkeyEvent::Released).unwrap();
+  listener.discard_pending();
+  listener._sender.send(HotkeyEvent::Toggled).unwrap();
+  assert_eq!(listener.next().await, Some(HotkeyEvent::Toggled));
+}
+
+#[test]
+fn test_hotkey_event_toggle() {
+  assert!(HotkeyEvent::Pressed.stops_recording(true));
+  assert!(!HotkeyEvent::Released.stops_recording(true));
+  assert!(HotkeyEvent::Toggled.starts_recording());
+  assert!(HotkeyEvent::Toggled.stops_recording(true));
+  assert!(HotkeyEvent::Toggled.stops_recording(false));
+}
+
+#[cfg(target_os = "macos")]
+#[tokio::test]
+async fn test_hotkey_is_refused_on_macos() {
+  match HotkeyListener::new("ctrl+alt+space") {
+    Err(HotkeyError::Unsupported) => {}
+    _ => panic!("Expected Unsupported error"),
+  }
+  assert!(HotkeyListener::new("").is_ok());
+}
@@ -0,0 +1,198 @@
+//! Global hotkey for dictation.
+//!
+//! Registers a system-wide keyboard shortcut and reports when it is pressed
+//! and released, so recordings can follow the key. The `SIGUSR1` signal
+//! toggles dictation like a key press, for desktops where shortcuts can
+//! only run commands, such as Wayland compositors and macOS.
+//!
+//! ## Main Components
+//!
+//! - [`HotkeyListener`]: Registered hotkey and signal with their events
+//! - [`HotkeyEvent`]: Press, release or toggle of the hotkey
+//! - [`HotkeyError`]: Error types for hotkey registration
+//! - [`HotkeyResult<T>`]: Result type alias for hotkey operations
+//!
+//! ## Limitations
+//!
+//! Global hotkeys are read from the X11 server on Linux. They are refused on
+//! macOS, where hotkey events are only delivered to an event loop on the
+//! main thread, so dictation is toggled with `SIGUSR1` there. Only one
+//! listener can receive hotkey events per process.
+
+pub mod errors;
+
+#[cfg(test)]
+mod hotkey_tests;
+
+use global_hotkey::hotkey::HotKey;
+use std::task::{Context, Poll, Waker};
+
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use tokio::signal::unix::{Signal, SignalKind, signal};
+use tokio::sync::mpsc;
+
+use crate::hotkey::errors::{HotkeyError, HotkeyResult};
+use crate::vlog;
+
+/// Event of the dictation hotkey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyEvent {
+  /// The hotkey was pressed
+  Pressed,
+  /// The hotkey was released
+  Released,
+  /// Dictation was toggled with the `SIGUSR1` signal
+  Toggled,
+}
+
+impl HotkeyEvent {
+  /// Checks whether the event starts a recording.
+  ///
+  /// # Returns
+  ///
+  /// `true` for presses and toggles, `false` otherwise.
+  pub fn starts_recording(&self) -> bool {
+    return matches!(self, HotkeyEvent::Pressed | HotkeyEvent::Toggled);
+  }
+
+  /// Checks whether the recording started by the event lasts while the
+  /// hotkey is held.
+  ///
+  /// # Arguments
+  ///
+  /// * `toggle` - Whether presses toggle recording instead of recording
+  ///   while the hotkey is held
+  ///
+  /// # Returns
+  ///
+  /// `true` for presses outside of toggle mode, `false` otherwise.
+  pub fn holds_recording(&self, toggle: bool) -> bool {
+    return *self == HotkeyEvent::Pressed && !toggle;
+  }
+
+  /// Checks whether the event stops a running recording.
+  ///
+  /// # Arguments
+  ///
+  /// * `toggle` - Whether presses toggle recording instead of recording
+  ///   while the hotkey is held
+  ///
+  /// # Returns
+  ///
+  /// `true` if the recording should stop, `false` otherwise.
+  pub fn stops_recording(&self, toggle: bool) -> bool {
+    return match self {
+      HotkeyEvent::Pressed => toggle,
+      HotkeyEvent::Released => !toggle,
+      HotkeyEvent::Toggled => true,
+    };
+  }
+}
+
+/// Listens for the dictation hotkey and the `SIGUSR1` signal.
+///
+/// The hotkey stays registered while the listener exists.
+pub struct HotkeyListener {
+  _manager: Option<GlobalHotKeyManager>,
+  _sender: mpsc::UnboundedSender<HotkeyEvent>,
+  receiver: mpsc::UnboundedReceiver<HotkeyEvent>,
+  signals: Signal,
+}
+
+impl HotkeyListener {
+  /// Registers the hotkey and starts listening for its events.
+  ///
+  /// # Arguments
+  ///
+  /// * `hotkey` - The hotkey, such as `ctrl+alt+space`, or an empty string
+  ///   to only listen for the `SIGUSR1` signal
+  ///
+  /// # Returns
+  ///
+  /// A `HotkeyResult<HotkeyListener>` containing the listener or an error if
+  /// the hotkey is invalid, could not be registered or global hotkeys are
+  /// not supported on this platform.
+  pub fn new(hotkey: &str) -> HotkeyResult<Self> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let manager = if hotkey.is_empty() {
+      None
+    } else {
+      Some(register(hotkey, sender.clone())?)
+    };
+    let signals = signal(SignalKind::user_defined1())
+      .map_err(|e| HotkeyError::Signal(e.to_string()))?;
+    return Ok(HotkeyListener {
+      _manager: manager,
+      _sender: sender,
+      receiver,
+      signals,
+    });
+  }
+
+  /// Waits for the next event of the hotkey or the signal.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<HotkeyEvent>` containing the event, or `None` when no more
+  /// events can be received.
+  pub async fn next(&mut self) -> Option<HotkeyEvent> {
+    return tokio::select! {
+      event = self.receiver.recv() => event,
+      signal = self.signals.recv() => signal.map(|_| HotkeyEvent::Toggled),
+    };
+  }
+
+  /// Discards the events received so far.
+  ///
+  /// Presses made while no recording can start, such as during
+  /// transcription, would otherwise start one as soon as it is possible.
+  pub fn discard_pending(&mut self) {
+    while self.receiver.try_recv().is_ok() {}
+    let mut context = Context::from_waker(Waker::noop());
+    while let Poll::Ready(Some(())) = self.signals.poll_recv(&mut context) {}
+  }
+}
+
+/// Parses a hotkey such as `ctrl+alt+space`.
+///
+/// # Arguments
+///
+/// * `hotkey` - Modifiers followed by one key, separated by `+`
+///
+/// # Returns
+///
+/// A `HotkeyResult<HotKey>` containing the parsed hotkey or an error if it
+/// is invalid.
+pub fn parse_hotkey(hotkey: &str) -> HotkeyResult<HotKey> {
+  return hotkey
+    .parse::<HotKey>()
+    .map_err(|_| HotkeyError::Invalid(hotkey.to_string()));
+}
+
+fn register(
+  hotkey: &str,
+  sender: mpsc::UnboundedSender<HotkeyEvent>,
+) -> HotkeyResult<GlobalHotKeyManager> {
+  let parsed = parse_hotkey(hotkey)?;
+  // The main thread runs the async runtime instead of the event loop that
+  // macOS delivers hotkey events to, so registered hotkeys would never fire.
+  if cfg!(target_os = "macos") {
+    return Err(HotkeyError::Unsupported);
+  }
+  GlobalHotKeyEvent::set_event_handler(Some(
+    move |event: GlobalHotKeyEvent| {
+      let event = match event.state() {
+        HotKeyState::Pressed => HotkeyEvent::Pressed,
+        HotKeyState::Released => HotkeyEvent::Released,
+      };
+      let _ = sender.send(event);
+    },
+  ));
+  let manager = GlobalHotKeyManager::new()
+    .map_err(|e| HotkeyError::Registration(e.to_string()))?;
+  manager
+    .register(parsed)
+    .map_err(|e| HotkeyError::Registration(e.to_string()))?;
+  vlog!("Registered dictation hotkey: {}", hotkey);
+  return Ok(manager);
+}
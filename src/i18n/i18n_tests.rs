@@ -0,0 +1,39 @@
+use crate::i18n::*;
+
+#[test]
+fn test_parse_language() {
+  assert_eq!(parse_language("de_DE.UTF-8"), "de");
+  assert_eq!(parse_language("en-US"), "en");
+  assert_eq!(parse_language("DE"), "de");
+  assert_eq!(parse_language("C.UTF-8"), "");
+  assert_eq!(parse_language("POSIX"), "");
+  assert_eq!(parse_language(""), "");
+}
+
+#[test]
+fn test_format_message() {
+  let messages = Messages::new("de").unwrap();
+  assert_eq!(
+    messages.format("recording-extended", &[("extension", String::from("10"))]),
+    Some(String::from("Aufnahme um 10 s verlängert."))
+  );
+  assert!(messages.format("missing-message", &[]).is_none());
+  assert!(Messages::new("xx").is_none());
+}
+
+#[test]
+fn test_translations_are_complete() {
+  let english = include_str!("locales/en.ftl");
+  let german = Messages::new("de").unwrap();
+  let ids = english
+    .lines()
+    .filter_map(|line| line.split_once(" = "))
+    .map(|(id, _)| id);
+  for id in ids {
+    assert!(
+      german.format(id, &[]).is_some(),
+      "missing translation: {}",
+      id
+    );
+  }
+}
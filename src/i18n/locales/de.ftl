@@ -0,0 +1,107 @@
+## Start und Befehle
+
+configuration-error = Konfigurationsfehler: { $error }
+reset-config-done = Die Konfiguration wurde auf die Standardwerte zurückgesetzt.
+reset-config-failed = Die Konfiguration konnte nicht zurückgesetzt werden: { $error }
+explain-causes = Mögliche Ursachen:
+explain-fixes = So lässt es sich beheben:
+explain-unknown-code = Unbekannter Fehlercode: { $code }. Fehlercodes sehen aus wie LUM-AUD-003.
+output-flag-unsupported = { $flag } kann nicht mit Live-Streams, getrennten Kanälen oder Anrufen verwendet werden, die nur reinen Text ausgeben.
+config-set-done = { $key } wurde auf { $value } gesetzt.
+config-source-default = Standardwert
+config-source-file = Konfigurationsdatei
+config-source-environment = Umgebungsvariable
+config-source-mode = Modus
+config-source-flag = Befehlszeilenoption
+update-disabled = Die Selbstaktualisierung ist im Abschnitt [update] der Konfiguration deaktiviert.
+update-current = Lumine { $version } ist aktuell.
+update-available = Lumine { $version } ist verfügbar. Führen Sie `lumine self-update` aus, um es zu installieren.
+update-installed = Lumine wurde auf { $version } aktualisiert: { $path }
+devices-none = Keine Audioeingabegeräte gefunden.
+device-entry = [{ $index }] { $name }
+device-entry-default = [{ $index }] { $name } (Systemstandard)
+device-test-started = Für den Gerätetest werden { $seconds } Sekunden aufgenommen, bitte jetzt sprechen…
+device-test-playing = Die Testaufnahme wird abgespielt…
+device-test-device = Gerät: [{ $index }] { $name }
+device-test-levels = Spitzenpegel: { $peak } dBFS, RMS-Pegel: { $rms } dBFS
+device-test-works = Das Gerät scheint zu funktionieren. Verwenden Sie es mit preferred_audio_input_device = "{ $name }".
+device-test-silent = Es wurde kein Signal aufgenommen. Das Gerät ist möglicherweise stummgeschaltet, nicht verbunden oder nicht das Mikrofon, in das Sie gesprochen haben.
+device-test-too-quiet = Der Pegel blieb unter der Stilleschwelle von -{ $threshold } dB, Sprache würde also als Stille gelten. Sprechen Sie näher am Mikrofon, erhöhen Sie seine Verstärkung oder senken Sie silence_detect_noise.
+device-test-clipping = Das Signal wurde übersteuert. Verringern Sie die Eingangslautstärke oder die Verstärkung des Geräts.
+stats-empty = Noch keine Nutzung erfasst. Nutzungsstatistiken werden lokal erfasst, wenn sie im Abschnitt [usage] der Konfiguration aktiviert sind.
+stats-total = { $minutes } Minuten Audio in { $runs } Durchläufen transkribiert
+stats-rtf = Durchschnittlicher Echtzeitfaktor: { $rtf }
+stats-backend = { $backend }: { $runs } Durchläufe, { $minutes } Minuten, Echtzeitfaktor { $rtf }
+stats-cost = { $month }: { $cost } { $currency } für { $minutes } Minuten
+temp-cleaned = Entfernte übrig gebliebene temporäre Dateien: { $count }
+notes-saved = Notizen gespeichert in: { $path }
+draft = Entwurf: { $text }
+
+## Warnungen und Statusmeldungen
+
+warning = Warnung: { $message }
+two-pass-no-draft-url = Warnung: Der Zwei-Durchgangs-Modus benötigt whisper.draft_url, kein Entwurf.
+duplicate-skipped = { $file } wird übersprungen: gleiche Aufnahme wie { $source }, deren Transkript wird verwendet.
+music-skipped = Musik übersprungen: { $ranges }
+encryption-failed = Warnung: { $path } konnte nicht verschlüsselt werden: { $error }
+email-attachment-failed = Warnung: Das Transkript wird ohne Audio verschickt: { $error }
+email-subject = Transkript { $date }
+email-duration = Dauer: { $seconds } s
+email-no-recipient = kein Empfänger konfiguriert
+obs-closed = OBS hat die Verbindung geschlossen. { $reason }
+obs-caption-rejected = OBS hat die Untertitel abgelehnt (Status { $code }). { $comment }
+file-skipped = Warnung: { $file } wird übersprungen: { $error }
+corpus-empty = Keine Audiodateien mit Referenztranskripten in '{ $directory }'.
+notes-started = Notizen werden in { $path } geschrieben, Strg+C zum Beenden.
+notes-chunk-skipped = Warnung: Abschnitt wird übersprungen: { $error }
+watch-started = { $directory } wird auf neue Audiodateien überwacht. Mit Strg+C beenden.
+watch-not-directory = Kein Verzeichnis: '{ $directory }'.
+watch-transcribed = { $file } wurde transkribiert
+watch-file-failed = { $file } konnte nicht transkribiert werden: { $error }
+watch-stopped = Überwachung beendet. Transkribierte Dateien: { $count }
+dictation-started = Diktieren mit { $trigger }. Mit Strg+C beenden.
+dictation-failed = Das Diktat konnte nicht transkribiert werden: { $error }
+dictation-stopped = Diktieren beendet. Transkribierte Diktate: { $count }
+stream-started = { $url } wird transkribiert, zum Beenden Strg+C drücken.
+stop-phrase-heard = Stoppphrase erkannt, wird beendet.
+stream-chunk-skipped = Warnung: Abschnitt des Streams wird übersprungen: { $error }
+call-no-source = Keine Anrufquelle konfiguriert. Setze `source` im Abschnitt [call] oder übergib --source.
+recording-name-invalid = Ungültiger Aufnahmename: '{ $name }'. Verwende einen einfachen Dateinamen ohne Verzeichnisse; das Verzeichnis wird mit --directory übergeben.
+recording-exists = '{ $path }' existiert bereits. Wähle einen anderen Namen oder übergib --force, um die Datei zu ersetzen.
+recording-exists-kept = '{ $path }' existiert bereits, daher wurde die Aufnahme als '{ $kept }' behalten. Benenne sie um oder übergib beim nächsten Mal --force, um die Datei zu ersetzen.
+no-kept-recording = Keine behaltene Aufnahme gefunden: { $error }
+no-saved-transcript = Kein gespeichertes Transkript gefunden: { $error }
+speaker-a = Sprecher A
+speaker-b = Sprecher B
+recording-interrupted = Aufnahme unterbrochen: Das Eingabegerät „{ $device }“ ist nicht mehr verfügbar. Die bisherige Aufnahme wurde behalten: { $path }
+recording-resumed = Aufnahme wird auf „{ $device }“ fortgesetzt.
+recording-stops-soon = Die Aufnahme endet in { $seconds } s (maximale Dauer erreicht).
+recording-stops-soon-extendable = Die Aufnahme endet in { $seconds } s. Eingabetaste drücken, um sie um { $extension } s zu verlängern.
+recording-extended = Aufnahme um { $extension } s verlängert.
+
+## Barrierefreie Statuszeilen
+
+status-device-selected = Mikrofon: { $name }.
+status-recording-started = Aufnahme läuft. Bitte sprechen.
+status-recording-stopped = Aufnahme nach { $seconds } Sekunden beendet.
+status-transcription-started = Transkription läuft.
+status-completed = Fertig.
+
+## Fehler
+
+error-file = Dateifehler: { $error }
+error-recording = Aufnahmefehler: { $error }
+error-audio-conversion = Fehler bei der Audiokonvertierung: { $error }
+error-transcription = Fehler bei der Transkription: { $error }
+error-post-processing = Fehler bei der Nachbearbeitung: { $error }
+error-output = Ausgabefehler: { $error }
+error-encryption = Verschlüsselungsfehler: { $error }
+error-update = Aktualisierungsfehler: { $error }
+error-hotkey = Tastenkürzelfehler: { $error }
+error-cancelled = Vorgang abgebrochen.
+error-force-quit = Beendet, ohne aufzuräumen.
+error-config-file-read = Die Konfigurationsdatei kann nicht gelesen werden: „{ $error }“. Bitte Dateiberechtigungen prüfen und sicherstellen, dass die Datei existiert.
+error-config-parse = Die Konfigurationsdatei ist ungültig: „{ $error }“. Bitte die Syntax prüfen und sicherstellen, dass alle erforderlichen Felder vorhanden sind.
+error-config-unknown-mode = Unbekannter Modus: „{ $mode }“. Bitte „interactive“, „archive“ oder einen in einem Abschnitt [mode.<name>] der Konfigurationsdatei definierten Modus verwenden.
+error-config-unknown-key = Unbekannter Konfigurationsschlüssel: '{ $key }'. Verwenden Sie einen Abschnitt und ein Feld wie 'recorder.silence_limit'.
+error-config-invalid-value = Ungültiger Wert '{ $value }' für '{ $key }': { $error }
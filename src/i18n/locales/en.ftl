@@ -0,0 +1,107 @@
+## Startup and commands
+
+configuration-error = Configuration Error: { $error }
+reset-config-done = Configuration has been reset to default values.
+reset-config-failed = Failed to reset configuration: { $error }
+explain-causes = Possible causes:
+explain-fixes = How to fix it:
+explain-unknown-code = Unknown error code: { $code }. Error codes look like LUM-AUD-003.
+output-flag-unsupported = { $flag } cannot be used with live streams, split channels or calls, which only print plain text.
+config-set-done = Set { $key } to { $value }.
+config-source-default = default
+config-source-file = config file
+config-source-environment = environment variable
+config-source-mode = mode
+config-source-flag = command-line flag
+update-disabled = Self-update is disabled in the [update] section of the configuration.
+update-current = Lumine { $version } is up to date.
+update-available = Lumine { $version } is available. Run `lumine self-update` to install it.
+update-installed = Updated Lumine to { $version }: { $path }
+devices-none = No audio input devices found.
+device-entry = [{ $index }] { $name }
+device-entry-default = [{ $index }] { $name } (system default)
+device-test-started = Recording { $seconds } seconds for the device test, please speak now…
+device-test-playing = Playing the test recording back…
+device-test-device = Device: [{ $index }] { $name }
+device-test-levels = Peak level: { $peak } dBFS, RMS level: { $rms } dBFS
+device-test-works = The device appears to work. Use it with preferred_audio_input_device = "{ $name }".
+device-test-silent = No signal was recorded. The device may be muted, disconnected or not the microphone you spoke into.
+device-test-too-quiet = The level stayed below the silence threshold of -{ $threshold } dB, so speech would count as silence. Speak closer to the microphone, raise its gain or lower silence_detect_noise.
+device-test-clipping = The signal clipped. Lower the input volume or the gain of the device.
+stats-empty = No usage recorded yet. Usage statistics are recorded locally when enabled in the [usage] section of the configuration.
+stats-total = { $minutes } minutes of audio transcribed in { $runs } runs
+stats-rtf = Average real-time factor: { $rtf }
+stats-backend = { $backend }: { $runs } runs, { $minutes } minutes, real-time factor { $rtf }
+stats-cost = { $month }: { $cost } { $currency } for { $minutes } minutes
+temp-cleaned = Removed leftover temporary files: { $count }
+notes-saved = Notes saved in: { $path }
+draft = Draft: { $text }
+
+## Warnings and status messages
+
+warning = Warning: { $message }
+two-pass-no-draft-url = Warning: Two-pass mode needs whisper.draft_url, no draft.
+duplicate-skipped = Skipping { $file }: same audio as { $source }, reusing its transcript.
+music-skipped = Skipped music: { $ranges }
+encryption-failed = Warning: Could not encrypt { $path }: { $error }
+email-attachment-failed = Warning: Mailing the transcript without audio: { $error }
+email-subject = Transcript { $date }
+email-duration = Duration: { $seconds }s
+email-no-recipient = no recipient configured
+obs-closed = OBS closed the connection. { $reason }
+obs-caption-rejected = OBS rejected the caption (status { $code }). { $comment }
+file-skipped = Warning: Skipping { $file }: { $error }
+corpus-empty = No audio files with reference transcripts in '{ $directory }'.
+notes-started = Taking notes in { $path }, press Ctrl+C to stop.
+notes-chunk-skipped = Warning: Skipping chunk: { $error }
+watch-started = Watching { $directory } for new audio files. Press Ctrl+C to stop.
+watch-not-directory = Not a directory: '{ $directory }'.
+watch-transcribed = Transcribed { $file }
+watch-file-failed = Could not transcribe { $file }: { $error }
+watch-stopped = Stopped watching. Files transcribed: { $count }
+dictation-started = Dictating with { $trigger }. Press Ctrl+C to stop.
+dictation-failed = Could not transcribe the dictation: { $error }
+dictation-stopped = Stopped dictating. Dictations transcribed: { $count }
+stream-started = Transcribing { $url }, press Ctrl+C to stop.
+stop-phrase-heard = Heard the stop phrase, stopping.
+stream-chunk-skipped = Warning: Skipping stream chunk: { $error }
+call-no-source = No call source configured. Set `source` in the [call] section or pass --source.
+recording-name-invalid = Invalid recording name: '{ $name }'. Use a plain file name without directories; pass the directory with --directory.
+recording-exists = '{ $path }' already exists. Choose another name or pass --force to replace it.
+recording-exists-kept = '{ $path }' already exists, so the recording was kept as '{ $kept }'. Rename it, or pass --force next time to replace the file.
+no-kept-recording = No kept recording found: { $error }
+no-saved-transcript = No saved transcript found: { $error }
+speaker-a = Speaker A
+speaker-b = Speaker B
+recording-interrupted = Recording interrupted: audio input device '{ $device }' is no longer available. Partial recording kept: { $path }
+recording-resumed = Resuming recording on '{ $device }'.
+recording-stops-soon = Recording will stop in { $seconds }s (maximum duration reached).
+recording-stops-soon-extendable = Recording will stop in { $seconds }s. Press Enter to extend by { $extension }s.
+recording-extended = Recording extended by { $extension }s.
+
+## Accessible status lines
+
+status-device-selected = Microphone: { $name }.
+status-recording-started = Recording. Speak now.
+status-recording-stopped = Recording stopped after { $seconds } seconds.
+status-transcription-started = Transcribing.
+status-completed = Done.
+
+## Errors
+
+error-file = File Error: { $error }
+error-recording = Recording Error: { $error }
+error-audio-conversion = Audio Conversion Error: { $error }
+error-transcription = Transcription Error: { $error }
+error-post-processing = Post-processing Error: { $error }
+error-output = Output Error: { $error }
+error-encryption = Encryption Error: { $error }
+error-update = Update Error: { $error }
+error-hotkey = Hotkey Error: { $error }
+error-cancelled = Operation cancelled.
+error-force-quit = Stopped without cleaning up.
+error-config-file-read = Cannot read configuration file: '{ $error }'. Please check file permissions and ensure the file exists.
+error-config-parse = Configuration file is invalid: '{ $error }'. Please check the syntax and ensure all required fields are present.
+error-config-unknown-mode = Unknown mode: '{ $mode }'. Use 'interactive', 'archive' or a mode defined in a [mode.<name>] section of the configuration file.
+error-config-unknown-key = Unknown configuration key: '{ $key }'. Use a section and field such as 'recorder.silence_limit'.
+error-config-invalid-value = Invalid value '{ $value }' for '{ $key }': { $error }
@@ -0,0 +1,190 @@
+//! Translation of user-facing messages.
+//!
+//! Errors and status messages are looked up by identifier in Fluent
+//! resources compiled into the binary. The language is taken from the
+//! configuration or, when not configured, from the `LC_ALL`, `LC_MESSAGES`
+//! and `LANG` environment variables. Messages missing from a translation
+//! fall back to English.
+//!
+//! ## Components
+//!
+//! - [`set_language`]: Select the message language at application startup
+//! - [`translate`]: Look up a message in the selected language
+//! - [`tr!`]: Macro for looking up messages with named arguments
+//! - [`Messages`]: Message bundle of a single language
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // In main.rs, after loading the configuration:
+//! set_language(&config.get_language());
+//!
+//! // Anywhere in the codebase:
+//! eprintln!("{}", tr!("recording-extended", extension = 10));
+//! ```
+
+#[cfg(test)]
+mod i18n_tests;
+
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+const DEFAULT_LANGUAGE: &str = "en";
+const LANGUAGE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+const RESOURCES: [(&str, &str); 2] = [
+  ("en", include_str!("locales/en.ftl")),
+  ("de", include_str!("locales/de.ftl")),
+];
+
+static MESSAGES: OnceLock<Vec<Messages>> = OnceLock::new();
+
+/// Message bundle of a single language.
+pub struct Messages {
+  bundle: FluentBundle<FluentResource>,
+}
+
+impl Messages {
+  /// Creates the message bundle of a language.
+  ///
+  /// # Arguments
+  ///
+  /// * `language` - Language code such as `de`
+  ///
+  /// # Returns
+  ///
+  /// An `Option<Messages>` containing the bundle, or `None` if there is no
+  /// translation for the language.
+  pub fn new(language: &str) -> Option<Messages> {
+    let (code, source) =
+      RESOURCES.iter().find(|(code, _)| *code == language)?;
+    let identifier: LanguageIdentifier = code.parse().ok()?;
+    let resource = FluentResource::try_new(source.to_string()).ok()?;
+    let mut bundle = FluentBundle::new_concurrent(vec![identifier]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).ok()?;
+    return Some(Messages { bundle });
+  }
+
+  /// Formats a message.
+  ///
+  /// # Arguments
+  ///
+  /// * `id` - Identifier of the message
+  /// * `args` - Named arguments referenced by the message
+  ///
+  /// # Returns
+  ///
+  /// An `Option<String>` containing the formatted message, or `None` if the
+  /// bundle has no such message.
+  pub fn format(&self, id: &str, args: &[(&str, String)]) -> Option<String> {
+    let pattern = self.bundle.get_message(id)?.value()?;
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+      fluent_args.set(*name, value.clone());
+    }
+    let mut errors = Vec::new();
+    let message =
+      self
+        .bundle
+        .format_pattern(pattern, Some(&fluent_args), &mut errors);
+    return Some(message.to_string());
+  }
+}
+
+/// Selects the language of user-facing messages.
+///
+/// This should be called once at application startup, after loading the
+/// configuration. Messages looked up before are shown in the language of
+/// the environment.
+///
+/// # Arguments
+///
+/// * `language` - Language code such as `de` (empty for the environment)
+pub fn set_language(language: &str) {
+  let _ = MESSAGES.set(load_messages(language));
+}
+
+/// Looks up a message in the selected language.
+///
+/// # Arguments
+///
+/// * `id` - Identifier of the message
+/// * `args` - Named arguments referenced by the message
+///
+/// # Returns
+///
+/// A `String` containing the message, its English version if it is not
+/// translated, or the identifier if it does not exist.
+pub fn translate(id: &str, args: &[(&str, String)]) -> String {
+  return MESSAGES
+    .get_or_init(|| load_messages(""))
+    .iter()
+    .find_map(|messages| messages.format(id, args))
+    .unwrap_or_else(|| id.to_string());
+}
+
+/// Resolves the message language from a locale such as `de_DE.UTF-8`.
+///
+/// # Arguments
+///
+/// * `locale` - Configured language or locale environment variable
+///
+/// # Returns
+///
+/// A `String` containing the lowercase language code, empty if the locale
+/// names no language.
+pub fn parse_language(locale: &str) -> String {
+  let language = locale
+    .split(['_', '-', '.', '@'])
+    .next()
+    .unwrap_or_default()
+    .to_lowercase();
+  if language == "c" || language == "posix" {
+    return String::new();
+  }
+  return language;
+}
+
+fn load_messages(language: &str) -> Vec<Messages> {
+  let language = if language.is_empty() {
+    LANGUAGE_VARIABLES
+      .iter()
+      .filter_map(|variable| std::env::var(variable).ok())
+      .map(|value| parse_language(&value))
+      .find(|language| !language.is_empty())
+      .unwrap_or_default()
+  } else {
+    parse_language(language)
+  };
+
+  return [language.as_str(), DEFAULT_LANGUAGE]
+    .into_iter()
+    .filter_map(Messages::new)
+    .collect();
+}
+
+/// Looks up a user-facing message in the selected language.
+///
+/// Named arguments are converted with `to_string()`.
+///
+/// # Examples
+///
+/// ```ignore
+/// tr!("error-cancelled");
+/// tr!("notes-saved", path = notes_path);
+/// ```
+#[macro_export]
+macro_rules! tr {
+  ($id:expr) => {
+    $crate::i18n::translate($id, &[])
+  };
+  ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {
+    $crate::i18n::translate(
+      $id,
+      &[$((stringify!($name), $value.to_string())),+],
+    )
+  };
+}
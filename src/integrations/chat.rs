@@ -0,0 +1,145 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::integrations::errors::{IntegrationError, IntegrationResult};
+use crate::network::HttpClient;
+use crate::vlog;
+
+const SNIPPET_LENGTH: usize = 280;
+const SENDER_NAME: &str = "lumine";
+
+/// Chat services that accept incoming-webhook style messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatService {
+  /// Slack incoming webhook
+  Slack,
+  /// Discord channel webhook
+  Discord,
+  /// Matrix generic webhook, as provided by hookshot
+  Matrix,
+}
+
+impl ChatService {
+  /// Parses a chat service from its configured name.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - Service name, case-insensitive
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<ChatService>` containing the service or an error.
+  pub fn from_name(name: &str) -> IntegrationResult<ChatService> {
+    return match name.trim().to_lowercase().as_str() {
+      "slack" => Ok(ChatService::Slack),
+      "discord" => Ok(ChatService::Discord),
+      "matrix" => Ok(ChatService::Matrix),
+      _ => Err(IntegrationError::UnknownChatService(name.to_string())),
+    };
+  }
+}
+
+/// Posts finished transcripts to a chat channel through a webhook.
+#[derive(Debug, Clone)]
+pub struct ChatNotifier {
+  service: ChatService,
+  client: HttpClient,
+}
+
+impl ChatNotifier {
+  /// Creates a new ChatNotifier for a service and webhook URL.
+  ///
+  /// # Arguments
+  ///
+  /// * `service` - Name of the chat service
+  /// * `webhook_url` - Incoming webhook URL of the channel
+  /// * `cancel` - Token that aborts an in-flight post when cancelled
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<ChatNotifier>` containing the notifier or an error
+  /// if the service is unknown.
+  pub fn new(
+    service: &str,
+    webhook_url: String,
+    cancel: CancellationToken,
+  ) -> IntegrationResult<Self> {
+    return Ok(ChatNotifier {
+      service: ChatService::from_name(service)?,
+      client: HttpClient::new(webhook_url).with_cancellation(cancel),
+    });
+  }
+
+  /// Posts a transcript to the configured channel.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - The transcript text
+  /// * `duration` - Duration of the audio in seconds, if known
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<()>` indicating success or failure.
+  pub async fn send(
+    &self,
+    text: &str,
+    duration: Option<f64>,
+  ) -> IntegrationResult<()> {
+    vlog!("Posting transcript to {:?}", self.service);
+    let payload = build_payload(self.service, &build_message(text, duration));
+    return self
+      .client
+      .post_json(&payload)
+      .await
+      .map_err(|e| IntegrationError::Delivery(e.to_string()));
+  }
+}
+
+/// Builds the chat message for a transcript.
+///
+/// The message contains the audio duration, when known, and a snippet of
+/// the transcript shortened to a fixed number of characters.
+///
+/// # Arguments
+///
+/// * `text` - The transcript text
+/// * `duration` - Duration of the audio in seconds, if known
+///
+/// # Returns
+///
+/// A `String` containing the message.
+pub fn build_message(text: &str, duration: Option<f64>) -> String {
+  let text = text.trim();
+  let snippet = match text.char_indices().nth(SNIPPET_LENGTH) {
+    Some((end, _)) => format!("{}…", text[..end].trim_end()),
+    None => text.to_string(),
+  };
+
+  return match duration {
+    Some(duration) => format!("New transcript ({:.0}s): {}", duration, snippet),
+    None => format!("New transcript: {}", snippet),
+  };
+}
+
+/// Shapes a message into the webhook payload expected by a service.
+///
+/// # Arguments
+///
+/// * `service` - The chat service receiving the message
+/// * `message` - The message to send
+///
+/// # Returns
+///
+/// A `serde_json::Value` containing the payload.
+pub fn build_payload(service: ChatService, message: &str) -> serde_json::Value {
+  return match service {
+    ChatService::Slack => serde_json::json!({ "text": message }),
+    ChatService::Discord => serde_json::json!({
+      "content": message,
+      "username": SENDER_NAME,
+    }),
+    ChatService::Matrix => serde_json::json!({
+      "text": message,
+      "username": SENDER_NAME,
+    }),
+  };
+}
@@ -0,0 +1,57 @@
+use crate::integrations::chat::{ChatService, build_message, build_payload};
+use crate::integrations::errors::IntegrationError;
+
+#[test]
+fn test_chat_service_from_name() {
+  assert_eq!(ChatService::from_name("slack").unwrap(), ChatService::Slack);
+  assert_eq!(
+    ChatService::from_name(" Discord ").unwrap(),
+    ChatService::Discord
+  );
+  assert_eq!(
+    ChatService::from_name("MATRIX").unwrap(),
+    ChatService::Matrix
+  );
+}
+
+#[test]
+fn test_chat_service_from_unknown_name() {
+  let result = ChatService::from_name("irc");
+  assert!(matches!(
+    result,
+    Err(IntegrationError::UnknownChatService(name)) if name == "irc"
+  ));
+}
+
+#[test]
+fn test_build_message_with_duration() {
+  let message = build_message(" Hello world ", Some(12.4));
+  assert_eq!(message, "New transcript (12s): Hello world");
+}
+
+#[test]
+fn test_build_message_without_duration() {
+  let message = build_message("Hello world", None);
+  assert_eq!(message, "New transcript: Hello world");
+}
+
+#[test]
+fn test_build_message_truncates_long_transcripts() {
+  let text = "é".repeat(300);
+  let message = build_message(&text, None);
+  assert!(message.ends_with('…'));
+  assert_eq!(message.chars().count(), "New transcript: ".len() + 281);
+}
+
+#[test]
+fn test_build_payload_per_service() {
+  let slack = build_payload(ChatService::Slack, "hi");
+  assert_eq!(slack, serde_json::json!({ "text": "hi" }));
+
+  let discord = build_payload(ChatService::Discord, "hi");
+  assert_eq!(discord["content"], "hi");
+
+  let matrix = build_payload(ChatService::Matrix, "hi");
+  assert_eq!(matrix["text"], "hi");
+  assert_eq!(matrix["username"], "lumine");
+}
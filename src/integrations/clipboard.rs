@@ -0,0 +1,121 @@
+use crate::integrations::errors::{IntegrationError, IntegrationResult};
+use crate::process::executor::ProcessExecutor;
+use crate::vlog;
+
+/// Command-line tools that copy their input to the system clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardTool {
+  /// `wl-copy` from wl-clipboard on Wayland
+  WlCopy,
+  /// `xclip` on X11
+  Xclip,
+  /// `xsel` on X11
+  Xsel,
+  /// `pbcopy` on macOS
+  Pbcopy,
+}
+
+impl ClipboardTool {
+  /// Gets the command and arguments that copy standard input.
+  ///
+  /// # Returns
+  ///
+  /// The command and its arguments.
+  pub fn command(&self) -> (&'static str, &'static [&'static str]) {
+    return match self {
+      ClipboardTool::WlCopy => ("wl-copy", &[]),
+      ClipboardTool::Xclip => ("xclip", &["-selection", "clipboard"]),
+      ClipboardTool::Xsel => ("xsel", &["--clipboard", "--input"]),
+      ClipboardTool::Pbcopy => ("pbcopy", &[]),
+    };
+  }
+
+  /// Lists the tools to try, in order, for a desktop session.
+  ///
+  /// # Arguments
+  ///
+  /// * `wayland` - Whether a Wayland display is available
+  /// * `x11` - Whether an X11 display is available
+  ///
+  /// # Returns
+  ///
+  /// A `Vec<ClipboardTool>` with the preferred tool first.
+  pub fn candidates(wayland: bool, x11: bool) -> Vec<ClipboardTool> {
+    if cfg!(target_os = "macos") {
+      return vec![ClipboardTool::Pbcopy];
+    }
+    let mut tools = Vec::new();
+    if wayland {
+      tools.push(ClipboardTool::WlCopy);
+    }
+    if x11 || wayland {
+      tools.extend([ClipboardTool::Xclip, ClipboardTool::Xsel]);
+    }
+    return tools;
+  }
+}
+
+/// Copies finished transcripts to the system clipboard.
+#[derive(Debug, Clone)]
+pub struct Clipboard {
+  tools: Vec<ClipboardTool>,
+}
+
+impl Clipboard {
+  /// Creates a Clipboard for the current desktop session.
+  ///
+  /// Wayland sessions are detected from `WAYLAND_DISPLAY` and X11 sessions
+  /// from `DISPLAY`.
+  ///
+  /// # Returns
+  ///
+  /// A new `Clipboard` instance.
+  pub fn new() -> Self {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let x11 = std::env::var_os("DISPLAY").is_some();
+    return Clipboard {
+      tools: ClipboardTool::candidates(wayland, x11),
+    };
+  }
+
+  /// Copies text to the clipboard.
+  ///
+  /// The tools for the session are tried in order until one of them is
+  /// installed.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - The text to copy
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<()>` indicating success or failure.
+  pub async fn copy(&self, text: &str) -> IntegrationResult<()> {
+    for tool in &self.tools {
+      let (command, args) = tool.command();
+      let Ok(status) =
+        ProcessExecutor::run_with_input(command, args, text.as_bytes()).await
+      else {
+        vlog!("Clipboard tool not available: {}", command);
+        continue;
+      };
+      if !status.success() {
+        return Err(IntegrationError::Clipboard(format!(
+          "{} exited with {}",
+          command, status
+        )));
+      }
+      vlog!("Copied transcript to the clipboard with {}", command);
+      return Ok(());
+    }
+    return Err(IntegrationError::Clipboard(String::from(
+      "no clipboard tool found, please install wl-clipboard, xclip or xsel",
+    )));
+  }
+}
+
+impl Default for Clipboard {
+  fn default() -> Self {
+    return Self::new();
+  }
+}
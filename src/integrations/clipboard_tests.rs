@@ -0,0 +1,37 @@
+use crate::integrations::clipboard::ClipboardTool;
+
+#[test]
+fn test_clipboard_tool_command() {
+  assert_eq!(ClipboardTool::WlCopy.command(), ("wl-copy", &[][..]));
+  assert_eq!(
+    ClipboardTool::Xclip.command(),
+    ("xclip", &["-selection", "clipboard"][..])
+  );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_clipboard_tool_candidates() {
+  assert_eq!(
+    ClipboardTool::candidates(true, true),
+    vec![
+      ClipboardTool::WlCopy,
+      ClipboardTool::Xclip,
+      ClipboardTool::Xsel
+    ]
+  );
+  assert_eq!(
+    ClipboardTool::candidates(false, true),
+    vec![ClipboardTool::Xclip, ClipboardTool::Xsel]
+  );
+  assert!(ClipboardTool::candidates(false, false).is_empty());
+}
+
+#[cfg(target_os = "macos")]
+#[test]
+fn test_clipboard_tool_candidates() {
+  assert_eq!(
+    ClipboardTool::candidates(false, false),
+    vec![ClipboardTool::Pbcopy]
+  );
+}
@@ -0,0 +1,94 @@
+use crate::integrations::email::{
+  EmailAttachment, attachment_content_type, build_body, build_email,
+};
+use crate::integrations::errors::IntegrationError;
+
+#[test]
+fn test_build_body() {
+  assert_eq!(
+    build_body(" Hello world ", Some(61.2)),
+    "Duration: 61s\n\nHello world\n"
+  );
+  assert_eq!(build_body("Hello world", None), "Hello world\n");
+}
+
+#[test]
+fn test_build_email_headers() {
+  let message = build_email(
+    "Lumine <lumine@example.com>",
+    "a@example.com, b@example.com",
+    "Transcript",
+    "Hello world\n",
+    None,
+  )
+  .unwrap();
+  let formatted = String::from_utf8(message.formatted()).unwrap();
+
+  assert!(formatted.contains("From: Lumine <lumine@example.com>"));
+  assert!(formatted.contains("a@example.com"));
+  assert!(formatted.contains("b@example.com"));
+  assert!(formatted.contains("Subject: Transcript"));
+  assert!(formatted.contains("Hello world"));
+}
+
+#[test]
+fn test_build_email_with_attachment() {
+  let attachment = EmailAttachment {
+    name: String::from("/tmp/recordings/audiocapture.wav"),
+    content: b"RIFF".to_vec(),
+  };
+  let message = build_email(
+    "lumine@example.com",
+    "a@example.com",
+    "Transcript",
+    "Hello world\n",
+    Some(attachment),
+  )
+  .unwrap();
+  let formatted = String::from_utf8(message.formatted()).unwrap();
+
+  assert!(formatted.contains("multipart/mixed"));
+  assert!(formatted.contains("audio/wav"));
+  assert!(formatted.contains("filename=\"audiocapture.wav\""));
+}
+
+#[test]
+fn test_attachment_content_type() {
+  assert_eq!(attachment_content_type("standup.wav"), "audio/wav");
+  assert_eq!(attachment_content_type("Interview.MP3"), "audio/mpeg");
+  assert_eq!(attachment_content_type("memo.m4a"), "audio/mp4");
+  assert_eq!(
+    attachment_content_type("recording"),
+    "application/octet-stream"
+  );
+}
+
+#[test]
+fn test_build_email_with_mp3_attachment() {
+  let attachment = EmailAttachment {
+    name: String::from("interview.mp3"),
+    content: b"ID3".to_vec(),
+  };
+  let message = build_email(
+    "lumine@example.com",
+    "a@example.com",
+    "Transcript",
+    "Hello world\n",
+    Some(attachment),
+  )
+  .unwrap();
+  let formatted = String::from_utf8(message.formatted()).unwrap();
+
+  assert!(formatted.contains("audio/mpeg"));
+  assert!(!formatted.contains("audio/wav"));
+}
+
+#[test]
+fn test_build_email_invalid_addresses() {
+  let result =
+    build_email("not an address", "a@example.com", "Transcript", "", None);
+  assert!(matches!(result, Err(IntegrationError::InvalidEmail(_))));
+
+  let result = build_email("lumine@example.com", " , ", "Transcript", "", None);
+  assert!(matches!(result, Err(IntegrationError::InvalidEmail(_))));
+}
@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+/// Integration-related errors.
+///
+/// Represents errors that can occur while delivering transcripts to
+/// external services.
+#[derive(Error, Debug)]
+pub enum IntegrationError {
+  #[error(
+    "Unknown chat service: '{0}'. Supported services are slack, discord and matrix."
+  )]
+  UnknownChatService(String),
+
+  #[error("Invalid email configuration: {0}")]
+  InvalidEmail(String),
+
+  #[error("Failed to deliver transcript: {0}")]
+  Delivery(String),
+
+  #[error("Cannot copy transcript to the clipboard: {0}")]
+  Clipboard(String),
+
+  #[error("Cannot send captions to OBS: {0}")]
+  Captions(String),
+}
+
+/// Result type for integration operations.
+pub type IntegrationResult<T> = Result<T, IntegrationError>;
@@ -0,0 +1,30 @@
+//! Delivery of transcripts to external services.
+//!
+//! ## Main Components
+//!
+//! - [`ChatNotifier`]: Posts transcripts to Slack, Discord or Matrix webhooks
+//! - [`EmailNotifier`]: Mails transcripts through an SMTP server
+//! - [`Clipboard`]: Copies transcripts with wl-copy, xclip, xsel or pbcopy
+//! - [`ObsCaptions`]: Shows live transcripts as OBS Studio stream captions
+//! - [`IntegrationError`]: Error types for integration operations
+//! - [`IntegrationResult<T>`]: Result type alias for integration operations
+
+pub mod chat;
+pub mod clipboard;
+pub mod email;
+pub mod errors;
+pub mod obs;
+
+#[cfg(test)]
+mod chat_tests;
+#[cfg(test)]
+mod clipboard_tests;
+#[cfg(test)]
+mod email_tests;
+#[cfg(test)]
+mod obs_tests;
+
+pub use chat::ChatNotifier;
+pub use clipboard::Clipboard;
+pub use email::{EmailAttachment, EmailNotifier};
+pub use obs::ObsCaptions;
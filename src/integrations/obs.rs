@@ -0,0 +1,207 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+
+use crate::integrations::errors::{IntegrationError, IntegrationResult};
+use crate::tr;
+use crate::vlog;
+
+const RPC_VERSION: u64 = 1;
+const OP_HELLO: u64 = 0;
+const OP_IDENTIFY: u64 = 1;
+const OP_IDENTIFIED: u64 = 2;
+const OP_REQUEST: u64 = 6;
+const OP_REQUEST_RESPONSE: u64 = 7;
+const CAPTION_REQUEST: &str = "SendStreamCaption";
+
+/// Shows live transcripts as captions of the OBS Studio stream.
+///
+/// Talks to OBS through obs-websocket 5, which is built into OBS 28 and
+/// later. OBS only embeds captions while it is streaming.
+pub struct ObsCaptions {
+  socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+  requests: u64,
+}
+
+impl ObsCaptions {
+  /// Connects to obs-websocket.
+  ///
+  /// Authenticates with the password when OBS asks for it.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - The obs-websocket URL, such as `ws://localhost:4455`
+  /// * `password` - The obs-websocket password, empty if not required
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<ObsCaptions>` containing the connection or an
+  /// error if OBS cannot be reached or rejects the password.
+  pub async fn connect(url: &str, password: &str) -> IntegrationResult<Self> {
+    let (socket, _) = connect_async(url).await.map_err(captions_error)?;
+    let mut captions = ObsCaptions {
+      socket,
+      requests: 0,
+    };
+    let hello = captions.receive(OP_HELLO).await?;
+    captions.send(identify_message(&hello, password)).await?;
+    captions.receive(OP_IDENTIFIED).await?;
+    vlog!("Connected to OBS at {}", url);
+    return Ok(captions);
+  }
+
+  /// Shows a caption in the stream.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - The caption text
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<()>` indicating whether OBS accepted the caption.
+  pub async fn show(&mut self, text: &str) -> IntegrationResult<()> {
+    self.requests += 1;
+    let request_id = self.requests.to_string();
+    self.send(caption_request(&request_id, text)).await?;
+    loop {
+      let response = self.receive(OP_REQUEST_RESPONSE).await?;
+      if response["requestId"] == request_id.as_str() {
+        return check_response(&response);
+      }
+    }
+  }
+
+  async fn send(&mut self, message: Value) -> IntegrationResult<()> {
+    return self
+      .socket
+      .send(Message::text(message.to_string()))
+      .await
+      .map_err(captions_error);
+  }
+
+  /// Waits for the next message with an opcode.
+  ///
+  /// # Returns
+  ///
+  /// An `IntegrationResult<Value>` containing the data of the message or an
+  /// error if the connection closed first.
+  async fn receive(&mut self, op: u64) -> IntegrationResult<Value> {
+    while let Some(message) = self.socket.next().await {
+      let text = match message.map_err(captions_error)? {
+        Message::Text(text) => text,
+        Message::Close(frame) => return Err(closed_error(frame)),
+        _ => continue,
+      };
+      let message: Value = serde_json::from_str(&text)
+        .map_err(|e| IntegrationError::Captions(e.to_string()))?;
+      if message["op"] == op {
+        return Ok(message["d"].clone());
+      }
+    }
+    return Err(closed_error(None));
+  }
+}
+
+/// Computes the obs-websocket authentication string.
+///
+/// # Arguments
+///
+/// * `password` - The obs-websocket password
+/// * `salt` - Salt sent by OBS
+/// * `challenge` - Challenge sent by OBS
+///
+/// # Returns
+///
+/// A `String` containing the Base64 encoded response to the challenge.
+pub fn authentication(password: &str, salt: &str, challenge: &str) -> String {
+  let secret = BASE64.encode(Sha256::digest(format!("{}{}", password, salt)));
+  return BASE64.encode(Sha256::digest(format!("{}{}", secret, challenge)));
+}
+
+/// Builds the message that answers the hello of OBS.
+///
+/// No events are subscribed to, since only caption requests are sent.
+///
+/// # Arguments
+///
+/// * `hello` - Data of the hello message
+/// * `password` - The obs-websocket password
+///
+/// # Returns
+///
+/// A `Value` containing the identify message.
+pub fn identify_message(hello: &Value, password: &str) -> Value {
+  let mut data = json!({
+    "rpcVersion": RPC_VERSION,
+    "eventSubscriptions": 0,
+  });
+  let challenge = &hello["authentication"];
+  if let (Some(challenge), Some(salt)) =
+    (challenge["challenge"].as_str(), challenge["salt"].as_str())
+  {
+    data["authentication"] = json!(authentication(password, salt, challenge));
+  }
+  return json!({ "op": OP_IDENTIFY, "d": data });
+}
+
+/// Builds the request that shows a caption.
+///
+/// # Arguments
+///
+/// * `request_id` - Identifier the response refers to
+/// * `text` - The caption text
+///
+/// # Returns
+///
+/// A `Value` containing the request message.
+pub fn caption_request(request_id: &str, text: &str) -> Value {
+  return json!({
+    "op": OP_REQUEST,
+    "d": {
+      "requestType": CAPTION_REQUEST,
+      "requestId": request_id,
+      "requestData": { "captionText": text },
+    },
+  });
+}
+
+/// Checks the response to a caption request.
+///
+/// # Arguments
+///
+/// * `response` - Data of the response message
+///
+/// # Returns
+///
+/// An `IntegrationResult<()>` containing an error if OBS rejected the
+/// caption, for example because it is not streaming.
+pub fn check_response(response: &Value) -> IntegrationResult<()> {
+  let status = &response["requestStatus"];
+  if status["result"].as_bool() == Some(true) {
+    return Ok(());
+  }
+  return Err(IntegrationError::Captions(tr!(
+    "obs-caption-rejected",
+    code = status["code"].as_i64().unwrap_or_default(),
+    comment = status["comment"].as_str().unwrap_or_default()
+  )));
+}
+
+fn captions_error(
+  error: tokio_tungstenite::tungstenite::Error,
+) -> IntegrationError {
+  return IntegrationError::Captions(error.to_string());
+}
+
+fn closed_error(frame: Option<CloseFrame>) -> IntegrationError {
+  let reason = frame
+    .map(|frame| return format!("{} {}", frame.code, frame.reason))
+    .unwrap_or_default();
+  return IntegrationError::Captions(tr!("obs-closed", reason = reason.trim()));
+}
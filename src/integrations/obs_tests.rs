@@ -0,0 +1,122 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::integrations::errors::IntegrationError;
+use crate::integrations::obs::{
+  ObsCaptions, authentication, caption_request, check_response,
+  identify_message,
+};
+
+#[test]
+fn test_authentication() {
+  assert_eq!(
+    authentication("supersecret", "lumine-salt", "lumine-challenge"),
+    "jxm/K81PAB+CjRXVu6Prt+wFkxriQyie5pHL0EHPI6Y="
+  );
+}
+
+#[test]
+fn test_identify_message_without_authentication() {
+  let message = identify_message(&json!({ "rpcVersion": 1 }), "");
+  assert_eq!(
+    message,
+    json!({ "op": 1, "d": { "rpcVersion": 1, "eventSubscriptions": 0 } })
+  );
+}
+
+#[test]
+fn test_identify_message_answers_challenge() {
+  let hello = json!({
+    "rpcVersion": 1,
+    "authentication": {
+      "challenge": "lumine-challenge",
+      "salt": "lumine-salt",
+    },
+  });
+  let message = identify_message(&hello, "supersecret");
+  assert_eq!(
+    message["d"]["authentication"],
+    "jxm/K81PAB+CjRXVu6Prt+wFkxriQyie5pHL0EHPI6Y="
+  );
+}
+
+#[test]
+fn test_caption_request() {
+  let request = caption_request("7", "Hello world");
+  assert_eq!(request["op"], 6);
+  assert_eq!(request["d"]["requestType"], "SendStreamCaption");
+  assert_eq!(request["d"]["requestId"], "7");
+  assert_eq!(request["d"]["requestData"]["captionText"], "Hello world");
+}
+
+#[test]
+fn test_check_response() {
+  let accepted = json!({ "requestStatus": { "result": true, "code": 100 } });
+  assert!(check_response(&accepted).is_ok());
+
+  let rejected = json!({
+    "requestStatus": {
+      "result": false,
+      "code": 501,
+      "comment": "Stream is not active.",
+    },
+  });
+  let result = check_response(&rejected);
+  assert!(matches!(
+    result,
+    Err(IntegrationError::Captions(message))
+      if message.contains("501") && message.contains("Stream is not active.")
+  ));
+}
+
+/// Accepts one connection like obs-websocket and answers every caption
+/// request, returning the captions it received.
+async fn serve_obs(listener: TcpListener) -> Vec<String> {
+  let (stream, _) = listener.accept().await.unwrap();
+  let mut socket = accept_async(stream).await.unwrap();
+  let hello = json!({ "op": 0, "d": { "rpcVersion": 1 } });
+  socket.send(Message::text(hello.to_string())).await.unwrap();
+
+  let mut captions = Vec::new();
+  while let Some(Ok(message)) = socket.next().await {
+    let Message::Text(text) = message else {
+      continue;
+    };
+    let message: Value = serde_json::from_str(&text).unwrap();
+    let reply = match message["op"].as_u64() {
+      Some(1) => json!({ "op": 2, "d": { "negotiatedRpcVersion": 1 } }),
+      Some(6) => {
+        let data = &message["d"];
+        captions.push(data["requestData"]["captionText"].to_string());
+        json!({
+          "op": 7,
+          "d": {
+            "requestType": data["requestType"],
+            "requestId": data["requestId"],
+            "requestStatus": { "result": true, "code": 100 },
+          },
+        })
+      }
+      _ => continue,
+    };
+    socket.send(Message::text(reply.to_string())).await.unwrap();
+  }
+  return captions;
+}
+
+#[tokio::test]
+async fn test_show_captions() {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let url = format!("ws://{}", listener.local_addr().unwrap());
+  let server = tokio::spawn(serve_obs(listener));
+
+  let mut captions = ObsCaptions::connect(&url, "").await.unwrap();
+  captions.show("Hello").await.unwrap();
+  captions.show("world").await.unwrap();
+  drop(captions);
+
+  assert_eq!(server.await.unwrap(), vec!["\"Hello\"", "\"world\""]);
+}
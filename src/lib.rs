@@ -0,0 +1,94 @@
+//! Lumine records audio and transcribes it with a Whisper service.
+//!
+//! The `lumine` binary is a thin command-line wrapper over this library, so
+//! other programs can embed recording and transcription without shelling
+//! out to the CLI.
+//!
+//! ## Features
+//!
+//! - `cli` (default): The full application and the `lumine` binary; enables
+//!   `network` and `recording`
+//! - `network`: The [`Whisper`] client, post-processing and translation
+//! - `recording`: Audio recording, conversion and the ffmpeg processes
+//! - `ffi`: A C ABI over the application, declared in `include/lumine.h`
+//! - `python`: The `lumine` Python module, built with maturin
+//!
+//! With default features disabled the crate keeps the transcript types,
+//! output formatting, evaluation and events, without recording, networking
+//! or spawning processes.
+//!
+//! ## Main Components
+//!
+//! - [`App`]: Recording, transcription and delivery workflows
+//! - [`Config`]: User configuration loaded from the XDG config directory
+//! - [`Audio`]: Audio recording, conversion and device discovery
+//! - [`Whisper`]: Client for the Whisper transcription service
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use lumine::output::format::OutputFormat;
+//! use lumine::state::State;
+//! use lumine::{App, Config};
+//!
+//! # async fn run() {
+//! let config = Config::load().await.unwrap();
+//! let app = App::new(config, State::default(), None);
+//! let outcome = app
+//!   .transcribe_file("meeting.wav", OutputFormat::Text, None)
+//!   .await
+//!   .unwrap();
+//! println!("{}", outcome.text);
+//! # }
+//! ```
+
+#[cfg(feature = "cli")]
+pub mod app;
+#[cfg(feature = "recording")]
+pub mod audio;
+#[cfg(feature = "cli")]
+pub mod calendar;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "cli")]
+pub mod config;
+#[cfg(feature = "cli")]
+pub mod encryption;
+pub mod evaluation;
+pub mod events;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "network", feature = "recording"))]
+pub mod files;
+#[cfg(feature = "cli")]
+pub mod hotkey;
+pub mod i18n;
+#[cfg(feature = "cli")]
+pub mod integrations;
+pub mod logging;
+#[cfg(feature = "network")]
+pub mod network;
+pub mod output;
+#[cfg(feature = "network")]
+pub mod postprocess;
+#[cfg(feature = "recording")]
+pub mod process;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "cli")]
+pub mod state;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "cli")]
+pub mod update;
+pub mod whisper;
+
+#[cfg(feature = "cli")]
+pub use crate::app::App;
+#[cfg(feature = "recording")]
+pub use crate::audio::Audio;
+#[cfg(feature = "cli")]
+pub use crate::config::Config;
+#[cfg(feature = "network")]
+pub use crate::whisper::Whisper;
@@ -0,0 +1,39 @@
+use crate::audio::{AudioError, FfmpegError};
+use crate::logging::{describe_error, error_chain, set_verbose};
+use crate::process::errors::ProcessError;
+
+fn missing_ffmpeg() -> AudioError {
+  let io = std::io::Error::from(std::io::ErrorKind::NotFound);
+  let process = ProcessError::ExecutionFailed(String::from("ffmpeg"), io);
+  return AudioError::CouldNotExecuteFFMPEG(FfmpegError::Process(process));
+}
+
+#[test]
+fn test_error_chain() {
+  let error = missing_ffmpeg();
+  let chain = error_chain(&error);
+
+  assert!(chain.starts_with("[LUM-AUD-004] Failed to run FFmpeg."));
+  assert!(chain.contains(": Command 'ffmpeg' failed to execute."));
+  assert!(chain.ends_with(": entity not found"));
+}
+
+#[test]
+fn test_error_chain_without_source() {
+  let error = AudioError::Cancelled;
+
+  assert_eq!(error_chain(&error), "[LUM-AUD-011] Recording cancelled.");
+}
+
+#[test]
+fn test_describe_error_lists_causes_in_verbose_mode() {
+  set_verbose(true);
+  let described = describe_error(&missing_ffmpeg());
+  set_verbose(false);
+
+  let lines: Vec<&str> = described.lines().collect();
+  assert_eq!(lines.len(), 3);
+  assert!(lines[0].starts_with("[LUM-AUD-004]"));
+  assert!(lines[1].starts_with("  Caused by: Command 'ffmpeg'"));
+  assert_eq!(lines[2], "  Caused by: entity not found");
+}
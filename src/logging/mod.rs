@@ -8,11 +8,14 @@
 //!
 //! - [`set_verbose`]: Set the global verbose flag at application startup
 //! - [`is_verbose`]: Check if verbose mode is enabled
+//! - [`set_log_to_stderr`]: Print verbose messages to stderr instead of stdout
 //! - [`vlog!`]: Macro for printing timestamped verbose messages
+//! - [`describe_error`]: Format an error, with its causes in verbose mode
+//! - [`error_chain`]: Format an error and its causes on one line
 //!
 //! ## Usage
 //!
-//! ```rust
+//! ```ignore
 //! // In main.rs, set verbose from CLI args:
 //! set_verbose(cli.verbose);
 //!
@@ -21,9 +24,14 @@
 //! vlog!("Device selected: {}", device_name);
 //! ```
 
+#[cfg(test)]
+mod logging_tests;
+
+use std::error::Error;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 static VERBOSE: AtomicBool = AtomicBool::new(false);
+static LOG_TO_STDERR: AtomicBool = AtomicBool::new(false);
 
 /// Sets the global verbose flag.
 ///
@@ -46,6 +54,71 @@ pub fn is_verbose() -> bool {
   return VERBOSE.load(Ordering::Relaxed);
 }
 
+/// Sets whether verbose messages are printed to stderr.
+///
+/// Used when stdout carries data, such as audio piped into another tool.
+///
+/// # Arguments
+///
+/// * `value` - Whether to print verbose messages to stderr
+pub fn set_log_to_stderr(value: bool) {
+  LOG_TO_STDERR.store(value, Ordering::Relaxed);
+}
+
+/// Checks if verbose messages are printed to stderr.
+///
+/// # Returns
+///
+/// `true` if verbose messages go to stderr, `false` if they go to stdout.
+pub fn is_log_to_stderr() -> bool {
+  return LOG_TO_STDERR.load(Ordering::Relaxed);
+}
+
+/// Formats an error for the user.
+///
+/// In verbose mode, each error that caused it follows on its own line, so
+/// the underlying FFmpeg output or HTTP error is visible.
+///
+/// # Arguments
+///
+/// * `error` - The error to format
+///
+/// # Returns
+///
+/// A `String` containing the error message and, in verbose mode, its causes.
+pub fn describe_error(error: &dyn Error) -> String {
+  let mut message = error.to_string();
+  if !is_verbose() {
+    return message;
+  }
+  let mut source = error.source();
+  while let Some(cause) = source {
+    message.push_str(&format!("\n  Caused by: {}", cause));
+    source = cause.source();
+  }
+  return message;
+}
+
+/// Formats an error and the errors that caused it on one line.
+///
+/// # Arguments
+///
+/// * `error` - The error to format
+///
+/// # Returns
+///
+/// A `String` with the messages of the error and its causes, separated by
+/// colons.
+pub fn error_chain(error: &dyn Error) -> String {
+  let mut message = error.to_string();
+  let mut source = error.source();
+  while let Some(cause) = source {
+    message.push_str(&format!(": {}", cause));
+    source = cause.source();
+  }
+  return message;
+}
+
 /// Prints a verbose message with timestamp if verbose mode is enabled.
 ///
 /// Messages are prefixed with the current time in HH:MM:SS format.
@@ -53,7 +126,7 @@ pub fn is_verbose() -> bool {
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```ignore
 /// vlog!("Starting recording...");
 /// vlog!("Device: {}", device_name);
 /// ```
@@ -62,7 +135,12 @@ macro_rules! vlog {
     ($($arg:tt)*) => {
         if $crate::logging::is_verbose() {
             let now = chrono::Local::now();
-            println!("[{}] {}", now.format("%H:%M:%S"), format!($($arg)*));
+            let message = format!("[{}] {}", now.format("%H:%M:%S"), format!($($arg)*));
+            if $crate::logging::is_log_to_stderr() {
+                eprintln!("{}", message);
+            } else {
+                println!("{}", message);
+            }
         }
     };
 }
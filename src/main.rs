@@ -1,63 +1,353 @@
-mod app;
-mod audio;
-mod cli;
-mod config;
-mod files;
-mod logging;
-mod network;
-mod output;
-mod process;
-mod whisper;
-
 use clap::Parser;
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
-use crate::app::App;
-use crate::cli::{Cli, Commands};
-use crate::config::Config;
-use crate::logging::set_verbose;
-use crate::output::format::OutputFormat;
+use lumine::app::{App, RuntimeResult, TranscriptionOutcome, report_events};
+use lumine::audio::{RawAudioFormat, is_stream_url};
+use lumine::cli::{Cli, Commands, ConfigCommands, DeviceCommands};
+use lumine::config::errors::ConfigResult;
+use lumine::config::{Config, ConfigSources, ValueSource};
+use lumine::evaluation::Normalization;
+use lumine::events::{Event, PartialTranscript};
+use lumine::explain::{find_explanation, format_explanation};
+use lumine::files::operations::set_secure_delete;
+use lumine::i18n::set_language;
+use lumine::logging::{set_log_to_stderr, set_verbose};
+use lumine::output::format::OutputFormat;
+use lumine::output::status::format_status;
+use lumine::state::State;
+use lumine::{tr, vlog};
 
 #[tokio::main]
 async fn main() {
   let cli = Cli::parse();
+  if let Err(e) = cli.validate() {
+    e.exit();
+  }
 
   set_verbose(cli.verbose);
+  set_log_to_stderr(matches!(
+    &cli.command,
+    Some(Commands::Record {
+      stdout_audio: true,
+      ..
+    })
+  ));
 
-  let config = match Config::load().await {
+  // Explaining an error must work while the configuration is broken.
+  if let Some(Commands::Explain { code }) = &cli.command {
+    match find_explanation(code) {
+      Some(explanation) => {
+        println!("{}", format_explanation(explanation));
+        return;
+      }
+      None => {
+        eprintln!("{}", tr!("explain-unknown-code", code = code));
+        std::process::exit(1);
+      }
+    }
+  }
+
+  let mut config = match Config::load_file().await {
     Ok(config) => config,
     Err(e) => {
-      eprintln!("Configuration Error: {}", e);
+      eprintln!("{}", tr!("configuration-error", error = e));
       std::process::exit(1);
     }
   };
 
-  let app = App::new(config);
-  let format = OutputFormat::from_flags(cli.output_json, cli.output_json_full);
+  let mut sources = ConfigSources::new();
+  let loaded_from = match Config::find_file() {
+    Some(_) => ValueSource::File,
+    None => ValueSource::Default,
+  };
+  sources.record(loaded_from, &config);
+  if let Err(e) = config.apply_environment() {
+    eprintln!("{}", tr!("configuration-error", error = e));
+    std::process::exit(1);
+  }
+  sources.record(ValueSource::Environment, &config);
+  set_language(&config.get_language());
+  set_secure_delete(config.get_secure_delete());
 
-  let result = match cli.command {
-    Some(Commands::Transcribe {
-      file,
-      output_json,
-      output_json_full,
-    }) => {
-      let format = OutputFormat::from_flags(output_json, output_json_full);
-      app.transcribe_file(&file, format).await
+  if let Some(mode) = &cli.mode
+    && let Err(e) = config.apply_mode(mode)
+  {
+    eprintln!("{}", tr!("configuration-error", error = e));
+    std::process::exit(1);
+  }
+  sources.record(ValueSource::Mode, &config);
+
+  if let Some(Commands::Dictate { hotkey, toggle, .. }) = &cli.command {
+    if let Some(hotkey) = hotkey {
+      config.dictation.hotkey = Some(hotkey.clone());
+    }
+    if *toggle {
+      config.dictation.toggle = Some(true);
     }
-    Some(Commands::Record) => app.record_only().await,
+  }
+  cli.recorder.apply(&mut config);
+  cli.transcription.apply(&mut config);
+  sources.record(ValueSource::Flag, &config);
+
+  // Managing the configuration must work without the passphrase, so that
+  // encryption can be turned off after the key command or passphrase is
+  // lost.
+  match &cli.command {
     Some(Commands::ResetConfig) => match Config::reset_to_defaults().await {
       Ok(_) => {
-        println!("Configuration has been reset to default values.");
+        println!("{}", tr!("reset-config-done"));
         return;
       }
       Err(e) => {
-        eprintln!("Failed to reset configuration: {}", e);
+        eprintln!("{}", tr!("reset-config-failed", error = e));
         std::process::exit(1);
       }
     },
-    None => app.record_and_transcribe(format).await,
+    Some(Commands::Config { action }) => {
+      match run_config_command(action, &sources).await {
+        Ok(output) => {
+          println!("{}", output);
+          return;
+        }
+        Err(e) => {
+          eprintln!("{}", tr!("configuration-error", error = e));
+          std::process::exit(1);
+        }
+      }
+    }
+    _ => {}
+  }
+
+  // Decrypting needs the passphrase, but not the state.
+  if let Some(Commands::Decrypt {
+    file,
+    output,
+    force,
+  }) = &cli.command
+  {
+    let encryptor = match App::load_encryptor(&config).await {
+      Ok(encryptor) => encryptor,
+      Err(e) => {
+        eprintln!("{}", e);
+        std::process::exit(1);
+      }
+    };
+    let app = App::new(config, State::default(), encryptor);
+    match app.decrypt_file(file, output.clone(), *force).await {
+      Ok(path) => {
+        println!("{}", path);
+        return;
+      }
+      Err(e) => {
+        eprintln!("{}", e);
+        std::process::exit(1);
+      }
+    }
+  }
+  let format = cli.format.unwrap_or(OutputFormat::from_flags(
+    cli.output_json,
+    cli.output_json_full,
+  ));
+  let print_draft = match &cli.command {
+    Some(Commands::Transcribe {
+      output_json,
+      output_json_full,
+      format,
+      ..
+    }) => format
+      .unwrap_or(OutputFormat::from_flags(*output_json, *output_json_full)),
+    _ => format,
+  } == OutputFormat::Text
+    && !cli.json_summary;
+
+  let accessible_status = cli.a11y || config.get_accessible_status();
+  let audio_cues = config.get_audio_cues();
+
+  let app = match App::bootstrap(config).await {
+    Ok(app) => app,
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    }
   };
+  let (partials, mut partial_receiver) = mpsc::unbounded_channel();
+  let app = app.with_partial_transcripts(partials);
+  let printer = tokio::spawn(async move {
+    while let Some(partial) = partial_receiver.recv().await {
+      match partial {
+        PartialTranscript::Draft(text) => {
+          print_draft_transcript(&text, print_draft);
+        }
+        PartialTranscript::Chunk(text) | PartialTranscript::Dictation(text) => {
+          println!("{}", text)
+        }
+      }
+    }
+  });
+
+  let events = app.subscribe();
+  let events_done = CancellationToken::new();
+  let reporter = tokio::spawn({
+    let done = events_done.clone();
+    async move {
+      let report =
+        |event: &Event| print_event(event, accessible_status, audio_cues);
+      report_events(events, &report, &done).await;
+    }
+  });
+
+  let cancel = app.cancellation_token();
+  tokio::spawn(async move {
+    if !shutdown_requested().await {
+      return;
+    }
+    cancel.cancel();
+    // A second signal quits right away if cleaning up gets stuck.
+    if shutdown_requested().await {
+      eprintln!("{}", tr!("error-force-quit"));
+      std::process::exit(1);
+    }
+  });
+
+  let result = match cli.command {
+    Some(Commands::Transcribe {
+      file,
+      split_channels: true,
+      ..
+    }) => app.transcribe_split_channels(&file).await,
+    Some(Commands::Transcribe { file, .. }) if is_stream_url(&file) => {
+      app.transcribe_stream(&file).await.map(|_| String::new())
+    }
+    Some(Commands::Transcribe {
+      file,
+      input_format,
+      rate,
+      channels,
+      output_json,
+      output_json_full,
+      format: file_format,
+      ..
+    }) => {
+      let format = file_format
+        .unwrap_or(OutputFormat::from_flags(output_json, output_json_full));
+      let raw_format = input_format.map(|sample_format| RawAudioFormat {
+        sample_format,
+        sample_rate: rate.unwrap_or_default(),
+        channels: channels.unwrap_or_default(),
+      });
+      app
+        .transcribe_file(&file, format, raw_format)
+        .await
+        .and_then(|outcome| render(&outcome, format, cli.json_summary))
+    }
+    Some(Commands::Record {
+      stdout_audio: true, ..
+    }) => app.record_to_stdout().await.map(|_| String::new()),
+    Some(Commands::Record {
+      directory,
+      name,
+      force,
+      output_json,
+      intervals,
+      ..
+    }) => {
+      let format = OutputFormat::from_flags(
+        output_json || intervals || cli.json_summary,
+        false,
+      );
+      app
+        .record_only(directory, name, force, intervals)
+        .await
+        .and_then(|output| output.format(format))
+    }
+    Some(Commands::Eval {
+      file,
+      reference,
+      keep_case,
+      keep_punctuation,
+      output_json,
+      ..
+    }) => {
+      let normalization = Normalization {
+        keep_case,
+        keep_punctuation,
+      };
+      let format = OutputFormat::from_flags(output_json, false);
+      app.evaluate(&file, &reference, normalization, format).await
+    }
+    Some(Commands::EvalCorpus {
+      directory,
+      keep_case,
+      keep_punctuation,
+      output_json,
+      ..
+    }) => {
+      let normalization = Normalization {
+        keep_case,
+        keep_punctuation,
+      };
+      let format = OutputFormat::from_flags(output_json, false);
+      app.evaluate_corpus(&directory, normalization, format).await
+    }
+    Some(Commands::Watch { directory, .. }) => app.watch(&directory).await,
+    Some(Commands::Notes { output, .. }) => app
+      .take_notes(output)
+      .await
+      .map(|path| tr!("notes-saved", path = path)),
+    Some(Commands::Call { source, .. }) => app.record_call(source).await,
+    Some(Commands::Dictate { output_json, .. }) => {
+      app
+        .dictate(OutputFormat::from_flags(output_json, false))
+        .await
+    }
+    Some(Commands::Clean { .. }) => app.clean_temp().await,
+    Some(Commands::Devices {
+      action:
+        Some(DeviceCommands::Test {
+          device,
+          play,
+          output_json,
+        }),
+      ..
+    }) => {
+      app
+        .test_device(device, play, OutputFormat::from_flags(output_json, false))
+        .await
+    }
+    Some(Commands::Devices { output_json, .. }) => {
+      app
+        .list_devices(OutputFormat::from_flags(output_json, false))
+        .await
+    }
+    Some(Commands::Stats { output_json }) => {
+      app.usage_stats(OutputFormat::from_flags(output_json, false))
+    }
+    Some(Commands::SelfUpdate { check }) => app.self_update(check).await,
+    Some(Commands::Last { transcript: false }) => app.latest_recording().await,
+    Some(Commands::Last { transcript: true }) => app.latest_transcript().await,
+    // These commands run before the state is loaded.
+    Some(Commands::Explain { .. })
+    | Some(Commands::ResetConfig)
+    | Some(Commands::Config { .. })
+    | Some(Commands::Decrypt { .. }) => return,
+    None => app
+      .record_and_transcribe(format)
+      .await
+      .and_then(|outcome| render(&outcome, format, cli.json_summary)),
+  };
+
+  // Dropping the App closes the channel, so the printer ends once every
+  // partial transcript is printed. Warnings sent at the end of the run are
+  // printed before the result as well.
+  drop(app);
+  let _ = printer.await;
+  events_done.cancel();
+  let _ = reporter.await;
 
   match result {
+    Ok(output) if output.is_empty() => {}
     Ok(output) => println!("{}", output),
     Err(e) => {
       eprintln!("{}", e);
@@ -65,3 +355,74 @@ async fn main() {
     }
   }
 }
+
+async fn run_config_command(
+  action: &ConfigCommands,
+  sources: &ConfigSources,
+) -> ConfigResult<String> {
+  // Read the file again so that environment variables, modes and
+  // command-line overrides applied to the running configuration are not
+  // written back.
+  let mut config = Config::load_file().await?;
+  match action {
+    ConfigCommands::Get { key } => {
+      config.apply_environment()?;
+      return config.get_value(key);
+    }
+    ConfigCommands::Show { output_json } => {
+      return sources.format(OutputFormat::from_flags(*output_json, false));
+    }
+    ConfigCommands::Set { key, value } => {
+      config.set_value(key, value)?;
+      let stored = config.get_value(key)?;
+      config.save().await?;
+      return Ok(tr!("config-set-done", key = key, value = stored));
+    }
+  }
+}
+
+/// Waits for Ctrl+C or SIGTERM, so that runs stopped by a service manager
+/// clean up like interrupted ones.
+async fn shutdown_requested() -> bool {
+  let Ok(mut terminate) = signal(SignalKind::terminate()) else {
+    return tokio::signal::ctrl_c().await.is_ok();
+  };
+  return tokio::select! {
+    result = tokio::signal::ctrl_c() => result.is_ok(),
+    _ = terminate.recv() => true,
+  };
+}
+
+fn print_event(event: &Event, accessible_status: bool, audio_cues: bool) {
+  vlog!("Event: {:?}", event);
+  match event {
+    Event::Warning { message } | Event::Notice { message } => {
+      eprintln!("{}", message);
+    }
+    _ => {
+      if accessible_status && let Some(line) = format_status(event, audio_cues)
+      {
+        eprintln!("{}", line);
+      }
+    }
+  }
+}
+
+fn print_draft_transcript(text: &str, to_stdout: bool) {
+  if to_stdout {
+    println!("{}", text);
+  } else {
+    eprintln!("{}", tr!("draft", text = text.trim()));
+  }
+}
+
+fn render(
+  outcome: &TranscriptionOutcome,
+  format: OutputFormat,
+  json_summary: bool,
+) -> RuntimeResult<String> {
+  if json_summary {
+    return outcome.summary();
+  }
+  return outcome.format(format);
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use thiserror::Error;
 
 /// Network-related errors.
@@ -5,24 +7,67 @@ use thiserror::Error;
 /// Represents errors that can occur during HTTP requests and network communication.
 #[derive(Error, Debug)]
 pub enum NetworkError {
-  #[error("Invalid service URL: '{0}'. Please check your configuration file.")]
+  #[error(
+    "[LUM-NET-001] Invalid service URL: '{0}'. Please check your configuration file."
+  )]
   InvalidURL(String),
 
   #[error(
-    "Failed to connect to service. Please verify the service is running and accessible."
+    "[LUM-NET-002] Failed to connect to service. Please verify the service is running and accessible."
   )]
-  RequestFailed,
+  RequestFailed(#[source] reqwest::Error),
 
   #[error(
-    "Service returned an error. Please check the service logs and try again."
+    "[LUM-NET-003] Service returned an error. Please check the service logs and try again."
   )]
-  ResponseError,
+  ResponseError(#[source] StatusError),
 
   #[error(
-    "Failed to decode service response. The service may be experiencing issues or the format may be unsupported."
+    "[LUM-NET-004] Failed to decode service response. The service may be experiencing issues or the format may be unsupported."
   )]
-  DecodeError,
+  DecodeError(#[source] reqwest::Error),
+
+  #[error("[LUM-NET-005] Request cancelled.")]
+  Cancelled,
+
+  #[error("[LUM-NET-006] Invalid HTTP method: '{0}'.")]
+  InvalidMethod(String),
+}
+
+impl NetworkError {
+  /// Returns the stable code of this error, such as `LUM-NET-001`.
+  pub fn code(&self) -> &'static str {
+    return match self {
+      NetworkError::InvalidURL(..) => "LUM-NET-001",
+      NetworkError::RequestFailed(..) => "LUM-NET-002",
+      NetworkError::ResponseError(..) => "LUM-NET-003",
+      NetworkError::DecodeError(..) => "LUM-NET-004",
+      NetworkError::Cancelled => "LUM-NET-005",
+      NetworkError::InvalidMethod(..) => "LUM-NET-006",
+    };
+  }
 }
 
+/// Unsuccessful status returned by a service.
+///
+/// Carries the start of the response body, which usually says what went
+/// wrong.
+#[derive(Debug)]
+pub struct StatusError {
+  pub status: reqwest::StatusCode,
+  pub body: String,
+}
+
+impl fmt::Display for StatusError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.body.is_empty() {
+      return write!(f, "HTTP {}", self.status);
+    }
+    return write!(f, "HTTP {}: {}", self.status, self.body);
+  }
+}
+
+impl std::error::Error for StatusError {}
+
 /// Result type for network operations.
 pub type NetworkResult<T> = Result<T, NetworkError>;
@@ -13,25 +13,47 @@
 //! ## Features
 //!
 //! - POST requests with multipart form data
+//! - POST requests with JSON bodies, optionally decoding a JSON response
+//! - GET requests returning text or raw bytes
+//! - WebDAV REPORT requests for CalDAV calendars
 //! - JSON response deserialization
 //! - URL validation before requests
+//! - Cancellation of in-flight requests and an overall request timeout
+//! - One connection pool shared by all clients, so repeated requests to the
+//!   same service reuse connections
 
 pub mod errors;
 
 #[cfg(test)]
 mod network_tests;
 
-use reqwest::multipart;
+use std::sync::OnceLock;
+use std::time::Duration;
 
-use crate::network::errors::{NetworkError, NetworkResult};
+use reqwest::{Method, RequestBuilder, Response, Url, multipart};
+use tokio_util::sync::CancellationToken;
+
+use crate::network::errors::{NetworkError, NetworkResult, StatusError};
 use crate::vlog;
 
+const CONNECT_TIMEOUT_SECONDS: u64 = 10;
+const REQUEST_TIMEOUT_SECONDS: u64 = 300;
+const PROCESSING_TIMEOUT_SECONDS: u64 = 3600;
+const POOL_IDLE_TIMEOUT_SECONDS: u64 = 90;
+const MAX_ERROR_BODY_LENGTH: usize = 200;
+const USER_AGENT: &str = concat!("lumine/", env!("CARGO_PKG_VERSION"));
+const REPORT_METHOD: &str = "REPORT";
+
+static SHARED_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
 /// HTTP client for network requests to external services.
 ///
 /// Provides generic POST functionality with multipart form support.
 #[derive(Debug, Clone)]
 pub struct HttpClient {
   base_url: String,
+  client: reqwest::Client,
+  cancel: CancellationToken,
 }
 
 impl HttpClient {
@@ -45,7 +67,25 @@ impl HttpClient {
   ///
   /// A new `HttpClient` instance.
   pub fn new(base_url: String) -> Self {
-    return HttpClient { base_url };
+    return HttpClient {
+      base_url,
+      client: shared_client(),
+      cancel: CancellationToken::new(),
+    };
+  }
+
+  /// Makes requests sent by this client cancellable.
+  ///
+  /// # Arguments
+  ///
+  /// * `cancel` - Token that aborts in-flight requests when cancelled
+  ///
+  /// # Returns
+  ///
+  /// The `HttpClient` with the cancellation token attached.
+  pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+    self.cancel = cancel;
+    return self;
   }
 
   /// Sends a POST request with multipart form data to the given endpoint.
@@ -73,51 +113,253 @@ impl HttpClient {
   where
     T: serde::de::DeserializeOwned,
   {
-    self.check_url().await?;
+    let request = async {
+      self.check_url().await?;
+
+      let full_url = format!("{}/{}", self.base_url, endpoint);
+
+      vlog!("Sending POST request to: {}", full_url);
+
+      let request = self
+        .client
+        .post(&full_url)
+        .timeout(Duration::from_secs(PROCESSING_TIMEOUT_SECONDS))
+        .multipart(form);
+      let response = self.send(request).await?;
+
+      return response
+        .json::<T>()
+        .await
+        .map_err(NetworkError::DecodeError);
+    };
+
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a POST request with a JSON body and decodes the JSON response.
+  ///
+  /// Intended for APIs such as chat completions. The request is aborted when
+  /// the client's cancellation token is cancelled.
+  ///
+  /// # Type Parameters
+  ///
+  /// * `B` - Type of the body to serialize as JSON
+  /// * `T` - Type to deserialize the JSON response into
+  ///
+  /// # Arguments
+  ///
+  /// * `endpoint` - The API endpoint path, appended to the base URL
+  /// * `body` - Body to send in the request
+  /// * `authorization` - Value of the `Authorization` header, if any
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<T>` containing the deserialized response or an error.
+  pub async fn post_json_with_response<B, T>(
+    &self,
+    endpoint: &str,
+    body: &B,
+    authorization: Option<&str>,
+  ) -> NetworkResult<T>
+  where
+    B: serde::Serialize,
+    T: serde::de::DeserializeOwned,
+  {
+    let request = async {
+      let full_url =
+        format!("{}/{}", self.base_url.trim_end_matches('/'), endpoint);
+      let url = self.parse_url(&full_url)?;
+
+      vlog!("Sending POST request to: {}", full_url);
+
+      let mut request = self
+        .client
+        .post(url)
+        .timeout(Duration::from_secs(PROCESSING_TIMEOUT_SECONDS))
+        .json(body);
+      if let Some(authorization) = authorization {
+        request = request.header(reqwest::header::AUTHORIZATION, authorization);
+      }
+      let response = self.send(request).await?;
+
+      return response
+        .json::<T>()
+        .await
+        .map_err(NetworkError::DecodeError);
+    };
+
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a POST request with a JSON body to the base URL.
+  ///
+  /// Intended for incoming-webhook style endpoints, which usually reject GET
+  /// requests, so only the URL format is validated before sending. Any 2xx
+  /// status is treated as success and the response body is ignored.
+  ///
+  /// # Type Parameters
+  ///
+  /// * `B` - Type of the body to serialize as JSON
+  ///
+  /// # Arguments
+  ///
+  /// * `body` - Body to send in the request
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<()>` indicating success or failure.
+  pub async fn post_json<B>(&self, body: &B) -> NetworkResult<()>
+  where
+    B: serde::Serialize,
+  {
+    let request = async {
+      let url = self.parse_url(&self.base_url)?;
 
-    let client = reqwest::Client::new();
-    let full_url = format!("{}/{}", self.base_url, endpoint);
+      vlog!("Sending POST request to: {}", self.base_url);
 
-    vlog!("Sending POST request to: {}", full_url);
+      self.send(self.client.post(url).json(body)).await?;
+      return Ok(());
+    };
 
-    let response = client
-      .post(&full_url)
-      .multipart(form)
-      .send()
-      .await
-      .map_err(|_| NetworkError::RequestFailed)?;
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a GET request to the base URL and returns the response body.
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<String>` containing the response body or an error.
+  pub async fn get_text(&self) -> NetworkResult<String> {
+    let request = async {
+      let url = self.parse_url(&self.base_url)?;
+
+      vlog!("Sending GET request to: {}", self.base_url);
+
+      let response = self.send(self.client.get(url)).await?;
+      return response.text().await.map_err(NetworkError::DecodeError);
+    };
+
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a WebDAV `REPORT` request to the base URL and returns the
+  /// response body.
+  ///
+  /// Used for CalDAV queries, which ask for the resources directly in the
+  /// collection at the base URL. Credentials in the URL are sent with basic
+  /// authentication.
+  ///
+  /// # Arguments
+  ///
+  /// * `body` - XML body of the report
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<String>` containing the response body or an error.
+  pub async fn report(&self, body: String) -> NetworkResult<String> {
+    let request = async {
+      let url = self.parse_url(&self.base_url)?;
+      let method = Method::from_bytes(REPORT_METHOD.as_bytes())
+        .map_err(|_| NetworkError::InvalidMethod(REPORT_METHOD.to_string()))?;
+
+      vlog!("Sending REPORT request to: {}", self.base_url);
+
+      let request = self
+        .client
+        .request(method, url)
+        .header("Depth", "1")
+        .header(
+          reqwest::header::CONTENT_TYPE,
+          "application/xml; charset=utf-8",
+        )
+        .body(body);
+      let response = self.send(request).await?;
+      return response.text().await.map_err(NetworkError::DecodeError);
+    };
+
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a GET request to the base URL and returns the raw response body.
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<Vec<u8>>` containing the response body or an error.
+  pub async fn get_bytes(&self) -> NetworkResult<Vec<u8>> {
+    let request = async {
+      let url = self.parse_url(&self.base_url)?;
+
+      vlog!("Sending GET request to: {}", self.base_url);
+
+      let response = self.send(self.client.get(url)).await?;
+      return response
+        .bytes()
+        .await
+        .map(|bytes| bytes.to_vec())
+        .map_err(NetworkError::DecodeError);
+    };
+
+    return self.cancellable(request).await;
+  }
+
+  /// Sends a request and checks that the service answered with a 2xx
+  /// status.
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - The request to send
+  ///
+  /// # Returns
+  ///
+  /// A `NetworkResult<Response>` containing the successful response or an
+  /// error carrying the status and the start of the body.
+  async fn send(&self, request: RequestBuilder) -> NetworkResult<Response> {
+    let response = request.send().await.map_err(|e| {
+      vlog!("Failed to send request: {}", e);
+      NetworkError::RequestFailed(e)
+    })?;
 
     vlog!(
       "Received response from service. Status: {}",
       response.status()
     );
 
-    if response.status() != reqwest::StatusCode::OK {
-      return Err(NetworkError::ResponseError);
+    if !response.status().is_success() {
+      return Err(status_error(response).await);
     }
+    return Ok(response);
+  }
 
-    let parsed_response = response
-      .json::<T>()
-      .await
-      .map_err(|_| NetworkError::DecodeError)?;
+  /// Parses a URL of this client, reporting the base URL when it is invalid.
+  fn parse_url(&self, url: &str) -> NetworkResult<Url> {
+    return Url::parse(url).map_err(|e| {
+      vlog!("Invalid URL format: {}", e);
+      return NetworkError::InvalidURL(self.base_url.clone());
+    });
+  }
 
-    return Ok(parsed_response);
+  /// Runs a request until it completes or the client is cancelled.
+  async fn cancellable<T>(
+    &self,
+    request: impl Future<Output = NetworkResult<T>>,
+  ) -> NetworkResult<T> {
+    return tokio::select! {
+      biased;
+      _ = self.cancel.cancelled() => Err(NetworkError::Cancelled),
+      result = request => result,
+    };
   }
 
   async fn check_url(&self) -> NetworkResult<()> {
     vlog!("Checking if service URL is reachable...");
 
-    let _url = reqwest::Url::parse(&self.base_url).map_err(|e| {
-      vlog!("Invalid URL format: {}", e);
-      NetworkError::InvalidURL(self.base_url.clone())
-    })?;
+    self.parse_url(&self.base_url)?;
 
-    let client = reqwest::Client::new();
-
-    let response = client.get(&self.base_url).send().await.map_err(|e| {
-      vlog!("Failed to connect to URL: {}", e);
-      NetworkError::RequestFailed
-    })?;
+    let response =
+      self.client.get(&self.base_url).send().await.map_err(|e| {
+        vlog!("Failed to connect to URL: {}", e);
+        NetworkError::RequestFailed(e)
+      })?;
 
     let status = response.status();
     if status != reqwest::StatusCode::OK
@@ -132,3 +374,50 @@ impl HttpClient {
     return Ok(());
   }
 }
+
+/// Builds the error for an unsuccessful response.
+///
+/// Keeps the start of the response body, where services usually explain
+/// the error.
+async fn status_error(response: reqwest::Response) -> NetworkError {
+  let status = response.status();
+  let body = response.text().await.unwrap_or_default();
+  let body = body.trim();
+  let mut excerpt: String = body.chars().take(MAX_ERROR_BODY_LENGTH).collect();
+  if excerpt.len() < body.len() {
+    excerpt.push('…');
+  }
+  return NetworkError::ResponseError(StatusError {
+    status,
+    body: excerpt,
+  });
+}
+
+/// Gets the HTTP client shared by all requests.
+///
+/// The client is built on first use with a connect timeout and an overall
+/// request timeout, so a stalled server cannot hold up a run forever. Requests
+/// that make a service process audio or text, such as transcription, allow
+/// longer. Idle connections are kept open so later requests to the same host
+/// reuse them. Proxies are taken from the standard `HTTP_PROXY`,
+/// `HTTPS_PROXY` and `NO_PROXY` environment variables.
+///
+/// # Returns
+///
+/// A `reqwest::Client` sharing the connection pool of all clients.
+fn shared_client() -> reqwest::Client {
+  return SHARED_CLIENT
+    .get_or_init(|| {
+      return reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(CONNECT_TIMEOUT_SECONDS))
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECONDS))
+        .pool_idle_timeout(Duration::from_secs(POOL_IDLE_TIMEOUT_SECONDS))
+        .user_agent(USER_AGENT)
+        .build()
+        .unwrap_or_else(|e| {
+          vlog!("Could not configure HTTP client: {}", e);
+          return reqwest::Client::new();
+        });
+    })
+    .clone();
+}
@@ -1,6 +1,8 @@
 use reqwest::multipart;
 use serde::{Deserialize, Serialize};
 
+use tokio_util::sync::CancellationToken;
+
 use crate::network::{HttpClient, NetworkError};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -28,7 +30,7 @@ async fn test_check_url_unreachable_service() {
 
   assert!(result.is_err());
   match result.unwrap_err() {
-    NetworkError::RequestFailed => {}
+    NetworkError::RequestFailed(_) => {}
     NetworkError::InvalidURL(_) => {}
     _ => panic!("Expected RequestFailed or InvalidURL error"),
   }
@@ -57,7 +59,7 @@ async fn test_post_with_form_unreachable_service() {
     client.post_with_form(form, "test").await;
   assert!(result.is_err());
   match result.unwrap_err() {
-    NetworkError::RequestFailed => {}
+    NetworkError::RequestFailed(_) => {}
     NetworkError::InvalidURL(_) => {}
     _ => panic!("Expected RequestFailed or InvalidURL error"),
   }
@@ -93,3 +95,87 @@ async fn test_post_with_form_with_zero_length_file() {
     client.post_with_form(form, "test").await;
   assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_post_json_invalid_url() {
+  let client = HttpClient::new("invalid-url".to_string());
+  let body = serde_json::json!({ "text": "hello" });
+  let result = client.post_json(&body).await;
+
+  assert!(matches!(result, Err(NetworkError::InvalidURL(_))));
+}
+
+#[tokio::test]
+async fn test_post_json_unreachable_service() {
+  let client = HttpClient::new("http://127.0.0.1:9".to_string());
+  let body = serde_json::json!({ "text": "hello" });
+  let result = client.post_json(&body).await;
+
+  assert!(matches!(result, Err(NetworkError::RequestFailed(_))));
+}
+
+#[tokio::test]
+async fn test_get_text_invalid_url() {
+  let client = HttpClient::new("invalid-url".to_string());
+  let result = client.get_text().await;
+
+  assert!(matches!(result, Err(NetworkError::InvalidURL(_))));
+}
+
+#[tokio::test]
+async fn test_post_with_form_cancelled() {
+  let cancel = CancellationToken::new();
+  cancel.cancel();
+  let client =
+    HttpClient::new("http://127.0.0.1:9".to_string()).with_cancellation(cancel);
+  let form = multipart::Form::new();
+  let result = client.post_with_form::<TestResponse>(form, "test").await;
+
+  assert!(matches!(result, Err(NetworkError::Cancelled)));
+}
+
+#[tokio::test]
+async fn test_post_json_with_response_invalid_url() {
+  let client = HttpClient::new("invalid-url".to_string());
+  let body = TestResponse {
+    message: String::from("hello"),
+    status: String::from("ok"),
+  };
+
+  let result: Result<TestResponse, _> = client
+    .post_json_with_response("chat/completions", &body, None)
+    .await;
+  assert!(matches!(result, Err(NetworkError::InvalidURL(_))));
+}
+
+#[tokio::test]
+async fn test_get_bytes_invalid_url() {
+  let client = HttpClient::new("invalid-url".to_string());
+  let result = client.get_bytes().await;
+
+  assert!(matches!(result, Err(NetworkError::InvalidURL(_))));
+}
+
+#[tokio::test]
+async fn test_get_text_cancelled() {
+  let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let url = format!("http://{}", listener.local_addr().unwrap());
+  // Accept the connection but never answer, like a stalled server.
+  let server = tokio::spawn(async move {
+    let connection = listener.accept().await;
+    std::future::pending::<()>().await;
+    drop(connection);
+  });
+
+  let cancel = CancellationToken::new();
+  let client = HttpClient::new(url).with_cancellation(cancel.clone());
+  let request = tokio::spawn(async move {
+    return client.get_text().await;
+  });
+  tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+  cancel.cancel();
+
+  let result = request.await.unwrap();
+  assert!(matches!(result, Err(NetworkError::Cancelled)));
+  server.abort();
+}
@@ -0,0 +1,107 @@
+//! Broadcast caption formatting for timed transcripts.
+//!
+//! Produces TTML and SAMI documents from transcript segments for workflows
+//! that cannot ingest SRT. Both documents define a single `speaker` style
+//! that captions refer to, so colors can be adjusted in one place.
+
+use crate::whisper::WhisperSegment;
+
+const SPEAKER_COLOR: &str = "white";
+
+/// Formats timed transcript segments as a TTML document.
+///
+/// # Arguments
+///
+/// * `language` - Language code of the transcript, such as `en`
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` containing the TTML document.
+pub fn format_ttml(language: &str, segments: &[WhisperSegment]) -> String {
+  let mut document = format!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+     <tt xmlns=\"http://www.w3.org/ns/ttml\" \
+     xmlns:tts=\"http://www.w3.org/ns/ttml#styling\" xml:lang=\"{}\">\n  \
+     <head>\n    <styling>\n      \
+     <style xml:id=\"speaker\" tts:color=\"{}\"/>\n    \
+     </styling>\n  </head>\n  <body>\n    <div>\n",
+    escape_xml(language),
+    SPEAKER_COLOR
+  );
+  for segment in segments {
+    document.push_str(&format!(
+      "      <p begin=\"{}\" end=\"{}\" style=\"speaker\">{}</p>\n",
+      format_clock_time(segment.start),
+      format_clock_time(segment.end),
+      escape_xml(segment.text.trim())
+    ));
+  }
+  document.push_str("    </div>\n  </body>\n</tt>");
+  return document;
+}
+
+/// Formats timed transcript segments as a SAMI document.
+///
+/// Each caption is cleared at the end of its segment unless the next
+/// caption starts at the same time.
+///
+/// # Arguments
+///
+/// * `language` - Language code of the transcript, such as `en`
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` containing the SAMI document.
+pub fn format_sami(language: &str, segments: &[WhisperSegment]) -> String {
+  let class = format!("{}CC", language.to_uppercase());
+  let mut document = format!(
+    "<SAMI>\n<HEAD>\n<STYLE TYPE=\"text/css\">\n<!--\n\
+     P {{ font-family: Arial; color: {}; }}\n\
+     .{} {{ Name: {}; lang: {}; }}\n\
+     -->\n</STYLE>\n</HEAD>\n<BODY>\n",
+    SPEAKER_COLOR, class, language, language
+  );
+  for (index, segment) in segments.iter().enumerate() {
+    document.push_str(&format!(
+      "<SYNC Start={}><P Class={}>{}</P></SYNC>\n",
+      format_milliseconds(segment.start),
+      class,
+      escape_xml(segment.text.trim())
+    ));
+    let next_start = segments.get(index + 1).map(|next| next.start);
+    if next_start.is_none_or(|start| start > segment.end) {
+      document.push_str(&format!(
+        "<SYNC Start={}><P Class={}>&nbsp;</P></SYNC>\n",
+        format_milliseconds(segment.end),
+        class
+      ));
+    }
+  }
+  document.push_str("</BODY>\n</SAMI>");
+  return document;
+}
+
+fn format_clock_time(seconds: f64) -> String {
+  let milliseconds = format_milliseconds(seconds);
+  return format!(
+    "{:02}:{:02}:{:02}.{:03}",
+    milliseconds / 3_600_000,
+    (milliseconds % 3_600_000) / 60_000,
+    (milliseconds % 60_000) / 1000,
+    milliseconds % 1000
+  );
+}
+
+fn format_milliseconds(seconds: f64) -> u64 {
+  return (seconds.max(0.0) * 1000.0).round() as u64;
+}
+
+fn escape_xml(text: &str) -> String {
+  return text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;");
+}
@@ -0,0 +1,39 @@
+use crate::output::captions::{format_sami, format_ttml};
+use crate::whisper::WhisperSegment;
+
+#[test]
+fn test_format_ttml() {
+  let segments = vec![
+    WhisperSegment::sample(" Fish & chips", 1.2, 3.0),
+    WhisperSegment::sample(" <laughs>", 3661.5, 3662.0),
+  ];
+  let document = format_ttml("en", &segments);
+
+  assert!(document.contains("xml:lang=\"en\""));
+  assert!(document.contains("<style xml:id=\"speaker\" tts:color=\"white\"/>"));
+  assert!(document.contains(
+    "<p begin=\"00:00:01.200\" end=\"00:00:03.000\" style=\"speaker\">\
+     Fish &amp; chips</p>"
+  ));
+  assert!(document.contains(
+    "<p begin=\"01:01:01.500\" end=\"01:01:02.000\" style=\"speaker\">\
+     &lt;laughs&gt;</p>"
+  ));
+  assert!(document.ends_with("</tt>"));
+}
+
+#[test]
+fn test_format_sami() {
+  let segments = vec![
+    WhisperSegment::sample(" And so", 1.2, 3.0),
+    WhisperSegment::sample(" my fellow", 3.0, 4.5),
+  ];
+  let document = format_sami("en", &segments);
+
+  assert!(document.contains(".ENCC { Name: en; lang: en; }"));
+  assert!(document.contains(
+    "<SYNC Start=1200><P Class=ENCC>And so</P></SYNC>\n\
+       <SYNC Start=3000><P Class=ENCC>my fellow</P></SYNC>\n\
+       <SYNC Start=4500><P Class=ENCC>&nbsp;</P></SYNC>\n"
+  ));
+}
@@ -0,0 +1,131 @@
+//! Chapter lists for long timed transcripts.
+//!
+//! Splits a transcript into chapters at long pauses between segments and
+//! formats the chapters as a YouTube-style list such as `00:00 Intro`.
+
+use crate::whisper::WhisperSegment;
+
+const FIRST_CHAPTER_TITLE: &str = "Intro";
+const TITLE_WORDS: usize = 6;
+
+/// Chapter of a transcript.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Chapter {
+  /// Start time of the chapter in seconds
+  pub start: f64,
+  /// Title of the chapter
+  pub title: String,
+  /// Transcript text of the chapter
+  #[serde(skip)]
+  pub text: String,
+}
+
+/// Splits timed transcript segments into chapters at long pauses.
+///
+/// A new chapter starts at a pause of at least `min_pause` seconds once the
+/// current chapter is at least `min_duration` seconds long. The first
+/// chapter is titled "Intro", later chapters are titled with their first
+/// words.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+/// * `min_pause` - Minimum pause in seconds that starts a chapter
+/// * `min_duration` - Minimum duration of a chapter in seconds
+///
+/// # Returns
+///
+/// A `Vec<Chapter>`, empty if there are no segments.
+pub fn split_chapters(
+  segments: &[WhisperSegment],
+  min_pause: f64,
+  min_duration: f64,
+) -> Vec<Chapter> {
+  let mut chapters: Vec<Chapter> = Vec::new();
+  let mut previous_end = 0.0;
+  for segment in segments {
+    let text = segment.text.trim();
+    let starts_chapter = match chapters.last() {
+      None => true,
+      Some(chapter) => {
+        segment.start - previous_end >= min_pause
+          && segment.start - chapter.start >= min_duration
+      }
+    };
+
+    if starts_chapter {
+      let title = if chapters.is_empty() {
+        String::from(FIRST_CHAPTER_TITLE)
+      } else {
+        title_from_text(text)
+      };
+      chapters.push(Chapter {
+        start: if chapters.is_empty() {
+          0.0
+        } else {
+          segment.start
+        },
+        title,
+        text: text.to_string(),
+      });
+    } else if let Some(chapter) = chapters.last_mut() {
+      chapter.text.push(' ');
+      chapter.text.push_str(text);
+    }
+    previous_end = segment.end;
+  }
+  return chapters;
+}
+
+/// Formats chapters as a YouTube-style chapter list.
+///
+/// # Arguments
+///
+/// * `chapters` - The chapters to format
+///
+/// # Returns
+///
+/// A `String` with one `MM:SS Title` line per chapter, using `H:MM:SS` once
+/// the transcript reaches an hour.
+pub fn format_chapters(chapters: &[Chapter]) -> String {
+  let hours = chapters.iter().any(|chapter| chapter.start >= 3600.0);
+  return chapters
+    .iter()
+    .map(|chapter| {
+      format!(
+        "{} {}",
+        format_chapter_time(chapter.start, hours),
+        chapter.title
+      )
+    })
+    .collect::<Vec<String>>()
+    .join("\n");
+}
+
+fn format_chapter_time(seconds: f64, hours: bool) -> String {
+  let seconds = seconds.max(0.0) as u64;
+  if hours {
+    return format!(
+      "{}:{:02}:{:02}",
+      seconds / 3600,
+      (seconds % 3600) / 60,
+      seconds % 60
+    );
+  }
+  return format!("{:02}:{:02}", seconds / 60, seconds % 60);
+}
+
+fn title_from_text(text: &str) -> String {
+  let words: Vec<&str> = text.split_whitespace().collect();
+  let title = words
+    .iter()
+    .take(TITLE_WORDS)
+    .copied()
+    .collect::<Vec<&str>>()
+    .join(" ");
+  let title = title.trim_end_matches(|c: char| c.is_ascii_punctuation());
+  if words.len() > TITLE_WORDS {
+    return format!("{}…", title);
+  }
+  return title.to_string();
+}
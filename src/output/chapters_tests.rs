@@ -0,0 +1,52 @@
+use crate::output::chapters::{Chapter, format_chapters, split_chapters};
+use crate::whisper::WhisperSegment;
+
+#[test]
+fn test_split_chapters_at_long_pauses() {
+  let segments = vec![
+    WhisperSegment::sample(" Welcome to the show.", 1.0, 4.0),
+    WhisperSegment::sample(" Short pause here.", 10.0, 20.0),
+    WhisperSegment::sample(
+      " Today we talk about audio codecs and more.",
+      25.0,
+      30.0,
+    ),
+    WhisperSegment::sample(" Opus is great.", 31.0, 35.0),
+  ];
+  let chapters = split_chapters(&segments, 4.0, 15.0);
+
+  assert_eq!(chapters.len(), 2);
+  assert_eq!(chapters[0].start, 0.0);
+  assert_eq!(chapters[0].title, "Intro");
+  assert_eq!(chapters[0].text, "Welcome to the show. Short pause here.");
+  assert_eq!(chapters[1].start, 25.0);
+  assert_eq!(chapters[1].title, "Today we talk about audio codecs…");
+}
+
+#[test]
+fn test_split_chapters_respects_min_duration() {
+  let segments = vec![
+    WhisperSegment::sample(" One.", 0.0, 1.0),
+    WhisperSegment::sample(" Two.", 10.0, 11.0),
+  ];
+  assert_eq!(split_chapters(&segments, 2.0, 60.0).len(), 1);
+  assert!(split_chapters(&[], 2.0, 60.0).is_empty());
+}
+
+#[test]
+fn test_format_chapters() {
+  let chapter = |start: f64, title: &str| Chapter {
+    start,
+    title: String::from(title),
+    text: String::new(),
+  };
+
+  assert_eq!(
+    format_chapters(&[chapter(0.0, "Intro"), chapter(754.2, "Codecs")]),
+    "00:00 Intro\n12:34 Codecs"
+  );
+  assert_eq!(
+    format_chapters(&[chapter(0.0, "Intro"), chapter(3725.0, "Wrap-up")]),
+    "0:00:00 Intro\n1:02:05 Wrap-up"
+  );
+}
@@ -0,0 +1,49 @@
+//! Dialogue formatting for recordings with one speaker per channel.
+//!
+//! Call recordings and many call-center recordings carry each party on a
+//! separate channel. Each channel is transcribed on its own and the timed
+//! segments are interleaved by start time into labeled dialogue lines.
+
+use crate::whisper::WhisperSegment;
+
+/// Interleaves the segments of several speakers into dialogue lines.
+///
+/// Segments are ordered by start time and consecutive segments of the same
+/// speaker are joined into one line.
+///
+/// # Arguments
+///
+/// * `speakers` - Label and timed segments of each speaker
+///
+/// # Returns
+///
+/// A `String` with one `Label: text` line per turn.
+pub fn format_dialogue(speakers: &[(String, Vec<WhisperSegment>)]) -> String {
+  let mut turns: Vec<(f64, &str, &str)> = speakers
+    .iter()
+    .flat_map(|(label, segments)| {
+      return segments.iter().map(move |segment| {
+        return (segment.start, label.as_str(), segment.text.trim());
+      });
+    })
+    .filter(|(_, _, text)| !text.is_empty())
+    .collect();
+  turns.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+  let mut lines: Vec<(&str, String)> = Vec::new();
+  for (_, label, text) in turns {
+    match lines.last_mut() {
+      Some((last_label, line)) if *last_label == label => {
+        line.push(' ');
+        line.push_str(text);
+      }
+      _ => lines.push((label, text.to_string())),
+    }
+  }
+
+  return lines
+    .iter()
+    .map(|(label, line)| format!("{}: {}", label, line))
+    .collect::<Vec<String>>()
+    .join("\n");
+}
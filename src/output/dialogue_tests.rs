@@ -0,0 +1,35 @@
+use crate::output::dialogue::format_dialogue;
+use crate::whisper::WhisperSegment;
+
+#[test]
+fn test_format_dialogue() {
+  let speakers = vec![
+    (
+      String::from("Me"),
+      vec![
+        WhisperSegment::sample(" Hello, this is Anna.", 0.0, 2.0),
+        WhisperSegment::sample(" Sure.", 6.0, 6.5),
+        WhisperSegment::sample(" Is Friday fine?", 7.0, 8.0),
+      ],
+    ),
+    (
+      String::from("Caller"),
+      vec![
+        WhisperSegment::sample(" Hi Anna.", 2.5, 3.0),
+        WhisperSegment::sample(" Can we move the meeting?", 3.0, 5.5),
+        WhisperSegment::sample(" ", 9.0, 9.5),
+      ],
+    ),
+  ];
+  assert_eq!(
+    format_dialogue(&speakers),
+    "Me: Hello, this is Anna.\n\
+     Caller: Hi Anna. Can we move the meeting?\n\
+     Me: Sure. Is Friday fine?"
+  );
+}
+
+#[test]
+fn test_format_dialogue_empty() {
+  assert_eq!(format_dialogue(&[]), "");
+}
@@ -1,5 +1,7 @@
 /// Output format for transcription results.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "snake_case")]
 pub enum OutputFormat {
   /// Plain text output
   Text,
@@ -7,6 +9,24 @@ pub enum OutputFormat {
   Json,
   /// Full JSON output with all Whisper metadata
   FullJson,
+  /// Enhanced LRC lyrics with segment and word timestamps
+  Lrc,
+  /// TTML captions
+  Ttml,
+  /// SAMI captions
+  Sami,
+  /// Audacity label track
+  Labels,
+  /// SubRip subtitles
+  Srt,
+  /// WebVTT subtitles
+  Vtt,
+  /// Markdown with segment timestamps
+  Markdown,
+  /// Word timings as JSON
+  WordsJson,
+  /// Word timings as tab-separated values
+  WordsTsv,
 }
 
 impl OutputFormat {
@@ -29,4 +49,24 @@ impl OutputFormat {
     }
     return Self::Text;
   }
+
+  /// Gets whether the format needs timed segments.
+  ///
+  /// Timed formats are built from the verbose Whisper response.
+  ///
+  /// # Returns
+  ///
+  /// A `bool` indicating whether the format needs timed segments.
+  pub fn is_timed(&self) -> bool {
+    return !matches!(self, Self::Text | Self::Json);
+  }
+
+  /// Gets whether the format is a subtitle format.
+  ///
+  /// # Returns
+  ///
+  /// A `bool` indicating whether the format is SRT or WebVTT.
+  pub fn is_subtitles(&self) -> bool {
+    return matches!(self, Self::Srt | Self::Vtt);
+  }
 }
@@ -0,0 +1,26 @@
+//! Audacity label track formatting for timed transcripts.
+//!
+//! Produces tab-separated `start end text` lines that Audacity imports as a
+//! label track, so editors can jump to spoken phrases in the original audio.
+
+use crate::whisper::WhisperSegment;
+
+/// Formats timed transcript segments as an Audacity label track.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` with one label per segment.
+pub fn format_labels(segments: &[WhisperSegment]) -> String {
+  return segments
+    .iter()
+    .map(|segment| {
+      let text = segment.text.trim().replace(['\t', '\n'], " ");
+      return format!("{:.6}\t{:.6}\t{}", segment.start, segment.end, text);
+    })
+    .collect::<Vec<String>>()
+    .join("\n");
+}
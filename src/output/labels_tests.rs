@@ -0,0 +1,19 @@
+use crate::output::labels::format_labels;
+use crate::whisper::WhisperSegment;
+
+#[test]
+fn test_format_labels() {
+  let segments = vec![
+    WhisperSegment::sample(" And so", 0.0, 1.5),
+    WhisperSegment::sample(" my\tfellow", 1.5, 3.25),
+  ];
+  assert_eq!(
+    format_labels(&segments),
+    "0.000000\t1.500000\tAnd so\n1.500000\t3.250000\tmy fellow"
+  );
+}
+
+#[test]
+fn test_format_labels_without_segments() {
+  assert_eq!(format_labels(&[]), "");
+}
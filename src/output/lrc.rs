@@ -0,0 +1,45 @@
+//! LRC lyrics formatting for timed transcripts.
+//!
+//! Produces enhanced LRC where each line carries the segment start time and,
+//! when word timestamps are available, each word carries its own start time
+//! so players can highlight words karaoke-style.
+
+use crate::whisper::WhisperSegment;
+
+/// Formats timed transcript segments as enhanced LRC.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` with one LRC line per segment.
+pub fn format_lrc(segments: &[WhisperSegment]) -> String {
+  return segments
+    .iter()
+    .map(format_line)
+    .collect::<Vec<String>>()
+    .join("\n");
+}
+
+fn format_line(segment: &WhisperSegment) -> String {
+  let words: Vec<String> = segment
+    .words
+    .iter()
+    .filter(|word| !word.word.trim().is_empty())
+    .map(|word| format!("<{}>{}", format_time(word.start), word.word.trim()))
+    .collect();
+
+  if words.is_empty() {
+    return format!("[{}]{}", format_time(segment.start), segment.text.trim());
+  }
+  return format!("[{}]{}", format_time(segment.start), words.join(" "));
+}
+
+fn format_time(seconds: f64) -> String {
+  let hundredths = (seconds.max(0.0) * 100.0).round() as u64;
+  let minutes = hundredths / 6000;
+  let seconds = (hundredths % 6000) / 100;
+  return format!("{:02}:{:02}.{:02}", minutes, seconds, hundredths % 100);
+}
@@ -0,0 +1,40 @@
+use crate::output::lrc::format_lrc;
+use crate::whisper::WhisperSegment;
+use crate::whisper::responses::WhisperWord;
+
+fn create_segment(
+  text: &str,
+  start: f64,
+  words: Vec<(&str, f64)>,
+) -> WhisperSegment {
+  let words = words
+    .into_iter()
+    .map(|(word, start)| WhisperWord {
+      word: String::from(word),
+      start,
+      end: start + 0.3,
+      t_dtw: -1,
+      probability: 0.9,
+    })
+    .collect();
+  return WhisperSegment::sample(text, start, start + 2.0).with_words(words);
+}
+
+#[test]
+fn test_format_lrc_with_word_timestamps() {
+  let segments = vec![
+    create_segment(" And so", 1.2, vec![(" And", 1.2), (" so", 1.55)]),
+    create_segment(" my fellow", 65.0, vec![(" my", 65.0), (" fellow", 65.4)]),
+  ];
+  assert_eq!(
+    format_lrc(&segments),
+    "[00:01.20]<00:01.20>And <00:01.55>so\n\
+     [01:05.00]<01:05.00>my <01:05.40>fellow"
+  );
+}
+
+#[test]
+fn test_format_lrc_without_word_timestamps() {
+  let segments = vec![create_segment(" Ask not", 3.456, Vec::new())];
+  assert_eq!(format_lrc(&segments), "[00:03.46]Ask not");
+}
@@ -0,0 +1,62 @@
+//! Markdown formatting for timed transcripts.
+//!
+//! Lists each segment with its start time, or shows the original and
+//! translated text side by side in a table when a translation is given.
+
+use crate::whisper::WhisperSegment;
+
+/// Formats timed transcript segments as Markdown.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+/// * `translations` - Translations of the segments, in the same order
+///
+/// # Returns
+///
+/// A `String` containing the Markdown document.
+pub fn format_markdown(
+  segments: &[WhisperSegment],
+  translations: Option<&[String]>,
+) -> String {
+  let Some(translations) = translations else {
+    return segments
+      .iter()
+      .map(|segment| {
+        format!(
+          "**[{}]** {}",
+          format_time(segment.start),
+          segment.text.trim()
+        )
+      })
+      .collect::<Vec<String>>()
+      .join("\n\n");
+  };
+
+  let mut table =
+    String::from("| Time | Original | Translation |\n| --- | --- | --- |");
+  for (index, segment) in segments.iter().enumerate() {
+    let translation = translations.get(index).map_or("", |t| t.as_str());
+    table.push_str(&format!(
+      "\n| {} | {} | {} |",
+      format_time(segment.start),
+      escape_cell(segment.text.trim()),
+      escape_cell(translation.trim())
+    ));
+  }
+  return table;
+}
+
+fn format_time(seconds: f64) -> String {
+  let seconds = seconds.max(0.0) as u64;
+  return format!(
+    "{:02}:{:02}:{:02}",
+    seconds / 3600,
+    (seconds % 3600) / 60,
+    seconds % 60
+  );
+}
+
+fn escape_cell(text: &str) -> String {
+  return text.replace('|', "\\|").replace('\n', " ");
+}
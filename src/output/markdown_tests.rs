@@ -0,0 +1,29 @@
+use crate::output::markdown::format_markdown;
+use crate::whisper::WhisperSegment;
+
+fn create_segment(text: &str, start: f64) -> WhisperSegment {
+  return WhisperSegment::sample(text, start, start + 1.0);
+}
+
+#[test]
+fn test_format_markdown() {
+  let segments = vec![
+    create_segment(" Hello", 0.0),
+    create_segment(" world", 65.2),
+  ];
+  assert_eq!(
+    format_markdown(&segments, None),
+    "**[00:00:00]** Hello\n\n**[00:01:05]** world"
+  );
+}
+
+#[test]
+fn test_format_markdown_side_by_side() {
+  let segments = vec![create_segment(" Yes | no", 0.0)];
+  let translations = vec![String::from("Ja | nein")];
+  assert_eq!(
+    format_markdown(&segments, Some(&translations)),
+    "| Time | Original | Translation |\n| --- | --- | --- |\n\
+     | 00:00:00 | Yes \\| no | Ja \\| nein |"
+  );
+}
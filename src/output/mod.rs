@@ -1,6 +1,52 @@
 //! Output format handling for transcription results.
 //!
 //! ## Components
-//! - [`OutputFormat`]: Enum for text/JSON/full-JSON output formats
+//! - [`OutputFormat`]: Enum for text, JSON, lyrics and caption output formats
+//! - [`format_lrc`]: Formats timed segments as enhanced LRC lyrics
+//! - [`format_ttml`] and [`format_sami`]: Format timed segments as captions
+//! - [`format_dialogue`]: Interleaves per-channel segments into dialogue lines
+//! - [`format_labels`]: Formats timed segments as an Audacity label track
+//! - [`format_notes_header`] and [`format_note`]: Format live meeting notes
+//! - [`format_srt`] and [`format_vtt`]: Format timed segments as subtitles
+//! - [`split_chapters`] and [`format_chapters`]: Build chapter lists
+//! - [`format_markdown`]: Formats timed segments, optionally with translations
+//! - [`strip_stop_phrase`]: Removes a spoken stop phrase from transcripts
+//! - [`strip_annotations`]: Removes non-speech annotations such as `[Music]`
+//! - [`format_status`]: Formats progress events as screen-reader-friendly lines
+//! - [`format_words_json`] and [`format_words_tsv`]: Format word timings
 
+pub mod captions;
+pub mod chapters;
+pub mod dialogue;
 pub mod format;
+pub mod labels;
+pub mod lrc;
+pub mod markdown;
+pub mod notes;
+pub mod status;
+pub mod subtitles;
+pub mod transcript;
+pub mod words;
+
+#[cfg(test)]
+mod captions_tests;
+#[cfg(test)]
+mod chapters_tests;
+#[cfg(test)]
+mod dialogue_tests;
+#[cfg(test)]
+mod labels_tests;
+#[cfg(test)]
+mod lrc_tests;
+#[cfg(test)]
+mod markdown_tests;
+#[cfg(test)]
+mod notes_tests;
+#[cfg(test)]
+mod status_tests;
+#[cfg(test)]
+mod subtitles_tests;
+#[cfg(test)]
+mod transcript_tests;
+#[cfg(test)]
+mod words_tests;
@@ -0,0 +1,54 @@
+//! Markdown formatting for live meeting notes.
+//!
+//! Notes start with a header and grow by one paragraph per transcribed
+//! chunk, each labeled with the wall-clock time the chunk started.
+
+use chrono::{DateTime, TimeZone};
+
+const DEFAULT_NOTES_TITLE: &str = "Meeting notes";
+
+/// Formats the header of a notes file.
+///
+/// # Arguments
+///
+/// * `title` - Title of the meeting (empty for a generic title)
+/// * `started` - When the meeting started
+///
+/// # Returns
+///
+/// A `String` containing the Markdown header.
+pub fn format_notes_header<Tz: TimeZone>(
+  title: &str,
+  started: &DateTime<Tz>,
+) -> String
+where
+  Tz::Offset: std::fmt::Display,
+{
+  let title = if title.is_empty() {
+    DEFAULT_NOTES_TITLE
+  } else {
+    title
+  };
+  return format!(
+    "# {}\n\n_Started {}_\n\n",
+    title,
+    started.format("%Y-%m-%d %H:%M")
+  );
+}
+
+/// Formats a transcribed chunk as a notes paragraph.
+///
+/// # Arguments
+///
+/// * `started` - When the chunk started
+/// * `text` - The transcribed text of the chunk
+///
+/// # Returns
+///
+/// A `String` containing the Markdown paragraph.
+pub fn format_note<Tz: TimeZone>(started: &DateTime<Tz>, text: &str) -> String
+where
+  Tz::Offset: std::fmt::Display,
+{
+  return format!("**{}** {}\n\n", started.format("%H:%M:%S"), text.trim());
+}
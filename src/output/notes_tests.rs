@@ -0,0 +1,23 @@
+use chrono::{TimeZone, Utc};
+
+use crate::output::notes::{format_note, format_notes_header};
+
+#[test]
+fn test_format_notes_header() {
+  let started = Utc.with_ymd_and_hms(2026, 3, 9, 14, 5, 0).unwrap();
+
+  assert_eq!(
+    format_notes_header("Weekly sync", &started),
+    "# Weekly sync\n\n_Started 2026-03-09 14:05_\n\n"
+  );
+  assert!(format_notes_header("", &started).starts_with("# Meeting notes\n"));
+}
+
+#[test]
+fn test_format_note() {
+  let started = Utc.with_ymd_and_hms(2026, 3, 9, 14, 7, 31).unwrap();
+  assert_eq!(
+    format_note(&started, " Let's ship on Friday. "),
+    "**14:07:31** Let's ship on Friday.\n\n"
+  );
+}
@@ -0,0 +1,43 @@
+//! Screen-reader-friendly status lines.
+//!
+//! Describes progress events as short, punctuated sentences that read well
+//! aloud, optionally preceded by a terminal bell as an audio cue when
+//! recording starts or stops.
+
+use crate::events::Event;
+use crate::tr;
+
+const AUDIO_CUE: &str = "\x07";
+
+/// Formats a progress event as a status line.
+///
+/// # Arguments
+///
+/// * `event` - The progress event
+/// * `audio_cues` - Whether to ring the terminal bell when recording starts
+///   or stops
+///
+/// # Returns
+///
+/// An `Option<String>` containing the status line, or `None` for events
+/// that are not announced.
+pub fn format_status(event: &Event, audio_cues: bool) -> Option<String> {
+  let (line, cue) = match event {
+    Event::DeviceSelected { name } => {
+      (tr!("status-device-selected", name = name), false)
+    }
+    Event::RecordingStarted { .. } => (tr!("status-recording-started"), true),
+    Event::RecordingStopped { duration, .. } => (
+      tr!("status-recording-stopped", seconds = duration.round()),
+      true,
+    ),
+    Event::TranscriptionStarted => (tr!("status-transcription-started"), false),
+    Event::Completed => (tr!("status-completed"), false),
+    _ => return None,
+  };
+
+  if cue && audio_cues {
+    return Some(format!("{}{}", AUDIO_CUE, line));
+  }
+  return Some(line);
+}
@@ -0,0 +1,30 @@
+use crate::events::Event;
+use crate::output::status::format_status;
+
+#[test]
+fn test_format_status() {
+  let stopped = Event::RecordingStopped {
+    path: String::from("note.wav"),
+    duration: 4.6,
+  };
+  assert_eq!(
+    format_status(&stopped, false),
+    Some(String::from("Recording stopped after 5 seconds."))
+  );
+  assert_eq!(
+    format_status(&Event::TranscriptionStarted, true),
+    Some(String::from("Transcribing."))
+  );
+  assert_eq!(format_status(&Event::SilenceDetected, false), None);
+}
+
+#[test]
+fn test_format_status_with_audio_cue() {
+  let started = Event::RecordingStarted {
+    path: String::from("note.wav"),
+  };
+  assert_eq!(
+    format_status(&started, true),
+    Some(String::from("\x07Recording. Speak now."))
+  );
+}
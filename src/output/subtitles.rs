@@ -0,0 +1,132 @@
+//! SubRip and WebVTT subtitle formatting for timed transcripts.
+
+use crate::whisper::WhisperSegment;
+
+/// Formats timed transcript segments as SubRip (SRT) subtitles.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` with one numbered cue per segment.
+pub fn format_srt(segments: &[WhisperSegment]) -> String {
+  return segments
+    .iter()
+    .enumerate()
+    .map(|(index, segment)| {
+      return format!(
+        "{}\n{} --> {}\n{}\n",
+        index + 1,
+        format_timestamp(segment.start, ','),
+        format_timestamp(segment.end, ','),
+        segment.text.trim()
+      );
+    })
+    .collect::<Vec<String>>()
+    .join("\n");
+}
+
+/// Formats timed transcript segments as WebVTT subtitles.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` with the WebVTT header and one cue per segment.
+pub fn format_vtt(segments: &[WhisperSegment]) -> String {
+  let mut output = String::from("WEBVTT\n");
+  for segment in segments {
+    output.push_str(&format!(
+      "\n{} --> {}\n{}\n",
+      format_timestamp(segment.start, '.'),
+      format_timestamp(segment.end, '.'),
+      segment.text.trim()
+    ));
+  }
+  return output;
+}
+
+/// Adds the translation of each segment as a second line of its cue.
+///
+/// Segments without a matching translation keep only their text.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+/// * `translations` - Translations of the segments, in the same order
+///
+/// # Returns
+///
+/// A `Vec<WhisperSegment>` with the original and translated lines.
+pub fn bilingual_segments(
+  segments: &[WhisperSegment],
+  translations: &[String],
+) -> Vec<WhisperSegment> {
+  return segments
+    .iter()
+    .zip(translations.iter().map(Some).chain(std::iter::repeat(None)))
+    .map(|(segment, translation)| {
+      let mut segment = segment.clone();
+      if let Some(translation) = translation {
+        segment.text =
+          format!("{}\n{}", segment.text.trim(), translation.trim());
+      }
+      return segment;
+    })
+    .collect();
+}
+
+/// Replaces the text of each segment with its translation.
+///
+/// Segments without a matching translation keep their text.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+/// * `translations` - Translations of the segments, in the same order
+///
+/// # Returns
+///
+/// A `Vec<WhisperSegment>` with translated texts and the original timing.
+pub fn translate_segments(
+  segments: &[WhisperSegment],
+  translations: &[String],
+) -> Vec<WhisperSegment> {
+  return segments
+    .iter()
+    .zip(translations.iter().map(Some).chain(std::iter::repeat(None)))
+    .map(|(segment, translation)| {
+      let mut segment = segment.clone();
+      if let Some(translation) = translation {
+        segment.text = translation.clone();
+      }
+      return segment;
+    })
+    .collect();
+}
+
+/// Formats seconds as an `HH:MM:SS,mmm` subtitle timestamp.
+///
+/// # Arguments
+///
+/// * `seconds` - The time in seconds
+/// * `separator` - Separator between seconds and milliseconds
+///
+/// # Returns
+///
+/// A `String` containing the timestamp.
+pub fn format_timestamp(seconds: f64, separator: char) -> String {
+  let milliseconds = (seconds.max(0.0) * 1000.0).round() as u64;
+  return format!(
+    "{:02}:{:02}:{:02}{}{:03}",
+    milliseconds / 3_600_000,
+    (milliseconds % 3_600_000) / 60_000,
+    (milliseconds % 60_000) / 1000,
+    separator,
+    milliseconds % 1000
+  );
+}
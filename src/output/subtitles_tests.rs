@@ -0,0 +1,64 @@
+use crate::output::subtitles::{
+  bilingual_segments, format_srt, format_timestamp, format_vtt,
+  translate_segments,
+};
+use crate::whisper::WhisperSegment;
+
+#[test]
+fn test_format_timestamp() {
+  assert_eq!(format_timestamp(3661.5, ','), "01:01:01,500");
+  assert_eq!(format_timestamp(0.0, '.'), "00:00:00.000");
+}
+
+#[test]
+fn test_format_srt() {
+  let segments = vec![
+    WhisperSegment::sample(" And so", 0.0, 1.5),
+    WhisperSegment::sample(" my fellow", 1.5, 3.25),
+  ];
+  assert_eq!(
+    format_srt(&segments),
+    "1\n00:00:00,000 --> 00:00:01,500\nAnd so\n\n\
+     2\n00:00:01,500 --> 00:00:03,250\nmy fellow\n"
+  );
+}
+
+#[test]
+fn test_translate_segments_keeps_timing() {
+  let segments = vec![
+    WhisperSegment::sample(" Hello", 0.0, 1.0),
+    WhisperSegment::sample(" world", 1.0, 2.0),
+  ];
+  let translated = translate_segments(&segments, &[String::from("Hallo")]);
+
+  assert_eq!(translated[0].text, "Hallo");
+  assert_eq!(translated[0].end, 1.0);
+  assert_eq!(translated[1].text, " world");
+}
+
+#[test]
+fn test_format_vtt() {
+  let segments = vec![
+    WhisperSegment::sample(" And so", 0.0, 1.5),
+    WhisperSegment::sample(" my fellow", 1.5, 3.25),
+  ];
+  assert_eq!(
+    format_vtt(&segments),
+    "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nAnd so\n\n\
+     00:00:01.500 --> 00:00:03.250\nmy fellow\n"
+  );
+}
+
+#[test]
+fn test_bilingual_segments() {
+  let segments = vec![
+    WhisperSegment::sample(" Hello", 0.0, 1.0),
+    WhisperSegment::sample(" world", 1.0, 2.0),
+  ];
+  let cues = bilingual_segments(&segments, &[String::from(" Hallo")]);
+  assert_eq!(
+    format_srt(&cues),
+    "1\n00:00:00,000 --> 00:00:01,000\nHello\nHallo\n\n\
+     2\n00:00:01,000 --> 00:00:02,000\nworld\n"
+  );
+}
@@ -0,0 +1,83 @@
+use regex::{Regex, RegexBuilder};
+
+const ANNOTATION_PATTERN: &str = r"[\[(][^\[\]()]*[\])]";
+
+/// Finds where a stop phrase is spoken in a transcript.
+///
+/// Matching is case-insensitive, respects word boundaries and tolerates
+/// punctuation or extra whitespace between the words of the phrase.
+///
+/// # Arguments
+///
+/// * `text` - The transcript text
+/// * `stop_phrase` - The phrase that ends dictation (empty to disable)
+///
+/// # Returns
+///
+/// The byte offset of the first occurrence of the stop phrase, or `None` if
+/// it is not spoken.
+pub fn find_stop_phrase(text: &str, stop_phrase: &str) -> Option<usize> {
+  let words: Vec<String> =
+    stop_phrase.split_whitespace().map(regex::escape).collect();
+  if words.is_empty() {
+    return None;
+  }
+
+  let pattern = format!(r"\b{}\b", words.join(r"[\s\p{P}]+"));
+  let regex = RegexBuilder::new(&pattern)
+    .case_insensitive(true)
+    .build()
+    .ok()?;
+  return regex.find(text).map(|found| found.start());
+}
+
+/// Removes a stop phrase and anything spoken after it from a transcript.
+///
+/// The phrase is matched like in [`find_stop_phrase`].
+///
+/// # Arguments
+///
+/// * `text` - The transcript text
+/// * `stop_phrase` - The phrase that ends dictation (empty to disable)
+///
+/// # Returns
+///
+/// The transcript text up to the first occurrence of the stop phrase.
+pub fn strip_stop_phrase(text: &str, stop_phrase: &str) -> String {
+  return match find_stop_phrase(text, stop_phrase) {
+    Some(start) => trim_before_stop_phrase(&text[..start]).to_string(),
+    None => text.to_string(),
+  };
+}
+
+/// Trims the whitespace and commas left in front of a removed stop phrase.
+pub(crate) fn trim_before_stop_phrase(text: &str) -> &str {
+  return text.trim_end_matches(|c: char| c.is_whitespace() || c == ',');
+}
+
+/// Removes bracketed non-speech annotations such as `[Music]` or `(laughs)`.
+///
+/// Whitespace left behind by removed annotations is collapsed.
+///
+/// # Arguments
+///
+/// * `text` - The transcript text
+///
+/// # Returns
+///
+/// The transcript text without annotations.
+pub fn strip_annotations(text: &str) -> String {
+  let regex = Regex::new(ANNOTATION_PATTERN).unwrap();
+  let stripped = regex.replace_all(text, "");
+
+  let mut result = String::with_capacity(stripped.len());
+  let mut previous_space = false;
+  for c in stripped.chars() {
+    let space = c.is_whitespace();
+    if !(space && previous_space) {
+      result.push(if space { ' ' } else { c });
+    }
+    previous_space = space;
+  }
+  return result.trim_end().to_string();
+}
@@ -0,0 +1,58 @@
+use crate::output::transcript::{
+  find_stop_phrase, strip_annotations, strip_stop_phrase,
+};
+
+#[test]
+fn test_strip_stop_phrase() {
+  let text = "Buy milk and eggs. Stop dictation. Thanks";
+  assert_eq!(
+    strip_stop_phrase(text, "stop dictation"),
+    "Buy milk and eggs."
+  );
+}
+
+#[test]
+fn test_strip_stop_phrase_with_punctuation_between_words() {
+  let text = " Send the report, stop, dictation";
+  assert_eq!(
+    strip_stop_phrase(text, "stop dictation"),
+    " Send the report"
+  );
+}
+
+#[test]
+fn test_strip_stop_phrase_without_match() {
+  let text = "Nonstop dictations are fine";
+  assert_eq!(strip_stop_phrase(text, "stop dictation"), text);
+}
+
+#[test]
+fn test_strip_stop_phrase_empty_phrase() {
+  let text = "Keep everything";
+  assert_eq!(strip_stop_phrase(text, "  "), text);
+}
+
+#[test]
+fn test_strip_annotations() {
+  let text = " [Music] And so (laughs) my fellow Americans [BLANK_AUDIO]";
+  assert_eq!(strip_annotations(text), " And so my fellow Americans");
+}
+
+#[test]
+fn test_strip_annotations_without_annotations() {
+  let text = " Ask not what your country can do for you";
+  assert_eq!(strip_annotations(text), text);
+}
+
+#[test]
+fn test_find_stop_phrase() {
+  assert_eq!(
+    find_stop_phrase("Hello. Stop, dictation!", "stop dictation"),
+    Some(7)
+  );
+  assert_eq!(
+    find_stop_phrase("Nonstop dictation", "stop dictation"),
+    None
+  );
+  assert_eq!(find_stop_phrase("Stop dictation", ""), None);
+}
@@ -0,0 +1,103 @@
+//! Word-level timing output for timed transcripts.
+//!
+//! Whisper reports timings per token, so tokens that continue a word, such
+//! as punctuation or the second half of a long word, are merged into the
+//! word they belong to. The result can be fed to forced-alignment and
+//! editing tools as JSON or as tab-separated `start end word probability`
+//! lines.
+
+use crate::whisper::WhisperSegment;
+
+/// A spoken word with its timing.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TimedWord {
+  /// The word without surrounding whitespace
+  pub word: String,
+  /// Start time in seconds
+  pub start: f64,
+  /// End time in seconds
+  pub end: f64,
+  /// Lowest probability of the tokens that make up the word
+  pub probability: f64,
+}
+
+/// Collects the words of timed transcript segments.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// The words of all segments in order. Segments without word timings
+/// contribute no words.
+pub fn collect_words(segments: &[WhisperSegment]) -> Vec<TimedWord> {
+  let mut words: Vec<TimedWord> = Vec::new();
+  for segment in segments {
+    let mut starts_segment = true;
+    for token in &segment.words {
+      let text = token.word.trim();
+      if text.is_empty() {
+        continue;
+      }
+      let continues_word =
+        !starts_segment && !token.word.starts_with(char::is_whitespace);
+      starts_segment = false;
+
+      if continues_word && let Some(word) = words.last_mut() {
+        word.word.push_str(text);
+        word.end = word.end.max(token.end);
+        word.probability = word.probability.min(token.probability);
+        continue;
+      }
+      words.push(TimedWord {
+        word: text.to_string(),
+        start: token.start,
+        end: token.end,
+        probability: token.probability,
+      });
+    }
+  }
+  return words;
+}
+
+/// Formats the words of timed transcript segments as JSON.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `serde_json::Result<String>` containing a JSON array with one object
+/// per word.
+pub fn format_words_json(
+  segments: &[WhisperSegment],
+) -> serde_json::Result<String> {
+  return serde_json::to_string_pretty(&collect_words(segments));
+}
+
+/// Formats the words of timed transcript segments as tab-separated values.
+///
+/// # Arguments
+///
+/// * `segments` - The timed transcript segments
+///
+/// # Returns
+///
+/// A `String` with one `start end word probability` line per word.
+pub fn format_words_tsv(segments: &[WhisperSegment]) -> String {
+  return collect_words(segments)
+    .iter()
+    .map(|word| {
+      return format!(
+        "{:.3}\t{:.3}\t{}\t{:.4}",
+        word.start,
+        word.end,
+        word.word.replace(['\t', '\n'], " "),
+        word.probability
+      );
+    })
+    .collect::<Vec<String>>()
+    .join("\n");
+}
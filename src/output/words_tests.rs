@@ -0,0 +1,106 @@
+use crate::output::words::{
+  TimedWord, collect_words, format_words_json, format_words_tsv,
+};
+use crate::whisper::WhisperSegment;
+use crate::whisper::responses::WhisperWord;
+
+fn create_word(
+  word: &str,
+  start: f64,
+  end: f64,
+  probability: f64,
+) -> WhisperWord {
+  return WhisperWord {
+    word: String::from(word),
+    start,
+    end,
+    t_dtw: -1,
+    probability,
+  };
+}
+
+fn create_segment(words: Vec<WhisperWord>) -> WhisperSegment {
+  let text: String = words.iter().map(|word| word.word.as_str()).collect();
+  let start = words.first().map_or(0.0, |word| word.start);
+  let end = words.last().map_or(0.0, |word| word.end);
+  return WhisperSegment::sample(&text, start, end).with_words(words);
+}
+
+fn create_segments() -> Vec<WhisperSegment> {
+  return vec![
+    create_segment(vec![
+      create_word(" And", 0.0, 0.32, 0.98),
+      create_word(" so", 0.32, 0.6, 0.95),
+      create_word(",", 0.6, 0.62, 0.8),
+    ]),
+    create_segment(vec![
+      create_word("my", 1.0, 1.2, 0.99),
+      create_word(" fell", 1.2, 1.4, 0.9),
+      create_word("ow", 1.4, 1.7, 0.7),
+      create_word(" ", 1.7, 1.7, 0.1),
+    ]),
+  ];
+}
+
+#[test]
+fn test_collect_words_merges_continuation_tokens() {
+  let words = collect_words(&create_segments());
+
+  assert_eq!(
+    words,
+    vec![
+      TimedWord {
+        word: String::from("And"),
+        start: 0.0,
+        end: 0.32,
+        probability: 0.98,
+      },
+      TimedWord {
+        word: String::from("so,"),
+        start: 0.32,
+        end: 0.62,
+        probability: 0.8,
+      },
+      TimedWord {
+        word: String::from("my"),
+        start: 1.0,
+        end: 1.2,
+        probability: 0.99,
+      },
+      TimedWord {
+        word: String::from("fellow"),
+        start: 1.2,
+        end: 1.7,
+        probability: 0.7,
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_collect_words_without_word_timings() {
+  assert!(collect_words(&[create_segment(Vec::new())]).is_empty());
+}
+
+#[test]
+fn test_format_words_tsv() {
+  assert_eq!(
+    format_words_tsv(&create_segments()),
+    "0.000\t0.320\tAnd\t0.9800\n\
+     0.320\t0.620\tso,\t0.8000\n\
+     1.000\t1.200\tmy\t0.9900\n\
+     1.200\t1.700\tfellow\t0.7000"
+  );
+}
+
+#[test]
+fn test_format_words_json() {
+  let json = format_words_json(&create_segments()).unwrap();
+  let words: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+  assert_eq!(words.as_array().unwrap().len(), 4);
+  assert_eq!(words[3]["word"], "fellow");
+  assert_eq!(words[3]["start"], 1.2);
+  assert_eq!(words[3]["end"], 1.7);
+  assert_eq!(words[3]["probability"], 0.7);
+}
@@ -0,0 +1,77 @@
+use crate::postprocess::errors::{PostProcessError, PostProcessResult};
+use crate::postprocess::llm::LanguageModel;
+use crate::vlog;
+
+const CHAPTER_TITLE_INSTRUCTIONS: &str = "You title chapters of podcast \
+and meeting transcripts. Each line of the user's message is the transcript \
+of one chapter. Reply with exactly one short title of at most six words per \
+line, in the same order and language, without numbering, quotes or \
+timestamps.";
+
+/// Maximum number of characters of a chapter sent to the language model.
+const MAX_CHAPTER_CHARACTERS: usize = 2000;
+
+/// Titles transcript chapters with a language model.
+///
+/// # Arguments
+///
+/// * `model` - The language model to title chapters with
+/// * `texts` - The transcript text of each chapter
+///
+/// # Returns
+///
+/// A `PostProcessResult<Vec<String>>` containing one title per chapter or an
+/// error.
+pub async fn title_chapters(
+  model: &LanguageModel,
+  texts: &[String],
+) -> PostProcessResult<Vec<String>> {
+  if texts.is_empty() {
+    return Ok(Vec::new());
+  }
+  vlog!("Titling {} chapters with language model", texts.len());
+
+  let lines: Vec<String> =
+    texts.iter().map(|text| chapter_line(text)).collect();
+  let reply = model
+    .complete(CHAPTER_TITLE_INSTRUCTIONS, &lines.join("\n"))
+    .await?;
+  return split_titles(&reply, texts.len());
+}
+
+/// Splits a language model reply into one title per chapter.
+///
+/// # Arguments
+///
+/// * `reply` - The language model reply
+/// * `expected` - The number of chapters
+///
+/// # Returns
+///
+/// A `PostProcessResult<Vec<String>>` containing the titles or an error if
+/// the number of titles does not match.
+pub fn split_titles(
+  reply: &str,
+  expected: usize,
+) -> PostProcessResult<Vec<String>> {
+  let titles: Vec<String> = reply
+    .lines()
+    .map(|line| line.trim().trim_matches('"').trim())
+    .filter(|line| !line.is_empty())
+    .map(String::from)
+    .collect();
+  if titles.len() != expected {
+    return Err(PostProcessError::TitleMismatch(titles.len(), expected));
+  }
+  return Ok(titles);
+}
+
+fn chapter_line(text: &str) -> String {
+  return text
+    .split_whitespace()
+    .collect::<Vec<&str>>()
+    .join(" ")
+    .chars()
+    .take(MAX_CHAPTER_CHARACTERS)
+    .collect();
+}
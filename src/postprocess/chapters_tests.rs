@@ -0,0 +1,13 @@
+use crate::postprocess::chapters::split_titles;
+use crate::postprocess::errors::PostProcessError;
+
+#[test]
+fn test_split_titles() {
+  let titles = split_titles("\"Welcome\"\n\n Audio codecs \n", 2).unwrap();
+  assert_eq!(titles, vec!["Welcome", "Audio codecs"]);
+
+  assert!(matches!(
+    split_titles("Welcome", 2),
+    Err(PostProcessError::TitleMismatch(1, 2))
+  ));
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+/// Post-processing errors.
+///
+/// Represents errors that can occur while refining transcripts with a
+/// language model.
+#[derive(Error, Debug)]
+pub enum PostProcessError {
+  #[error(
+    "Unknown post-processing profile: '{0}'. Supported profiles are: meeting."
+  )]
+  UnknownProfile(String),
+
+  #[error(
+    "Language model is not configured. Please set url and model in the [llm] section."
+  )]
+  NotConfigured,
+
+  #[error(
+    "Unknown translation service: '{0}'. Supported services are llm and deepl."
+  )]
+  UnknownTranslationService(String),
+
+  #[error("Missing API key for {0}. Please check your configuration file.")]
+  MissingApiKey(String),
+
+  #[error("Translation returned {0} lines for {1} texts.")]
+  TranslationMismatch(usize, usize),
+
+  #[error("Language model returned {0} chapter titles for {1} chapters.")]
+  TitleMismatch(usize, usize),
+
+  #[error("Language model request failed: {0}")]
+  Request(String),
+
+  #[error("Language model returned an empty reply.")]
+  EmptyReply,
+}
+
+/// Result type for post-processing operations.
+pub type PostProcessResult<T> = Result<T, PostProcessError>;
@@ -0,0 +1,163 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::network::HttpClient;
+use crate::postprocess::errors::{PostProcessError, PostProcessResult};
+use crate::vlog;
+
+const CHAT_COMPLETIONS_ENDPOINT: &str = "chat/completions";
+
+/// Chat completion request of an OpenAI-compatible API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ChatRequest {
+  pub model: String,
+  pub messages: Vec<ChatMessage>,
+}
+
+/// Message of a chat completion request or reply.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ChatMessage {
+  pub role: String,
+  pub content: String,
+}
+
+/// Chat completion response of an OpenAI-compatible API.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ChatResponse {
+  pub choices: Vec<ChatChoice>,
+}
+
+/// Single completion choice of a chat completion response.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ChatChoice {
+  pub message: ChatMessage,
+}
+
+/// Language model served through an OpenAI-compatible chat completions API.
+///
+/// Works with OpenAI as well as local servers such as Ollama or llama.cpp.
+#[derive(Debug, Clone)]
+pub struct LanguageModel {
+  client: HttpClient,
+  model: String,
+  api_key: String,
+}
+
+impl LanguageModel {
+  /// Creates a new LanguageModel.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - Base URL of the API, such as `https://api.openai.com/v1`
+  /// * `model` - Name of the model
+  /// * `api_key` - API key sent as bearer token (empty to send none)
+  /// * `cancel` - Token that aborts requests when cancelled
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<LanguageModel>` containing the model or an error
+  /// if the URL or model is not configured.
+  pub fn new(
+    url: String,
+    model: String,
+    api_key: String,
+    cancel: CancellationToken,
+  ) -> PostProcessResult<Self> {
+    if url.is_empty() || model.is_empty() {
+      return Err(PostProcessError::NotConfigured);
+    }
+    return Ok(LanguageModel {
+      client: HttpClient::new(url).with_cancellation(cancel),
+      model,
+      api_key,
+    });
+  }
+
+  /// Asks the model to process a text following instructions.
+  ///
+  /// # Arguments
+  ///
+  /// * `instructions` - System instructions describing the task
+  /// * `text` - The text to process
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<String>` containing the reply or an error.
+  pub async fn complete(
+    &self,
+    instructions: &str,
+    text: &str,
+  ) -> PostProcessResult<String> {
+    vlog!("Sending transcript to language model {}", self.model);
+
+    let request = build_request(&self.model, instructions, text);
+    let authorization = if self.api_key.is_empty() {
+      None
+    } else {
+      Some(format!("Bearer {}", self.api_key))
+    };
+    let response = self
+      .client
+      .post_json_with_response::<ChatRequest, ChatResponse>(
+        CHAT_COMPLETIONS_ENDPOINT,
+        &request,
+        authorization.as_deref(),
+      )
+      .await
+      .map_err(|e| PostProcessError::Request(e.to_string()))?;
+    return read_reply(response);
+  }
+}
+
+/// Builds a chat completion request.
+///
+/// # Arguments
+///
+/// * `model` - Name of the model
+/// * `instructions` - System instructions describing the task
+/// * `text` - The text to process
+///
+/// # Returns
+///
+/// A `ChatRequest` with a system and a user message.
+pub fn build_request(
+  model: &str,
+  instructions: &str,
+  text: &str,
+) -> ChatRequest {
+  return ChatRequest {
+    model: model.to_string(),
+    messages: vec![
+      ChatMessage {
+        role: String::from("system"),
+        content: instructions.to_string(),
+      },
+      ChatMessage {
+        role: String::from("user"),
+        content: text.trim().to_string(),
+      },
+    ],
+  };
+}
+
+/// Reads the reply text from a chat completion response.
+///
+/// # Arguments
+///
+/// * `response` - The chat completion response
+///
+/// # Returns
+///
+/// A `PostProcessResult<String>` containing the trimmed reply or an error if
+/// it is empty.
+pub fn read_reply(response: ChatResponse) -> PostProcessResult<String> {
+  let reply = response
+    .choices
+    .into_iter()
+    .next()
+    .map(|choice| choice.message.content.trim().to_string())
+    .unwrap_or_default();
+  if reply.is_empty() {
+    return Err(PostProcessError::EmptyReply);
+  }
+  return Ok(reply);
+}
@@ -0,0 +1,53 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::postprocess::errors::PostProcessError;
+use crate::postprocess::llm::{
+  ChatResponse, LanguageModel, build_request, read_reply,
+};
+
+#[test]
+fn test_language_model_requires_url_and_model() {
+  let result = LanguageModel::new(
+    String::new(),
+    String::from("llama3"),
+    String::new(),
+    CancellationToken::new(),
+  );
+  assert!(matches!(result, Err(PostProcessError::NotConfigured)));
+
+  let result = LanguageModel::new(
+    String::from("http://127.0.0.1:11434/v1"),
+    String::from("llama3"),
+    String::new(),
+    CancellationToken::new(),
+  );
+  assert!(result.is_ok());
+}
+
+#[test]
+fn test_build_request() {
+  let request = build_request("llama3", "Summarize.", " We ship Friday. ");
+  let json = serde_json::to_value(&request).unwrap();
+
+  assert_eq!(json["model"], "llama3");
+  assert_eq!(json["messages"][0]["role"], "system");
+  assert_eq!(json["messages"][0]["content"], "Summarize.");
+  assert_eq!(json["messages"][1]["role"], "user");
+  assert_eq!(json["messages"][1]["content"], "We ship Friday.");
+}
+
+#[test]
+fn test_read_reply() {
+  let response: ChatResponse = serde_json::from_str(
+    r###"{"choices":[{"message":{"role":"assistant","content":"## Summary\n"}}]}"###,
+  )
+  .unwrap();
+  assert_eq!(read_reply(response).unwrap(), "## Summary");
+
+  let response: ChatResponse =
+    serde_json::from_str(r#"{"choices":[]}"#).unwrap();
+  assert!(matches!(
+    read_reply(response),
+    Err(PostProcessError::EmptyReply)
+  ));
+}
@@ -0,0 +1,35 @@
+//! Post-processing of transcripts with a language model.
+//!
+//! Sends finished transcripts to an OpenAI-compatible chat completions API
+//! to derive documents such as meeting summaries, and translates transcripts
+//! with the language model or the DeepL API. The language model can also
+//! title the chapters of long transcripts.
+//!
+//! ## Main Components
+//!
+//! - [`LanguageModel`]: Client of an OpenAI-compatible chat completions API
+//! - [`PostProfile`]: Built-in post-processing profiles
+//! - [`Translator`]: Translates transcripts segment by segment
+//! - [`title_chapters`]: Titles transcript chapters by topic
+//! - [`PostProcessError`]: Error types for post-processing operations
+//! - [`PostProcessResult<T>`]: Result type alias for post-processing operations
+
+pub mod chapters;
+pub mod errors;
+pub mod llm;
+pub mod profiles;
+pub mod translation;
+
+#[cfg(test)]
+mod chapters_tests;
+#[cfg(test)]
+mod llm_tests;
+#[cfg(test)]
+mod profiles_tests;
+#[cfg(test)]
+mod translation_tests;
+
+pub use chapters::title_chapters;
+pub use llm::LanguageModel;
+pub use profiles::PostProfile;
+pub use translation::{TranslationService, Translator};
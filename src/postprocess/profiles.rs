@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use crate::postprocess::errors::{PostProcessError, PostProcessResult};
+
+const MEETING_INSTRUCTIONS: &str = "You summarize meeting transcripts. \
+Reply in Markdown with exactly three sections: \"## Summary\" with a short \
+paragraph, \"## Decisions\" with a bullet list of decisions, and \
+\"## Action Items\" with a bullet list of \"- [ ] task (owner)\" entries, \
+using \"unassigned\" when no owner is mentioned. Write \"None\" in a section \
+without entries. Only use information from the transcript.";
+
+/// Built-in post-processing profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostProfile {
+  /// Meeting summary with decisions and action items
+  Meeting,
+}
+
+impl PostProfile {
+  /// Parses a profile from its configured name.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - Profile name, case-insensitive
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<PostProfile>` containing the profile or an error.
+  pub fn from_name(name: &str) -> PostProcessResult<PostProfile> {
+    return match name.trim().to_lowercase().as_str() {
+      "meeting" => Ok(PostProfile::Meeting),
+      _ => Err(PostProcessError::UnknownProfile(name.to_string())),
+    };
+  }
+
+  /// Gets the instructions given to the language model.
+  ///
+  /// # Returns
+  ///
+  /// The system instructions of the profile.
+  pub fn instructions(&self) -> &'static str {
+    return match self {
+      PostProfile::Meeting => MEETING_INSTRUCTIONS,
+    };
+  }
+
+  /// Gets the path the profile output is written to.
+  ///
+  /// The output is written next to the transcribed audio, replacing its
+  /// extension, for example `standup.summary.md` for `standup.wav`.
+  ///
+  /// # Arguments
+  ///
+  /// * `source_path` - Path to the transcribed audio file
+  ///
+  /// # Returns
+  ///
+  /// A `String` containing the output path.
+  pub fn output_path(&self, source_path: &str) -> String {
+    let extension = match self {
+      PostProfile::Meeting => "summary.md",
+    };
+    return Path::new(source_path)
+      .with_extension(extension)
+      .to_string_lossy()
+      .to_string();
+  }
+}
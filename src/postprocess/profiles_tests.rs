@@ -0,0 +1,24 @@
+use crate::postprocess::errors::PostProcessError;
+use crate::postprocess::profiles::PostProfile;
+
+#[test]
+fn test_post_profile_from_name() {
+  assert_eq!(
+    PostProfile::from_name(" Meeting ").unwrap(),
+    PostProfile::Meeting
+  );
+  assert!(matches!(
+    PostProfile::from_name("poem"),
+    Err(PostProcessError::UnknownProfile(name)) if name == "poem"
+  ));
+}
+
+#[test]
+fn test_meeting_output_path() {
+  let profile = PostProfile::Meeting;
+  assert_eq!(
+    profile.output_path("/recordings/standup.wav"),
+    "/recordings/standup.summary.md"
+  );
+  assert!(profile.instructions().contains("## Action Items"));
+}
@@ -0,0 +1,246 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::network::HttpClient;
+use crate::postprocess::errors::{PostProcessError, PostProcessResult};
+use crate::postprocess::llm::LanguageModel;
+use crate::vlog;
+
+const DEEPL_TRANSLATE_ENDPOINT: &str = "v2/translate";
+
+/// Services that can translate transcripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranslationService {
+  /// The language model configured in the `[llm]` section
+  LanguageModel,
+  /// The DeepL API
+  DeepL,
+}
+
+impl TranslationService {
+  /// Parses a translation service from its configured name.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - Service name (`llm` or `deepl`), case-insensitive
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<TranslationService>` containing the service or an
+  /// error.
+  pub fn from_name(name: &str) -> PostProcessResult<TranslationService> {
+    return match name.trim().to_lowercase().as_str() {
+      "llm" => Ok(TranslationService::LanguageModel),
+      "deepl" => Ok(TranslationService::DeepL),
+      _ => Err(PostProcessError::UnknownTranslationService(
+        name.to_string(),
+      )),
+    };
+  }
+}
+
+/// Translation request of the DeepL API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DeepLRequest {
+  pub text: Vec<String>,
+  pub target_lang: String,
+}
+
+/// Translation response of the DeepL API.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DeepLResponse {
+  pub translations: Vec<DeepLTranslation>,
+}
+
+/// Single translated text of a DeepL response.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DeepLTranslation {
+  pub text: String,
+}
+
+#[derive(Debug, Clone)]
+enum Backend {
+  LanguageModel(LanguageModel),
+  DeepL { client: HttpClient, api_key: String },
+}
+
+/// Translates transcript texts, keeping one translation per input text.
+///
+/// Keeping the texts aligned lets translated segments reuse the timing of
+/// the original segments.
+#[derive(Debug, Clone)]
+pub struct Translator {
+  backend: Backend,
+}
+
+impl Translator {
+  /// Creates a Translator that uses a language model.
+  ///
+  /// # Arguments
+  ///
+  /// * `model` - The language model to translate with
+  ///
+  /// # Returns
+  ///
+  /// A new `Translator` instance.
+  pub fn with_language_model(model: LanguageModel) -> Self {
+    return Translator {
+      backend: Backend::LanguageModel(model),
+    };
+  }
+
+  /// Creates a Translator that uses the DeepL API.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - Base URL of the DeepL API
+  /// * `api_key` - DeepL authentication key
+  /// * `cancel` - Token that aborts requests when cancelled
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<Translator>` containing the translator or an error
+  /// if no key is configured.
+  pub fn with_deepl(
+    url: String,
+    api_key: String,
+    cancel: CancellationToken,
+  ) -> PostProcessResult<Self> {
+    if api_key.is_empty() {
+      return Err(PostProcessError::MissingApiKey(String::from("DeepL")));
+    }
+    return Ok(Translator {
+      backend: Backend::DeepL {
+        client: HttpClient::new(url).with_cancellation(cancel),
+        api_key,
+      },
+    });
+  }
+
+  /// Translates texts to a target language.
+  ///
+  /// # Arguments
+  ///
+  /// * `texts` - The texts to translate
+  /// * `target_language` - The language to translate to, such as `de`
+  ///
+  /// # Returns
+  ///
+  /// A `PostProcessResult<Vec<String>>` containing one translation per text
+  /// or an error.
+  pub async fn translate(
+    &self,
+    texts: &[String],
+    target_language: &str,
+  ) -> PostProcessResult<Vec<String>> {
+    if texts.is_empty() {
+      return Ok(Vec::new());
+    }
+    vlog!("Translating {} texts to {}", texts.len(), target_language);
+
+    return match &self.backend {
+      Backend::LanguageModel(model) => {
+        let lines: Vec<String> =
+          texts.iter().map(|text| flatten_line(text)).collect();
+        let reply = model
+          .complete(
+            &translation_instructions(target_language),
+            &lines.join("\n"),
+          )
+          .await?;
+        split_translated_lines(&reply, texts.len())
+      }
+      Backend::DeepL { client, api_key } => {
+        let request = build_deepl_request(texts, target_language);
+        let response = client
+          .post_json_with_response::<DeepLRequest, DeepLResponse>(
+            DEEPL_TRANSLATE_ENDPOINT,
+            &request,
+            Some(&format!("DeepL-Auth-Key {}", api_key)),
+          )
+          .await
+          .map_err(|e| PostProcessError::Request(e.to_string()))?;
+        let translations: Vec<String> = response
+          .translations
+          .into_iter()
+          .map(|translation| translation.text)
+          .collect();
+        if translations.len() != texts.len() {
+          return Err(PostProcessError::TranslationMismatch(
+            translations.len(),
+            texts.len(),
+          ));
+        }
+        Ok(translations)
+      }
+    };
+  }
+}
+
+/// Builds the language model instructions for translating lines.
+///
+/// # Arguments
+///
+/// * `target_language` - The language to translate to
+///
+/// # Returns
+///
+/// A `String` containing the system instructions.
+pub fn translation_instructions(target_language: &str) -> String {
+  return format!(
+    "Translate each line of the user's message to the language '{}'. \
+     Reply with exactly one translated line per input line, in the same \
+     order, without numbering, quotes or comments.",
+    target_language
+  );
+}
+
+/// Splits a language model reply into one translation per input line.
+///
+/// # Arguments
+///
+/// * `reply` - The language model reply
+/// * `expected` - The number of input lines
+///
+/// # Returns
+///
+/// A `PostProcessResult<Vec<String>>` containing the translations or an
+/// error if the number of lines does not match.
+pub fn split_translated_lines(
+  reply: &str,
+  expected: usize,
+) -> PostProcessResult<Vec<String>> {
+  let lines: Vec<String> = reply
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty())
+    .map(String::from)
+    .collect();
+  if lines.len() != expected {
+    return Err(PostProcessError::TranslationMismatch(lines.len(), expected));
+  }
+  return Ok(lines);
+}
+
+/// Builds a DeepL translation request.
+///
+/// # Arguments
+///
+/// * `texts` - The texts to translate
+/// * `target_language` - The language to translate to
+///
+/// # Returns
+///
+/// A `DeepLRequest` with the texts and the upper-case target language.
+pub fn build_deepl_request(
+  texts: &[String],
+  target_language: &str,
+) -> DeepLRequest {
+  return DeepLRequest {
+    text: texts.iter().map(|text| text.trim().to_string()).collect(),
+    target_lang: target_language.trim().to_uppercase(),
+  };
+}
+
+fn flatten_line(text: &str) -> String {
+  return text.split_whitespace().collect::<Vec<&str>>().join(" ");
+}
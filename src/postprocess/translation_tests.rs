@@ -0,0 +1,59 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::postprocess::errors::PostProcessError;
+use crate::postprocess::translation::{
+  TranslationService, Translator, build_deepl_request, split_translated_lines,
+  translation_instructions,
+};
+
+#[test]
+fn test_translation_service_from_name() {
+  assert_eq!(
+    TranslationService::from_name("LLM").unwrap(),
+    TranslationService::LanguageModel
+  );
+  assert_eq!(
+    TranslationService::from_name("deepl").unwrap(),
+    TranslationService::DeepL
+  );
+  assert!(matches!(
+    TranslationService::from_name("babelfish"),
+    Err(PostProcessError::UnknownTranslationService(_))
+  ));
+}
+
+#[test]
+fn test_deepl_requires_api_key() {
+  let result = Translator::with_deepl(
+    String::from("https://api-free.deepl.com"),
+    String::new(),
+    CancellationToken::new(),
+  );
+  assert!(matches!(result, Err(PostProcessError::MissingApiKey(_))));
+}
+
+#[test]
+fn test_translation_instructions() {
+  assert!(translation_instructions("de").contains("'de'"));
+}
+
+#[test]
+fn test_split_translated_lines() {
+  let lines = split_translated_lines("Hallo\n\n Welt \n", 2).unwrap();
+  assert_eq!(lines, vec!["Hallo", "Welt"]);
+
+  assert!(matches!(
+    split_translated_lines("Hallo Welt", 2),
+    Err(PostProcessError::TranslationMismatch(1, 2))
+  ));
+}
+
+#[test]
+fn test_build_deepl_request() {
+  let request = build_deepl_request(
+    &[String::from(" Hello "), String::from("world")],
+    "de",
+  );
+  assert_eq!(request.text, vec!["Hello", "world"]);
+  assert_eq!(request.target_lang, "DE");
+}
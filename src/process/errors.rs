@@ -9,7 +9,7 @@ pub enum ProcessError {
   #[error(
     "Command '{0}' failed to execute. Please check the command exists and has proper permissions."
   )]
-  ExecutionFailed(String),
+  ExecutionFailed(String, #[source] std::io::Error),
 }
 
 /// Result type for process operations.
@@ -1,9 +1,32 @@
+#[cfg(any(test, feature = "testing"))]
+use std::collections::HashMap;
+#[cfg(any(test, feature = "testing"))]
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+#[cfg(any(test, feature = "testing"))]
+use std::sync::{Mutex, OnceLock};
 
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
 use crate::process::errors::{ProcessError, ProcessResult};
 
+/// Environment variables set for specific commands.
+///
+/// FFmpeg and the GStreamer tools run in the C locale, so that their output
+/// and the device names they list can be parsed whatever the language of
+/// the user.
+const COMMAND_ENVIRONMENT: [(&str, &[(&str, &str)]); 3] = [
+  ("ffmpeg", C_LOCALE),
+  ("gst-launch-1.0", C_LOCALE),
+  ("gst-device-monitor-1.0", C_LOCALE),
+];
+const C_LOCALE: &[(&str, &str)] = &[("LC_ALL", "C"), ("LANG", "C")];
+
+#[cfg(any(test, feature = "testing"))]
+static COMMAND_OVERRIDES: OnceLock<Mutex<HashMap<String, PathBuf>>> =
+  OnceLock::new();
+
 /// Command output wrapper.
 ///
 /// Contains the standard output, standard error, and exit status of a completed command.
@@ -37,6 +60,15 @@ impl CommandOutput {
       status,
     };
   }
+
+  /// Gets standard output followed by standard error.
+  ///
+  /// # Returns
+  ///
+  /// Both outputs of the command joined by a newline.
+  pub fn combined(&self) -> String {
+    return format!("{}\n{}", self.stdout, self.stderr);
+  }
 }
 
 /// Centralized process executor.
@@ -45,6 +77,33 @@ impl CommandOutput {
 pub struct ProcessExecutor;
 
 impl ProcessExecutor {
+  /// Runs another program whenever a command is executed.
+  ///
+  /// Every later call that executes `command`, in any thread, runs `program`
+  /// instead. Tests use this to replace external tools like ffmpeg with
+  /// shims, so it is only available with the `testing` feature.
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - The command to replace, for example `ffmpeg`
+  /// * `program` - Path to the program executed in its place
+  #[cfg(any(test, feature = "testing"))]
+  pub fn override_command(command: &str, program: impl AsRef<Path>) {
+    command_overrides()
+      .insert(command.to_string(), program.as_ref().to_path_buf());
+  }
+
+  /// Removes the override of a command set by
+  /// [`ProcessExecutor::override_command`].
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - The command to execute as is again
+  #[cfg(any(test, feature = "testing"))]
+  pub fn clear_override(command: &str) {
+    command_overrides().remove(command);
+  }
+
   /// Run a command and return output.
   ///
   /// Executes a command with the given arguments and captures both
@@ -63,19 +122,75 @@ impl ProcessExecutor {
     command: &str,
     args: &[&str],
   ) -> ProcessResult<CommandOutput> {
-    let output = Command::new(command)
+    let output = new_command(command)
       .args(args)
       .output()
       .await
-      .map_err(|_| ProcessError::ExecutionFailed(command.to_string()))?;
+      .map_err(|e| ProcessError::ExecutionFailed(command.to_string(), e))?;
+    return Ok(to_command_output(output));
+  }
 
-    let command_output = CommandOutput::new(
-      String::from_utf8_lossy(&output.stdout).to_string(),
-      String::from_utf8_lossy(&output.stderr).to_string(),
-      output.status,
-    );
+  /// Run a command that reads the standard input of this process.
+  ///
+  /// Like [`ProcessExecutor::run`], but the command inherits standard input
+  /// instead of reading an empty stream, so data piped into Lumine reaches
+  /// it.
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - The command to execute
+  /// * `args` - Arguments to pass to the command
+  ///
+  /// # Returns
+  ///
+  /// A `ProcessResult<CommandOutput>` containing the command output
+  /// or an error if execution failed.
+  pub async fn run_with_stdin(
+    command: &str,
+    args: &[&str],
+  ) -> ProcessResult<CommandOutput> {
+    let output = new_command(command)
+      .args(args)
+      .stdin(Stdio::inherit())
+      .output()
+      .await
+      .map_err(|e| ProcessError::ExecutionFailed(command.to_string(), e))?;
+    return Ok(to_command_output(output));
+  }
 
-    return Ok(command_output);
+  /// Run a command that reads the given data from standard input.
+  ///
+  /// The output of the command is discarded, so commands that keep running
+  /// in the background, such as clipboard tools serving their selection,
+  /// do not block the caller.
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - The command to execute
+  /// * `args` - Arguments to pass to the command
+  /// * `input` - Data written to the standard input of the command
+  ///
+  /// # Returns
+  ///
+  /// A `ProcessResult<std::process::ExitStatus>` containing the exit status
+  /// or an error if execution failed.
+  pub async fn run_with_input(
+    command: &str,
+    args: &[&str],
+    input: &[u8],
+  ) -> ProcessResult<std::process::ExitStatus> {
+    let error = |e| ProcessError::ExecutionFailed(command.to_string(), e);
+    let mut child = new_command(command)
+      .args(args)
+      .stdin(Stdio::piped())
+      .stdout(Stdio::null())
+      .stderr(Stdio::null())
+      .spawn()
+      .map_err(error)?;
+    if let Some(mut stdin) = child.stdin.take() {
+      stdin.write_all(input).await.map_err(error)?;
+    }
+    return child.wait().await.map_err(error);
   }
 
   /// Spawn a process with standard error piped.
@@ -96,12 +211,86 @@ impl ProcessExecutor {
     command: &str,
     args: &[&str],
   ) -> ProcessResult<tokio::process::Child> {
-    let child = Command::new(command)
+    let child = new_command(command)
+      .args(args)
+      .stderr(Stdio::piped())
+      .spawn()
+      .map_err(|e| ProcessError::ExecutionFailed(command.to_string(), e))?;
+
+    return Ok(child);
+  }
+
+  /// Spawn a process with standard output and standard error piped.
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - The command to execute
+  /// * `args` - Arguments to pass to the command
+  ///
+  /// # Returns
+  ///
+  /// A `ProcessResult<tokio::process::Child>` containing the spawned
+  /// child process or an error if spawning failed.
+  pub async fn spawn_with_output_piped(
+    command: &str,
+    args: &[&str],
+  ) -> ProcessResult<tokio::process::Child> {
+    let child = new_command(command)
       .args(args)
+      .stdout(Stdio::piped())
       .stderr(Stdio::piped())
       .spawn()
-      .map_err(|_| ProcessError::ExecutionFailed(command.to_string()))?;
+      .map_err(|e| ProcessError::ExecutionFailed(command.to_string(), e))?;
 
     return Ok(child);
   }
+
+  /// Asks a process to shut down as if Ctrl+C was pressed.
+  ///
+  /// Unlike killing it, this lets the process finish writing its output.
+  ///
+  /// # Arguments
+  ///
+  /// * `pid` - Process ID of the process
+  ///
+  /// # Returns
+  ///
+  /// A `ProcessResult<CommandOutput>` containing the output of `kill` or an
+  /// error if it could not be run.
+  pub async fn interrupt(pid: u32) -> ProcessResult<CommandOutput> {
+    return ProcessExecutor::run("kill", &["-INT", &pid.to_string()]).await;
+  }
+}
+
+#[cfg(any(test, feature = "testing"))]
+fn command_overrides()
+-> std::sync::MutexGuard<'static, HashMap<String, PathBuf>> {
+  return COMMAND_OVERRIDES
+    .get_or_init(|| Mutex::new(HashMap::new()))
+    .lock()
+    .unwrap_or_else(|e| e.into_inner());
+}
+
+/// Creates the process for a command, honoring command overrides and the
+/// environment of the command.
+fn new_command(command: &str) -> Command {
+  let mut process = Command::new(command);
+  #[cfg(any(test, feature = "testing"))]
+  if let Some(program) = command_overrides().get(command) {
+    process = Command::new(program);
+  }
+  for (name, variables) in COMMAND_ENVIRONMENT {
+    if name == command {
+      process.envs(variables.iter().copied());
+    }
+  }
+  return process;
+}
+
+fn to_command_output(output: std::process::Output) -> CommandOutput {
+  return CommandOutput::new(
+    String::from_utf8_lossy(&output.stdout).to_string(),
+    String::from_utf8_lossy(&output.stderr).to_string(),
+    output.status,
+  );
 }
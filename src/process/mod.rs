@@ -12,7 +12,9 @@
 //!
 //! - Run commands and capture output
 //! - Spawn processes with piped stderr for async streaming
+//! - Interrupt processes so they can shut down cleanly
 //! - Check command availability
+//! - Replace commands with other programs, for example shims in tests
 
 pub mod errors;
 pub mod executor;
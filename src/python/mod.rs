@@ -0,0 +1,272 @@
+//! Python module for driving Lumine from scripts and notebooks.
+//!
+//! Built with the `python` feature and published as `lumine-py` through
+//! maturin, which reads `pyproject.toml`. The module is imported as
+//! `lumine`:
+//!
+//! ```python
+//! import lumine
+//!
+//! config = lumine.Config.load()
+//! config.set("whisper.url", "http://whisper.local:9090")
+//! print(lumine.transcribe("meeting.wav", format="srt", config=config))
+//! ```
+//!
+//! Calls block until the workflow finishes but release the GIL while they
+//! wait, so other Python threads keep running. Ctrl+C cancels the running
+//! workflow and raises `KeyboardInterrupt`.
+//!
+//! ## Main Components
+//!
+//! - [`transcribe`]: Transcribe an audio file
+//! - [`record`]: Record from the microphone and transcribe
+//! - [`PyConfig`]: The configuration, exposed as `lumine.Config`
+//! - [`LumineError`]: Exception raised when a workflow fails
+
+#[cfg(test)]
+mod python_tests;
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use tokio::runtime::Runtime;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::{App, Workflow, shared_runtime};
+use crate::config::Config;
+use crate::events::Event;
+use crate::output::format::OutputFormat;
+
+/// How often a waiting call checks for Ctrl+C.
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+create_exception!(
+  lumine,
+  LumineError,
+  PyException,
+  "Raised when a Lumine workflow fails."
+);
+
+/// Lumine configuration, exposed to Python as `lumine.Config`.
+///
+/// Values are read and written by their dotted keys, the same keys
+/// `lumine config get` and `lumine config set` accept.
+#[pyclass(name = "Config", module = "lumine")]
+pub struct PyConfig {
+  config: Config,
+}
+
+#[pymethods]
+impl PyConfig {
+  /// Creates the default configuration, ignoring the configuration file.
+  #[new]
+  fn new() -> Self {
+    return PyConfig {
+      config: Config::default(),
+    };
+  }
+
+  /// Loads the configuration like `lumine` does, from the configuration
+  /// file and the `LUMINE_` environment variables.
+  #[staticmethod]
+  fn load(py: Python<'_>) -> PyResult<Self> {
+    let runtime = runtime()?;
+    let config = py
+      .detach(|| return runtime.block_on(Config::load()))
+      .map_err(|e| LumineError::new_err(e.to_string()))?;
+    return Ok(PyConfig { config });
+  }
+
+  /// Gets a value by its dotted key, such as `recorder.silence_limit`.
+  fn get(&self, key: &str) -> PyResult<String> {
+    return self
+      .config
+      .get_value(key)
+      .map_err(|e| LumineError::new_err(e.to_string()));
+  }
+
+  /// Sets a value by its dotted key. The change only applies to this
+  /// object and is not saved.
+  fn set(&mut self, key: &str, value: &str) -> PyResult<()> {
+    return self
+      .config
+      .set_value(key, value)
+      .map_err(|e| LumineError::new_err(e.to_string()));
+  }
+
+  /// Applies a quality preset, such as `interactive` or `archive`.
+  fn apply_mode(&mut self, name: &str) -> PyResult<()> {
+    return self
+      .config
+      .apply_mode(name)
+      .map_err(|e| LumineError::new_err(e.to_string()));
+  }
+}
+
+/// Transcribes an audio file.
+///
+/// # Arguments
+///
+/// * `path` - Path to the audio file to transcribe
+/// * `format` - Output format accepted by `--format`, text when `None`
+/// * `config` - Configuration to use, loaded like `lumine` when `None`
+/// * `progress` - Callable receiving the name and detail of each progress
+///   event, such as `("recording_started", "/tmp/a.wav")`
+///
+/// # Returns
+///
+/// The transcript in the requested format.
+#[pyfunction]
+#[pyo3(signature = (path, *, format = None, config = None, progress = None))]
+pub fn transcribe(
+  py: Python<'_>,
+  path: String,
+  format: Option<&str>,
+  config: Option<PyRef<'_, PyConfig>>,
+  progress: Option<Py<PyAny>>,
+) -> PyResult<String> {
+  let format = parse_format(format)?;
+  let workflow = Workflow::TranscribeFile { path, format };
+  let config = config.map(|config| config.config.clone());
+  return run(py, workflow, config, progress);
+}
+
+/// Records from the microphone and transcribes the recording.
+///
+/// Recording stops on silence like `lumine` without a command, or on
+/// Ctrl+C.
+///
+/// # Arguments
+///
+/// * `format` - Output format accepted by `--format`, text when `None`
+/// * `config` - Configuration to use, loaded like `lumine` when `None`
+/// * `progress` - Callable receiving the name and detail of each progress
+///   event
+///
+/// # Returns
+///
+/// The transcript in the requested format.
+#[pyfunction]
+#[pyo3(signature = (*, format = None, config = None, progress = None))]
+pub fn record(
+  py: Python<'_>,
+  format: Option<&str>,
+  config: Option<PyRef<'_, PyConfig>>,
+  progress: Option<Py<PyAny>>,
+) -> PyResult<String> {
+  let format = parse_format(format)?;
+  let config = config.map(|config| config.config.clone());
+  return run(py, Workflow::Record { format }, config, progress);
+}
+
+/// The `lumine` Python module.
+#[pymodule]
+#[pyo3(name = "lumine")]
+fn lumine_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+  module.add_class::<PyConfig>()?;
+  module.add_function(wrap_pyfunction!(transcribe, module)?)?;
+  module.add_function(wrap_pyfunction!(record, module)?)?;
+  module.add("LumineError", module.py().get_type::<LumineError>())?;
+  return Ok(());
+}
+
+/// Reads the output format argument, text when it is `None`.
+fn parse_format(format: Option<&str>) -> PyResult<OutputFormat> {
+  return match format {
+    Some(name) => OutputFormat::from_str(name, true).map_err(|_| {
+      return LumineError::new_err(format!(
+        "Unknown output format: '{}'. Use a format accepted by --format, such as 'text', 'json' or 'srt'.",
+        name
+      ));
+    }),
+    None => Ok(OutputFormat::Text),
+  };
+}
+
+fn runtime() -> PyResult<&'static Runtime> {
+  return shared_runtime().map_err(|e| {
+    return LumineError::new_err(format!(
+      "Cannot start the async runtime: {}",
+      e
+    ));
+  });
+}
+
+/// Runs a workflow to completion without holding the GIL.
+///
+/// Wakes up regularly to check for Ctrl+C, which cancels the workflow and
+/// raises `KeyboardInterrupt` once it has cleaned up.
+fn run(
+  py: Python<'_>,
+  workflow: Workflow,
+  config: Option<Config>,
+  progress: Option<Py<PyAny>>,
+) -> PyResult<String> {
+  let runtime = runtime()?;
+  let cancel = CancellationToken::new();
+  let mut task =
+    runtime.spawn(execute(workflow, config, progress, cancel.clone()));
+  loop {
+    let finished = py.detach(|| {
+      return runtime.block_on(async {
+        return tokio::time::timeout(SIGNAL_CHECK_INTERVAL, &mut task)
+          .await
+          .ok();
+      });
+    });
+    if let Some(result) = finished {
+      return result.unwrap_or_else(|e| {
+        return Err(LumineError::new_err(format!("Lumine stopped: {}", e)));
+      });
+    }
+    if let Err(interrupt) = py.check_signals() {
+      cancel.cancel();
+      let _ = py.detach(|| return runtime.block_on(&mut task));
+      return Err(interrupt);
+    }
+  }
+}
+
+/// Loads the App and runs the workflow, reporting its progress.
+async fn execute(
+  workflow: Workflow,
+  config: Option<Config>,
+  progress: Option<Py<PyAny>>,
+  cancel: CancellationToken,
+) -> PyResult<String> {
+  let config = match config {
+    Some(config) => config,
+    None => Config::load()
+      .await
+      .map_err(|e| LumineError::new_err(e.to_string()))?,
+  };
+  let app = App::bootstrap(config)
+    .await
+    .map_err(|e| LumineError::new_err(e.to_string()))?
+    .with_cancellation(cancel);
+  let report = |event: &Event| {
+    if let Some(progress) = &progress {
+      report(progress, event);
+    }
+  };
+  return app
+    .run_workflow(workflow, report)
+    .await
+    .map_err(|e| LumineError::new_err(e.to_string()));
+}
+
+/// Calls the progress callable with one event.
+///
+/// Exceptions raised by the callable are reported like other unraisable
+/// exceptions and do not stop the workflow.
+fn report(progress: &Py<PyAny>, event: &Event) {
+  let (name, detail) = event.describe();
+  Python::attach(|py| {
+    if let Err(e) = progress.call1(py, (name, detail)) {
+      e.write_unraisable(py, Some(progress.bind(py)));
+    }
+  });
+}
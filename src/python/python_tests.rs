@@ -0,0 +1,94 @@
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pyo3::wrap_pymodule;
+
+use crate::events::Event;
+use crate::output::format::OutputFormat;
+use crate::python::*;
+
+#[test]
+fn test_parse_format() {
+  assert_eq!(parse_format(None).unwrap(), OutputFormat::Text);
+  assert_eq!(parse_format(Some("srt")).unwrap(), OutputFormat::Srt);
+
+  Python::initialize();
+  Python::attach(|py| {
+    let error = parse_format(Some("midi")).unwrap_err();
+    assert!(error.is_instance_of::<LumineError>(py));
+    assert!(error.to_string().contains("'midi'"));
+  });
+}
+
+#[test]
+fn test_config_get_and_set() {
+  Python::initialize();
+  Python::attach(|py| {
+    let module = wrap_pymodule!(lumine_module)(py);
+    let config = module.getattr(py, "Config").unwrap().call0(py).unwrap();
+
+    config
+      .call_method1(py, "set", ("recorder.silence_limit", "5"))
+      .unwrap();
+    let value: String = config
+      .call_method1(py, "get", ("recorder.silence_limit",))
+      .unwrap()
+      .extract(py)
+      .unwrap();
+    assert_eq!(value, "5");
+
+    let error = config
+      .call_method1(py, "get", ("recorder.unknown",))
+      .unwrap_err();
+    assert!(error.is_instance_of::<LumineError>(py));
+  });
+}
+
+#[test]
+fn test_module_exports() {
+  Python::initialize();
+  Python::attach(|py| {
+    let module = wrap_pymodule!(lumine_module)(py);
+    for name in ["Config", "transcribe", "record", "LumineError"] {
+      assert!(module.getattr(py, name).is_ok(), "{} is not exported", name);
+    }
+  });
+}
+
+#[test]
+fn test_report_calls_progress() {
+  Python::initialize();
+  let (events, progress) = Python::attach(|py| {
+    let events = PyList::empty(py);
+    let make = py
+      .eval(
+        c"lambda events: lambda *event: events.append(event)",
+        None,
+        None,
+      )
+      .unwrap();
+    let progress = make.call1((&events,)).unwrap();
+    return (events.unbind(), progress.unbind());
+  });
+
+  report(
+    &progress,
+    &Event::RecordingStarted {
+      path: String::from("/tmp/a.wav"),
+    },
+  );
+  report(&progress, &Event::Completed);
+
+  Python::attach(|py| {
+    let events: Vec<(String, String)> = events.extract(py).unwrap();
+    assert_eq!(
+      events,
+      vec![
+        (
+          String::from("recording_started"),
+          String::from("/tmp/a.wav")
+        ),
+        (String::from("completed"), String::new()),
+      ]
+    );
+  });
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+/// Application state errors.
+///
+/// Represents errors that can occur while loading or saving persisted state.
+#[derive(Error, Debug)]
+pub enum StateError {
+  #[error(
+    "Cannot read state file: '{0}'. Please check file permissions and ensure the file exists."
+  )]
+  FileRead(String),
+
+  #[error(
+    "Cannot write state file: '{0}'. Please check file permissions and available disk space."
+  )]
+  FileWrite(String),
+
+  #[error("State file is invalid: '{0}'.")]
+  Parse(String),
+
+  #[error("Cannot decrypt state file: {0}")]
+  Decrypt(String),
+}
+
+/// Result type for state operations.
+pub type StateResult<T> = Result<T, StateError>;
@@ -0,0 +1,448 @@
+//! Persistent application state module with XDG compliance.
+//!
+//! This module stores information that Lumine learns while running, as
+//! opposed to user configuration. State is kept in a TOML file in the XDG
+//! state directory, encrypted when encryption at rest is enabled, and is safe
+//! to delete at any time.
+//!
+//! ## Main Components
+//!
+//! - [`State`]: Persisted application state
+//! - [`TranscriptRecord`]: Fingerprint and saved transcript of a transcribed
+//!   file
+//! - [`InputDeviceRecord`]: Audio input device from the cached device list
+//! - [`UsageRecord`]: Locally aggregated usage of a transcription backend
+//! - [`MonthlyCost`]: Estimated transcription cost of a month
+//! - [`ProcessedFile`]: Audio file transcribed by watch mode
+//! - [`StateError`]: Error types for state loading and saving
+//! - [`StateResult<T>`]: Result type alias for state operations
+//!
+//! ## State File Location
+//!
+//! State is loaded from:
+//! - `$XDG_STATE_HOME/lumine/state.toml`
+//! - Falls back to empty state if no state file exists
+
+pub mod errors;
+
+#[cfg(test)]
+mod state_tests;
+
+use std::path::PathBuf;
+
+use xdg::BaseDirectories;
+
+use crate::encryption::{Encryptor, is_encrypted};
+use crate::files::operations;
+use crate::state::errors::{StateError, StateResult};
+
+const DEFAULT_DIRECTORY: &str = "lumine";
+const DEFAULT_STATE_NAME: &str = "state.toml";
+const MAX_TRANSCRIPT_RECORDS: usize = 200;
+const MAX_PROCESSED_FILES: usize = 1000;
+
+/// Persisted application state.
+///
+/// Contains values remembered between runs.
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct State {
+  pub last_audio_input_device: Option<String>,
+  pub input_devices_listed_at: Option<i64>,
+  #[serde(default)]
+  pub transcripts: Vec<TranscriptRecord>,
+  #[serde(default)]
+  pub input_devices: Vec<InputDeviceRecord>,
+  #[serde(default)]
+  pub usage: Vec<UsageRecord>,
+  #[serde(default)]
+  pub costs: Vec<MonthlyCost>,
+  #[serde(default)]
+  pub processed_files: Vec<ProcessedFile>,
+}
+
+/// Fingerprint and saved transcript of a transcribed file.
+///
+/// Lets files with the same audio content reuse the transcript instead of
+/// being transcribed again. Only the path and digest of the transcript are
+/// kept, so the state file holds no transcript text.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TranscriptRecord {
+  /// Encoded audio fingerprint
+  pub fingerprint: String,
+  /// Duration of the audio in seconds
+  pub duration: f64,
+  /// Path of the transcribed file
+  pub source: String,
+  /// Path of the saved transcript, empty in records of older versions
+  #[serde(default)]
+  pub output: String,
+  /// SHA-256 digest of the saved transcript
+  #[serde(default)]
+  pub digest: String,
+}
+
+/// Audio input device from the cached device list.
+///
+/// Lets recordings start without listing the available devices again.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct InputDeviceRecord {
+  /// Device index used by the audio system
+  pub index: String,
+  /// Human-readable device name
+  pub name: String,
+  /// Whether the audio system reported the device as its default input
+  #[serde(default)]
+  pub system_default: bool,
+}
+
+/// Locally aggregated usage of a transcription backend.
+///
+/// Only kept when usage statistics are enabled and never sent anywhere.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct UsageRecord {
+  /// Backend that transcribed the audio, such as `remote (<url>)`
+  #[serde(alias = "model")]
+  pub backend: String,
+  /// Number of transcription runs
+  pub runs: u64,
+  /// Seconds of audio transcribed
+  pub audio_seconds: f64,
+  /// Seconds spent transcribing
+  pub transcription_seconds: f64,
+}
+
+/// Estimated transcription cost of a month.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MonthlyCost {
+  /// Month in `YYYY-MM` format
+  pub month: String,
+  /// Seconds of paid audio transcribed
+  pub audio_seconds: f64,
+  /// Estimated cost
+  pub cost: f64,
+}
+
+/// Audio file transcribed by watch mode.
+///
+/// Lets watch mode skip files it already transcribed when it is restarted.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ProcessedFile {
+  /// Path of the transcribed file
+  pub path: String,
+  /// Modification time of the file as a Unix timestamp in seconds
+  pub modified: i64,
+}
+
+impl UsageRecord {
+  /// Gets the real-time factor of the backend.
+  ///
+  /// # Returns
+  ///
+  /// An `Option<f64>` containing the seconds spent transcribing per second
+  /// of audio, or `None` if no audio was transcribed.
+  pub fn real_time_factor(&self) -> Option<f64> {
+    if self.audio_seconds <= 0.0 {
+      return None;
+    }
+    return Some(self.transcription_seconds / self.audio_seconds);
+  }
+}
+
+impl State {
+  /// Loads state from the XDG-compliant state directory.
+  ///
+  /// If no state file exists, returns empty state.
+  ///
+  /// # Arguments
+  ///
+  /// * `encryptor` - Encryptor for an encrypted state file
+  ///
+  /// # Returns
+  ///
+  /// A `StateResult<State>` containing the loaded state or an error.
+  pub async fn load(encryptor: Option<&Encryptor>) -> StateResult<State> {
+    let xdg_dirs = BaseDirectories::with_prefix(DEFAULT_DIRECTORY);
+    let state_path = match xdg_dirs.find_state_file(DEFAULT_STATE_NAME) {
+      Some(path) => path,
+      None => return Ok(State::default()),
+    };
+    return State::load_from_path(state_path, encryptor).await;
+  }
+
+  /// Saves state to the XDG-compliant state directory.
+  ///
+  /// # Arguments
+  ///
+  /// * `encryptor` - Encryptor to encrypt the state file with (`None` to
+  ///   save it as plain TOML)
+  ///
+  /// # Returns
+  ///
+  /// A `StateResult<()>` indicating success or failure.
+  pub async fn save(&self, encryptor: Option<&Encryptor>) -> StateResult<()> {
+    let xdg_dirs = BaseDirectories::with_prefix(DEFAULT_DIRECTORY);
+    let state_path = xdg_dirs
+      .place_state_file(DEFAULT_STATE_NAME)
+      .map_err(|e| StateError::FileWrite(e.to_string()))?;
+    return self.save_to_path(state_path, encryptor).await;
+  }
+
+  /// Gets the name of the last successfully used audio input device.
+  ///
+  /// # Returns
+  ///
+  /// A `String` containing the device name, or an empty string if unknown.
+  pub fn get_last_audio_input_device(&self) -> String {
+    return self.last_audio_input_device.clone().unwrap_or_default();
+  }
+
+  /// Sets the name of the last successfully used audio input device.
+  ///
+  /// # Arguments
+  ///
+  /// * `device` - The device name to remember
+  pub fn set_last_audio_input_device(&mut self, device: String) {
+    self.last_audio_input_device = Some(device);
+  }
+
+  /// Gets the remembered transcripts, oldest first.
+  ///
+  /// # Returns
+  ///
+  /// A slice of the remembered `TranscriptRecord`s.
+  pub fn get_transcripts(&self) -> &[TranscriptRecord] {
+    return &self.transcripts;
+  }
+
+  /// Remembers the transcript of a file.
+  ///
+  /// Only the most recent transcripts are kept so the state file stays
+  /// small.
+  ///
+  /// # Arguments
+  ///
+  /// * `record` - The fingerprint and saved transcript to remember
+  pub fn remember_transcript(&mut self, record: TranscriptRecord) {
+    self.transcripts.push(record);
+    let excess = self
+      .transcripts
+      .len()
+      .saturating_sub(MAX_TRANSCRIPT_RECORDS);
+    self.transcripts.drain(..excess);
+  }
+
+  /// Gets the usage aggregated per backend.
+  ///
+  /// # Returns
+  ///
+  /// A slice of the `UsageRecord`s, in the order the backends were first
+  /// used.
+  pub fn get_usage(&self) -> &[UsageRecord] {
+    return &self.usage;
+  }
+
+  /// Adds a transcription run to the usage of a backend.
+  ///
+  /// # Arguments
+  ///
+  /// * `backend` - The backend that transcribed the audio
+  /// * `audio_seconds` - Duration of the transcribed audio
+  /// * `transcription_seconds` - Time spent transcribing
+  pub fn record_usage(
+    &mut self,
+    backend: &str,
+    audio_seconds: f64,
+    transcription_seconds: f64,
+  ) {
+    let index = match self.usage.iter().position(|r| r.backend == backend) {
+      Some(index) => index,
+      None => {
+        self.usage.push(UsageRecord {
+          backend: backend.to_string(),
+          runs: 0,
+          audio_seconds: 0.0,
+          transcription_seconds: 0.0,
+        });
+        self.usage.len() - 1
+      }
+    };
+    let record = &mut self.usage[index];
+    record.runs += 1;
+    record.audio_seconds += audio_seconds;
+    record.transcription_seconds += transcription_seconds;
+  }
+
+  /// Gets the estimated costs per month.
+  ///
+  /// # Returns
+  ///
+  /// A slice of the `MonthlyCost`s, oldest first.
+  pub fn get_costs(&self) -> &[MonthlyCost] {
+    return &self.costs;
+  }
+
+  /// Adds the estimated cost of a transcription run to its month.
+  ///
+  /// # Arguments
+  ///
+  /// * `month` - The month in `YYYY-MM` format
+  /// * `audio_seconds` - Duration of the transcribed audio
+  /// * `cost` - Estimated cost of the run
+  pub fn record_cost(&mut self, month: &str, audio_seconds: f64, cost: f64) {
+    match self.costs.iter_mut().find(|record| record.month == month) {
+      Some(record) => {
+        record.audio_seconds += audio_seconds;
+        record.cost += cost;
+      }
+      None => self.costs.push(MonthlyCost {
+        month: month.to_string(),
+        audio_seconds,
+        cost,
+      }),
+    }
+  }
+
+  /// Checks whether watch mode already transcribed a file.
+  ///
+  /// A file that was changed after it was transcribed counts as new.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - Path of the file
+  /// * `modified` - Modification time of the file as a Unix timestamp
+  ///
+  /// # Returns
+  ///
+  /// `true` if the file was already transcribed, `false` otherwise.
+  pub fn is_processed(&self, path: &str, modified: i64) -> bool {
+    return self
+      .processed_files
+      .iter()
+      .any(|file| file.path == path && file.modified == modified);
+  }
+
+  /// Remembers that watch mode transcribed a file.
+  ///
+  /// Only the most recently transcribed files are kept so the state file
+  /// stays small.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - Path of the file
+  /// * `modified` - Modification time of the file as a Unix timestamp
+  pub fn remember_processed(&mut self, path: &str, modified: i64) {
+    self.processed_files.retain(|file| file.path != path);
+    self.processed_files.push(ProcessedFile {
+      path: path.to_string(),
+      modified,
+    });
+    let excess = self
+      .processed_files
+      .len()
+      .saturating_sub(MAX_PROCESSED_FILES);
+    self.processed_files.drain(..excess);
+  }
+
+  /// Gets the cached audio input devices if they are recent enough.
+  ///
+  /// # Arguments
+  ///
+  /// * `max_age` - Maximum age of the cached list in seconds (0 to disable)
+  /// * `now` - Current Unix timestamp in seconds
+  ///
+  /// # Returns
+  ///
+  /// A slice of the cached `InputDeviceRecord`s, empty if there is no recent
+  /// list.
+  pub fn get_input_devices(
+    &self,
+    max_age: i64,
+    now: i64,
+  ) -> &[InputDeviceRecord] {
+    let Some(listed_at) = self.input_devices_listed_at else {
+      return &[];
+    };
+    if max_age <= 0 || now - listed_at > max_age || now < listed_at {
+      return &[];
+    }
+    return &self.input_devices;
+  }
+
+  /// Caches the listed audio input devices.
+  ///
+  /// # Arguments
+  ///
+  /// * `devices` - The available audio input devices
+  /// * `now` - Current Unix timestamp in seconds
+  pub fn set_input_devices(
+    &mut self,
+    devices: Vec<InputDeviceRecord>,
+    now: i64,
+  ) {
+    self.input_devices = devices;
+    self.input_devices_listed_at = Some(now);
+  }
+
+  /// Loads state from a specific file path.
+  ///
+  /// # Arguments
+  ///
+  /// * `state_path` - Path to the state file to load
+  /// * `encryptor` - Encryptor for an encrypted state file
+  ///
+  /// # Returns
+  ///
+  /// A `StateResult<State>` containing the loaded state or an error.
+  pub(crate) async fn load_from_path(
+    state_path: PathBuf,
+    encryptor: Option<&Encryptor>,
+  ) -> StateResult<State> {
+    let mut bytes = operations::read_bytes(&state_path.to_string_lossy())
+      .await
+      .map_err(|e| StateError::FileRead(e.to_string()))?;
+    if is_encrypted(&bytes) {
+      let encryptor = encryptor
+        .ok_or_else(|| StateError::Decrypt(String::from("no passphrase")))?;
+      bytes = encryptor
+        .decrypt_async(bytes)
+        .await
+        .map_err(|e| StateError::Decrypt(e.to_string()))?;
+    }
+    let state_content =
+      String::from_utf8(bytes).map_err(|e| StateError::Parse(e.to_string()))?;
+    let state = toml::from_str(&state_content)
+      .map_err(|e| StateError::Parse(e.to_string()))?;
+    return Ok(state);
+  }
+
+  /// Saves state to a specific file path.
+  ///
+  /// # Arguments
+  ///
+  /// * `state_path` - Path where the state should be saved
+  /// * `encryptor` - Encryptor to encrypt the state file with (`None` to
+  ///   save it as plain TOML)
+  ///
+  /// # Returns
+  ///
+  /// A `StateResult<()>` indicating success or failure.
+  pub(crate) async fn save_to_path(
+    &self,
+    state_path: PathBuf,
+    encryptor: Option<&Encryptor>,
+  ) -> StateResult<()> {
+    let state_content = toml::to_string_pretty(self)
+      .map_err(|e| StateError::Parse(e.to_string()))?;
+    let bytes = match encryptor {
+      Some(encryptor) => encryptor
+        .encrypt_async(state_content.into_bytes())
+        .await
+        .map_err(|e| StateError::FileWrite(e.to_string()))?,
+      None => state_content.into_bytes(),
+    };
+    tokio::fs::write(&state_path, bytes)
+      .await
+      .map_err(|e| StateError::FileWrite(e.to_string()))?;
+    return Ok(());
+  }
+}
@@ -0,0 +1,227 @@
+use crate::encryption::Encryptor;
+use crate::state::*;
+
+#[test]
+fn test_state_default() {
+  let state = State::default();
+  assert_eq!(state.get_last_audio_input_device(), "");
+}
+
+#[tokio::test]
+async fn test_state_save_and_load() {
+  let state_path = std::env::temp_dir().join("test_lumine_state.toml");
+  let _ = tokio::fs::remove_file(&state_path).await;
+
+  let mut state = State::default();
+  state.set_last_audio_input_device(String::from("USB Microphone"));
+  state.save_to_path(state_path.clone(), None).await.unwrap();
+
+  let loaded = State::load_from_path(state_path.clone(), None)
+    .await
+    .unwrap();
+  assert_eq!(loaded.get_last_audio_input_device(), "USB Microphone");
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[tokio::test]
+async fn test_load_from_path_with_wrong_path() {
+  let wrong_path = std::path::PathBuf::from("/non-existent-path/state.toml");
+  let result = State::load_from_path(wrong_path, None).await;
+  match result.unwrap_err() {
+    errors::StateError::FileRead(_) => (),
+    _ => panic!("Expected FileRead error"),
+  }
+}
+
+#[tokio::test]
+async fn test_remember_transcript() {
+  let state_path = std::env::temp_dir().join("test_lumine_transcripts.toml");
+  let _ = tokio::fs::remove_file(&state_path).await;
+
+  let mut state = State::default();
+  for index in 0..205 {
+    state.remember_transcript(TranscriptRecord {
+      fingerprint: String::from("4:a"),
+      duration: 12.5,
+      source: format!("episode-{}.mp3", index),
+      output: format!("episode-{}.txt", index),
+      digest: String::from("0a1b"),
+    });
+  }
+  assert_eq!(state.get_transcripts().len(), 200);
+  assert_eq!(state.get_transcripts()[0].source, "episode-5.mp3");
+
+  state.save_to_path(state_path.clone(), None).await.unwrap();
+  let loaded = State::load_from_path(state_path.clone(), None)
+    .await
+    .unwrap();
+  assert_eq!(loaded.get_transcripts(), state.get_transcripts());
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[tokio::test]
+async fn test_transcripts_of_older_versions_load() {
+  let state_path =
+    std::env::temp_dir().join("test_lumine_old_transcripts.toml");
+  tokio::fs::write(
+    &state_path,
+    "[[transcripts]]\nfingerprint = \"4:a\"\nduration = 12.5\n\
+     source = \"episode.mp3\"\ntext = \"Welcome back\"\n",
+  )
+  .await
+  .unwrap();
+
+  let state = State::load_from_path(state_path.clone(), None)
+    .await
+    .unwrap();
+  assert_eq!(state.get_transcripts().len(), 1);
+  assert!(state.get_transcripts()[0].output.is_empty());
+
+  state.save_to_path(state_path.clone(), None).await.unwrap();
+  let saved = tokio::fs::read_to_string(&state_path).await.unwrap();
+  assert!(!saved.contains("Welcome back"));
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[tokio::test]
+async fn test_input_devices_expire() {
+  let state_path = std::env::temp_dir().join("test_lumine_devices.toml");
+  let _ = tokio::fs::remove_file(&state_path).await;
+
+  let mut state = State::default();
+  assert!(state.get_input_devices(60, 1_000).is_empty());
+
+  let device = InputDeviceRecord {
+    index: String::from("alsa_input.usb"),
+    name: String::from("USB Microphone"),
+    system_default: true,
+  };
+  state.set_input_devices(vec![device.clone()], 1_000);
+  state.save_to_path(state_path.clone(), None).await.unwrap();
+  let loaded = State::load_from_path(state_path.clone(), None)
+    .await
+    .unwrap();
+
+  assert_eq!(loaded.get_input_devices(60, 1_060), &[device]);
+  assert!(loaded.get_input_devices(60, 1_061).is_empty());
+  assert!(loaded.get_input_devices(0, 1_000).is_empty());
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[tokio::test]
+async fn test_encrypted_state_save_and_load() {
+  let state_path =
+    std::env::temp_dir().join("test_lumine_state_encrypted.toml");
+  let _ = tokio::fs::remove_file(&state_path).await;
+  let encryptor = Encryptor::new(String::from("correct horse"));
+
+  let mut state = State::default();
+  state.set_last_audio_input_device(String::from("USB Microphone"));
+  state
+    .save_to_path(state_path.clone(), Some(&encryptor))
+    .await
+    .unwrap();
+
+  let content = tokio::fs::read(&state_path).await.unwrap();
+  assert!(!content.windows(3).any(|window| window == b"USB"));
+  let loaded = State::load_from_path(state_path.clone(), Some(&encryptor))
+    .await
+    .unwrap();
+  assert_eq!(loaded.get_last_audio_input_device(), "USB Microphone");
+  match State::load_from_path(state_path.clone(), None).await {
+    Err(errors::StateError::Decrypt(_)) => (),
+    _ => panic!("Expected Decrypt error"),
+  }
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[tokio::test]
+async fn test_record_usage() {
+  let state_path = std::env::temp_dir().join("test_lumine_usage.toml");
+  let _ = tokio::fs::remove_file(&state_path).await;
+
+  let mut state = State::default();
+  state.record_usage("remote (http://127.0.0.1:9090)", 60.0, 6.0);
+  state.record_usage("remote (http://127.0.0.1:9090)", 30.0, 3.0);
+  state.record_usage("remote (http://draft:9090)", 10.0, 0.5);
+
+  let usage = state.get_usage();
+  assert_eq!(usage.len(), 2);
+  assert_eq!(usage[0].runs, 2);
+  assert_eq!(usage[0].audio_seconds, 90.0);
+  assert_eq!(usage[0].real_time_factor(), Some(0.1));
+  assert_eq!(usage[1].runs, 1);
+
+  state.save_to_path(state_path.clone(), None).await.unwrap();
+  let loaded = State::load_from_path(state_path.clone(), None)
+    .await
+    .unwrap();
+  assert_eq!(loaded.get_usage(), state.get_usage());
+
+  let _ = tokio::fs::remove_file(&state_path).await;
+}
+
+#[test]
+fn test_usage_reads_model_key() {
+  let state: State = toml::from_str(
+    r#"
+[[usage]]
+model = "remote (http://127.0.0.1:9090)"
+runs = 2
+audio_seconds = 90.0
+transcription_seconds = 9.0
+"#,
+  )
+  .unwrap();
+
+  assert_eq!(
+    state.get_usage()[0].backend,
+    "remote (http://127.0.0.1:9090)"
+  );
+}
+
+#[test]
+fn test_record_cost() {
+  let mut state = State::default();
+  state.record_cost("2026-09", 600.0, 0.06);
+  state.record_cost("2026-10", 60.0, 0.006);
+  state.record_cost("2026-10", 120.0, 0.012);
+
+  let costs = state.get_costs();
+  assert_eq!(costs.len(), 2);
+  assert_eq!(costs[1].month, "2026-10");
+  assert_eq!(costs[1].audio_seconds, 180.0);
+  assert!((costs[1].cost - 0.018).abs() < 1e-9);
+}
+
+#[test]
+fn test_remember_processed() {
+  let mut state = State::default();
+  assert!(!state.is_processed("/inbox/memo.mp3", 100));
+
+  state.remember_processed("/inbox/memo.mp3", 100);
+  assert!(state.is_processed("/inbox/memo.mp3", 100));
+  assert!(!state.is_processed("/inbox/memo.mp3", 200));
+
+  state.remember_processed("/inbox/memo.mp3", 200);
+  assert!(state.is_processed("/inbox/memo.mp3", 200));
+  assert_eq!(state.processed_files.len(), 1);
+}
+
+#[test]
+fn test_remember_processed_keeps_recent_files() {
+  let mut state = State::default();
+  for index in 0..1005 {
+    state.remember_processed(&format!("/inbox/memo-{}.mp3", index), 100);
+  }
+
+  assert_eq!(state.processed_files.len(), 1000);
+  assert!(!state.is_processed("/inbox/memo-4.mp3", 100));
+  assert!(state.is_processed("/inbox/memo-5.mp3", 100));
+  assert!(state.is_processed("/inbox/memo-1004.mp3", 100));
+}
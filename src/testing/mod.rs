@@ -0,0 +1,412 @@
+//! Test doubles for exercising Lumine without external services.
+//!
+//! This module is compiled for the unit tests of this crate and for
+//! programs enabling the `testing` feature, so embedders can test their
+//! integration with Lumine end to end without a Whisper server or ffmpeg.
+//!
+//! ## Main Components
+//!
+//! - [`MockWhisperServer`]: Local HTTP server answering `/inference`
+//! - [`MockResponse`]: Response returned by the mock server
+//! - [`MockRequest`]: Request received by the mock server
+//! - [`FfmpegShim`]: Script that replaces ffmpeg while it is alive
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use lumine::output::format::OutputFormat;
+//! use lumine::state::State;
+//! use lumine::testing::{FfmpegShim, MockResponse, MockWhisperServer};
+//! use lumine::{App, Config};
+//!
+//! # async fn run() {
+//! let server = MockWhisperServer::start(MockResponse::json("Hello"))
+//!   .await
+//!   .unwrap();
+//! let _ffmpeg = FfmpegShim::new().unwrap();
+//!
+//! let mut config = Config::default();
+//! config.whisper.url = Some(server.url());
+//! let app = App::new(config, State::default(), None);
+//! let outcome = app
+//!   .transcribe_file("meeting.mp3", OutputFormat::Text, None)
+//!   .await
+//!   .unwrap();
+//! assert_eq!(outcome.text, "Hello");
+//! # }
+//! ```
+
+#[cfg(test)]
+mod testing_tests;
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::process::executor::ProcessExecutor;
+
+const INFERENCE_PATH: &str = "/inference";
+const HEADER_END: &[u8] = b"\r\n\r\n";
+const FFMPEG_COMMAND: &str = "ffmpeg";
+
+static SHIM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Response returned by [`MockWhisperServer`] for `/inference` requests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+  pub status: u16,
+  pub body: String,
+}
+
+impl MockResponse {
+  /// Creates a successful response with the given transcript.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - Transcript returned in the `text` field
+  ///
+  /// # Returns
+  ///
+  /// A `MockResponse` with status 200 and a JSON body.
+  pub fn json(text: &str) -> Self {
+    return MockResponse {
+      status: 200,
+      body: serde_json::json!({ "text": text }).to_string(),
+    };
+  }
+
+  /// Creates a response with any status and body.
+  ///
+  /// # Arguments
+  ///
+  /// * `status` - HTTP status code
+  /// * `body` - Response body, sent as is
+  ///
+  /// # Returns
+  ///
+  /// A `MockResponse` with the given status and body.
+  pub fn status(status: u16, body: &str) -> Self {
+    return MockResponse {
+      status,
+      body: body.to_string(),
+    };
+  }
+}
+
+/// Request received by [`MockWhisperServer`].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+  pub method: String,
+  pub path: String,
+  pub body: Vec<u8>,
+}
+
+impl MockRequest {
+  /// Returns whether the body contains the given text.
+  ///
+  /// Multipart fields are sent as plain text, so this checks form values
+  /// like the requested response format.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - Text to search for
+  ///
+  /// # Returns
+  ///
+  /// `true` if the body contains `text`.
+  pub fn body_contains(&self, text: &str) -> bool {
+    return self
+      .body
+      .windows(text.len().max(1))
+      .any(|window| window == text.as_bytes());
+  }
+}
+
+/// Local HTTP server that stands in for a Whisper service.
+///
+/// Listens on a random port of the loopback interface until it is dropped.
+/// `GET` requests succeed so the service counts as reachable, and `POST`
+/// requests to `/inference` return the configured [`MockResponse`].
+pub struct MockWhisperServer {
+  url: String,
+  response: Arc<Mutex<MockResponse>>,
+  requests: Arc<Mutex<Vec<MockRequest>>>,
+  handle: JoinHandle<()>,
+}
+
+impl MockWhisperServer {
+  /// Starts a server returning the given response.
+  ///
+  /// # Arguments
+  ///
+  /// * `response` - Response returned for `/inference` requests
+  ///
+  /// # Returns
+  ///
+  /// A `std::io::Result<MockWhisperServer>` containing the running server or
+  /// an error if no port could be bound.
+  pub async fn start(response: MockResponse) -> std::io::Result<Self> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    let response = Arc::new(Mutex::new(response));
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let handle = tokio::spawn({
+      let response = Arc::clone(&response);
+      let requests = Arc::clone(&requests);
+      async move {
+        while let Ok((stream, _)) = listener.accept().await {
+          let response = Arc::clone(&response);
+          let requests = Arc::clone(&requests);
+          tokio::spawn(async move {
+            let _ = serve(stream, &response, &requests).await;
+          });
+        }
+      }
+    });
+
+    return Ok(MockWhisperServer {
+      url,
+      response,
+      requests,
+      handle,
+    });
+  }
+
+  /// Returns the base URL of the server, for `whisper.url`.
+  pub fn url(&self) -> String {
+    return self.url.clone();
+  }
+
+  /// Replaces the response returned for later `/inference` requests.
+  ///
+  /// # Arguments
+  ///
+  /// * `response` - The new response
+  pub fn set_response(&self, response: MockResponse) {
+    *lock(&self.response) = response;
+  }
+
+  /// Returns the `/inference` requests received so far.
+  pub fn requests(&self) -> Vec<MockRequest> {
+    return lock(&self.requests).clone();
+  }
+}
+
+impl Drop for MockWhisperServer {
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
+
+/// Shell script that replaces ffmpeg while it is alive.
+///
+/// Conversions copy the input to the output unchanged, and every other
+/// invocation succeeds without output. The arguments of each invocation
+/// are logged, so tests can check how ffmpeg was called.
+///
+/// The shim is installed with [`ProcessExecutor::override_command`], which
+/// affects the whole process, so tests using it should not run in parallel
+/// with tests that need the real ffmpeg.
+pub struct FfmpegShim {
+  directory: PathBuf,
+}
+
+impl FfmpegShim {
+  /// Installs a shim whose conversions succeed.
+  ///
+  /// # Returns
+  ///
+  /// A `std::io::Result<FfmpegShim>` containing the installed shim or an
+  /// error if the script could not be written.
+  pub fn new() -> std::io::Result<Self> {
+    return FfmpegShim::install(true);
+  }
+
+  /// Installs a shim whose invocations all fail.
+  ///
+  /// # Returns
+  ///
+  /// A `std::io::Result<FfmpegShim>` containing the installed shim or an
+  /// error if the script could not be written.
+  pub fn failing() -> std::io::Result<Self> {
+    return FfmpegShim::install(false);
+  }
+
+  /// Returns the path of the shim script.
+  pub fn path(&self) -> PathBuf {
+    return self.directory.join(FFMPEG_COMMAND);
+  }
+
+  /// Returns the arguments of each invocation so far, one line per call.
+  pub fn invocations(&self) -> Vec<String> {
+    return std::fs::read_to_string(self.log_path())
+      .unwrap_or_default()
+      .lines()
+      .map(|line| line.to_string())
+      .collect();
+  }
+
+  fn install(succeed: bool) -> std::io::Result<Self> {
+    let directory = std::env::temp_dir().join(format!(
+      "lumine-ffmpeg-shim-{}-{}",
+      std::process::id(),
+      SHIM_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&directory)?;
+    let shim = FfmpegShim { directory };
+
+    let script = shim_script(&shim.log_path(), succeed);
+    std::fs::write(shim.path(), script)?;
+    std::fs::set_permissions(
+      shim.path(),
+      std::fs::Permissions::from_mode(0o755),
+    )?;
+    ProcessExecutor::override_command(FFMPEG_COMMAND, shim.path());
+    return Ok(shim);
+  }
+
+  fn log_path(&self) -> PathBuf {
+    return self.directory.join("invocations.log");
+  }
+}
+
+impl Drop for FfmpegShim {
+  fn drop(&mut self) {
+    ProcessExecutor::clear_override(FFMPEG_COMMAND);
+    let _ = std::fs::remove_dir_all(&self.directory);
+  }
+}
+
+/// Builds the ffmpeg shim script.
+///
+/// A conversion is recognized by its trailing `-y`, with the output file
+/// right before it.
+fn shim_script(log_path: &Path, succeed: bool) -> String {
+  let outcome = if succeed {
+    r#"input=""
+output=""
+previous=""
+for argument in "$@"; do
+  if [ "$argument" = "-version" ]; then
+    echo "ffmpeg version shim"
+    exit 0
+  fi
+  if [ "$previous" = "-i" ]; then
+    input="$argument"
+  fi
+  if [ "$argument" = "-y" ]; then
+    output="$previous"
+  fi
+  previous="$argument"
+done
+if [ -n "$output" ]; then
+  if [ "$input" = "pipe:0" ]; then
+    cat > "$output"
+  else
+    cp "$input" "$output"
+  fi
+fi
+exit 0
+"#
+  } else {
+    "echo \"ffmpeg shim failure\" >&2\nexit 1\n"
+  };
+  return format!(
+    "#!/bin/sh\necho \"$*\" >> '{}'\n{}",
+    log_path.display(),
+    outcome
+  );
+}
+
+/// Answers one request on a connection, then closes it.
+async fn serve(
+  mut stream: TcpStream,
+  response: &Mutex<MockResponse>,
+  requests: &Mutex<Vec<MockRequest>>,
+) -> std::io::Result<()> {
+  let request = read_request(&mut stream).await?;
+  let (status, body) = match (request.method.as_str(), request.path.as_str()) {
+    ("GET", _) => (200, String::new()),
+    ("POST", INFERENCE_PATH) => {
+      let response = lock(response).clone();
+      lock(requests).push(request);
+      (response.status, response.body)
+    }
+    _ => (404, String::new()),
+  };
+
+  let head = format!(
+    "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+    status,
+    reason_phrase(status),
+    body.len()
+  );
+  stream.write_all(head.as_bytes()).await?;
+  stream.write_all(body.as_bytes()).await?;
+  return stream.shutdown().await;
+}
+
+/// Reads a request with a `Content-Length` body from a connection.
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<MockRequest> {
+  let mut buffer = Vec::new();
+  let mut chunk = [0u8; 8192];
+  let header_end = loop {
+    if let Some(position) = find(&buffer, HEADER_END) {
+      break position;
+    }
+    let read = stream.read(&mut chunk).await?;
+    if read == 0 {
+      return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    buffer.extend_from_slice(&chunk[..read]);
+  };
+
+  let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+  let mut lines = head.lines();
+  let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+  let method = request_line.next().unwrap_or_default().to_string();
+  let path = request_line.next().unwrap_or_default().to_string();
+  let content_length = lines
+    .filter_map(|line| line.split_once(':'))
+    .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+    .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+    .unwrap_or(0);
+
+  let mut body = buffer.split_off(header_end + HEADER_END.len());
+  while body.len() < content_length {
+    let read = stream.read(&mut chunk).await?;
+    if read == 0 {
+      break;
+    }
+    body.extend_from_slice(&chunk[..read]);
+  }
+
+  return Ok(MockRequest { method, path, body });
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+  return haystack
+    .windows(needle.len())
+    .position(|window| window == needle);
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+  return match status {
+    200 => "OK",
+    400 => "Bad Request",
+    404 => "Not Found",
+    500 => "Internal Server Error",
+    503 => "Service Unavailable",
+    _ => "Unknown",
+  };
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+  return mutex.lock().unwrap_or_else(|e| e.into_inner());
+}
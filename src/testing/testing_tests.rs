@@ -0,0 +1,90 @@
+use reqwest::multipart;
+
+use crate::network::HttpClient;
+use crate::network::errors::NetworkError;
+use crate::testing::{
+  MockRequest, MockResponse, MockWhisperServer, shim_script,
+};
+use crate::whisper::responses::WhisperJsonResponse;
+
+#[test]
+fn test_mock_response_json() {
+  let response = MockResponse::json("Hello \"world\"");
+
+  assert_eq!(response.status, 200);
+  assert_eq!(response.body, r#"{"text":"Hello \"world\""}"#);
+}
+
+#[test]
+fn test_mock_request_body_contains() {
+  let request = MockRequest {
+    method: String::from("POST"),
+    path: String::from("/inference"),
+    body: b"name=\"response_format\"\r\n\r\njson".to_vec(),
+  };
+
+  assert!(request.body_contains("response_format"));
+  assert!(!request.body_contains("verbose_json"));
+}
+
+#[test]
+fn test_shim_script_logs_invocations() {
+  let script = shim_script(std::path::Path::new("/tmp/shim.log"), true);
+
+  assert!(script.starts_with("#!/bin/sh\n"));
+  assert!(script.contains("echo \"$*\" >> '/tmp/shim.log'"));
+  assert!(script.contains("cp \"$input\" \"$output\""));
+}
+
+#[test]
+fn test_failing_shim_script_exits_with_error() {
+  let script = shim_script(std::path::Path::new("/tmp/shim.log"), false);
+
+  assert!(script.ends_with("exit 1\n"));
+  assert!(!script.contains("cp "));
+}
+
+#[tokio::test]
+async fn test_mock_server_answers_inference() {
+  let server = MockWhisperServer::start(MockResponse::json("Hello"))
+    .await
+    .unwrap();
+  let client = HttpClient::new(server.url());
+  let form = multipart::Form::new().text("response_format", "json");
+
+  let response = client
+    .post_with_form::<WhisperJsonResponse>(form, "inference")
+    .await
+    .unwrap();
+
+  assert_eq!(response.text, "Hello");
+  let requests = server.requests();
+  assert_eq!(requests.len(), 1);
+  assert_eq!(requests[0].method, "POST");
+  assert!(requests[0].body_contains("response_format"));
+}
+
+#[tokio::test]
+async fn test_mock_server_returns_configured_error() {
+  let server = MockWhisperServer::start(MockResponse::json("Hello"))
+    .await
+    .unwrap();
+  server.set_response(MockResponse::status(500, "overloaded"));
+  let client = HttpClient::new(server.url());
+
+  let result = client
+    .post_with_form::<WhisperJsonResponse>(multipart::Form::new(), "inference")
+    .await;
+
+  match result {
+    Err(NetworkError::ResponseError(error)) => {
+      assert_eq!(error.status, reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+      assert_eq!(error.body, "overloaded");
+      assert_eq!(
+        error.to_string(),
+        "HTTP 500 Internal Server Error: overloaded"
+      );
+    }
+    _ => panic!("Expected ResponseError"),
+  }
+}
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+/// Self-update errors.
+///
+/// Represents errors that can occur while checking for or installing a new
+/// release.
+#[derive(Error, Debug)]
+pub enum UpdateError {
+  #[error("Could not check for updates: {0}")]
+  Request(String),
+
+  #[error("No release archive for '{0}' was found.")]
+  NoAsset(String),
+
+  #[error("The release has no checksum for '{0}'. Refusing to install it.")]
+  NoChecksum(String),
+
+  #[error(
+    "Checksum of '{0}' does not match the release. Refusing to install it."
+  )]
+  ChecksumMismatch(String),
+
+  #[error(
+    "This build has no release signing key, so updates cannot be verified. Please update Lumine the way it was installed."
+  )]
+  NoPublicKey,
+
+  #[error(
+    "The release has no signature for its checksums. Refusing to install it."
+  )]
+  NoSignature,
+
+  #[error(
+    "The signature of the release checksums is invalid: {0}. Refusing to install it."
+  )]
+  InvalidSignature(String),
+
+  #[error("Could not install the update: {0}")]
+  Install(String),
+}
+
+/// Result type for update operations.
+pub type UpdateResult<T> = Result<T, UpdateError>;
@@ -0,0 +1,22 @@
+//! Self-update from GitHub releases.
+//!
+//! Looks up the latest release, downloads the archive built for this
+//! platform, verifies it against the release's `SHA256SUMS` file, whose
+//! minisign signature is checked with the key embedded at build time, and
+//! replaces the running binary.
+//!
+//! ## Main Components
+//!
+//! - [`Updater`]: Checks for and installs new releases
+//! - [`release::Release`]: A published release and its assets
+//! - [`errors::UpdateError`]: Error types for update operations
+//! - [`errors::UpdateResult<T>`]: Result type alias for update operations
+
+pub mod errors;
+pub mod release;
+pub mod updater;
+
+#[cfg(test)]
+mod release_tests;
+
+pub use updater::Updater;
@@ -0,0 +1,153 @@
+use minisign_verify::{PublicKey, Signature};
+
+use crate::update::errors::{UpdateError, UpdateResult};
+
+/// Name of the release asset listing the SHA-256 checksums of the archives.
+pub const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
+/// Name of the release asset with the minisign signature of the checksums.
+pub const SIGNATURE_ASSET: &str = "SHA256SUMS.minisig";
+
+/// A published release as returned by the GitHub releases API.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Release {
+  pub tag_name: String,
+  #[serde(default)]
+  pub assets: Vec<ReleaseAsset>,
+}
+
+/// A downloadable file attached to a release.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ReleaseAsset {
+  pub name: String,
+  pub browser_download_url: String,
+}
+
+impl Release {
+  /// Gets the version of the release without the `v` prefix of its tag.
+  ///
+  /// # Returns
+  ///
+  /// A `&str` containing the version, such as `0.2.0`.
+  pub fn version(&self) -> &str {
+    return self.tag_name.trim_start_matches('v');
+  }
+
+  /// Checks whether the release is newer than a version.
+  ///
+  /// # Arguments
+  ///
+  /// * `version` - The version to compare with, such as `0.1.0`
+  ///
+  /// # Returns
+  ///
+  /// `true` if the release is newer, `false` if it is not or either version
+  /// cannot be parsed.
+  pub fn is_newer_than(&self, version: &str) -> bool {
+    return match (parse_version(self.version()), parse_version(version)) {
+      (Some(release), Some(current)) => release > current,
+      _ => false,
+    };
+  }
+
+  /// Gets the name of the release archive built for a target.
+  ///
+  /// # Arguments
+  ///
+  /// * `target` - The target triple, such as `x86_64-unknown-linux-gnu`
+  ///
+  /// # Returns
+  ///
+  /// A `String` containing the archive name.
+  pub fn archive_name(&self, target: &str) -> String {
+    return format!("lumine-{}-{}.tar.gz", self.tag_name, target);
+  }
+
+  /// Finds an asset of the release by name.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The asset name
+  ///
+  /// # Returns
+  ///
+  /// An `Option<&ReleaseAsset>` containing the asset if it exists.
+  pub fn asset(&self, name: &str) -> Option<&ReleaseAsset> {
+    return self.assets.iter().find(|asset| asset.name == name);
+  }
+}
+
+/// Gets the target triple release archives are built for on this platform.
+///
+/// # Returns
+///
+/// An `Option<&str>` containing the target triple, or `None` if no release
+/// is built for this platform.
+pub fn current_target() -> Option<&'static str> {
+  return match (std::env::consts::ARCH, std::env::consts::OS) {
+    ("x86_64", "linux") => Some("x86_64-unknown-linux-gnu"),
+    ("aarch64", "linux") => Some("aarch64-unknown-linux-gnu"),
+    ("x86_64", "macos") => Some("x86_64-apple-darwin"),
+    ("aarch64", "macos") => Some("aarch64-apple-darwin"),
+    _ => None,
+  };
+}
+
+/// Finds the checksum of a file in the output of `sha256sum`.
+///
+/// # Arguments
+///
+/// * `checksums` - Lines of a hex checksum followed by a file name
+/// * `name` - The file name to look up
+///
+/// # Returns
+///
+/// An `Option<String>` containing the lowercase hex checksum.
+pub fn find_checksum(checksums: &str, name: &str) -> Option<String> {
+  return checksums.lines().find_map(|line| {
+    let (checksum, file) = line.trim().split_once(char::is_whitespace)?;
+    let file = file.trim_start().trim_start_matches('*');
+    if file != name {
+      return None;
+    }
+    return Some(checksum.to_lowercase());
+  });
+}
+
+/// Verifies the minisign signature of a checksums file.
+///
+/// Only signatures of pre-hashed files, the default of current minisign
+/// versions, are accepted.
+///
+/// # Arguments
+///
+/// * `checksums` - Content of the checksums file
+/// * `signature` - Content of the signature file
+/// * `public_key` - Base64 minisign public key the release is signed with
+///
+/// # Returns
+///
+/// An `UpdateResult<()>` indicating whether the signature is valid.
+pub fn verify_signature(
+  checksums: &str,
+  signature: &str,
+  public_key: &str,
+) -> UpdateResult<()> {
+  let public_key = PublicKey::from_base64(public_key)
+    .map_err(|e| UpdateError::InvalidSignature(e.to_string()))?;
+  let signature = Signature::decode(signature)
+    .map_err(|e| UpdateError::InvalidSignature(e.to_string()))?;
+  return public_key
+    .verify(checksums.as_bytes(), &signature, false)
+    .map_err(|e| UpdateError::InvalidSignature(e.to_string()));
+}
+
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+  let core = version.split(['-', '+']).next()?;
+  let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+  let version = (parts.next()??, parts.next()??, parts.next()??);
+  if parts.next().is_some() {
+    return None;
+  }
+  return Some(version);
+}
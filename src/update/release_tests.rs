@@ -0,0 +1,107 @@
+use crate::update::errors::UpdateError;
+use crate::update::release::{
+  Release, current_target, find_checksum, verify_signature,
+};
+
+const RELEASE_JSON: &str = r#"{
+  "tag_name": "v0.2.0",
+  "assets": [
+    {
+      "name": "lumine-v0.2.0-x86_64-unknown-linux-gnu.tar.gz",
+      "browser_download_url": "https://example.com/linux.tar.gz"
+    },
+    {
+      "name": "SHA256SUMS",
+      "browser_download_url": "https://example.com/SHA256SUMS"
+    }
+  ]
+}"#;
+
+fn release(tag: &str) -> Release {
+  return Release {
+    tag_name: tag.to_string(),
+    assets: Vec::new(),
+  };
+}
+
+#[test]
+fn test_parse_release() {
+  let release: Release = serde_json::from_str(RELEASE_JSON).unwrap();
+  assert_eq!(release.version(), "0.2.0");
+  let name = release.archive_name("x86_64-unknown-linux-gnu");
+  assert_eq!(name, "lumine-v0.2.0-x86_64-unknown-linux-gnu.tar.gz");
+  assert_eq!(
+    release.asset(&name).unwrap().browser_download_url,
+    "https://example.com/linux.tar.gz"
+  );
+  assert!(
+    release
+      .asset("lumine-v0.2.0-x86_64-pc-windows.zip")
+      .is_none()
+  );
+}
+
+#[test]
+fn test_is_newer_than() {
+  assert!(release("v0.2.0").is_newer_than("0.1.0"));
+  assert!(release("v0.10.0").is_newer_than("0.9.3"));
+  assert!(release("v1.0.0-beta.1").is_newer_than("0.9.0"));
+  assert!(!release("v0.1.0").is_newer_than("0.1.0"));
+  assert!(!release("v0.1.0").is_newer_than("0.2.0"));
+  assert!(!release("nightly").is_newer_than("0.1.0"));
+}
+
+#[test]
+fn test_find_checksum() {
+  let checksums = "\
+ABC123  lumine-v0.2.0-x86_64-unknown-linux-gnu.tar.gz
+def456 *lumine-v0.2.0-aarch64-apple-darwin.tar.gz
+";
+  assert_eq!(
+    find_checksum(checksums, "lumine-v0.2.0-x86_64-unknown-linux-gnu.tar.gz"),
+    Some(String::from("abc123"))
+  );
+  assert_eq!(
+    find_checksum(checksums, "lumine-v0.2.0-aarch64-apple-darwin.tar.gz"),
+    Some(String::from("def456"))
+  );
+  assert_eq!(find_checksum(checksums, "lumine.tar.gz"), None);
+}
+
+#[test]
+fn test_current_target() {
+  if cfg!(all(target_arch = "x86_64", target_os = "linux")) {
+    assert_eq!(current_target(), Some("x86_64-unknown-linux-gnu"));
+  }
+}
+
+const CHECKSUMS: &str =
+  "0123456789abcdef  lumine-v0.2.0-x86_64-unknown-linux-gnu.tar.gz\n";
+const PUBLIC_KEY: &str =
+  "RWSvSFnqIQfccfd6uSLbLbiFr8tV5wXpFafka4iztdMTZxdapHOo4PGA";
+const SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUSvSFnqIQfccUTcrcsbOINornSj+H71uEPRJgcOMlFP2FSf4tDK8iqn4ClpBEhgcIzqH5a+SsGF+yu1HQthuzLUYySblvn5FQM=
+trusted comment: timestamp:1767225600\tfile:SHA256SUMS\thashed
+h+maHLNbtNTg7kshVeandF/L7ohG3ovOX6y/o2DMmSjkt3pAUTDeWfVfj3dHWMUO3praFuHBC/ofJpCnq4kOAQ==
+";
+
+#[test]
+fn test_verify_signature() {
+  assert!(verify_signature(CHECKSUMS, SIGNATURE, PUBLIC_KEY).is_ok());
+}
+
+#[test]
+fn test_verify_signature_rejects_modified_checksums() {
+  let checksums = CHECKSUMS.replace("0123", "3210");
+  match verify_signature(&checksums, SIGNATURE, PUBLIC_KEY).unwrap_err() {
+    UpdateError::InvalidSignature(_) => {}
+    _ => panic!("Expected InvalidSignature error"),
+  }
+}
+
+#[test]
+fn test_verify_signature_rejects_other_key() {
+  let other_key = "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3";
+  assert!(verify_signature(CHECKSUMS, SIGNATURE, other_key).is_err());
+  assert!(verify_signature(CHECKSUMS, "not a signature", PUBLIC_KEY).is_err());
+}
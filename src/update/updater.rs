@@ -0,0 +1,196 @@
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::files::digest::sha256_hex;
+use crate::files::operations;
+use crate::network::HttpClient;
+use crate::process::executor::ProcessExecutor;
+use crate::update::errors::{UpdateError, UpdateResult};
+use crate::update::release::{
+  CHECKSUMS_ASSET, Release, SIGNATURE_ASSET, current_target, find_checksum,
+  verify_signature,
+};
+use crate::vlog;
+
+const RELEASES_URL: &str =
+  "https://api.github.com/repos/MahanRahmati/lumine/releases/latest";
+const STAGING_DIRECTORY: &str = ".lumine-update";
+const BINARY_NAME: &str = "lumine";
+
+/// Minisign public key the release checksums are signed with, embedded by
+/// the release workflow.
+const RELEASE_PUBLIC_KEY: Option<&str> =
+  option_env!("LUMINE_RELEASE_PUBLIC_KEY");
+
+/// Checks for and installs new releases of Lumine.
+///
+/// Archives are only installed when the release's `SHA256SUMS` file is
+/// signed with the embedded release key and the archive's SHA-256 checksum
+/// matches the one in it.
+pub struct Updater {
+  releases_url: String,
+  public_key: Option<String>,
+  cancel: CancellationToken,
+}
+
+impl Updater {
+  /// Creates a new updater for the official GitHub releases.
+  ///
+  /// # Returns
+  ///
+  /// A new `Updater` instance.
+  pub fn new() -> Self {
+    return Updater {
+      releases_url: String::from(RELEASES_URL),
+      public_key: RELEASE_PUBLIC_KEY
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(String::from),
+      cancel: CancellationToken::new(),
+    };
+  }
+
+  /// Makes downloads of this updater cancellable.
+  ///
+  /// # Arguments
+  ///
+  /// * `cancel` - Token that aborts in-flight downloads when cancelled
+  ///
+  /// # Returns
+  ///
+  /// The `Updater` with the cancellation token attached.
+  pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+    self.cancel = cancel;
+    return self;
+  }
+
+  /// Looks up the latest published release.
+  ///
+  /// # Returns
+  ///
+  /// An `UpdateResult<Release>` containing the latest release or an error.
+  pub async fn latest_release(&self) -> UpdateResult<Release> {
+    let body = self.download_text(&self.releases_url).await?;
+    return serde_json::from_str(&body)
+      .map_err(|e| UpdateError::Request(e.to_string()));
+  }
+
+  /// Downloads, verifies and installs a release over the running binary.
+  ///
+  /// Refuses to install anything in builds without an embedded release key.
+  ///
+  /// # Arguments
+  ///
+  /// * `release` - The release to install
+  ///
+  /// # Returns
+  ///
+  /// An `UpdateResult<String>` containing the path of the replaced binary or
+  /// an error.
+  pub async fn install(&self, release: &Release) -> UpdateResult<String> {
+    let public_key =
+      self.public_key.as_deref().ok_or(UpdateError::NoPublicKey)?;
+    let target = current_target().ok_or_else(|| {
+      return UpdateError::NoAsset(format!(
+        "{}-{}",
+        std::env::consts::ARCH,
+        std::env::consts::OS
+      ));
+    })?;
+    let archive_name = release.archive_name(target);
+    let archive = release
+      .asset(&archive_name)
+      .ok_or_else(|| UpdateError::NoAsset(archive_name.clone()))?;
+    let checksums = release
+      .asset(CHECKSUMS_ASSET)
+      .ok_or_else(|| UpdateError::NoChecksum(archive_name.clone()))?;
+    let signature = release
+      .asset(SIGNATURE_ASSET)
+      .ok_or(UpdateError::NoSignature)?;
+
+    let checksums = self.download_text(&checksums.browser_download_url).await?;
+    let signature = self.download_text(&signature.browser_download_url).await?;
+    verify_signature(&checksums, &signature, public_key)?;
+    vlog!("Checksums signature verified");
+    let expected = find_checksum(&checksums, &archive_name)
+      .ok_or_else(|| UpdateError::NoChecksum(archive_name.clone()))?;
+    vlog!("Downloading {}", archive.browser_download_url);
+    let data = HttpClient::new(archive.browser_download_url.clone())
+      .with_cancellation(self.cancel.clone())
+      .get_bytes()
+      .await
+      .map_err(|e| UpdateError::Request(e.to_string()))?;
+    if sha256_hex(&data) != expected {
+      return Err(UpdateError::ChecksumMismatch(archive_name));
+    }
+    vlog!("Checksum verified: {}", expected);
+
+    let executable = std::env::current_exe()
+      .and_then(|path| path.canonicalize())
+      .map_err(|e| UpdateError::Install(e.to_string()))?;
+    let staging = executable
+      .parent()
+      .unwrap_or_else(|| Path::new("."))
+      .join(STAGING_DIRECTORY);
+    let installed = replace_binary(&executable, &staging, &data).await;
+    let _ = tokio::fs::remove_dir_all(&staging).await;
+    installed?;
+    return Ok(executable.to_string_lossy().to_string());
+  }
+
+  async fn download_text(&self, url: &str) -> UpdateResult<String> {
+    return HttpClient::new(url.to_string())
+      .with_cancellation(self.cancel.clone())
+      .get_text()
+      .await
+      .map_err(|e| UpdateError::Request(e.to_string()));
+  }
+}
+
+impl Default for Updater {
+  fn default() -> Self {
+    return Updater::new();
+  }
+}
+
+async fn replace_binary(
+  executable: &Path,
+  staging: &Path,
+  archive: &[u8],
+) -> UpdateResult<()> {
+  let staging_str = staging.to_string_lossy().to_string();
+  operations::create_directory_all(&staging_str)
+    .await
+    .map_err(|e| UpdateError::Install(e.to_string()))?;
+  let archive_path = staging.join("lumine.tar.gz");
+  tokio::fs::write(&archive_path, archive)
+    .await
+    .map_err(|e| UpdateError::Install(e.to_string()))?;
+
+  let output = ProcessExecutor::run(
+    "tar",
+    &["xzf", &archive_path.to_string_lossy(), "-C", &staging_str],
+  )
+  .await
+  .map_err(|e| UpdateError::Install(e.to_string()))?;
+  if !output.status.success() {
+    return Err(UpdateError::Install(output.stderr));
+  }
+
+  let binary = staging.join(BINARY_NAME);
+  set_executable(&binary).await?;
+  return tokio::fs::rename(&binary, executable)
+    .await
+    .map_err(|e| UpdateError::Install(e.to_string()));
+}
+
+async fn set_executable(path: &Path) -> UpdateResult<()> {
+  return tokio::fs::set_permissions(
+    path,
+    std::fs::Permissions::from_mode(0o755),
+  )
+  .await
+  .map_err(|e| UpdateError::Install(e.to_string()));
+}
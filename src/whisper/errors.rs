@@ -1,32 +1,60 @@
 use thiserror::Error;
 
+#[cfg(feature = "network")]
+use crate::network::errors::StatusError;
+
 /// Whisper transcription service errors.
 ///
 /// Represents errors that can occur during audio transcription operations.
 #[derive(Error, Debug)]
 pub enum WhisperError {
   #[error(
-    "Audio file not found: '{0}'. Please ensure the file exists and is readable."
+    "[LUM-WSP-001] Audio file not found: '{0}'. Please ensure the file exists and is readable."
   )]
   FileNotFound(String),
 
   #[error(
-    "Invalid Whisper service URL: '{0}'. Please check your configuration file."
+    "[LUM-WSP-002] Invalid Whisper service URL: '{0}'. Please check your configuration file."
   )]
   InvalidURL(String),
 
+  #[cfg(feature = "network")]
   #[error(
-    "Failed to connect to Whisper service. Please verify the service is running and accessible."
+    "[LUM-WSP-003] Failed to connect to Whisper service. Please verify the service is running and accessible."
   )]
-  RequestFailed,
+  RequestFailed(#[source] reqwest::Error),
 
+  #[cfg(feature = "network")]
   #[error(
-    "Whisper service returned an error. Please check the service logs and try again."
+    "[LUM-WSP-004] Whisper service returned an error. Please check the service logs and try again."
   )]
-  ResponseError,
+  ResponseError(#[source] StatusError),
 
-  #[error("Failed to decode Whisper response. {0}")]
+  #[error("[LUM-WSP-005] Failed to decode Whisper response. {0}")]
   DecodeError(String),
+
+  #[error("[LUM-WSP-006] Transcription cancelled.")]
+  Cancelled,
+
+  #[error("[LUM-WSP-007] Cannot read audio file: '{0}'.")]
+  FileRead(String, #[source] std::io::Error),
+}
+
+impl WhisperError {
+  /// Returns the stable code of this error, such as `LUM-WSP-001`.
+  pub fn code(&self) -> &'static str {
+    return match self {
+      WhisperError::FileNotFound(..) => "LUM-WSP-001",
+      WhisperError::InvalidURL(..) => "LUM-WSP-002",
+      #[cfg(feature = "network")]
+      WhisperError::RequestFailed(..) => "LUM-WSP-003",
+      #[cfg(feature = "network")]
+      WhisperError::ResponseError(..) => "LUM-WSP-004",
+      WhisperError::DecodeError(..) => "LUM-WSP-005",
+      WhisperError::Cancelled => "LUM-WSP-006",
+      WhisperError::FileRead(..) => "LUM-WSP-007",
+    };
+  }
 }
 
 /// Result type for Whisper operations.
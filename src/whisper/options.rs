@@ -0,0 +1,79 @@
+/// Decoding options sent to the Whisper service with each request.
+///
+/// Unset options are not sent, so the service keeps its own defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TranscriptionOptions {
+  /// Whether voice activity detection runs before transcription
+  pub vad: Option<bool>,
+  /// Initial sampling temperature
+  pub temperature: Option<f64>,
+  /// Temperature added on each fallback retry (0 disables fallback)
+  pub temperature_increment: Option<f64>,
+  /// Entropy above which a segment is decoded again at a higher temperature
+  pub entropy_threshold: Option<f64>,
+  /// Average log probability below which a segment is decoded again
+  pub logprob_threshold: Option<f64>,
+  /// No-speech probability above which a segment counts as silence
+  pub no_speech_threshold: Option<f64>,
+  /// Whether non-speech tokens such as `[Music]` are suppressed while decoding
+  pub suppress_non_speech: Option<bool>,
+  /// Maximum segment length in characters (0 means no limit)
+  pub max_segment_length: Option<i32>,
+  /// Whether segments are split on word rather than token boundaries
+  pub split_on_word: Option<bool>,
+  /// Probability threshold for token timestamps
+  pub word_threshold: Option<f64>,
+  /// Number of beams in beam search (1 decodes greedily)
+  pub beam_size: Option<i32>,
+  /// Number of candidates sampled when decoding with a temperature
+  pub best_of: Option<i32>,
+}
+
+impl TranscriptionOptions {
+  /// Converts the options to multipart form fields.
+  ///
+  /// # Returns
+  ///
+  /// A `Vec<(&'static str, String)>` of field names and values for the set
+  /// options.
+  pub fn to_form_fields(&self) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+    if let Some(vad) = self.vad {
+      fields.push(("vad", vad.to_string()));
+    }
+    if let Some(temperature) = self.temperature {
+      fields.push(("temperature", temperature.to_string()));
+    }
+    if let Some(increment) = self.temperature_increment {
+      fields.push(("temperature_inc", increment.to_string()));
+    }
+    if let Some(threshold) = self.entropy_threshold {
+      fields.push(("entropy_thold", threshold.to_string()));
+    }
+    if let Some(threshold) = self.logprob_threshold {
+      fields.push(("logprob_thold", threshold.to_string()));
+    }
+    if let Some(threshold) = self.no_speech_threshold {
+      fields.push(("no_speech_thold", threshold.to_string()));
+    }
+    if let Some(suppress) = self.suppress_non_speech {
+      fields.push(("suppress_nst", suppress.to_string()));
+    }
+    if let Some(length) = self.max_segment_length {
+      fields.push(("max_len", length.to_string()));
+    }
+    if let Some(split) = self.split_on_word {
+      fields.push(("split_on_word", split.to_string()));
+    }
+    if let Some(threshold) = self.word_threshold {
+      fields.push(("word_thold", threshold.to_string()));
+    }
+    if let Some(beam_size) = self.beam_size {
+      fields.push(("beam_size", beam_size.to_string()));
+    }
+    if let Some(best_of) = self.best_of {
+      fields.push(("best_of", best_of.to_string()));
+    }
+    return fields;
+  }
+}
@@ -0,0 +1,73 @@
+use crate::whisper::options::TranscriptionOptions;
+
+#[test]
+fn test_default_options_send_no_fields() {
+  let options = TranscriptionOptions::default();
+  assert!(options.to_form_fields().is_empty());
+}
+
+#[test]
+fn test_vad_form_field() {
+  let options = TranscriptionOptions {
+    vad: Some(false),
+    ..Default::default()
+  };
+  assert_eq!(
+    options.to_form_fields(),
+    vec![("vad", String::from("false"))]
+  );
+}
+
+#[test]
+fn test_decoding_form_fields() {
+  let options = TranscriptionOptions {
+    temperature: Some(0.0),
+    temperature_increment: Some(0.2),
+    entropy_threshold: Some(2.4),
+    logprob_threshold: Some(-1.0),
+    ..Default::default()
+  };
+  assert_eq!(
+    options.to_form_fields(),
+    vec![
+      ("temperature", String::from("0")),
+      ("temperature_inc", String::from("0.2")),
+      ("entropy_thold", String::from("2.4")),
+      ("logprob_thold", String::from("-1")),
+    ]
+  );
+}
+
+#[test]
+fn test_segmentation_form_fields() {
+  let options = TranscriptionOptions {
+    max_segment_length: Some(42),
+    split_on_word: Some(true),
+    word_threshold: Some(0.01),
+    ..Default::default()
+  };
+  assert_eq!(
+    options.to_form_fields(),
+    vec![
+      ("max_len", String::from("42")),
+      ("split_on_word", String::from("true")),
+      ("word_thold", String::from("0.01")),
+    ]
+  );
+}
+
+#[test]
+fn test_beam_search_form_fields() {
+  let options = TranscriptionOptions {
+    beam_size: Some(5),
+    best_of: Some(5),
+    ..Default::default()
+  };
+  assert_eq!(
+    options.to_form_fields(),
+    vec![
+      ("beam_size", String::from("5")),
+      ("best_of", String::from("5")),
+    ]
+  );
+}
@@ -3,7 +3,16 @@
 //! This module defines the various response formats that Whisper API can return,
 //! including plain text, simple JSON, and verbose JSON with full metadata.
 
+use crate::output::captions::{format_sami, format_ttml};
 use crate::output::format::OutputFormat;
+use crate::output::labels::format_labels;
+use crate::output::lrc::format_lrc;
+use crate::output::markdown::format_markdown;
+use crate::output::subtitles::{format_srt, format_vtt};
+use crate::output::transcript::{
+  find_stop_phrase, strip_annotations, trim_before_stop_phrase,
+};
+use crate::output::words::{format_words_json, format_words_tsv};
 use crate::whisper::errors::{WhisperError, WhisperResult};
 
 /// Response from the Whisper transcription service.
@@ -20,6 +29,153 @@ pub enum WhisperResponse {
 }
 
 impl WhisperResponse {
+  /// Gets the transcribed text.
+  ///
+  /// # Returns
+  ///
+  /// A reference to the transcript text.
+  pub fn text(&self) -> &str {
+    return match self {
+      WhisperResponse::Text(response) => &response.text,
+      WhisperResponse::Json(response) => &response.text,
+      WhisperResponse::VerboseJson(response) => &response.text,
+    };
+  }
+
+  /// Gets the detected language, if the response includes it.
+  ///
+  /// # Returns
+  ///
+  /// The language reported by Whisper, or `None` for text and simple JSON.
+  pub fn language(&self) -> Option<String> {
+    return match self {
+      WhisperResponse::VerboseJson(response) => Some(response.language.clone()),
+      _ => None,
+    };
+  }
+
+  /// Gets the transcript segments, if the response includes them.
+  ///
+  /// # Returns
+  ///
+  /// The segments reported by Whisper, empty for text and simple JSON.
+  pub fn segments(&self) -> Vec<WhisperSegment> {
+    return match self {
+      WhisperResponse::VerboseJson(response) => response.segments.clone(),
+      _ => Vec::new(),
+    };
+  }
+
+  /// Removes the stop phrase and anything after it from the transcript.
+  ///
+  /// Segments of verbose responses are cut where the phrase starts as well,
+  /// so timed formats end there too.
+  ///
+  /// # Arguments
+  ///
+  /// * `stop_phrase` - The phrase that ends dictation (empty to disable)
+  ///
+  /// # Returns
+  ///
+  /// `true` if the stop phrase was spoken, `false` otherwise.
+  pub fn strip_stop_phrase(&mut self, stop_phrase: &str) -> bool {
+    let text = match self {
+      WhisperResponse::Text(response) => &mut response.text,
+      WhisperResponse::Json(response) => &mut response.text,
+      WhisperResponse::VerboseJson(response) => &mut response.text,
+    };
+    let Some(start) = find_stop_phrase(text, stop_phrase) else {
+      return false;
+    };
+    *text = trim_before_stop_phrase(&text[..start]).to_string();
+
+    if let WhisperResponse::VerboseJson(response) = self {
+      truncate_segments(&mut response.segments, stop_phrase);
+    }
+    return true;
+  }
+
+  /// Removes non-speech annotations from plain text responses.
+  ///
+  /// JSON responses keep their annotations so structured consumers still
+  /// see them.
+  pub fn strip_annotations(&mut self) {
+    if let WhisperResponse::Text(response) = self {
+      response.text = strip_annotations(&response.text);
+    }
+  }
+
+  /// Drops segments that most likely contain no speech.
+  ///
+  /// A segment is dropped when its no-speech probability is above
+  /// `no_speech_threshold` and, if a log probability threshold is given, its
+  /// average log probability is also below it. The transcript text is rebuilt
+  /// from the remaining segments. Only verbose responses contain segments.
+  ///
+  /// # Arguments
+  ///
+  /// * `no_speech_threshold` - No-speech probability above which a segment is junk
+  /// * `logprob_threshold` - Average log probability below which a segment is junk
+  ///
+  /// # Returns
+  ///
+  /// The number of dropped segments.
+  pub fn drop_silent_segments(
+    &mut self,
+    no_speech_threshold: f64,
+    logprob_threshold: Option<f64>,
+  ) -> usize {
+    let WhisperResponse::VerboseJson(response) = self else {
+      return 0;
+    };
+
+    let count = response.segments.len();
+    response.segments.retain(|segment| {
+      let silent = segment.no_speech_prob > no_speech_threshold;
+      let unlikely = logprob_threshold
+        .is_none_or(|threshold| segment.avg_logprob < threshold);
+      return !(silent && unlikely);
+    });
+
+    let dropped = count - response.segments.len();
+    if dropped > 0 {
+      response.text = response
+        .segments
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect();
+    }
+    return dropped;
+  }
+
+  /// Converts a verbose response to the response type of another format.
+  ///
+  /// Used when a verbose response was requested internally, for example to
+  /// filter segments, but the user asked for plain text or simple JSON.
+  ///
+  /// # Arguments
+  ///
+  /// * `format` - The output format the response should match
+  ///
+  /// # Returns
+  ///
+  /// The converted `WhisperResponse`, unchanged if no conversion is needed.
+  pub fn into_format(self, format: OutputFormat) -> WhisperResponse {
+    return match (self, format) {
+      (WhisperResponse::VerboseJson(response), OutputFormat::Text) => {
+        WhisperResponse::Text(WhisperTextResponse {
+          text: response.text,
+        })
+      }
+      (WhisperResponse::VerboseJson(response), OutputFormat::Json) => {
+        WhisperResponse::Json(WhisperJsonResponse {
+          text: response.text,
+        })
+      }
+      (response, _) => response,
+    };
+  }
+
   pub fn format(&self, format: OutputFormat) -> WhisperResult<String> {
     return match (&self, format) {
       (WhisperResponse::Text(text_response), OutputFormat::Text) => {
@@ -34,6 +190,44 @@ impl WhisperResponse {
         OutputFormat::FullJson,
       ) => serde_json::to_string_pretty(verbose_response)
         .map_err(|e| WhisperError::DecodeError(e.to_string())),
+      (WhisperResponse::VerboseJson(verbose_response), OutputFormat::Lrc) => {
+        Ok(format_lrc(&verbose_response.segments))
+      }
+      (
+        WhisperResponse::VerboseJson(verbose_response),
+        OutputFormat::Labels,
+      ) => Ok(format_labels(&verbose_response.segments)),
+      (WhisperResponse::VerboseJson(verbose_response), OutputFormat::Ttml) => {
+        Ok(format_ttml(
+          &verbose_response.language,
+          &verbose_response.segments,
+        ))
+      }
+      (WhisperResponse::VerboseJson(verbose_response), OutputFormat::Srt) => {
+        Ok(format_srt(&verbose_response.segments))
+      }
+      (WhisperResponse::VerboseJson(verbose_response), OutputFormat::Vtt) => {
+        Ok(format_vtt(&verbose_response.segments))
+      }
+      (
+        WhisperResponse::VerboseJson(verbose_response),
+        OutputFormat::Markdown,
+      ) => Ok(format_markdown(&verbose_response.segments, None)),
+      (WhisperResponse::VerboseJson(verbose_response), OutputFormat::Sami) => {
+        Ok(format_sami(
+          &verbose_response.language,
+          &verbose_response.segments,
+        ))
+      }
+      (
+        WhisperResponse::VerboseJson(verbose_response),
+        OutputFormat::WordsJson,
+      ) => format_words_json(&verbose_response.segments)
+        .map_err(|e| WhisperError::DecodeError(e.to_string())),
+      (
+        WhisperResponse::VerboseJson(verbose_response),
+        OutputFormat::WordsTsv,
+      ) => Ok(format_words_tsv(&verbose_response.segments)),
       _ => Err(WhisperError::DecodeError(
         "Response format mismatch".to_string(),
       )),
@@ -84,6 +278,42 @@ pub struct WhisperSegment {
   pub no_speech_prob: f64,
 }
 
+#[cfg(test)]
+impl WhisperSegment {
+  /// Creates a segment for tests, without words and with the decoding
+  /// statistics of confident speech.
+  pub(crate) fn sample(text: &str, start: f64, end: f64) -> WhisperSegment {
+    return WhisperSegment {
+      id: 0,
+      text: String::from(text),
+      start,
+      end,
+      tokens: Vec::new(),
+      words: Vec::new(),
+      temperature: 0.0,
+      avg_logprob: -0.2,
+      no_speech_prob: 0.01,
+    };
+  }
+
+  /// Sets the words of a test segment.
+  pub(crate) fn with_words(mut self, words: Vec<WhisperWord>) -> Self {
+    self.words = words;
+    return self;
+  }
+
+  /// Sets the decoding statistics of a test segment.
+  pub(crate) fn with_probabilities(
+    mut self,
+    avg_logprob: f64,
+    no_speech_prob: f64,
+  ) -> Self {
+    self.avg_logprob = avg_logprob;
+    self.no_speech_prob = no_speech_prob;
+    return self;
+  }
+}
+
 /// Response from Whisper API when using `verbose_json` response format.
 ///
 /// Contains full metadata including segments, word-level timing, and
@@ -103,6 +333,44 @@ pub struct WhisperVerboseJsonResponse {
   pub language_probabilities: std::collections::HashMap<String, f64>,
 }
 
+/// Cuts segments where the stop phrase starts, which may be in the middle of
+/// a segment or span two of them.
+fn truncate_segments(segments: &mut Vec<WhisperSegment>, stop_phrase: &str) {
+  let text: String = segments
+    .iter()
+    .map(|segment| segment.text.as_str())
+    .collect();
+  let Some(start) = find_stop_phrase(&text, stop_phrase) else {
+    return;
+  };
+
+  let mut offset = 0;
+  let mut kept = 0;
+  for segment in segments.iter_mut() {
+    if offset + segment.text.len() <= start {
+      offset += segment.text.len();
+      kept += 1;
+      continue;
+    }
+
+    let cut = start - offset;
+    segment.text = trim_before_stop_phrase(&segment.text[..cut]).to_string();
+    let mut length = 0;
+    segment.words.retain(|word| {
+      length += word.word.len();
+      return length <= cut;
+    });
+    if let Some(word) = segment.words.last() {
+      segment.end = word.end;
+    }
+    if !segment.text.trim().is_empty() {
+      kept += 1;
+    }
+    break;
+  }
+  segments.truncate(kept);
+}
+
 /// Maps the internal OutputFormat to the Whisper API response format string.
 ///
 /// Whisper API accepts different format parameters that control the level of
@@ -116,11 +384,10 @@ pub struct WhisperVerboseJsonResponse {
 /// # Returns
 ///
 /// The Whisper API format string to use in the request ("json" or "verbose_json")
+#[cfg(feature = "network")]
 pub fn get_whisper_format(format: OutputFormat) -> String {
-  let whisper_format = match format {
-    OutputFormat::Text => String::from("json"),
-    OutputFormat::Json => String::from("json"),
-    OutputFormat::FullJson => String::from("verbose_json"),
-  };
-  return whisper_format;
+  if format.is_timed() {
+    return String::from("verbose_json");
+  }
+  return String::from("json");
 }
@@ -0,0 +1,184 @@
+use crate::output::format::OutputFormat;
+use crate::whisper::responses::{
+  WhisperJsonResponse, WhisperResponse, WhisperSegment, WhisperTextResponse,
+  WhisperVerboseJsonResponse, WhisperWord,
+};
+
+fn create_segment(
+  text: &str,
+  avg_logprob: f64,
+  no_speech_prob: f64,
+) -> WhisperSegment {
+  return WhisperSegment::sample(text, 0.0, 1.0)
+    .with_probabilities(avg_logprob, no_speech_prob);
+}
+
+fn create_verbose_response() -> WhisperResponse {
+  let segments = vec![
+    create_segment(" And so my fellow Americans", -0.2, 0.01),
+    create_segment(" ask not", -0.3, 0.7),
+    create_segment(" Thank you for watching.", -1.4, 0.9),
+  ];
+  return WhisperResponse::VerboseJson(WhisperVerboseJsonResponse {
+    task: String::from("transcribe"),
+    language: String::from("en"),
+    duration: 3.0,
+    text: segments
+      .iter()
+      .map(|segment| segment.text.as_str())
+      .collect(),
+    segments,
+    detected_language: String::from("en"),
+    detected_language_probability: 0.98,
+    language_probabilities: Default::default(),
+  });
+}
+
+#[test]
+fn test_drop_silent_segments_with_both_thresholds() {
+  let mut response = create_verbose_response();
+  let dropped = response.drop_silent_segments(0.6, Some(-1.0));
+
+  assert_eq!(dropped, 1);
+  assert_eq!(response.text(), " And so my fellow Americans ask not");
+  assert_eq!(response.segments().len(), 2);
+}
+
+#[test]
+fn test_drop_silent_segments_with_no_speech_threshold_only() {
+  let mut response = create_verbose_response();
+  let dropped = response.drop_silent_segments(0.6, None);
+
+  assert_eq!(dropped, 2);
+  assert_eq!(response.text(), " And so my fellow Americans");
+}
+
+#[test]
+fn test_drop_silent_segments_keeps_text_responses() {
+  let mut response = create_verbose_response().into_format(OutputFormat::Text);
+  assert_eq!(response.drop_silent_segments(0.0, None), 0);
+  assert!(matches!(response, WhisperResponse::Text(_)));
+}
+
+#[test]
+fn test_into_format() {
+  let response = create_verbose_response().into_format(OutputFormat::Json);
+  assert!(matches!(response, WhisperResponse::Json(_)));
+
+  let response = create_verbose_response().into_format(OutputFormat::FullJson);
+  assert!(matches!(response, WhisperResponse::VerboseJson(_)));
+}
+
+#[test]
+fn test_strip_annotations_only_from_text() {
+  let mut response = WhisperResponse::Text(WhisperTextResponse {
+    text: String::from(" [Music] Hello"),
+  });
+  response.strip_annotations();
+  assert_eq!(response.text(), " Hello");
+
+  let mut response = WhisperResponse::Json(WhisperJsonResponse {
+    text: String::from(" [Music] Hello"),
+  });
+  response.strip_annotations();
+  assert_eq!(response.text(), " [Music] Hello");
+}
+
+#[test]
+fn test_format_lrc_requires_verbose_response() {
+  let response = create_verbose_response();
+  assert!(
+    response
+      .format(OutputFormat::Lrc)
+      .unwrap()
+      .starts_with("[00:")
+  );
+
+  let response = create_verbose_response().into_format(OutputFormat::Text);
+  assert!(response.format(OutputFormat::Lrc).is_err());
+}
+
+#[test]
+fn test_format_words_requires_verbose_response() {
+  let response = create_verbose_response();
+  assert_eq!(response.format(OutputFormat::WordsJson).unwrap(), "[]");
+  assert_eq!(response.format(OutputFormat::WordsTsv).unwrap(), "");
+
+  let response = create_verbose_response().into_format(OutputFormat::Json);
+  assert!(response.format(OutputFormat::WordsJson).is_err());
+}
+
+fn create_word(word: &str, start: f64, end: f64) -> WhisperWord {
+  return WhisperWord {
+    word: String::from(word),
+    start,
+    end,
+    t_dtw: -1,
+    probability: 0.9,
+  };
+}
+
+fn create_dictation_response(segments: Vec<WhisperSegment>) -> WhisperResponse {
+  return WhisperResponse::VerboseJson(WhisperVerboseJsonResponse {
+    task: String::from("transcribe"),
+    language: String::from("en"),
+    duration: 2.0,
+    text: segments
+      .iter()
+      .map(|segment| segment.text.as_str())
+      .collect(),
+    segments,
+    detected_language: String::from("en"),
+    detected_language_probability: 0.98,
+    language_probabilities: Default::default(),
+  });
+}
+
+#[test]
+fn test_strip_stop_phrase_drops_segments_after_phrase() {
+  let mut response = create_dictation_response(vec![
+    create_segment(" Buy milk and eggs.", -0.2, 0.01),
+    create_segment(" Stop dictation.", -0.2, 0.01),
+    create_segment(" Thank you.", -0.2, 0.01),
+  ]);
+
+  assert!(response.strip_stop_phrase("stop dictation"));
+  assert_eq!(response.text(), " Buy milk and eggs.");
+  let segments = response.segments();
+  assert_eq!(segments.len(), 1);
+  assert_eq!(segments[0].text, " Buy milk and eggs.");
+}
+
+#[test]
+fn test_strip_stop_phrase_truncates_segment_and_words() {
+  let mut segment = create_segment(" And eggs, stop dictation", -0.2, 0.01);
+  segment.end = 1.2;
+  segment.words = vec![
+    create_word(" And", 0.0, 0.3),
+    create_word(" eggs,", 0.3, 0.6),
+    create_word(" stop", 0.6, 0.9),
+    create_word(" dictation", 0.9, 1.2),
+  ];
+  let mut response = create_dictation_response(vec![segment]);
+
+  assert!(response.strip_stop_phrase("stop dictation"));
+  assert_eq!(response.text(), " And eggs");
+  let segments = response.segments();
+  assert_eq!(segments.len(), 1);
+  assert_eq!(segments[0].text, " And eggs");
+  assert_eq!(segments[0].words.len(), 2);
+  assert_eq!(segments[0].end, 0.6);
+}
+
+#[test]
+fn test_strip_stop_phrase_reports_whether_phrase_was_heard() {
+  let mut response = WhisperResponse::Text(WhisperTextResponse {
+    text: String::from("Hello, stop dictation"),
+  });
+  assert!(response.strip_stop_phrase("stop dictation"));
+  assert_eq!(response.text(), "Hello");
+
+  assert!(!response.strip_stop_phrase("stop dictation"));
+  assert!(!response.strip_stop_phrase(""));
+  assert_eq!(response.text(), "Hello");
+}
@@ -1,6 +1,9 @@
 use std::fs;
 
+use tokio_util::sync::CancellationToken;
+
 use crate::config::*;
+use crate::output::format::OutputFormat;
 use crate::whisper::*;
 
 #[tokio::test]
@@ -17,6 +20,8 @@ async fn test_send_audio() {
     config.get_whisper_url(),
     sample_file_path.to_string(),
     OutputFormat::Text,
+    TranscriptionOptions::default(),
+    CancellationToken::new(),
   );
 
   let result = whisper.transcribe().await;
@@ -29,8 +34,8 @@ async fn test_send_audio() {
     },
     Err(error) => match error {
       WhisperError::InvalidURL(_)
-      | WhisperError::RequestFailed
-      | WhisperError::ResponseError
+      | WhisperError::RequestFailed(_)
+      | WhisperError::ResponseError(_)
       | WhisperError::DecodeError(_) => (),
       _ => panic!("Expected network-related error, got: {:?}", error),
     },
@@ -44,6 +49,8 @@ async fn test_send_audio_file_not_found() {
     config.get_whisper_url(),
     "nonexistent_file.wav".to_string(),
     OutputFormat::Text,
+    TranscriptionOptions::default(),
+    CancellationToken::new(),
   );
 
   let result = whisper.transcribe().await;
@@ -67,6 +74,8 @@ async fn test_send_audio_with_sample_file_invalid_url() {
     "invalid-url".to_string(),
     sample_file_path.to_string(),
     OutputFormat::Text,
+    TranscriptionOptions::default(),
+    CancellationToken::new(),
   );
 
   let result = whisper.transcribe().await;
@@ -76,3 +85,19 @@ async fn test_send_audio_with_sample_file_invalid_url() {
     _ => panic!("Expected InvalidURL error"),
   }
 }
+
+#[tokio::test]
+async fn test_send_audio_cancelled() {
+  let cancel = CancellationToken::new();
+  cancel.cancel();
+  let whisper = Whisper::new(
+    String::from("http://127.0.0.1:9"),
+    String::from("sample/jfk.wav"),
+    OutputFormat::Text,
+    TranscriptionOptions::default(),
+    cancel,
+  );
+
+  let result = whisper.transcribe().await;
+  assert!(matches!(result, Err(WhisperError::Cancelled)));
+}
@@ -0,0 +1,62 @@
+//! Transcription through the C ABI against a mock Whisper service.
+//!
+//! The C ABI loads its configuration from the environment, so this test
+//! lives in its own binary where changing the environment is safe.
+
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::path::Path;
+use std::ptr;
+
+use lumine::ffi::{lumine_string_free, lumine_transcribe_file};
+use lumine::testing::{FfmpegShim, MockResponse, MockWhisperServer};
+
+unsafe extern "C" fn collect_event(
+  event: *const c_char,
+  _detail: *const c_char,
+  user_data: *mut c_void,
+) {
+  let events = unsafe { &mut *(user_data as *mut Vec<String>) };
+  let event = unsafe { CStr::from_ptr(event) };
+  events.push(event.to_str().unwrap().to_string());
+}
+
+#[test]
+fn test_transcribe_file_through_c_abi() {
+  let runtime = tokio::runtime::Runtime::new().unwrap();
+  let server = runtime
+    .block_on(MockWhisperServer::start(MockResponse::json("And so my")))
+    .unwrap();
+  let _ffmpeg = FfmpegShim::failing().unwrap();
+  let home = std::env::temp_dir()
+    .join(format!("lumine-ffi-home-{}", std::process::id()));
+  // SAFETY: This test binary runs a single test, so no other thread reads
+  // the environment while it changes.
+  unsafe {
+    std::env::set_var("XDG_CONFIG_HOME", home.join("config"));
+    std::env::set_var("XDG_DATA_HOME", home.join("data"));
+    std::env::set_var("XDG_STATE_HOME", home.join("state"));
+    std::env::set_var("XDG_CACHE_HOME", home.join("cache"));
+    std::env::set_var("LUMINE_WHISPER_URL", server.url());
+  }
+  let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("sample/jfk.wav");
+  let path = CString::new(input.to_str().unwrap()).unwrap();
+  let mut events: Vec<String> = Vec::new();
+
+  let transcript = unsafe {
+    lumine_transcribe_file(
+      path.as_ptr(),
+      ptr::null(),
+      Some(collect_event),
+      &mut events as *mut Vec<String> as *mut c_void,
+    )
+  };
+
+  assert!(!transcript.is_null());
+  let text = unsafe { CStr::from_ptr(transcript) }.to_str().unwrap();
+  assert_eq!(text, "And so my");
+  unsafe { lumine_string_free(transcript) };
+  assert!(events.contains(&String::from("transcription_started")));
+  assert_eq!(events.last().map(String::as_str), Some("completed"));
+  assert_eq!(server.requests().len(), 1);
+  let _ = std::fs::remove_dir_all(&home);
+}
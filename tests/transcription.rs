@@ -0,0 +1,270 @@
+//! End-to-end transcription tests against a mock Whisper service.
+
+use std::path::{Path, PathBuf};
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use lumine::app::RuntimeError;
+use lumine::encryption::Encryptor;
+use lumine::output::format::OutputFormat;
+use lumine::state::State;
+use lumine::testing::{FfmpegShim, MockResponse, MockWhisperServer};
+use lumine::{App, Config};
+
+/// The ffmpeg shim replaces ffmpeg for the whole process, so tests using it
+/// run one at a time.
+static FFMPEG: Mutex<()> = Mutex::const_new(());
+
+async fn lock_ffmpeg() -> MutexGuard<'static, ()> {
+  return FFMPEG.lock().await;
+}
+
+fn create_app(url: String) -> App {
+  let mut config = Config::default();
+  config.whisper.url = Some(url);
+  return App::new(config, State::default(), None);
+}
+
+/// Writes a short stereo recording that needs conversion for Whisper.
+fn write_stereo_wav(name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!(
+    "lumine-{}-{}.wav",
+    name,
+    std::process::id()
+  ));
+  let spec = hound::WavSpec {
+    channels: 2,
+    sample_rate: 44100,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+  for sample in 0..44100 {
+    writer.write_sample((sample % 128) as i16).unwrap();
+    writer.write_sample((sample % 64) as i16).unwrap();
+  }
+  writer.finalize().unwrap();
+  return path;
+}
+
+fn path_str(path: &Path) -> &str {
+  return path.to_str().unwrap();
+}
+
+#[tokio::test]
+async fn test_transcribe_file_converts_and_transcribes() {
+  let _guard = lock_ffmpeg().await;
+  let ffmpeg = FfmpegShim::new().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("Hello world"))
+    .await
+    .unwrap();
+  let input = write_stereo_wav("convert");
+  let app = create_app(server.url());
+
+  let outcome = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await
+    .unwrap();
+
+  assert_eq!(outcome.text, "Hello world");
+  assert_eq!(outcome.source_path, path_str(&input));
+  assert!(outcome.converted_path.is_none());
+  assert!(
+    ffmpeg
+      .invocations()
+      .iter()
+      .any(|call| call.contains(path_str(&input)))
+  );
+  let requests = server.requests();
+  assert_eq!(requests.len(), 1);
+  assert!(requests[0].body_contains("response_format"));
+  assert!(input.exists());
+  let _ = std::fs::remove_file(&input);
+}
+
+#[tokio::test]
+async fn test_transcribe_file_passes_whisper_ready_audio_through() {
+  let _guard = lock_ffmpeg().await;
+  let ffmpeg = FfmpegShim::failing().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("And so my"))
+    .await
+    .unwrap();
+  let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("sample/jfk.wav");
+  let app = create_app(server.url());
+
+  let outcome = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await
+    .unwrap();
+
+  assert_eq!(outcome.text, "And so my");
+  assert!(ffmpeg.invocations().is_empty());
+  assert!(input.exists());
+}
+
+#[tokio::test]
+async fn test_transcribe_file_removes_decrypted_copy() {
+  let _guard = lock_ffmpeg().await;
+  let _ffmpeg = FfmpegShim::failing().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("And so my"))
+    .await
+    .unwrap();
+  let directory = std::env::temp_dir()
+    .join(format!("lumine-decrypted-copy-{}", std::process::id()));
+  let temp_directory = directory.join("temp");
+  std::fs::create_dir_all(&temp_directory).unwrap();
+  let encryptor = Encryptor::new(String::from("passphrase"));
+  let sample = Path::new(env!("CARGO_MANIFEST_DIR")).join("sample/jfk.wav");
+  let input = directory.join("jfk.wav.enc");
+  let data = encryptor.encrypt(&std::fs::read(sample).unwrap()).unwrap();
+  std::fs::write(&input, data).unwrap();
+  let mut config = Config::default();
+  config.whisper.url = Some(server.url());
+  config.general.remove_after_transcript = Some(false);
+  config.general.temp_directory =
+    Some(temp_directory.to_string_lossy().to_string());
+  let app = App::new(config, State::default(), Some(encryptor));
+
+  let outcome = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await
+    .unwrap();
+
+  assert_eq!(outcome.text, "And so my");
+  assert!(input.exists());
+  assert_eq!(std::fs::read_dir(&temp_directory).unwrap().count(), 0);
+  let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[tokio::test]
+async fn test_transcribe_file_reports_server_error() {
+  let _guard = lock_ffmpeg().await;
+  let _ffmpeg = FfmpegShim::new().unwrap();
+  let server =
+    MockWhisperServer::start(MockResponse::status(500, "overloaded"))
+      .await
+      .unwrap();
+  let input = write_stereo_wav("server-error");
+  let app = create_app(server.url());
+
+  let result = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await;
+
+  assert!(matches!(result, Err(RuntimeError::Transcription(_))));
+  assert_eq!(server.requests().len(), 1);
+  let _ = std::fs::remove_file(&input);
+}
+
+#[tokio::test]
+async fn test_transcribe_file_reports_invalid_response() {
+  let _guard = lock_ffmpeg().await;
+  let _ffmpeg = FfmpegShim::new().unwrap();
+  let server = MockWhisperServer::start(MockResponse::status(200, "not json"))
+    .await
+    .unwrap();
+  let input = write_stereo_wav("invalid-response");
+  let app = create_app(server.url());
+
+  let result = app
+    .transcribe_file(path_str(&input), OutputFormat::Json, None)
+    .await;
+
+  assert!(matches!(result, Err(RuntimeError::Transcription(_))));
+  let _ = std::fs::remove_file(&input);
+}
+
+#[tokio::test]
+async fn test_transcribe_file_reports_conversion_failure() {
+  let _guard = lock_ffmpeg().await;
+  let _ffmpeg = FfmpegShim::failing().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("unused"))
+    .await
+    .unwrap();
+  let input = write_stereo_wav("conversion-failure");
+  let app = create_app(server.url());
+
+  let result = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await;
+
+  assert!(matches!(result, Err(RuntimeError::AudioConversion(_))));
+  assert!(server.requests().is_empty());
+  let _ = std::fs::remove_file(&input);
+}
+
+#[tokio::test]
+async fn test_transcribe_file_keeps_converted_file_next_to_input() {
+  let _guard = lock_ffmpeg().await;
+  let ffmpeg = FfmpegShim::new().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("Hello world"))
+    .await
+    .unwrap();
+  let input = write_stereo_wav("kept-converted");
+  let temp_directory = std::env::temp_dir()
+    .join(format!("lumine-temp-directory-{}", std::process::id()));
+  let mut config = Config::default();
+  config.whisper.url = Some(server.url());
+  config.general.keep_converted = Some(true);
+  config.general.temp_directory =
+    Some(temp_directory.to_string_lossy().to_string());
+  let app = App::new(config, State::default(), None);
+
+  let outcome = app
+    .transcribe_file(path_str(&input), OutputFormat::Text, None)
+    .await
+    .unwrap();
+
+  let converted = input.with_file_name(format!(
+    "{}_whisper.wav",
+    input.file_stem().unwrap().to_string_lossy()
+  ));
+  assert_eq!(
+    outcome.converted_path.as_deref(),
+    Some(path_str(&converted))
+  );
+  assert!(
+    ffmpeg
+      .invocations()
+      .iter()
+      .all(|call| !call.contains(path_str(&temp_directory)))
+  );
+  let _ = std::fs::remove_file(&converted);
+  let _ = std::fs::remove_file(&input);
+}
+
+#[tokio::test]
+async fn test_two_pass_does_not_wait_for_stalled_draft() {
+  let _guard = lock_ffmpeg().await;
+  let _ffmpeg = FfmpegShim::failing().unwrap();
+  let server = MockWhisperServer::start(MockResponse::json("And so my"))
+    .await
+    .unwrap();
+  // Accepts connections but never answers, like a stalled draft service.
+  let draft = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let draft_url = format!("http://{}", draft.local_addr().unwrap());
+  let stalled = tokio::spawn(async move {
+    let mut connections = Vec::new();
+    while let Ok((stream, _)) = draft.accept().await {
+      connections.push(stream);
+    }
+  });
+
+  let mut config = Config::default();
+  config.whisper.url = Some(server.url());
+  config.whisper.draft_url = Some(draft_url);
+  config.whisper.two_pass = Some(true);
+  let app = App::new(config, State::default(), None);
+  let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("sample/jfk.wav");
+
+  let outcome = tokio::time::timeout(
+    std::time::Duration::from_secs(10),
+    app.transcribe_file(path_str(&input), OutputFormat::Text, None),
+  )
+  .await
+  .expect("final transcript waited for the draft")
+  .unwrap();
+
+  assert_eq!(outcome.text, "And so my");
+  stalled.abort();
+}
@@ -0,0 +1,87 @@
+//! Usage statistics recorded by workflows other than file transcription.
+//!
+//! The state file is found through `XDG_STATE_HOME`, which is set for the
+//! whole process, so this binary holds a single test.
+
+use std::path::PathBuf;
+
+use lumine::state::State;
+use lumine::testing::{FfmpegShim, MockResponse, MockWhisperServer};
+use lumine::{App, Config};
+
+const VERBOSE_JSON: &str = r#"{
+  "task": "transcribe",
+  "language": "en",
+  "duration": 1.0,
+  "text": "Hello",
+  "segments": [{
+    "id": 0,
+    "text": "Hello",
+    "start": 0.0,
+    "end": 1.0,
+    "tokens": [],
+    "words": [],
+    "temperature": 0.0,
+    "avg_logprob": -0.2,
+    "no_speech_prob": 0.01
+  }],
+  "detected_language": "en",
+  "detected_language_probability": 1.0,
+  "language_probabilities": {}
+}"#;
+
+/// Writes a one second stereo recording.
+fn write_stereo_wav(path: &PathBuf) {
+  let spec = hound::WavSpec {
+    channels: 2,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+  };
+  let mut writer = hound::WavWriter::create(path, spec).unwrap();
+  for sample in 0..16000 {
+    writer.write_sample((sample % 128) as i16).unwrap();
+    writer.write_sample((sample % 64) as i16).unwrap();
+  }
+  writer.finalize().unwrap();
+}
+
+#[tokio::test]
+async fn test_split_channels_records_usage_and_cost() {
+  let directory = std::env::temp_dir()
+    .join(format!("lumine-usage-test-{}", std::process::id()));
+  std::fs::create_dir_all(&directory).unwrap();
+  // SAFETY: This is the only test of this binary, and it sets the variable
+  // on the test thread before anything else reads the environment.
+  unsafe { std::env::set_var("XDG_STATE_HOME", directory.join("state")) };
+
+  let _ffmpeg = FfmpegShim::new().unwrap();
+  let server =
+    MockWhisperServer::start(MockResponse::status(200, VERBOSE_JSON))
+      .await
+      .unwrap();
+  let input = directory.join("call.wav");
+  write_stereo_wav(&input);
+
+  let mut config = Config::default();
+  config.whisper.url = Some(server.url());
+  config.usage.enabled = Some(true);
+  config.usage.cost_per_minute = Some(0.6);
+  let app = App::new(config, State::default(), None);
+
+  app
+    .transcribe_split_channels(input.to_str().unwrap())
+    .await
+    .unwrap();
+
+  let state = State::load(None).await.unwrap();
+  let usage = state.get_usage();
+  assert_eq!(usage.len(), 1);
+  assert_eq!(usage[0].runs, 2);
+  assert!((usage[0].audio_seconds - 2.0).abs() < 1e-6);
+  let costs = state.get_costs();
+  assert_eq!(costs.len(), 1);
+  assert!((costs[0].cost - 0.02).abs() < 1e-6);
+
+  let _ = std::fs::remove_dir_all(&directory);
+}